@@ -0,0 +1,36 @@
+use soroban_sdk::contracterror;
+
+/// Assessment contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AssessmentError {
+    // Initialization errors
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+
+    // Authorization errors
+    Unauthorized = 3,
+
+    // Assessment errors
+    AssessmentNotFound = 4,
+    InvalidAssessment = 5,
+    KeyNotRevealed = 6,
+    KeyAlreadyRevealed = 7,
+
+    // Submission errors
+    SubmissionClosed = 8,
+    AttemptLimitReached = 9,
+    SubmissionNotFound = 10,
+    AlreadyRevealed = 11,
+
+    // Reveal errors
+    RevealNotOpen = 12,
+    RevealClosed = 13,
+    CommitmentMismatch = 14,
+    InvalidAnswers = 15,
+
+    // Attempt errors
+    CooldownActive = 16,
+    RevealOutOfOrder = 17,
+}
@@ -0,0 +1,39 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+/// Assessment contract events
+pub struct AssessmentEvents;
+
+impl AssessmentEvents {
+    fn topic(env: &Env, name: &str) -> (Symbol, Symbol) {
+        (Symbol::new(env, "assessment"), Symbol::new(env, name))
+    }
+
+    /// Emit event when an assessment is created
+    pub fn emit_created(env: &Env, assessment_id: u64, course_id: &Symbol, module: u32, submission_deadline: u64) {
+        env.events().publish(
+            Self::topic(env, "created"),
+            (assessment_id, course_id.clone(), module, submission_deadline),
+        );
+    }
+
+    /// Emit event when a student commits their answers
+    pub fn emit_submitted(env: &Env, assessment_id: u64, student: &Address, attempt: u32) {
+        env.events().publish(
+            Self::topic(env, "submitted"),
+            (assessment_id, student.clone(), attempt),
+        );
+    }
+
+    /// Emit event when the instructor reveals the answer key
+    pub fn emit_key_revealed(env: &Env, assessment_id: u64) {
+        env.events().publish(Self::topic(env, "key_revealed"), assessment_id);
+    }
+
+    /// Emit event when a student's reveal is scored
+    pub fn emit_graded(env: &Env, assessment_id: u64, student: &Address, attempt: u32, score: u32, passed: bool) {
+        env.events().publish(
+            Self::topic(env, "graded"),
+            (assessment_id, student.clone(), attempt, score, passed),
+        );
+    }
+}
@@ -0,0 +1,342 @@
+#![no_std]
+
+mod types;
+mod errors;
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod tests;
+
+use soroban_sdk::{
+    contract, contractimpl, vec, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, Symbol, Vec,
+};
+
+use shared::{
+    access_control::AccessControl,
+    cross_contract::CrossContract,
+    roles::{Permission, RoleLevel},
+    time::Clock,
+};
+
+pub use types::{Assessment, AssessmentRules, ScoringPolicy, Submission};
+pub use errors::AssessmentError;
+use events::AssessmentEvents;
+use storage::AssessmentStorage;
+
+#[contract]
+pub struct AssessmentContract;
+
+#[contractimpl]
+impl AssessmentContract {
+    /// Initialize the contract with the Progress contract passes are reported to.
+    /// This contract must hold `UpdateProgress` there for passes to be recorded.
+    pub fn initialize(env: Env, admin: Address, progress: Address) -> Result<(), AssessmentError> {
+        if AssessmentStorage::get_admin(&env).is_some() {
+            return Err(AssessmentError::AlreadyInitialized);
+        }
+
+        AccessControl::initialize(&env, &admin).map_err(|_| AssessmentError::AlreadyInitialized)?;
+        AssessmentStorage::set_admin(&env, &admin);
+        AssessmentStorage::set_progress(&env, &progress);
+
+        Ok(())
+    }
+
+    /// Grant a role in this contract (requires GrantRole)
+    pub fn grant_role(env: Env, admin: Address, user: Address, level: RoleLevel) -> Result<(), AssessmentError> {
+        admin.require_auth();
+        AccessControl::grant_role(&env, &admin, &user, level).map_err(|_| AssessmentError::Unauthorized)
+    }
+
+    /// Revoke a user's role in this contract (requires RevokeRole)
+    pub fn revoke_role(env: Env, admin: Address, user: Address) -> Result<(), AssessmentError> {
+        admin.require_auth();
+        AccessControl::revoke_role(&env, &admin, &user).map_err(|_| AssessmentError::Unauthorized)
+    }
+
+    /// Create an assessment, committing to its answer key as
+    /// `sha256(answers || salt)` with one byte per question
+    pub fn create_assessment(
+        env: Env,
+        instructor: Address,
+        course_id: Symbol,
+        module: u32,
+        key_commitment: BytesN<32>,
+        rules: AssessmentRules,
+    ) -> Result<u64, AssessmentError> {
+        instructor.require_auth();
+        AccessControl::require_permission(&env, &instructor, &Permission::UpdateCourse)
+            .map_err(|_| AssessmentError::Unauthorized)?;
+
+        if module == 0
+            || rules.question_count == 0
+            || rules.pass_score > 100
+            || Clock::has_passed(&env, rules.submission_deadline)
+            || rules.reveal_deadline <= rules.submission_deadline
+            || rules.max_attempts == 0
+        {
+            return Err(AssessmentError::InvalidAssessment);
+        }
+
+        let assessment = Assessment {
+            id: AssessmentStorage::next_assessment_id(&env),
+            course_id: course_id.clone(),
+            module,
+            instructor,
+            key_commitment,
+            rules,
+            answer_key: Bytes::new(&env),
+        };
+        AssessmentStorage::set_assessment(&env, &assessment);
+        AssessmentEvents::emit_created(&env, assessment.id, &course_id, module, assessment.rules.submission_deadline);
+
+        Ok(assessment.id)
+    }
+
+    /// Commit to answers before the submission deadline, starting a new
+    /// attempt. The commitment is `sha256(student_xdr || answers || salt)`;
+    /// binding the student stops one student replaying another's commitment
+    /// and reveal. Returns the attempt number.
+    pub fn submit(
+        env: Env,
+        student: Address,
+        assessment_id: u64,
+        commitment: BytesN<32>,
+    ) -> Result<u32, AssessmentError> {
+        student.require_auth();
+
+        let assessment = Self::load_assessment(&env, assessment_id)?;
+        if Clock::has_passed(&env, assessment.rules.submission_deadline) {
+            return Err(AssessmentError::SubmissionClosed);
+        }
+
+        let attempts = AssessmentStorage::get_attempt_count(&env, assessment_id, &student);
+        if attempts >= assessment.rules.max_attempts {
+            return Err(AssessmentError::AttemptLimitReached);
+        }
+        if let Some(previous) = AssessmentStorage::get_submission(&env, assessment_id, &student, attempts) {
+            if Clock::now(&env) < previous.submitted_at.saturating_add(assessment.rules.cooldown) {
+                return Err(AssessmentError::CooldownActive);
+            }
+        }
+
+        let submission = Submission {
+            assessment_id,
+            student: student.clone(),
+            attempt: attempts + 1,
+            commitment,
+            submitted_at: Clock::now(&env),
+            revealed: false,
+            score: 0,
+            passed: false,
+        };
+        AssessmentStorage::set_submission(&env, &submission);
+        AssessmentStorage::set_attempt_count(&env, assessment_id, &student, submission.attempt);
+        AssessmentEvents::emit_submitted(&env, assessment_id, &student, submission.attempt);
+
+        Ok(submission.attempt)
+    }
+
+    /// Publish the answer key once submissions have closed
+    pub fn reveal_key(
+        env: Env,
+        instructor: Address,
+        assessment_id: u64,
+        answers: Bytes,
+        salt: Bytes,
+    ) -> Result<(), AssessmentError> {
+        instructor.require_auth();
+
+        let mut assessment = Self::load_assessment(&env, assessment_id)?;
+        if instructor != assessment.instructor {
+            return Err(AssessmentError::Unauthorized);
+        }
+        if !assessment.answer_key.is_empty() {
+            return Err(AssessmentError::KeyAlreadyRevealed);
+        }
+        Self::require_reveal_window(&env, &assessment)?;
+        if answers.len() != assessment.rules.question_count {
+            return Err(AssessmentError::InvalidAnswers);
+        }
+        if Self::key_commitment(&env, &answers, &salt) != assessment.key_commitment {
+            return Err(AssessmentError::CommitmentMismatch);
+        }
+
+        assessment.answer_key = answers;
+        AssessmentStorage::set_assessment(&env, &assessment);
+        AssessmentEvents::emit_key_revealed(&env, assessment_id);
+
+        Ok(())
+    }
+
+    /// Reveal one attempt's answers and score them against the published
+    /// key. Attempts are revealed in order, so a weak later attempt cannot
+    /// be skipped. When the student's result under the scoring policy is a
+    /// pass, it is reported to the Progress contract as a completed module.
+    /// Returns the attempt's score in percent.
+    pub fn reveal(
+        env: Env,
+        student: Address,
+        assessment_id: u64,
+        attempt: u32,
+        answers: Bytes,
+        salt: Bytes,
+    ) -> Result<u32, AssessmentError> {
+        student.require_auth();
+
+        let assessment = Self::load_assessment(&env, assessment_id)?;
+        let mut submission = AssessmentStorage::get_submission(&env, assessment_id, &student, attempt)
+            .ok_or(AssessmentError::SubmissionNotFound)?;
+        if submission.revealed {
+            return Err(AssessmentError::AlreadyRevealed);
+        }
+        if attempt > 1 {
+            let previous = AssessmentStorage::get_submission(&env, assessment_id, &student, attempt - 1)
+                .ok_or(AssessmentError::SubmissionNotFound)?;
+            if !previous.revealed {
+                return Err(AssessmentError::RevealOutOfOrder);
+            }
+        }
+        Self::require_reveal_window(&env, &assessment)?;
+        if assessment.answer_key.is_empty() {
+            return Err(AssessmentError::KeyNotRevealed);
+        }
+        if answers.len() != assessment.rules.question_count {
+            return Err(AssessmentError::InvalidAnswers);
+        }
+        if Self::submission_commitment(&env, &student, &answers, &salt) != submission.commitment {
+            return Err(AssessmentError::CommitmentMismatch);
+        }
+
+        let score = Self::score(&assessment, &answers);
+        submission.revealed = true;
+        submission.score = score;
+        submission.passed = score >= assessment.rules.pass_score;
+        AssessmentStorage::set_submission(&env, &submission);
+        AssessmentEvents::emit_graded(&env, assessment_id, &student, attempt, score, submission.passed);
+
+        let result = Self::result_of(&env, &assessment, &student).unwrap_or(0);
+        if submission.passed && result >= assessment.rules.pass_score {
+            Self::report_pass(&env, &assessment, &student)?;
+        }
+
+        Ok(score)
+    }
+
+    /// Get an assessment by id
+    pub fn get_assessment(env: Env, assessment_id: u64) -> Option<Assessment> {
+        AssessmentStorage::get_assessment(&env, assessment_id)
+    }
+
+    /// Get one of a student's attempts at an assessment
+    pub fn get_submission(env: Env, assessment_id: u64, student: Address, attempt: u32) -> Option<Submission> {
+        AssessmentStorage::get_submission(&env, assessment_id, &student, attempt)
+    }
+
+    /// Get every attempt a student has made, oldest first, for
+    /// academic-integrity review
+    pub fn get_attempts(env: Env, assessment_id: u64, student: Address) -> Vec<Submission> {
+        let mut attempts = Vec::new(&env);
+        for attempt in 1..=AssessmentStorage::get_attempt_count(&env, assessment_id, &student) {
+            if let Some(submission) = AssessmentStorage::get_submission(&env, assessment_id, &student, attempt) {
+                attempts.push_back(submission);
+            }
+        }
+        attempts
+    }
+
+    /// Get a student's score under the assessment's scoring policy, if any
+    /// attempt has been revealed
+    pub fn get_result(env: Env, assessment_id: u64, student: Address) -> Option<u32> {
+        let assessment = AssessmentStorage::get_assessment(&env, assessment_id)?;
+        Self::result_of(&env, &assessment, &student)
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        AssessmentStorage::get_admin(&env)
+    }
+}
+
+impl AssessmentContract {
+    fn load_assessment(env: &Env, assessment_id: u64) -> Result<Assessment, AssessmentError> {
+        AssessmentStorage::get_assessment(env, assessment_id).ok_or(AssessmentError::AssessmentNotFound)
+    }
+
+    /// Reveals are accepted after the submission deadline and up to the reveal deadline
+    fn require_reveal_window(env: &Env, assessment: &Assessment) -> Result<(), AssessmentError> {
+        if !Clock::has_passed(env, assessment.rules.submission_deadline) {
+            return Err(AssessmentError::RevealNotOpen);
+        }
+        if Clock::has_passed(env, assessment.rules.reveal_deadline) {
+            return Err(AssessmentError::RevealClosed);
+        }
+        Ok(())
+    }
+
+    /// `sha256(answers || salt)`
+    pub(crate) fn key_commitment(env: &Env, answers: &Bytes, salt: &Bytes) -> BytesN<32> {
+        let mut data = answers.clone();
+        data.append(salt);
+        env.crypto().sha256(&data).to_bytes()
+    }
+
+    /// `sha256(student_xdr || answers || salt)`
+    pub(crate) fn submission_commitment(env: &Env, student: &Address, answers: &Bytes, salt: &Bytes) -> BytesN<32> {
+        let mut data = student.clone().to_xdr(env);
+        data.append(answers);
+        data.append(salt);
+        env.crypto().sha256(&data).to_bytes()
+    }
+
+    /// Score of the attempt that counts under the scoring policy. An
+    /// unrevealed attempt scores 0, so withholding a reveal never helps.
+    fn result_of(env: &Env, assessment: &Assessment, student: &Address) -> Option<u32> {
+        let mut result = None;
+        let mut any_revealed = false;
+        for attempt in 1..=AssessmentStorage::get_attempt_count(env, assessment.id, student) {
+            let score = match AssessmentStorage::get_submission(env, assessment.id, student, attempt) {
+                Some(submission) if submission.revealed => {
+                    any_revealed = true;
+                    submission.score
+                }
+                _ => 0,
+            };
+            result = match (assessment.rules.policy, result) {
+                (ScoringPolicy::Best, Some(best)) if best >= score => Some(best),
+                _ => Some(score),
+            };
+        }
+        if any_revealed { result } else { None }
+    }
+
+    /// Percentage of answers matching the key
+    fn score(assessment: &Assessment, answers: &Bytes) -> u32 {
+        let correct = answers
+            .iter()
+            .zip(assessment.answer_key.iter())
+            .filter(|(given, expected)| given == expected)
+            .count() as u32;
+        correct * 100 / assessment.rules.question_count
+    }
+
+    /// Credit the assessment's module in Progress
+    fn report_pass(env: &Env, assessment: &Assessment, student: &Address) -> Result<(), AssessmentError> {
+        let progress = AssessmentStorage::get_progress(env).ok_or(AssessmentError::NotInitialized)?;
+        // Best effort: a module already marked complete must not block grading
+        CrossContract::try_invoke::<u32>(
+            env,
+            &progress,
+            &Symbol::new(env, "attest_module"),
+            vec![
+                env,
+                env.current_contract_address().into_val(env),
+                student.into_val(env),
+                assessment.course_id.into_val(env),
+                assessment.module.into_val(env),
+            ],
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use soroban_sdk::{Address, Env};
+use crate::types::{Assessment, DataKey, Submission};
+
+/// Storage utilities for the assessment contract
+pub struct AssessmentStorage;
+
+impl AssessmentStorage {
+    /// Get the contract admin
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Set the contract admin
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Get the Progress contract passes are reported to
+    pub fn get_progress(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Progress)
+    }
+
+    /// Set the Progress contract passes are reported to
+    pub fn set_progress(env: &Env, progress: &Address) {
+        env.storage().instance().set(&DataKey::Progress, progress);
+    }
+
+    /// Allocate the next assessment id
+    pub fn next_assessment_id(env: &Env) -> u64 {
+        let id: u64 = env.storage().instance().get(&DataKey::AssessmentCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::AssessmentCount, &id);
+        id
+    }
+
+    /// Get an assessment by id
+    pub fn get_assessment(env: &Env, assessment_id: u64) -> Option<Assessment> {
+        env.storage().persistent().get(&DataKey::Assessment(assessment_id))
+    }
+
+    /// Store an assessment
+    pub fn set_assessment(env: &Env, assessment: &Assessment) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Assessment(assessment.id), assessment);
+    }
+
+    /// Get one of a student's attempts at an assessment
+    pub fn get_submission(env: &Env, assessment_id: u64, student: &Address, attempt: u32) -> Option<Submission> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Submission(assessment_id, student.clone(), attempt))
+    }
+
+    /// Store an attempt
+    pub fn set_submission(env: &Env, submission: &Submission) {
+        env.storage().persistent().set(
+            &DataKey::Submission(
+                submission.assessment_id,
+                submission.student.clone(),
+                submission.attempt,
+            ),
+            submission,
+        );
+    }
+
+    /// Get how many attempts a student has made at an assessment
+    pub fn get_attempt_count(env: &Env, assessment_id: u64, student: &Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AttemptCount(assessment_id, student.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Set how many attempts a student has made at an assessment
+    pub fn set_attempt_count(env: &Env, assessment_id: u64, student: &Address, count: u32) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::AttemptCount(assessment_id, student.clone()), &count);
+    }
+}
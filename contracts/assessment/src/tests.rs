@@ -0,0 +1,261 @@
+use soroban_sdk::{
+    symbol_short, testutils::{Address as _, Ledger}, Address, Bytes, BytesN, Env, Symbol,
+};
+use shared::{roles::RoleLevel, time::{DAY, HOUR}};
+
+use progress::{Progress, ProgressClient};
+
+use crate::{AssessmentContract, AssessmentContractClient, AssessmentError, AssessmentRules, ScoringPolicy};
+
+fn setup_test_env() -> (Env, AssessmentContractClient<'static>, ProgressClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let instructor = Address::generate(&env);
+
+    let contract_id = env.register(AssessmentContract, ());
+    let client = AssessmentContractClient::new(&env, &contract_id);
+
+    let progress_id = env.register(Progress, ());
+    let progress = ProgressClient::new(&env, &progress_id);
+    progress.initialize(&admin);
+    progress.add_course(&course(), &3);
+    // The assessment contract reports passes as a grader
+    progress.grant_role(&admin, &contract_id, &RoleLevel::Moderator);
+
+    client.initialize(&admin, &progress_id);
+    client.grant_role(&admin, &instructor, &RoleLevel::Instructor);
+
+    (env, client, progress, instructor)
+}
+
+fn course() -> Symbol {
+    symbol_short!("RUST101")
+}
+
+fn bytes(env: &Env, data: &[u8]) -> Bytes {
+    Bytes::from_slice(env, data)
+}
+
+fn key(env: &Env) -> Bytes {
+    bytes(env, &[1, 2, 3, 4])
+}
+
+fn key_salt(env: &Env) -> Bytes {
+    bytes(env, b"instructor-salt")
+}
+
+fn rules(pass_score: u32, reveal_deadline: u64) -> AssessmentRules {
+    AssessmentRules {
+        question_count: 4,
+        pass_score,
+        submission_deadline: DAY,
+        reveal_deadline,
+        max_attempts: 1,
+        cooldown: 0,
+        policy: ScoringPolicy::Best,
+    }
+}
+
+fn create(env: &Env, client: &AssessmentContractClient, instructor: &Address) -> u64 {
+    create_with(env, client, instructor, rules(75, 2 * DAY))
+}
+
+fn create_with(
+    env: &Env,
+    client: &AssessmentContractClient,
+    instructor: &Address,
+    rules: AssessmentRules,
+) -> u64 {
+    let commitment = env.as_contract(&client.address, || {
+        AssessmentContract::key_commitment(env, &key(env), &key_salt(env))
+    });
+    client.create_assessment(instructor, &course(), &2, &commitment, &rules)
+}
+
+fn commit(
+    env: &Env,
+    client: &AssessmentContractClient,
+    student: &Address,
+    answers: &Bytes,
+    salt: &Bytes,
+) -> BytesN<32> {
+    env.as_contract(&client.address, || {
+        AssessmentContract::submission_commitment(env, student, answers, salt)
+    })
+}
+
+#[test]
+fn test_commit_reveal_scores_and_feeds_progress() {
+    let (env, client, progress, instructor) = setup_test_env();
+    let id = create(&env, &client, &instructor);
+
+    let passing = Address::generate(&env);
+    let failing = Address::generate(&env);
+    let good = bytes(&env, &[1, 2, 3, 9]);
+    let bad = bytes(&env, &[1, 9, 9, 9]);
+    let salt = bytes(&env, b"student-salt");
+    client.submit(&passing, &id, &commit(&env, &client, &passing, &good, &salt));
+    client.submit(&failing, &id, &commit(&env, &client, &failing, &bad, &salt));
+
+    // Nothing can be revealed while submissions are open
+    assert_eq!(
+        client.try_reveal_key(&instructor, &id, &key(&env), &key_salt(&env)),
+        Err(Ok(AssessmentError::RevealNotOpen))
+    );
+
+    env.ledger().set_timestamp(DAY + 1);
+    assert_eq!(
+        client.try_submit(&Address::generate(&env), &id, &commit(&env, &client, &passing, &good, &salt)),
+        Err(Ok(AssessmentError::SubmissionClosed))
+    );
+    assert_eq!(
+        client.try_reveal(&passing, &id, &1, &good, &salt),
+        Err(Ok(AssessmentError::KeyNotRevealed))
+    );
+
+    client.reveal_key(&instructor, &id, &key(&env), &key_salt(&env));
+    assert_eq!(client.reveal(&passing, &id, &1, &good, &salt), 75);
+    assert_eq!(client.reveal(&failing, &id, &1, &bad, &salt), 25);
+
+    assert!(client.get_submission(&id, &passing, &1).unwrap().passed);
+    assert!(!client.get_submission(&id, &failing, &1).unwrap().passed);
+
+    // Module 2 of 3 credited for the student who passed
+    assert_eq!(progress.get_progress(&passing, &course()).get(2), Some(true));
+    assert_eq!(progress.get_completion_percentage(&passing, &course()), 33);
+    assert!(progress.try_get_progress(&failing, &course()).is_err());
+}
+
+#[test]
+fn test_commitments_cannot_change_or_be_copied() {
+    let (env, client, _progress, instructor) = setup_test_env();
+    let id = create(&env, &client, &instructor);
+
+    let student = Address::generate(&env);
+    let copier = Address::generate(&env);
+    let answers = bytes(&env, &[1, 2, 3, 4]);
+    let salt = bytes(&env, b"student-salt");
+    let commitment = commit(&env, &client, &student, &answers, &salt);
+    client.submit(&student, &id, &commitment);
+    client.submit(&copier, &id, &commitment);
+    assert_eq!(
+        client.try_submit(&student, &id, &commitment),
+        Err(Ok(AssessmentError::AttemptLimitReached))
+    );
+
+    env.ledger().set_timestamp(DAY + 1);
+    assert_eq!(
+        client.try_reveal_key(&instructor, &id, &bytes(&env, &[4, 3, 2, 1]), &key_salt(&env)),
+        Err(Ok(AssessmentError::CommitmentMismatch))
+    );
+    client.reveal_key(&instructor, &id, &key(&env), &key_salt(&env));
+
+    // Changed answers do not match the commitment
+    assert_eq!(
+        client.try_reveal(&student, &id, &1, &bytes(&env, &[1, 2, 3, 5]), &salt),
+        Err(Ok(AssessmentError::CommitmentMismatch))
+    );
+    // A copied commitment is bound to the original student
+    assert_eq!(
+        client.try_reveal(&copier, &id, &1, &answers, &salt),
+        Err(Ok(AssessmentError::CommitmentMismatch))
+    );
+    assert_eq!(client.reveal(&student, &id, &1, &answers, &salt), 100);
+
+    env.ledger().set_timestamp(2 * DAY + 1);
+    assert_eq!(
+        client.try_reveal(&copier, &id, &1, &answers, &salt),
+        Err(Ok(AssessmentError::RevealClosed))
+    );
+}
+
+#[test]
+fn test_create_assessment_validation() {
+    let (env, client, _progress, instructor) = setup_test_env();
+    let commitment = BytesN::from_array(&env, &[0; 32]);
+    let student = Address::generate(&env);
+
+    assert_eq!(
+        client.try_create_assessment(&student, &course(), &1, &commitment, &rules(50, 2 * DAY)),
+        Err(Ok(AssessmentError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_create_assessment(&instructor, &course(), &1, &commitment, &rules(50, DAY)),
+        Err(Ok(AssessmentError::InvalidAssessment))
+    );
+    assert_eq!(
+        client.try_create_assessment(&instructor, &course(), &1, &commitment, &rules(101, 2 * DAY)),
+        Err(Ok(AssessmentError::InvalidAssessment))
+    );
+    let no_attempts = AssessmentRules { max_attempts: 0, ..rules(50, 2 * DAY) };
+    assert_eq!(
+        client.try_create_assessment(&instructor, &course(), &1, &commitment, &no_attempts),
+        Err(Ok(AssessmentError::InvalidAssessment))
+    );
+}
+
+fn retake(
+    env: &Env,
+    client: &AssessmentContractClient,
+    instructor: &Address,
+    policy: ScoringPolicy,
+) -> (u64, Address) {
+    let rules = AssessmentRules { max_attempts: 2, cooldown: 4 * HOUR, policy, ..rules(75, 2 * DAY) };
+    let id = create_with(env, client, instructor, rules);
+    let student = Address::generate(env);
+    let strong = bytes(env, &[1, 2, 3, 4]);
+    let weak = bytes(env, &[1, 9, 9, 9]);
+    let salt = bytes(env, b"student-salt");
+
+    assert_eq!(client.submit(&student, &id, &commit(env, client, &student, &strong, &salt)), 1);
+    assert_eq!(
+        client.try_submit(&student, &id, &commit(env, client, &student, &weak, &salt)),
+        Err(Ok(AssessmentError::CooldownActive))
+    );
+    env.ledger().set_timestamp(4 * HOUR);
+    assert_eq!(client.submit(&student, &id, &commit(env, client, &student, &weak, &salt)), 2);
+    assert_eq!(
+        client.try_submit(&student, &id, &commit(env, client, &student, &weak, &salt)),
+        Err(Ok(AssessmentError::AttemptLimitReached))
+    );
+
+    env.ledger().set_timestamp(DAY + 1);
+    client.reveal_key(instructor, &id, &key(env), &key_salt(env));
+    assert_eq!(
+        client.try_reveal(&student, &id, &2, &weak, &salt),
+        Err(Ok(AssessmentError::RevealOutOfOrder))
+    );
+    assert_eq!(client.reveal(&student, &id, &1, &strong, &salt), 100);
+    assert_eq!(client.reveal(&student, &id, &2, &weak, &salt), 25);
+    (id, student)
+}
+
+#[test]
+fn test_best_of_attempts() {
+    let (env, client, progress, instructor) = setup_test_env();
+    let (id, student) = retake(&env, &client, &instructor, ScoringPolicy::Best);
+
+    assert_eq!(client.get_result(&id, &student), Some(100));
+    assert!(progress.get_progress(&student, &course()).get(2).unwrap());
+
+    // Full history is kept for integrity review
+    let attempts = client.get_attempts(&id, &student);
+    assert_eq!(attempts.len(), 2);
+    assert_eq!(attempts.get(0).unwrap().score, 100);
+    assert_eq!(attempts.get(1).unwrap().score, 25);
+    assert_eq!(attempts.get(1).unwrap().submitted_at, 4 * HOUR);
+}
+
+#[test]
+fn test_latest_attempt_counts() {
+    let (env, client, progress, instructor) = setup_test_env();
+    let (id, student) = retake(&env, &client, &instructor, ScoringPolicy::Latest);
+
+    assert_eq!(client.get_result(&id, &student), Some(25));
+    assert_eq!(client.get_result(&id, &Address::generate(&env)), None);
+    // The passing first attempt never counted: the later one was still
+    // unrevealed, and so scored 0, when it was revealed
+    assert!(progress.try_get_progress(&student, &course()).is_err());
+}
@@ -0,0 +1,61 @@
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Symbol};
+
+/// Which attempt counts towards a student's result
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum ScoringPolicy {
+    Best,
+    Latest, // most recent revealed attempt
+}
+
+/// How an assessment is run and graded
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AssessmentRules {
+    pub question_count: u32,
+    pub pass_score: u32, // percent
+    pub submission_deadline: u64,
+    pub reveal_deadline: u64,
+    pub max_attempts: u32,
+    pub cooldown: u64, // seconds between attempts
+    pub policy: ScoringPolicy,
+}
+
+/// A quiz or exam whose answer key is committed up front
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Assessment {
+    pub id: u64,
+    pub course_id: Symbol,
+    pub module: u32, // Progress module credited on a pass
+    pub instructor: Address,
+    pub key_commitment: BytesN<32>, // sha256(answers || salt)
+    pub rules: AssessmentRules,
+    pub answer_key: Bytes, // empty until the instructor reveals it
+}
+
+/// One attempt's committed answers and, once revealed, its score
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Submission {
+    pub assessment_id: u64,
+    pub student: Address,
+    pub attempt: u32, // 1-based
+    pub commitment: BytesN<32>, // sha256(student || answers || salt)
+    pub submitted_at: u64,
+    pub revealed: bool,
+    pub score: u32, // percent
+    pub passed: bool,
+}
+
+/// Storage keys for the assessment contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Progress,
+    AssessmentCount,
+    Assessment(u64),
+    Submission(u64, Address, u32),
+    AttemptCount(u64, Address),
+}
@@ -0,0 +1,71 @@
+use shared::errors::{ConsentError, SignatureError};
+use soroban_sdk::contracterror;
+
+/// Attestations contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AttestationsError {
+    // Initialization errors
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+
+    // Authorization errors
+    Unauthorized = 3,
+
+    // Attestor errors
+    InvalidAttestor = 4,
+    AttestorNotFound = 5,
+    AttestorInactive = 6,
+    ClaimNotAllowed = 7,
+
+    // Attestation errors
+    InvalidExpiry = 8,
+    AttestationNotFound = 9,
+    AlreadyRevoked = 10,
+    TooManyClaims = 11,
+
+    // Requirement errors
+    InvalidRequirement = 12,
+
+    // Import errors
+    SigningKeyNotFound = 13,
+    NonceAlreadyUsed = 14,
+    SignatureExpired = 15,
+    InvalidCredential = 16,
+    AlreadyImported = 17,
+    ImportNotFound = 18,
+    TooManyImports = 19,
+
+    // Consent errors
+    InvalidGrant = 20,
+    GrantNotFound = 21,
+    AccessDenied = 22,
+
+    // Erasure errors
+    ErasureNotFound = 23,
+    ErasurePending = 24,
+    AlreadyErased = 25,
+    InvalidErasureState = 26,
+    NotComplianceOfficer = 27,
+}
+
+impl From<SignatureError> for AttestationsError {
+    fn from(error: SignatureError) -> Self {
+        match error {
+            SignatureError::SigningKeyNotFound => AttestationsError::SigningKeyNotFound,
+            SignatureError::NonceAlreadyUsed => AttestationsError::NonceAlreadyUsed,
+            SignatureError::SignatureExpired => AttestationsError::SignatureExpired,
+        }
+    }
+}
+
+impl From<ConsentError> for AttestationsError {
+    fn from(error: ConsentError) -> Self {
+        match error {
+            ConsentError::InvalidGrant => AttestationsError::InvalidGrant,
+            ConsentError::GrantNotFound => AttestationsError::GrantNotFound,
+            ConsentError::AccessDenied => AttestationsError::AccessDenied,
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+/// Attestations contract events
+pub struct AttestationsEvents;
+
+impl AttestationsEvents {
+    fn topic(env: &Env, name: &str) -> (Symbol, Symbol) {
+        (Symbol::new(env, "attestations"), Symbol::new(env, name))
+    }
+
+    /// Emit event when an attestor is approved or its claim types change
+    pub fn emit_attestor_approved(env: &Env, attestor: &Address, claims: &Vec<Symbol>) {
+        env.events().publish(
+            Self::topic(env, "attestor_approved"),
+            (attestor.clone(), claims.clone()),
+        );
+    }
+
+    /// Emit event when an attestor is suspended
+    pub fn emit_attestor_suspended(env: &Env, attestor: &Address) {
+        env.events().publish(Self::topic(env, "attestor_suspended"), attestor.clone());
+    }
+
+    /// Emit event when a claim is attested
+    pub fn emit_attested(env: &Env, subject: &Address, claim: &Symbol, attestor: &Address) {
+        env.events().publish(
+            Self::topic(env, "attested"),
+            (subject.clone(), claim.clone(), attestor.clone()),
+        );
+    }
+
+    /// Emit event when an attestation is revoked
+    pub fn emit_revoked(env: &Env, subject: &Address, claim: &Symbol, revoked_by: &Address) {
+        env.events().publish(
+            Self::topic(env, "revoked"),
+            (subject.clone(), claim.clone(), revoked_by.clone()),
+        );
+    }
+
+    /// Emit event when an attestor registers the key it signs imports with
+    pub fn emit_signing_key_set(env: &Env, attestor: &Address) {
+        env.events().publish(Self::topic(env, "signing_key_set"), attestor.clone());
+    }
+
+    /// Emit event when an external credential is imported
+    pub fn emit_credential_imported(env: &Env, import_id: u64, subject: &Address, course: &Symbol, attestor: &Address) {
+        env.events().publish(
+            Self::topic(env, "credential_imported"),
+            (import_id, subject.clone(), course.clone(), attestor.clone()),
+        );
+    }
+
+    /// Emit event when an imported credential is revoked
+    pub fn emit_import_revoked(env: &Env, import_id: u64, revoked_by: &Address) {
+        env.events().publish(
+            Self::topic(env, "import_revoked"),
+            (import_id, revoked_by.clone()),
+        );
+    }
+
+    /// Emit event when a holder grants a viewer access to their credential details
+    pub fn emit_access_granted(env: &Env, holder: &Address, viewer: &Address, expires_at: Option<u64>) {
+        env.events().publish(
+            Self::topic(env, "access_granted"),
+            (holder.clone(), viewer.clone(), expires_at),
+        );
+    }
+
+    /// Emit event when a holder revokes a viewer's access
+    pub fn emit_access_revoked(env: &Env, holder: &Address, viewer: &Address) {
+        env.events().publish(
+            Self::topic(env, "access_revoked"),
+            (holder.clone(), viewer.clone()),
+        );
+    }
+
+    /// Emit event when a holder asks for an import's details to be erased
+    pub fn emit_erasure_requested(env: &Env, request_id: u64, import_id: u64, subject: &Address) {
+        env.events().publish(
+            Self::topic(env, "erasure_requested"),
+            (request_id, import_id, subject.clone()),
+        );
+    }
+
+    /// Emit event when a compliance officer approves or rejects an erasure
+    pub fn emit_erasure_processed(env: &Env, request_id: u64, officer: &Address, approved: bool) {
+        env.events().publish(
+            Self::topic(env, "erasure_processed"),
+            (request_id, officer.clone(), approved),
+        );
+    }
+
+    /// Emit event when a credential level's required claims change
+    pub fn emit_requirement_set(env: &Env, level: &Symbol, claims: &Vec<Symbol>) {
+        env.events().publish(
+            Self::topic(env, "requirement_set"),
+            (level.clone(), claims.clone()),
+        );
+    }
+}
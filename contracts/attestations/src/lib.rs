@@ -0,0 +1,544 @@
+#![no_std]
+
+mod types;
+mod errors;
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod tests;
+
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, vec, xdr::ToXdr, Address, Bytes, BytesN, Env, String, Symbol, Vec,
+};
+
+use shared::{
+    access_control::AccessControl, consent::{Consent, ConsentGrant}, roles::{Permission, RoleLevel},
+    signature::SignatureVerifier, time::Clock,
+};
+
+pub use types::{
+    Attestation, Attestor, ErasureRequest, ErasureStatus, ExternalCredential, ImportStatus, ImportedCredential,
+};
+pub use errors::AttestationsError;
+use events::AttestationsEvents;
+use storage::AttestationsStorage;
+
+/// Upper bound on claim types per attestor and per requirement
+const MAX_CLAIM_TYPES: u32 = 10;
+
+/// Upper bound on distinct claims recorded about one subject
+const MAX_SUBJECT_CLAIMS: u32 = 20;
+
+/// Upper bound on imported credentials per subject
+const MAX_SUBJECT_IMPORTS: u32 = 50;
+
+/// Claim type an attestor must be approved for to sign credential imports
+const IMPORT_CLAIM: Symbol = symbol_short!("import");
+
+/// The payload an attestor vouches for; `verify_once` appends the nonce and
+/// deadline before checking the signature. Binding the contract and subject
+/// stops a signature being replayed elsewhere.
+pub fn import_payload(env: &Env, subject: &Address, credential: &ExternalCredential) -> Bytes {
+    (env.current_contract_address(), subject.clone(), credential.clone()).to_xdr(env)
+}
+
+/// The hash an erased import keeps of its original credential
+pub fn tombstone(env: &Env, credential: &ExternalCredential) -> BytesN<32> {
+    env.crypto().sha256(&credential.clone().to_xdr(env)).to_bytes()
+}
+
+#[contract]
+pub struct AttestationsContract;
+
+#[contractimpl]
+impl AttestationsContract {
+    /// Initialize the contract
+    pub fn initialize(env: Env, admin: Address) -> Result<(), AttestationsError> {
+        if AttestationsStorage::get_admin(&env).is_some() {
+            return Err(AttestationsError::AlreadyInitialized);
+        }
+
+        AccessControl::initialize(&env, &admin).map_err(|_| AttestationsError::AlreadyInitialized)?;
+        AttestationsStorage::set_admin(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Approve an attestor for the given claim types (admin only).
+    /// Approving an existing attestor replaces its claim types and
+    /// reactivates it.
+    pub fn approve_attestor(
+        env: Env,
+        admin: Address,
+        attestor: Address,
+        name: String,
+        claims: Vec<Symbol>,
+    ) -> Result<(), AttestationsError> {
+        Self::require_admin(&env, &admin)?;
+        if name.is_empty() || !Self::is_claim_set(&claims) {
+            return Err(AttestationsError::InvalidAttestor);
+        }
+
+        let approved_at = AttestationsStorage::get_attestor(&env, &attestor)
+            .map(|existing| existing.approved_at)
+            .unwrap_or(Clock::now(&env));
+        let record = Attestor { address: attestor.clone(), name, claims: claims.clone(), active: true, approved_at };
+        AttestationsStorage::set_attestor(&env, &record);
+        AttestationsEvents::emit_attestor_approved(&env, &attestor, &claims);
+
+        Ok(())
+    }
+
+    /// Suspend an attestor (admin only). Its attestations stop counting
+    /// until it is approved again.
+    pub fn suspend_attestor(env: Env, admin: Address, attestor: Address) -> Result<(), AttestationsError> {
+        Self::require_admin(&env, &admin)?;
+        let mut record = AttestationsStorage::get_attestor(&env, &attestor).ok_or(AttestationsError::AttestorNotFound)?;
+        if !record.active {
+            return Err(AttestationsError::AttestorInactive);
+        }
+
+        record.active = false;
+        AttestationsStorage::set_attestor(&env, &record);
+        AttestationsEvents::emit_attestor_suspended(&env, &attestor);
+
+        Ok(())
+    }
+
+    /// Attest a claim about a subject, replacing any earlier attestation of
+    /// the same claim. The evidence itself stays with the attestor; only its
+    /// hash is recorded.
+    pub fn attest(
+        env: Env,
+        attestor: Address,
+        subject: Address,
+        claim: Symbol,
+        evidence_hash: BytesN<32>,
+        expires_at: Option<u64>,
+    ) -> Result<(), AttestationsError> {
+        attestor.require_auth();
+        let record = AttestationsStorage::get_attestor(&env, &attestor).ok_or(AttestationsError::AttestorNotFound)?;
+        if !record.active {
+            return Err(AttestationsError::AttestorInactive);
+        }
+        if !record.claims.contains(&claim) {
+            return Err(AttestationsError::ClaimNotAllowed);
+        }
+        let now = Clock::now(&env);
+        if expires_at.is_some_and(|expires_at| expires_at <= now) {
+            return Err(AttestationsError::InvalidExpiry);
+        }
+
+        let mut claims = AttestationsStorage::get_subject_claims(&env, &subject);
+        if !claims.contains(&claim) {
+            if claims.len() >= MAX_SUBJECT_CLAIMS {
+                return Err(AttestationsError::TooManyClaims);
+            }
+            claims.push_back(claim.clone());
+            AttestationsStorage::set_subject_claims(&env, &subject, &claims);
+        }
+
+        let attestation = Attestation {
+            subject: subject.clone(),
+            claim: claim.clone(),
+            attestor: attestor.clone(),
+            evidence_hash,
+            issued_at: now,
+            expires_at,
+            revoked: false,
+        };
+        AttestationsStorage::set_attestation(&env, &attestation);
+        AttestationsEvents::emit_attested(&env, &subject, &claim, &attestor);
+
+        Ok(())
+    }
+
+    /// Revoke an attestation (the attestor that made it, or an admin)
+    pub fn revoke(env: Env, caller: Address, subject: Address, claim: Symbol) -> Result<(), AttestationsError> {
+        caller.require_auth();
+        let mut attestation = AttestationsStorage::get_attestation(&env, &subject, &claim)
+            .ok_or(AttestationsError::AttestationNotFound)?;
+        if attestation.attestor != caller && !AccessControl::is_admin(&env, &caller) {
+            return Err(AttestationsError::Unauthorized);
+        }
+        if attestation.revoked {
+            return Err(AttestationsError::AlreadyRevoked);
+        }
+
+        attestation.revoked = true;
+        AttestationsStorage::set_attestation(&env, &attestation);
+        AttestationsEvents::emit_revoked(&env, &subject, &claim, &caller);
+
+        Ok(())
+    }
+
+    /// Set the claims a credential level requires, e.g. `degree` requiring
+    /// `kyc` and `proctored` (admin only). An empty list removes the
+    /// requirement.
+    pub fn set_requirement(env: Env, admin: Address, level: Symbol, claims: Vec<Symbol>) -> Result<(), AttestationsError> {
+        Self::require_admin(&env, &admin)?;
+        if claims.is_empty() {
+            AttestationsStorage::remove_requirement(&env, &level);
+        } else if Self::is_claim_set(&claims) {
+            AttestationsStorage::set_requirement(&env, &level, &claims);
+        } else {
+            return Err(AttestationsError::InvalidRequirement);
+        }
+        AttestationsEvents::emit_requirement_set(&env, &level, &claims);
+
+        Ok(())
+    }
+
+    /// Register the ed25519 key an attestor signs credential imports with.
+    /// The attestor must be approved for the `import` claim.
+    pub fn set_signing_key(env: Env, attestor: Address, public_key: BytesN<32>) -> Result<(), AttestationsError> {
+        Self::load_importer(&env, &attestor)?;
+        SignatureVerifier::set_signing_key(&env, &attestor, &public_key);
+        AttestationsEvents::emit_signing_key_set(&env, &attestor);
+
+        Ok(())
+    }
+
+    /// Import an externally earned credential using an attestor's signature
+    /// over `import_payload`. The learner submits it; each nonce can be used
+    /// once and the signature lapses at `deadline`. Returns the import id.
+    pub fn import_credential(
+        env: Env,
+        subject: Address,
+        attestor: Address,
+        credential: ExternalCredential,
+        nonce: u64,
+        deadline: u64,
+        signature: BytesN<64>,
+    ) -> Result<u64, AttestationsError> {
+        subject.require_auth();
+        Self::load_importer(&env, &attestor)?;
+        let now = Clock::now(&env);
+        if credential.issuer.is_empty() || credential.completed_at > now {
+            return Err(AttestationsError::InvalidCredential);
+        }
+        if let Some(existing) = AttestationsStorage::get_imported_course(&env, &subject, &credential.course)
+            .and_then(|import_id| AttestationsStorage::get_import(&env, import_id))
+        {
+            if !existing.revoked {
+                return Err(AttestationsError::AlreadyImported);
+            }
+        }
+        let mut imports = AttestationsStorage::get_subject_imports(&env, &subject);
+        if imports.len() >= MAX_SUBJECT_IMPORTS {
+            return Err(AttestationsError::TooManyImports);
+        }
+
+        let payload = import_payload(&env, &subject, &credential);
+        SignatureVerifier::verify_once(&env, &attestor, &payload, &signature, nonce, deadline)?;
+
+        let import = ImportedCredential {
+            id: AttestationsStorage::next_import_id(&env),
+            subject: subject.clone(),
+            attestor: attestor.clone(),
+            credential,
+            imported_at: now,
+            revoked: false,
+            erased: false,
+        };
+        AttestationsStorage::set_import(&env, &import);
+        AttestationsStorage::set_imported_course(&env, &subject, &import.credential.course, import.id);
+        imports.push_back(import.id);
+        AttestationsStorage::set_subject_imports(&env, &subject, &imports);
+        AttestationsEvents::emit_credential_imported(&env, import.id, &subject, &import.credential.course, &attestor);
+
+        Ok(import.id)
+    }
+
+    /// Revoke an imported credential (the attestor that signed it, or an
+    /// admin), e.g. when the original is found to be forged
+    pub fn revoke_import(env: Env, caller: Address, import_id: u64) -> Result<(), AttestationsError> {
+        caller.require_auth();
+        let mut import = AttestationsStorage::get_import(&env, import_id).ok_or(AttestationsError::ImportNotFound)?;
+        if import.attestor != caller && !AccessControl::is_admin(&env, &caller) {
+            return Err(AttestationsError::Unauthorized);
+        }
+        if import.revoked {
+            return Err(AttestationsError::AlreadyRevoked);
+        }
+
+        import.revoked = true;
+        AttestationsStorage::set_import(&env, &import);
+        AttestationsEvents::emit_import_revoked(&env, import_id, &caller);
+
+        Ok(())
+    }
+
+    /// Ask for the personal details of one of the holder's imported
+    /// credentials to be erased. A compliance officer approves or rejects
+    /// the request. Returns the request id.
+    pub fn request_erasure(env: Env, subject: Address, import_id: u64) -> Result<u64, AttestationsError> {
+        subject.require_auth();
+        let import = AttestationsStorage::get_import(&env, import_id).ok_or(AttestationsError::ImportNotFound)?;
+        if import.subject != subject {
+            return Err(AttestationsError::Unauthorized);
+        }
+        if import.erased {
+            return Err(AttestationsError::AlreadyErased);
+        }
+        if AttestationsStorage::get_pending_erasure(&env, import_id).is_some() {
+            return Err(AttestationsError::ErasurePending);
+        }
+
+        let request = ErasureRequest {
+            id: AttestationsStorage::next_erasure_id(&env),
+            import_id,
+            subject: subject.clone(),
+            requested_at: Clock::now(&env),
+            status: ErasureStatus::Pending,
+            processed_by: None,
+            processed_at: None,
+        };
+        AttestationsStorage::set_erasure(&env, &request);
+        AttestationsStorage::set_pending_erasure(&env, import_id, request.id);
+        AttestationsEvents::emit_erasure_requested(&env, request.id, import_id, &subject);
+
+        Ok(request.id)
+    }
+
+    /// Appoint a compliance officer who may process erasure requests
+    /// (admin only). Admins do not hold ProcessErasure themselves.
+    pub fn grant_compliance_officer(env: Env, admin: Address, officer: Address) -> Result<(), AttestationsError> {
+        Self::require_admin(&env, &admin)?;
+        let permissions = vec![&env, Permission::ProcessErasure];
+        AccessControl::grant_custom_role(&env, &admin, &officer, RoleLevel::Moderator, permissions)
+            .map_err(|_| AttestationsError::Unauthorized)
+    }
+
+    /// Remove a compliance officer's role (admin only)
+    pub fn revoke_compliance_officer(env: Env, admin: Address, officer: Address) -> Result<(), AttestationsError> {
+        Self::require_admin(&env, &admin)?;
+        if !AccessControl::has_permission(&env, &officer, &Permission::ProcessErasure) {
+            return Err(AttestationsError::NotComplianceOfficer);
+        }
+        AccessControl::revoke_role(&env, &admin, &officer).map_err(|_| AttestationsError::Unauthorized)
+    }
+
+    /// Approve or reject an erasure request (requires ProcessErasure). On
+    /// approval the issuer and completion date are cleared and the evidence
+    /// hash is replaced by a tombstone: the SHA-256 of the original
+    /// credential's XDR. The course and validity are kept, so prerequisite
+    /// checks are unaffected.
+    pub fn process_erasure(
+        env: Env,
+        officer: Address,
+        request_id: u64,
+        approve: bool,
+    ) -> Result<(), AttestationsError> {
+        officer.require_auth();
+        AccessControl::require_permission(&env, &officer, &Permission::ProcessErasure)
+            .map_err(|_| AttestationsError::Unauthorized)?;
+        let mut request = AttestationsStorage::get_erasure(&env, request_id).ok_or(AttestationsError::ErasureNotFound)?;
+        if request.status != ErasureStatus::Pending {
+            return Err(AttestationsError::InvalidErasureState);
+        }
+
+        if approve {
+            let mut import = AttestationsStorage::get_import(&env, request.import_id)
+                .ok_or(AttestationsError::ImportNotFound)?;
+            import.credential.evidence_hash = tombstone(&env, &import.credential);
+            import.credential.issuer = String::from_str(&env, "");
+            import.credential.completed_at = 0;
+            import.erased = true;
+            AttestationsStorage::set_import(&env, &import);
+        }
+
+        request.status = if approve { ErasureStatus::Completed } else { ErasureStatus::Rejected };
+        request.processed_by = Some(officer.clone());
+        request.processed_at = Some(Clock::now(&env));
+        AttestationsStorage::set_erasure(&env, &request);
+        AttestationsStorage::remove_pending_erasure(&env, request.import_id);
+        AttestationsEvents::emit_erasure_processed(&env, request_id, &officer, approve);
+
+        Ok(())
+    }
+
+    /// Let a viewer see the details of the holder's imported credentials,
+    /// optionally until `expires_at`. Replaces any earlier grant.
+    pub fn grant_access(
+        env: Env,
+        holder: Address,
+        viewer: Address,
+        expires_at: Option<u64>,
+    ) -> Result<(), AttestationsError> {
+        Consent::grant(&env, &holder, &viewer, expires_at)?;
+        AttestationsEvents::emit_access_granted(&env, &holder, &viewer, expires_at);
+        Ok(())
+    }
+
+    /// Withdraw a viewer's access to the holder's credential details
+    pub fn revoke_access(env: Env, holder: Address, viewer: Address) -> Result<(), AttestationsError> {
+        Consent::revoke(&env, &holder, &viewer)?;
+        AttestationsEvents::emit_access_revoked(&env, &holder, &viewer);
+        Ok(())
+    }
+
+    /// Get an attestor
+    pub fn get_attestor(env: Env, attestor: Address) -> Option<Attestor> {
+        AttestationsStorage::get_attestor(&env, &attestor)
+    }
+
+    /// Get the latest attestation of a claim about a subject, valid or not
+    pub fn get_attestation(env: Env, subject: Address, claim: Symbol) -> Option<Attestation> {
+        AttestationsStorage::get_attestation(&env, &subject, &claim)
+    }
+
+    /// Get every claim ever attested about a subject
+    pub fn get_subject_claims(env: Env, subject: Address) -> Vec<Symbol> {
+        AttestationsStorage::get_subject_claims(&env, &subject)
+    }
+
+    /// Whether a subject holds a claim that is unrevoked, unexpired and made
+    /// by an attestor that is still active
+    pub fn has_valid(env: Env, subject: Address, claim: Symbol) -> bool {
+        Self::is_valid(&env, &subject, &claim)
+    }
+
+    /// Get the claims a credential level requires
+    pub fn get_requirement(env: Env, level: Symbol) -> Vec<Symbol> {
+        AttestationsStorage::get_requirement(&env, &level)
+    }
+
+    /// Get the required claims a subject does not validly hold for a
+    /// credential level
+    pub fn get_missing(env: Env, subject: Address, level: Symbol) -> Vec<Symbol> {
+        let mut missing = Vec::new(&env);
+        for claim in AttestationsStorage::get_requirement(&env, &level).iter() {
+            if !Self::is_valid(&env, &subject, &claim) {
+                missing.push_back(claim);
+            }
+        }
+        missing
+    }
+
+    /// Whether a subject may receive a credential of the given level. Levels
+    /// without a requirement are always met.
+    pub fn meets_requirement(env: Env, subject: Address, level: Symbol) -> bool {
+        Self::get_missing(env, subject, level).is_empty()
+    }
+
+    /// Get the bytes an attestor must sign to import a credential
+    pub fn get_import_payload(
+        env: Env,
+        subject: Address,
+        credential: ExternalCredential,
+        nonce: u64,
+        deadline: u64,
+    ) -> Bytes {
+        SignatureVerifier::signed_message(&env, &import_payload(&env, &subject, &credential), nonce, deadline)
+    }
+
+    /// Get the details of an imported credential. Only its holder and
+    /// viewers the holder has granted access may read them.
+    pub fn get_imported_credential(
+        env: Env,
+        viewer: Address,
+        import_id: u64,
+    ) -> Result<ImportedCredential, AttestationsError> {
+        let import = AttestationsStorage::get_import(&env, import_id).ok_or(AttestationsError::ImportNotFound)?;
+        Consent::require(&env, &import.subject, &viewer)?;
+        Ok(import)
+    }
+
+    /// Get whether an imported credential is valid, without its details
+    pub fn get_import_status(env: Env, import_id: u64) -> Option<ImportStatus> {
+        AttestationsStorage::get_import(&env, import_id).map(|import| ImportStatus {
+            valid: !import.revoked && Self::is_active_attestor(&env, &import.attestor),
+            subject: import.subject,
+            course: import.credential.course,
+        })
+    }
+
+    /// Whether a credential presented off-chain is the original of an erased
+    /// import
+    pub fn verify_tombstone(env: Env, import_id: u64, credential: ExternalCredential) -> bool {
+        AttestationsStorage::get_import(&env, import_id)
+            .is_some_and(|import| import.erased && import.credential.evidence_hash == tombstone(&env, &credential))
+    }
+
+    /// Get an erasure request
+    pub fn get_erasure_request(env: Env, request_id: u64) -> Option<ErasureRequest> {
+        AttestationsStorage::get_erasure(&env, request_id)
+    }
+
+    /// Get how many erasure requests have been made; request ids run from 1
+    pub fn get_erasure_count(env: Env) -> u64 {
+        AttestationsStorage::get_erasure_count(&env)
+    }
+
+    /// Get the access a holder has granted a viewer, expired or not
+    pub fn get_access_grant(env: Env, holder: Address, viewer: Address) -> Option<ConsentGrant> {
+        Consent::get_grant(&env, &holder, &viewer)
+    }
+
+    /// Get the ids of every credential a subject has imported
+    pub fn get_imports(env: Env, subject: Address) -> Vec<u64> {
+        AttestationsStorage::get_subject_imports(&env, &subject)
+    }
+
+    /// Whether a subject has an imported credential standing in for a
+    /// course, for prerequisite checks. Like attestations, imports stop
+    /// counting when revoked or when their attestor is suspended.
+    pub fn has_imported(env: Env, subject: Address, course: Symbol) -> bool {
+        AttestationsStorage::get_imported_course(&env, &subject, &course)
+            .and_then(|import_id| AttestationsStorage::get_import(&env, import_id))
+            .is_some_and(|import| !import.revoked && Self::is_active_attestor(&env, &import.attestor))
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        AttestationsStorage::get_admin(&env)
+    }
+}
+
+impl AttestationsContract {
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), AttestationsError> {
+        caller.require_auth();
+        if !AccessControl::is_admin(env, caller) {
+            return Err(AttestationsError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// A non-empty, bounded list of distinct claim types
+    fn is_claim_set(claims: &Vec<Symbol>) -> bool {
+        if claims.is_empty() || claims.len() > MAX_CLAIM_TYPES {
+            return false;
+        }
+        claims
+            .iter()
+            .enumerate()
+            .all(|(index, claim)| claims.first_index_of(&claim) == Some(index as u32))
+    }
+
+    fn load_importer(env: &Env, attestor: &Address) -> Result<Attestor, AttestationsError> {
+        let record = AttestationsStorage::get_attestor(env, attestor).ok_or(AttestationsError::AttestorNotFound)?;
+        if !record.active {
+            return Err(AttestationsError::AttestorInactive);
+        }
+        if !record.claims.contains(IMPORT_CLAIM) {
+            return Err(AttestationsError::ClaimNotAllowed);
+        }
+        Ok(record)
+    }
+
+    fn is_active_attestor(env: &Env, attestor: &Address) -> bool {
+        AttestationsStorage::get_attestor(env, attestor).is_some_and(|attestor| attestor.active)
+    }
+
+    fn is_valid(env: &Env, subject: &Address, claim: &Symbol) -> bool {
+        let Some(attestation) = AttestationsStorage::get_attestation(env, subject, claim) else {
+            return false;
+        };
+        let now = Clock::now(env);
+        !attestation.revoked
+            && attestation.expires_at.is_none_or(|expires_at| now < expires_at)
+            && Self::is_active_attestor(env, &attestation.attestor)
+    }
+}
@@ -0,0 +1,164 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+use crate::types::{Attestation, Attestor, DataKey, ErasureRequest, ImportedCredential};
+
+/// Storage utilities for the attestations contract
+pub struct AttestationsStorage;
+
+impl AttestationsStorage {
+    /// Get the contract admin
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Set the contract admin
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Get an attestor
+    pub fn get_attestor(env: &Env, attestor: &Address) -> Option<Attestor> {
+        env.storage().persistent().get(&DataKey::Attestor(attestor.clone()))
+    }
+
+    /// Store an attestor
+    pub fn set_attestor(env: &Env, attestor: &Attestor) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Attestor(attestor.address.clone()), attestor);
+    }
+
+    /// Get the attestation of a claim about a subject
+    pub fn get_attestation(env: &Env, subject: &Address, claim: &Symbol) -> Option<Attestation> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Attestation(subject.clone(), claim.clone()))
+    }
+
+    /// Store an attestation
+    pub fn set_attestation(env: &Env, attestation: &Attestation) {
+        env.storage().persistent().set(
+            &DataKey::Attestation(attestation.subject.clone(), attestation.claim.clone()),
+            attestation,
+        );
+    }
+
+    /// Get every claim attested about a subject
+    pub fn get_subject_claims(env: &Env, subject: &Address) -> Vec<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SubjectClaims(subject.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Set every claim attested about a subject
+    pub fn set_subject_claims(env: &Env, subject: &Address, claims: &Vec<Symbol>) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::SubjectClaims(subject.clone()), claims);
+    }
+
+    /// Get the claims a credential level requires
+    pub fn get_requirement(env: &Env, level: &Symbol) -> Vec<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Requirement(level.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Set the claims a credential level requires
+    pub fn set_requirement(env: &Env, level: &Symbol, claims: &Vec<Symbol>) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Requirement(level.clone()), claims);
+    }
+
+    /// Remove a credential level's requirement
+    pub fn remove_requirement(env: &Env, level: &Symbol) {
+        env.storage().persistent().remove(&DataKey::Requirement(level.clone()));
+    }
+
+    /// Allocate the next import id
+    pub fn next_import_id(env: &Env) -> u64 {
+        let id: u64 = env.storage().instance().get(&DataKey::ImportCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::ImportCount, &id);
+        id
+    }
+
+    /// Get an imported credential
+    pub fn get_import(env: &Env, import_id: u64) -> Option<ImportedCredential> {
+        env.storage().persistent().get(&DataKey::Import(import_id))
+    }
+
+    /// Store an imported credential
+    pub fn set_import(env: &Env, import: &ImportedCredential) {
+        env.storage().persistent().set(&DataKey::Import(import.id), import);
+    }
+
+    /// Get the ids of a subject's imported credentials
+    pub fn get_subject_imports(env: &Env, subject: &Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SubjectImports(subject.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Set the ids of a subject's imported credentials
+    pub fn set_subject_imports(env: &Env, subject: &Address, imports: &Vec<u64>) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::SubjectImports(subject.clone()), imports);
+    }
+
+    /// Get the import standing in for a course for a subject
+    pub fn get_imported_course(env: &Env, subject: &Address, course: &Symbol) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ImportedCourse(subject.clone(), course.clone()))
+    }
+
+    /// Set the import standing in for a course for a subject
+    pub fn set_imported_course(env: &Env, subject: &Address, course: &Symbol, import_id: u64) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::ImportedCourse(subject.clone(), course.clone()), &import_id);
+    }
+
+    /// Allocate the next erasure request id
+    pub fn next_erasure_id(env: &Env) -> u64 {
+        let id: u64 = env.storage().instance().get(&DataKey::ErasureCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::ErasureCount, &id);
+        id
+    }
+
+    /// Get how many erasure requests have been made
+    pub fn get_erasure_count(env: &Env) -> u64 {
+        env.storage().instance().get(&DataKey::ErasureCount).unwrap_or(0)
+    }
+
+    /// Get an erasure request
+    pub fn get_erasure(env: &Env, request_id: u64) -> Option<ErasureRequest> {
+        env.storage().persistent().get(&DataKey::Erasure(request_id))
+    }
+
+    /// Store an erasure request
+    pub fn set_erasure(env: &Env, request: &ErasureRequest) {
+        env.storage().persistent().set(&DataKey::Erasure(request.id), request);
+    }
+
+    /// Get the open erasure request for an import
+    pub fn get_pending_erasure(env: &Env, import_id: u64) -> Option<u64> {
+        env.storage().persistent().get(&DataKey::PendingErasure(import_id))
+    }
+
+    /// Record the open erasure request for an import
+    pub fn set_pending_erasure(env: &Env, import_id: u64, request_id: u64) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingErasure(import_id), &request_id);
+    }
+
+    /// Clear the open erasure request for an import
+    pub fn remove_pending_erasure(env: &Env, import_id: u64) {
+        env.storage().persistent().remove(&DataKey::PendingErasure(import_id));
+    }
+}
@@ -0,0 +1,228 @@
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    symbol_short, testutils::{Address as _, Ledger}, vec, Address, BytesN, Env, String,
+};
+
+use crate::{AttestationsContract, AttestationsContractClient, AttestationsError, ErasureStatus, ExternalCredential};
+
+fn setup_test_env() -> (Env, AttestationsContractClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(AttestationsContract, ());
+    let client = AttestationsContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+
+    let kyc_provider = Address::generate(&env);
+    client.approve_attestor(&admin, &kyc_provider, &String::from_str(&env, "Acme KYC"), &vec![&env, symbol_short!("kyc")]);
+    let proctor = Address::generate(&env);
+    client.approve_attestor(&admin, &proctor, &String::from_str(&env, "Exam Center"), &vec![&env, symbol_short!("proctored")]);
+    client.set_requirement(&admin, &symbol_short!("degree"), &vec![&env, symbol_short!("kyc"), symbol_short!("proctored")]);
+
+    (env, client, admin, kyc_provider, proctor)
+}
+
+fn evidence(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[7; 32])
+}
+
+fn sign_import(
+    env: &Env,
+    client: &AttestationsContractClient,
+    key: &SigningKey,
+    student: &Address,
+    credential: &ExternalCredential,
+    nonce: u64,
+    deadline: u64,
+) -> BytesN<64> {
+    let payload = client.get_import_payload(student, credential, &nonce, &deadline);
+    let mut buffer = [0u8; 512];
+    let signed = &mut buffer[..payload.len() as usize];
+    payload.copy_into_slice(signed);
+    BytesN::from_array(env, &key.sign(signed).to_bytes())
+}
+
+#[test]
+fn test_degree_requires_every_attestation() {
+    let (env, client, _admin, kyc_provider, proctor) = setup_test_env();
+    let student = Address::generate(&env);
+    let degree = symbol_short!("degree");
+    assert!(!client.meets_requirement(&student, &degree));
+    assert!(client.meets_requirement(&student, &symbol_short!("short")));
+
+    client.attest(&kyc_provider, &student, &symbol_short!("kyc"), &evidence(&env), &None);
+    assert_eq!(client.get_missing(&student, &degree), vec![&env, symbol_short!("proctored")]);
+
+    assert_eq!(
+        client.try_attest(&kyc_provider, &student, &symbol_short!("proctored"), &evidence(&env), &None),
+        Err(Ok(AttestationsError::ClaimNotAllowed))
+    );
+    client.attest(&proctor, &student, &symbol_short!("proctored"), &evidence(&env), &None);
+    assert!(client.meets_requirement(&student, &degree));
+}
+
+#[test]
+fn test_expired_and_revoked_attestations_do_not_count() {
+    let (env, client, _admin, kyc_provider, proctor) = setup_test_env();
+    let student = Address::generate(&env);
+    let kyc = symbol_short!("kyc");
+    env.ledger().set_timestamp(100);
+    assert_eq!(
+        client.try_attest(&kyc_provider, &student, &kyc, &evidence(&env), &Some(100)),
+        Err(Ok(AttestationsError::InvalidExpiry))
+    );
+    client.attest(&kyc_provider, &student, &kyc, &evidence(&env), &Some(200));
+    assert!(client.has_valid(&student, &kyc));
+    env.ledger().set_timestamp(200);
+    assert!(!client.has_valid(&student, &kyc));
+
+    client.attest(&kyc_provider, &student, &kyc, &evidence(&env), &None);
+    assert_eq!(client.try_revoke(&proctor, &student, &kyc), Err(Ok(AttestationsError::Unauthorized)));
+    client.revoke(&kyc_provider, &student, &kyc);
+    assert!(!client.has_valid(&student, &kyc));
+    assert!(client.get_attestation(&student, &kyc).unwrap().revoked);
+}
+
+#[test]
+fn test_suspended_attestor_claims_stop_counting() {
+    let (env, client, admin, kyc_provider, _proctor) = setup_test_env();
+    let student = Address::generate(&env);
+    let kyc = symbol_short!("kyc");
+    client.attest(&kyc_provider, &student, &kyc, &evidence(&env), &None);
+
+    client.suspend_attestor(&admin, &kyc_provider);
+    assert!(!client.has_valid(&student, &kyc));
+    assert_eq!(
+        client.try_attest(&kyc_provider, &student, &kyc, &evidence(&env), &None),
+        Err(Ok(AttestationsError::AttestorInactive))
+    );
+
+    client.approve_attestor(&admin, &kyc_provider, &String::from_str(&env, "Acme KYC"), &vec![&env, kyc.clone()]);
+    assert!(client.has_valid(&student, &kyc));
+}
+
+#[test]
+fn test_imported_credential_requires_attestor_signature() {
+    let (env, client, admin, kyc_provider, _proctor) = setup_test_env();
+    let registrar = Address::generate(&env);
+    client.approve_attestor(&admin, &registrar, &String::from_str(&env, "Partner Registrar"), &vec![&env, symbol_short!("import")]);
+    let key = SigningKey::from_bytes(&[9; 32]);
+    assert_eq!(
+        client.try_set_signing_key(&kyc_provider, &BytesN::from_array(&env, &key.verifying_key().to_bytes())),
+        Err(Ok(AttestationsError::ClaimNotAllowed))
+    );
+    client.set_signing_key(&registrar, &BytesN::from_array(&env, &key.verifying_key().to_bytes()));
+
+    env.ledger().set_timestamp(1_000);
+    let student = Address::generate(&env);
+    let credential = ExternalCredential {
+        issuer: String::from_str(&env, "Open University"),
+        course: symbol_short!("rust101"),
+        completed_at: 500,
+        evidence_hash: evidence(&env),
+    };
+    let signature = sign_import(&env, &client, &key, &student, &credential, 1, 2_000);
+
+    let import_id = client.import_credential(&student, &registrar, &credential, &1, &2_000, &signature);
+    assert!(client.has_imported(&student, &symbol_short!("rust101")));
+    assert_eq!(client.get_imported_credential(&student, &import_id).credential, credential);
+    assert_eq!(
+        client.try_import_credential(&student, &registrar, &credential, &1, &2_000, &signature),
+        Err(Ok(AttestationsError::AlreadyImported))
+    );
+
+    client.revoke_import(&registrar, &import_id);
+    assert!(!client.has_imported(&student, &symbol_short!("rust101")));
+    assert_eq!(
+        client.try_import_credential(&student, &registrar, &credential, &1, &2_000, &signature),
+        Err(Ok(AttestationsError::NonceAlreadyUsed))
+    );
+}
+
+/// Import a completed `rust101` for `student` through a newly approved registrar
+fn import_for(
+    env: &Env,
+    client: &AttestationsContractClient,
+    admin: &Address,
+    student: &Address,
+) -> (u64, ExternalCredential) {
+    let registrar = Address::generate(env);
+    client.approve_attestor(admin, &registrar, &String::from_str(env, "Partner Registrar"), &vec![env, symbol_short!("import")]);
+    let key = SigningKey::from_bytes(&[9; 32]);
+    client.set_signing_key(&registrar, &BytesN::from_array(env, &key.verifying_key().to_bytes()));
+
+    let credential = ExternalCredential {
+        issuer: String::from_str(env, "Open University"),
+        course: symbol_short!("rust101"),
+        completed_at: 0,
+        evidence_hash: evidence(env),
+    };
+    let signature = sign_import(env, client, &key, student, &credential, 1, 1_000);
+    let import_id = client.import_credential(student, &registrar, &credential, &1, &1_000, &signature);
+    (import_id, credential)
+}
+
+#[test]
+fn test_import_details_require_holder_consent() {
+    let (env, client, admin, _kyc_provider, _proctor) = setup_test_env();
+    let student = Address::generate(&env);
+    let (import_id, credential) = import_for(&env, &client, &admin, &student);
+
+    // Validity is public; details are not
+    let employer = Address::generate(&env);
+    assert!(client.get_import_status(&import_id).unwrap().valid);
+    assert_eq!(client.try_get_imported_credential(&employer, &import_id), Err(Ok(AttestationsError::AccessDenied)));
+
+    client.grant_access(&student, &employer, &Some(500));
+    assert_eq!(client.get_imported_credential(&employer, &import_id).credential, credential);
+    env.ledger().set_timestamp(501);
+    assert_eq!(client.try_get_imported_credential(&employer, &import_id), Err(Ok(AttestationsError::AccessDenied)));
+
+    client.grant_access(&student, &employer, &None);
+    client.revoke_access(&student, &employer);
+    assert_eq!(client.try_get_imported_credential(&employer, &import_id), Err(Ok(AttestationsError::AccessDenied)));
+    assert_eq!(client.try_revoke_access(&student, &employer), Err(Ok(AttestationsError::GrantNotFound)));
+}
+
+#[test]
+fn test_approved_erasure_leaves_verifiable_tombstone() {
+    let (env, client, admin, _kyc_provider, _proctor) = setup_test_env();
+    let student = Address::generate(&env);
+    let (import_id, credential) = import_for(&env, &client, &admin, &student);
+    let officer = Address::generate(&env);
+    assert_eq!(
+        client.try_grant_compliance_officer(&student, &officer),
+        Err(Ok(AttestationsError::Unauthorized))
+    );
+    client.grant_compliance_officer(&admin, &officer);
+
+    assert_eq!(client.try_request_erasure(&admin, &import_id), Err(Ok(AttestationsError::Unauthorized)));
+    let request_id = client.request_erasure(&student, &import_id);
+    assert_eq!(client.try_request_erasure(&student, &import_id), Err(Ok(AttestationsError::ErasurePending)));
+    // Admins do not hold ProcessErasure by default
+    assert_eq!(client.try_process_erasure(&admin, &request_id, &true), Err(Ok(AttestationsError::Unauthorized)));
+
+    client.process_erasure(&officer, &request_id, &true);
+    let erased = client.get_imported_credential(&student, &import_id);
+    assert!(erased.erased);
+    assert!(erased.credential.issuer.is_empty());
+    assert!(client.has_imported(&student, &symbol_short!("rust101")));
+    assert!(client.verify_tombstone(&import_id, &credential));
+
+    let request = client.get_erasure_request(&request_id).unwrap();
+    assert_eq!(request.status, ErasureStatus::Completed);
+    assert_eq!(request.processed_by, Some(officer.clone()));
+    assert_eq!(
+        client.try_process_erasure(&officer, &request_id, &false),
+        Err(Ok(AttestationsError::InvalidErasureState))
+    );
+    assert_eq!(client.try_request_erasure(&student, &import_id), Err(Ok(AttestationsError::AlreadyErased)));
+
+    client.revoke_compliance_officer(&admin, &officer);
+    assert_eq!(client.try_process_erasure(&officer, &request_id, &true), Err(Ok(AttestationsError::Unauthorized)));
+    assert_eq!(
+        client.try_revoke_compliance_officer(&admin, &officer),
+        Err(Ok(AttestationsError::NotComplianceOfficer))
+    );
+}
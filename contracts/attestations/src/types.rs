@@ -0,0 +1,100 @@
+use soroban_sdk::{contracttype, Address, BytesN, String, Symbol, Vec};
+
+/// An organization approved to make claims about learners
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Attestor {
+    pub address: Address,
+    pub name: String,
+    pub claims: Vec<Symbol>, // claim types it may attest, e.g. `kyc`, `proctored`
+    pub active: bool,
+    pub approved_at: u64,
+}
+
+/// A claim an attestor made about an address
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Attestation {
+    pub subject: Address,
+    pub claim: Symbol,
+    pub attestor: Address,
+    pub evidence_hash: BytesN<32>, // hash of the off-chain evidence the attestor keeps
+    pub issued_at: u64,
+    pub expires_at: Option<u64>,
+    pub revoked: bool,
+}
+
+/// An externally earned credential, as signed by an attestor
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ExternalCredential {
+    pub issuer: String,  // institution that issued it, e.g. "MIT OpenCourseWare"
+    pub course: Symbol,  // StrellerMinds course it stands in for in prerequisite checks
+    pub completed_at: u64,
+    pub evidence_hash: BytesN<32>, // hash of the original certificate
+}
+
+/// An external credential brought on-chain. Kept apart from native
+/// certificates: it only satisfies prerequisite checks.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ImportedCredential {
+    pub id: u64,
+    pub subject: Address,
+    pub attestor: Address,
+    pub credential: ExternalCredential,
+    pub imported_at: u64,
+    pub revoked: bool,
+    pub erased: bool, // personal details overwritten; `evidence_hash` holds the tombstone
+}
+
+/// What anyone may learn about an imported credential without the
+/// holder's consent
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ImportStatus {
+    pub subject: Address,
+    pub course: Symbol,
+    pub valid: bool,
+}
+
+/// Where an erasure request stands
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum ErasureStatus {
+    Pending,
+    Completed,
+    Rejected,
+}
+
+/// A holder's request to erase the personal details of an imported
+/// credential. Once processed it is never changed, forming the erasure log.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ErasureRequest {
+    pub id: u64,
+    pub import_id: u64,
+    pub subject: Address,
+    pub requested_at: u64,
+    pub status: ErasureStatus,
+    pub processed_by: Option<Address>,
+    pub processed_at: Option<u64>,
+}
+
+/// Storage keys for the attestations contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Attestor(Address),
+    Attestation(Address, Symbol), // (subject, claim)
+    SubjectClaims(Address),
+    Requirement(Symbol), // claims a credential level requires
+    ImportCount,
+    Import(u64),
+    SubjectImports(Address),
+    ImportedCourse(Address, Symbol), // (subject, course) -> import id
+    ErasureCount,
+    Erasure(u64),
+    PendingErasure(u64), // import id -> open request id
+}
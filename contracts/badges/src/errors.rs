@@ -0,0 +1,27 @@
+use soroban_sdk::contracterror;
+
+/// Badges contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum BadgesError {
+    // Initialization errors
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+
+    // Authorization errors
+    Unauthorized = 3,
+
+    // Badge type errors
+    InvalidBadge = 4,
+    BadgeExists = 5,
+    BadgeNotFound = 6,
+    TooManyBadges = 7,
+
+    // Award errors
+    BadgeInactive = 8,
+    AlreadyAwarded = 9,
+    NotManual = 10,
+    NotEligible = 11,
+    InvalidAmount = 12,
+}
@@ -0,0 +1,39 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+/// Badges contract events
+pub struct BadgesEvents;
+
+impl BadgesEvents {
+    fn topic(env: &Env, name: &str) -> (Symbol, Symbol) {
+        (Symbol::new(env, "badges"), Symbol::new(env, name))
+    }
+
+    /// Emit event when a badge type is defined
+    pub fn emit_badge_defined(env: &Env, badge_id: &Symbol) {
+        env.events().publish(Self::topic(env, "badge_defined"), badge_id.clone());
+    }
+
+    /// Emit event when a badge type is switched on or off
+    pub fn emit_badge_status(env: &Env, badge_id: &Symbol, active: bool) {
+        env.events().publish(
+            Self::topic(env, "badge_status"),
+            (badge_id.clone(), active),
+        );
+    }
+
+    /// Emit event when a user's metric changes
+    pub fn emit_metric_updated(env: &Env, user: &Address, metric: &Symbol, value: u32) {
+        env.events().publish(
+            Self::topic(env, "metric_updated"),
+            (user.clone(), metric.clone(), value),
+        );
+    }
+
+    /// Emit event when a user earns a badge
+    pub fn emit_awarded(env: &Env, user: &Address, badge_id: &Symbol, awarded_by: &Address) {
+        env.events().publish(
+            Self::topic(env, "awarded"),
+            (user.clone(), badge_id.clone(), awarded_by.clone()),
+        );
+    }
+}
@@ -0,0 +1,284 @@
+#![no_std]
+
+mod types;
+mod errors;
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod tests;
+
+use soroban_sdk::{contract, contractimpl, Address, Env, String, Symbol, Vec};
+
+use shared::{
+    access_control::AccessControl,
+    roles::{Permission, RoleLevel},
+    time::Clock,
+};
+
+pub use types::{AwardRule, BadgeType};
+pub use errors::BadgesError;
+use events::BadgesEvents;
+use storage::BadgesStorage;
+
+/// Upper bound on badge types, so the catalog fits in one instance entry
+const MAX_BADGE_TYPES: u32 = 100;
+
+/// Upper bound on threshold badges per metric, which bounds the work done
+/// on every metric update
+const MAX_BADGES_PER_METRIC: u32 = 10;
+
+#[contract]
+pub struct Badges;
+
+#[contractimpl]
+impl Badges {
+    /// Initialize the contract
+    pub fn initialize(env: Env, admin: Address) -> Result<(), BadgesError> {
+        if BadgesStorage::get_admin(&env).is_some() {
+            return Err(BadgesError::AlreadyInitialized);
+        }
+
+        AccessControl::initialize(&env, &admin).map_err(|_| BadgesError::AlreadyInitialized)?;
+        BadgesStorage::set_admin(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Grant a role in this contract (requires GrantRole)
+    pub fn grant_role(env: Env, admin: Address, user: Address, level: RoleLevel) -> Result<(), BadgesError> {
+        admin.require_auth();
+        AccessControl::grant_role(&env, &admin, &user, level).map_err(|_| BadgesError::Unauthorized)
+    }
+
+    /// Revoke a user's role in this contract (requires RevokeRole)
+    pub fn revoke_role(env: Env, admin: Address, user: Address) -> Result<(), BadgesError> {
+        admin.require_auth();
+        AccessControl::revoke_role(&env, &admin, &user).map_err(|_| BadgesError::Unauthorized)
+    }
+
+    /// Define a new kind of badge (admin only). Definitions cannot be
+    /// changed once created, only switched off.
+    pub fn define_badge(
+        env: Env,
+        admin: Address,
+        badge_id: Symbol,
+        name: String,
+        uri: String,
+        rule: AwardRule,
+    ) -> Result<(), BadgesError> {
+        Self::require_admin(&env, &admin)?;
+        if name.is_empty() {
+            return Err(BadgesError::InvalidBadge);
+        }
+        if BadgesStorage::get_badge_type(&env, &badge_id).is_some() {
+            return Err(BadgesError::BadgeExists);
+        }
+        let mut ids = BadgesStorage::get_badge_ids(&env);
+        if ids.len() >= MAX_BADGE_TYPES {
+            return Err(BadgesError::TooManyBadges);
+        }
+
+        if let AwardRule::Threshold(metric, threshold) = &rule {
+            if *threshold == 0 {
+                return Err(BadgesError::InvalidBadge);
+            }
+            let mut watching = BadgesStorage::get_metric_badges(&env, metric);
+            if watching.len() >= MAX_BADGES_PER_METRIC {
+                return Err(BadgesError::TooManyBadges);
+            }
+            watching.push_back(badge_id.clone());
+            BadgesStorage::set_metric_badges(&env, metric, &watching);
+        }
+
+        BadgesStorage::set_badge_type(
+            &env,
+            &BadgeType {
+                id: badge_id.clone(),
+                name,
+                uri,
+                rule,
+                active: true,
+                awarded: 0,
+            },
+        );
+        ids.push_back(badge_id.clone());
+        BadgesStorage::set_badge_ids(&env, &ids);
+        BadgesEvents::emit_badge_defined(&env, &badge_id);
+
+        Ok(())
+    }
+
+    /// Stop or resume awarding a badge (admin only). Badges already
+    /// awarded are kept.
+    pub fn set_badge_active(env: Env, admin: Address, badge_id: Symbol, active: bool) -> Result<(), BadgesError> {
+        Self::require_admin(&env, &admin)?;
+        let mut badge = BadgesStorage::get_badge_type(&env, &badge_id).ok_or(BadgesError::BadgeNotFound)?;
+        badge.active = active;
+        BadgesStorage::set_badge_type(&env, &badge);
+        BadgesEvents::emit_badge_status(&env, &badge_id, active);
+        Ok(())
+    }
+
+    /// Award a manual badge to a user (issuers only)
+    pub fn award(env: Env, issuer: Address, user: Address, badge_id: Symbol) -> Result<(), BadgesError> {
+        Self::require_issuer(&env, &issuer)?;
+        let badge = BadgesStorage::get_badge_type(&env, &badge_id).ok_or(BadgesError::BadgeNotFound)?;
+        if badge.rule != AwardRule::Manual {
+            return Err(BadgesError::NotManual);
+        }
+        if !badge.active {
+            return Err(BadgesError::BadgeInactive);
+        }
+        if BadgesStorage::get_award(&env, &user, &badge_id).is_some() {
+            return Err(BadgesError::AlreadyAwarded);
+        }
+
+        Self::grant(&env, &user, badge, &issuer);
+        Ok(())
+    }
+
+    /// Add to a cumulative metric such as community contributions (issuers
+    /// only). Returns the badges this earned.
+    pub fn record_activity(
+        env: Env,
+        issuer: Address,
+        user: Address,
+        metric: Symbol,
+        amount: u32,
+    ) -> Result<Vec<Symbol>, BadgesError> {
+        Self::require_issuer(&env, &issuer)?;
+        if amount == 0 {
+            return Err(BadgesError::InvalidAmount);
+        }
+
+        let value = BadgesStorage::get_metric(&env, &user, &metric).saturating_add(amount);
+        Ok(Self::update_metric(&env, &issuer, &user, &metric, value))
+    }
+
+    /// Report the current value of a metric that can fall back, such as a
+    /// learning streak (issuers only). The best value reported is kept, so
+    /// a broken streak does not undo progress. Returns the badges this
+    /// earned.
+    pub fn report_metric(
+        env: Env,
+        issuer: Address,
+        user: Address,
+        metric: Symbol,
+        value: u32,
+    ) -> Result<Vec<Symbol>, BadgesError> {
+        Self::require_issuer(&env, &issuer)?;
+
+        let best = BadgesStorage::get_metric(&env, &user, &metric);
+        if value <= best {
+            return Ok(Vec::new(&env));
+        }
+        Ok(Self::update_metric(&env, &issuer, &user, &metric, value))
+    }
+
+    /// Award a threshold badge the user already qualifies for, e.g. one
+    /// defined after they reached the threshold
+    pub fn claim_badge(env: Env, user: Address, badge_id: Symbol) -> Result<(), BadgesError> {
+        user.require_auth();
+        let badge = BadgesStorage::get_badge_type(&env, &badge_id).ok_or(BadgesError::BadgeNotFound)?;
+        if !badge.active {
+            return Err(BadgesError::BadgeInactive);
+        }
+        if BadgesStorage::get_award(&env, &user, &badge_id).is_some() {
+            return Err(BadgesError::AlreadyAwarded);
+        }
+        match &badge.rule {
+            AwardRule::Threshold(metric, threshold)
+                if BadgesStorage::get_metric(&env, &user, metric) >= *threshold => {}
+            _ => return Err(BadgesError::NotEligible),
+        }
+
+        let contract = env.current_contract_address();
+        Self::grant(&env, &user, badge, &contract);
+        Ok(())
+    }
+
+    /// Get a badge definition
+    pub fn get_badge_type(env: Env, badge_id: Symbol) -> Option<BadgeType> {
+        BadgesStorage::get_badge_type(&env, &badge_id)
+    }
+
+    /// Get the ids of every defined badge
+    pub fn get_badge_ids(env: Env) -> Vec<Symbol> {
+        BadgesStorage::get_badge_ids(&env)
+    }
+
+    /// Whether a user holds a badge
+    pub fn has_badge(env: Env, user: Address, badge_id: Symbol) -> bool {
+        BadgesStorage::get_award(&env, &user, &badge_id).is_some()
+    }
+
+    /// Get when a user was awarded a badge
+    pub fn get_awarded_at(env: Env, user: Address, badge_id: Symbol) -> Option<u64> {
+        BadgesStorage::get_award(&env, &user, &badge_id)
+    }
+
+    /// Get the badges a user holds, in award order
+    pub fn get_user_badges(env: Env, user: Address) -> Vec<Symbol> {
+        BadgesStorage::get_user_badges(&env, &user)
+    }
+
+    /// Get a user's value for a metric
+    pub fn get_metric(env: Env, user: Address, metric: Symbol) -> u32 {
+        BadgesStorage::get_metric(&env, &user, &metric)
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        BadgesStorage::get_admin(&env)
+    }
+}
+
+impl Badges {
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), BadgesError> {
+        caller.require_auth();
+        if !AccessControl::is_admin(env, caller) {
+            return Err(BadgesError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Issuers are the accounts trusted to attest progress: instructors,
+    /// moderators and admins
+    fn require_issuer(env: &Env, caller: &Address) -> Result<(), BadgesError> {
+        caller.require_auth();
+        let permissions = Vec::from_array(env, [Permission::IssueCertificate, Permission::UpdateProgress]);
+        AccessControl::require_any_permission(env, caller, &permissions).map_err(|_| BadgesError::Unauthorized)
+    }
+
+    /// Store a new metric value and award every active threshold badge it
+    /// reaches
+    fn update_metric(env: &Env, issuer: &Address, user: &Address, metric: &Symbol, value: u32) -> Vec<Symbol> {
+        BadgesStorage::set_metric(env, user, metric, value);
+        BadgesEvents::emit_metric_updated(env, user, metric, value);
+
+        let mut earned = Vec::new(env);
+        for badge_id in BadgesStorage::get_metric_badges(env, metric).iter() {
+            let Some(badge) = BadgesStorage::get_badge_type(env, &badge_id) else {
+                continue;
+            };
+            let reached = matches!(&badge.rule, AwardRule::Threshold(_, threshold) if value >= *threshold);
+            if reached && badge.active && BadgesStorage::get_award(env, user, &badge_id).is_none() {
+                Self::grant(env, user, badge, issuer);
+                earned.push_back(badge_id);
+            }
+        }
+        earned
+    }
+
+    fn grant(env: &Env, user: &Address, mut badge: BadgeType, awarded_by: &Address) {
+        BadgesStorage::set_award(env, user, &badge.id, Clock::now(env));
+        let mut held = BadgesStorage::get_user_badges(env, user);
+        held.push_back(badge.id.clone());
+        BadgesStorage::set_user_badges(env, user, &held);
+
+        badge.awarded += 1;
+        BadgesStorage::set_badge_type(env, &badge);
+        BadgesEvents::emit_awarded(env, user, &badge.id, awarded_by);
+    }
+}
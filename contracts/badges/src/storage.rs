@@ -0,0 +1,101 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+use crate::types::{BadgeType, DataKey};
+
+/// Storage utilities for the badges contract
+pub struct BadgesStorage;
+
+impl BadgesStorage {
+    /// Get the contract admin
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Set the contract admin
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Get the ids of every defined badge
+    pub fn get_badge_ids(env: &Env) -> Vec<Symbol> {
+        env.storage()
+            .instance()
+            .get(&DataKey::BadgeIds)
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Set the ids of every defined badge
+    pub fn set_badge_ids(env: &Env, ids: &Vec<Symbol>) {
+        env.storage().instance().set(&DataKey::BadgeIds, ids);
+    }
+
+    /// Get a badge definition
+    pub fn get_badge_type(env: &Env, badge_id: &Symbol) -> Option<BadgeType> {
+        env.storage().persistent().get(&DataKey::BadgeType(badge_id.clone()))
+    }
+
+    /// Store a badge definition
+    pub fn set_badge_type(env: &Env, badge: &BadgeType) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::BadgeType(badge.id.clone()), badge);
+    }
+
+    /// Get the threshold badges watching a metric
+    pub fn get_metric_badges(env: &Env, metric: &Symbol) -> Vec<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MetricBadges(metric.clone()))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Set the threshold badges watching a metric
+    pub fn set_metric_badges(env: &Env, metric: &Symbol, badge_ids: &Vec<Symbol>) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::MetricBadges(metric.clone()), badge_ids);
+    }
+
+    /// Get when a user was awarded a badge
+    pub fn get_award(env: &Env, user: &Address, badge_id: &Symbol) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Award(user.clone(), badge_id.clone()))
+    }
+
+    /// Record a badge award
+    pub fn set_award(env: &Env, user: &Address, badge_id: &Symbol, awarded_at: u64) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Award(user.clone(), badge_id.clone()), &awarded_at);
+    }
+
+    /// Get the badges a user holds, in award order
+    pub fn get_user_badges(env: &Env, user: &Address) -> Vec<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::UserBadges(user.clone()))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Set the badges a user holds
+    pub fn set_user_badges(env: &Env, user: &Address, badge_ids: &Vec<Symbol>) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::UserBadges(user.clone()), badge_ids);
+    }
+
+    /// Get a user's value for a metric
+    pub fn get_metric(env: &Env, user: &Address, metric: &Symbol) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Metric(user.clone(), metric.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Set a user's value for a metric
+    pub fn set_metric(env: &Env, user: &Address, metric: &Symbol, value: u32) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Metric(user.clone(), metric.clone()), &value);
+    }
+}
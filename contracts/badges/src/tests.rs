@@ -0,0 +1,143 @@
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env, String, Symbol};
+use shared::roles::RoleLevel;
+
+use crate::{AwardRule, Badges, BadgesClient, BadgesError};
+
+fn setup_test_env() -> (Env, BadgesClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let contract_id = env.register(Badges, ());
+    let client = BadgesClient::new(&env, &contract_id);
+    client.initialize(&admin);
+    client.grant_role(&admin, &issuer, &RoleLevel::Moderator);
+
+    (env, client, admin, issuer)
+}
+
+fn define(env: &Env, client: &BadgesClient, admin: &Address, badge_id: &Symbol, rule: AwardRule) {
+    let name = String::from_str(env, "Badge");
+    let uri = String::from_str(env, "ipfs://badge");
+    client.define_badge(admin, badge_id, &name, &uri, &rule);
+}
+
+fn hackathon() -> Symbol {
+    symbol_short!("HACK24")
+}
+
+fn streak() -> Symbol {
+    symbol_short!("streak")
+}
+
+#[test]
+fn test_manual_badges_are_awarded_once_by_issuers() {
+    let (env, client, admin, issuer) = setup_test_env();
+    define(&env, &client, &admin, &hackathon(), AwardRule::Manual);
+    let user = Address::generate(&env);
+
+    assert_eq!(
+        client.try_award(&user, &user, &hackathon()),
+        Err(Ok(BadgesError::Unauthorized))
+    );
+    client.award(&issuer, &user, &hackathon());
+    assert!(client.has_badge(&user, &hackathon()));
+    assert_eq!(
+        client.try_award(&issuer, &user, &hackathon()),
+        Err(Ok(BadgesError::AlreadyAwarded))
+    );
+
+    // Switching a badge off stops new awards but keeps existing ones
+    client.set_badge_active(&admin, &hackathon(), &false);
+    let late = Address::generate(&env);
+    assert_eq!(
+        client.try_award(&issuer, &late, &hackathon()),
+        Err(Ok(BadgesError::BadgeInactive))
+    );
+    assert_eq!(client.get_user_badges(&user), vec![&env, hackathon()]);
+    assert_eq!(client.get_badge_type(&hackathon()).unwrap().awarded, 1);
+}
+
+#[test]
+fn test_threshold_badges_award_automatically() {
+    let (env, client, admin, issuer) = setup_test_env();
+    let week = symbol_short!("STREAK7");
+    let month = symbol_short!("STREAK30");
+    define(&env, &client, &admin, &week, AwardRule::Threshold(streak(), 7));
+    define(&env, &client, &admin, &month, AwardRule::Threshold(streak(), 30));
+    assert_eq!(
+        client.try_award(&issuer, &admin, &week),
+        Err(Ok(BadgesError::NotManual))
+    );
+
+    let user = Address::generate(&env);
+    assert_eq!(client.report_metric(&issuer, &user, &streak(), &6).len(), 0);
+    assert_eq!(client.report_metric(&issuer, &user, &streak(), &8), vec![&env, week.clone()]);
+
+    // A broken streak keeps the best value reached
+    client.report_metric(&issuer, &user, &streak(), &2);
+    assert_eq!(client.get_metric(&user, &streak()), 8);
+    assert_eq!(client.report_metric(&issuer, &user, &streak(), &31), vec![&env, month]);
+
+    // Cumulative metrics add up across reports
+    let helper = symbol_short!("HELPER");
+    let answers = symbol_short!("answers");
+    define(&env, &client, &admin, &helper, AwardRule::Threshold(answers.clone(), 10));
+    client.record_activity(&issuer, &user, &answers, &4);
+    assert_eq!(client.record_activity(&issuer, &user, &answers, &6), vec![&env, helper]);
+}
+
+#[test]
+fn test_claim_badge_defined_after_threshold_reached() {
+    let (env, client, admin, issuer) = setup_test_env();
+    let user = Address::generate(&env);
+    client.report_metric(&issuer, &user, &streak(), &100);
+
+    let century = symbol_short!("STREAK100");
+    define(&env, &client, &admin, &century, AwardRule::Threshold(streak(), 100));
+    let newcomer = Address::generate(&env);
+    assert_eq!(
+        client.try_claim_badge(&newcomer, &century),
+        Err(Ok(BadgesError::NotEligible))
+    );
+    client.claim_badge(&user, &century);
+    assert!(client.has_badge(&user, &century));
+    assert_eq!(
+        client.try_claim_badge(&user, &century),
+        Err(Ok(BadgesError::AlreadyAwarded))
+    );
+}
+
+#[test]
+fn test_definition_and_reporting_errors() {
+    let (env, client, admin, issuer) = setup_test_env();
+    let name = String::from_str(&env, "Badge");
+    let uri = String::from_str(&env, "ipfs://badge");
+    let user = Address::generate(&env);
+
+    assert_eq!(
+        client.try_define_badge(&issuer, &hackathon(), &name, &uri, &AwardRule::Manual),
+        Err(Ok(BadgesError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_define_badge(&admin, &hackathon(), &String::from_str(&env, ""), &uri, &AwardRule::Manual),
+        Err(Ok(BadgesError::InvalidBadge))
+    );
+    assert_eq!(
+        client.try_define_badge(&admin, &hackathon(), &name, &uri, &AwardRule::Threshold(streak(), 0)),
+        Err(Ok(BadgesError::InvalidBadge))
+    );
+    define(&env, &client, &admin, &hackathon(), AwardRule::Manual);
+    assert_eq!(
+        client.try_define_badge(&admin, &hackathon(), &name, &uri, &AwardRule::Manual),
+        Err(Ok(BadgesError::BadgeExists))
+    );
+
+    assert_eq!(client.try_award(&issuer, &user, &streak()), Err(Ok(BadgesError::BadgeNotFound)));
+    assert_eq!(client.try_set_badge_active(&issuer, &hackathon(), &false), Err(Ok(BadgesError::Unauthorized)));
+    assert_eq!(client.try_record_activity(&issuer, &user, &streak(), &0), Err(Ok(BadgesError::InvalidAmount)));
+    assert_eq!(client.try_report_metric(&user, &user, &streak(), &5), Err(Ok(BadgesError::Unauthorized)));
+    // Manual badges are only handed out by issuers, never claimed
+    assert_eq!(client.try_claim_badge(&user, &hackathon()), Err(Ok(BadgesError::NotEligible)));
+}
@@ -0,0 +1,34 @@
+use soroban_sdk::{contracttype, Address, String, Symbol};
+
+/// How a badge is earned
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum AwardRule {
+    Manual,                  // awarded by an issuer, e.g. hackathon participation
+    Threshold(Symbol, u32),  // awarded automatically once a metric reaches the value
+}
+
+/// Definition of a kind of badge
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct BadgeType {
+    pub id: Symbol,
+    pub name: String,
+    pub uri: String, // image and description metadata
+    pub rule: AwardRule,
+    pub active: bool,
+    pub awarded: u32, // number of holders
+}
+
+/// Storage keys for the badges contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    BadgeIds,
+    BadgeType(Symbol),
+    MetricBadges(Symbol),    // threshold badges watching a metric
+    Award(Address, Symbol),  // award timestamp
+    UserBadges(Address),
+    Metric(Address, Symbol),
+}
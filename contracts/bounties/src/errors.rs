@@ -0,0 +1,36 @@
+use soroban_sdk::contracterror;
+
+/// Bounties contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum BountiesError {
+    // Initialization errors
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+
+    // Authorization errors
+    Unauthorized = 3,
+
+    // Bounty errors
+    InvalidBounty = 4,
+    BountyNotFound = 5,
+    BountyClosed = 6,
+    SubmissionsOpen = 7,
+
+    // Submission errors
+    DeadlinePassed = 8,
+    AlreadySubmitted = 9,
+    TooManySubmissions = 10,
+    SubmissionNotFound = 11,
+    SubmissionClosed = 12,
+
+    // Review errors
+    NotReviewer = 13,
+    AlreadyReviewed = 14,
+
+    // Dispute errors
+    NotRejected = 15,
+    DisputeWindowClosed = 16,
+    NotDisputed = 17,
+}
@@ -0,0 +1,55 @@
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+/// Bounties contract events
+pub struct BountiesEvents;
+
+impl BountiesEvents {
+    fn topic(env: &Env, name: &str) -> (Symbol, Symbol) {
+        (Symbol::new(env, "bounties"), Symbol::new(env, name))
+    }
+
+    /// Emit event when a bounty is posted and its reward locked
+    pub fn emit_posted(env: &Env, bounty_id: u64, poster: &Address, reward: i128) {
+        env.events().publish(
+            Self::topic(env, "posted"),
+            (bounty_id, poster.clone(), reward),
+        );
+    }
+
+    /// Emit event when a contributor submits work
+    pub fn emit_submitted(env: &Env, bounty_id: u64, contributor: &Address, work_hash: &BytesN<32>) {
+        env.events().publish(
+            Self::topic(env, "submitted"),
+            (bounty_id, contributor.clone(), work_hash.clone()),
+        );
+    }
+
+    /// Emit event when a reviewer approves or rejects a submission
+    pub fn emit_reviewed(env: &Env, bounty_id: u64, contributor: &Address, reviewer: &Address, approved: bool) {
+        env.events().publish(
+            Self::topic(env, "reviewed"),
+            (bounty_id, contributor.clone(), reviewer.clone(), approved),
+        );
+    }
+
+    /// Emit event when a rejected contributor disputes the decision
+    pub fn emit_disputed(env: &Env, bounty_id: u64, contributor: &Address) {
+        env.events().publish(
+            Self::topic(env, "disputed"),
+            (bounty_id, contributor.clone()),
+        );
+    }
+
+    /// Emit event when the reward is paid to the winning contributor
+    pub fn emit_awarded(env: &Env, bounty_id: u64, contributor: &Address, reward: i128) {
+        env.events().publish(
+            Self::topic(env, "awarded"),
+            (bounty_id, contributor.clone(), reward),
+        );
+    }
+
+    /// Emit event when a bounty is withdrawn and its reward refunded
+    pub fn emit_cancelled(env: &Env, bounty_id: u64) {
+        env.events().publish(Self::topic(env, "cancelled"), bounty_id);
+    }
+}
@@ -0,0 +1,353 @@
+#![no_std]
+
+mod types;
+mod errors;
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod tests;
+
+use soroban_sdk::{contract, contractimpl, token, Address, BytesN, Env, String, Vec};
+
+use shared::{
+    access_control::AccessControl,
+    reentrancy_guard::ReentrancyLock,
+    roles::{Permission, RoleLevel},
+    time::Clock,
+};
+
+pub use types::{Bounty, BountyKind, BountyStatus, BountyTerms, Submission, SubmissionStatus};
+pub use errors::BountiesError;
+use events::BountiesEvents;
+use storage::BountiesStorage;
+
+/// Upper bound on reviewers per bounty
+const MAX_REVIEWERS: u32 = 10;
+
+/// Upper bound on submissions per bounty
+const MAX_SUBMISSIONS: u32 = 50;
+
+#[contract]
+pub struct Bounties;
+
+#[contractimpl]
+impl Bounties {
+    /// Initialize the contract with how long rejected contributors have to
+    /// dispute, in seconds
+    pub fn initialize(env: Env, admin: Address, dispute_window: u64) -> Result<(), BountiesError> {
+        if BountiesStorage::get_admin(&env).is_some() {
+            return Err(BountiesError::AlreadyInitialized);
+        }
+
+        AccessControl::initialize(&env, &admin).map_err(|_| BountiesError::AlreadyInitialized)?;
+        BountiesStorage::set_admin(&env, &admin);
+        BountiesStorage::set_dispute_window(&env, dispute_window);
+
+        Ok(())
+    }
+
+    /// Grant a role in this contract (requires GrantRole)
+    pub fn grant_role(env: Env, admin: Address, user: Address, level: RoleLevel) -> Result<(), BountiesError> {
+        admin.require_auth();
+        AccessControl::grant_role(&env, &admin, &user, level).map_err(|_| BountiesError::Unauthorized)
+    }
+
+    /// Revoke a user's role in this contract (requires RevokeRole)
+    pub fn revoke_role(env: Env, admin: Address, user: Address) -> Result<(), BountiesError> {
+        admin.require_auth();
+        AccessControl::revoke_role(&env, &admin, &user).map_err(|_| BountiesError::Unauthorized)
+    }
+
+    /// Post a bounty, locking its reward in the contract (requires
+    /// CreateCourse). Returns the bounty id.
+    pub fn post_bounty(env: Env, poster: Address, terms: BountyTerms) -> Result<u64, BountiesError> {
+        let _guard = ReentrancyLock::new(&env);
+        poster.require_auth();
+        AccessControl::require_permission(&env, &poster, &Permission::CreateCourse)
+            .map_err(|_| BountiesError::Unauthorized)?;
+        Self::validate_terms(&env, &terms)?;
+
+        token::Client::new(&env, &terms.token).transfer(&poster, &env.current_contract_address(), &terms.reward);
+
+        let bounty = Bounty {
+            id: BountiesStorage::next_bounty_id(&env),
+            poster: poster.clone(),
+            terms,
+            status: BountyStatus::Open,
+            winner: None,
+            open_submissions: 0,
+            created_at: Clock::now(&env),
+        };
+        BountiesStorage::set_bounty(&env, &bounty);
+        BountiesEvents::emit_posted(&env, bounty.id, &poster, bounty.terms.reward);
+
+        Ok(bounty.id)
+    }
+
+    /// Submit work for a bounty before its deadline. The work itself lives
+    /// at `uri`; `work_hash` anchors it. One submission per contributor.
+    pub fn submit(
+        env: Env,
+        contributor: Address,
+        bounty_id: u64,
+        work_hash: BytesN<32>,
+        uri: String,
+    ) -> Result<(), BountiesError> {
+        contributor.require_auth();
+        let mut bounty = Self::load_open(&env, bounty_id)?;
+        if Clock::now(&env) > bounty.terms.deadline {
+            return Err(BountiesError::DeadlinePassed);
+        }
+        if bounty.terms.reviewers.contains(&contributor) {
+            return Err(BountiesError::Unauthorized);
+        }
+        if BountiesStorage::get_submission(&env, bounty_id, &contributor).is_some() {
+            return Err(BountiesError::AlreadySubmitted);
+        }
+        let mut contributors = BountiesStorage::get_contributors(&env, bounty_id);
+        if contributors.len() >= MAX_SUBMISSIONS {
+            return Err(BountiesError::TooManySubmissions);
+        }
+
+        BountiesStorage::set_submission(
+            &env,
+            &Submission {
+                bounty_id,
+                contributor: contributor.clone(),
+                work_hash: work_hash.clone(),
+                uri,
+                submitted_at: Clock::now(&env),
+                approvals: Vec::new(&env),
+                rejections: Vec::new(&env),
+                status: SubmissionStatus::Pending,
+                decided_at: 0,
+            },
+        );
+        contributors.push_back(contributor.clone());
+        BountiesStorage::set_contributors(&env, bounty_id, &contributors);
+        bounty.open_submissions += 1;
+        BountiesStorage::set_bounty(&env, &bounty);
+        BountiesEvents::emit_submitted(&env, bounty_id, &contributor, &work_hash);
+
+        Ok(())
+    }
+
+    /// Approve or reject a submission (the bounty's reviewers). The reward
+    /// is paid as soon as a submission reaches the required approvals; a
+    /// submission is rejected once it can no longer reach them.
+    pub fn review(
+        env: Env,
+        reviewer: Address,
+        bounty_id: u64,
+        contributor: Address,
+        approve: bool,
+    ) -> Result<SubmissionStatus, BountiesError> {
+        let _guard = ReentrancyLock::new(&env);
+        reviewer.require_auth();
+        let mut bounty = Self::load_open(&env, bounty_id)?;
+        if !bounty.terms.reviewers.contains(&reviewer) {
+            return Err(BountiesError::NotReviewer);
+        }
+        let mut submission = Self::load_submission(&env, bounty_id, &contributor, SubmissionStatus::Pending)?;
+        if submission.approvals.contains(&reviewer) || submission.rejections.contains(&reviewer) {
+            return Err(BountiesError::AlreadyReviewed);
+        }
+
+        if approve {
+            submission.approvals.push_back(reviewer.clone());
+        } else {
+            submission.rejections.push_back(reviewer.clone());
+        }
+        BountiesEvents::emit_reviewed(&env, bounty_id, &contributor, &reviewer, approve);
+
+        let required = bounty.terms.approvals_required;
+        if submission.approvals.len() >= required {
+            Self::award(&env, &mut bounty, &mut submission);
+        } else if bounty.terms.reviewers.len() - submission.rejections.len() < required {
+            submission.status = SubmissionStatus::Rejected;
+            submission.decided_at = Clock::now(&env);
+            bounty.open_submissions -= 1;
+            BountiesStorage::set_bounty(&env, &bounty);
+        }
+        BountiesStorage::set_submission(&env, &submission);
+
+        Ok(submission.status)
+    }
+
+    /// Dispute a rejection within the dispute window, leaving the decision
+    /// to an admin
+    pub fn dispute(env: Env, contributor: Address, bounty_id: u64) -> Result<(), BountiesError> {
+        contributor.require_auth();
+        let mut bounty = Self::load_open(&env, bounty_id)?;
+        let mut submission = BountiesStorage::get_submission(&env, bounty_id, &contributor)
+            .ok_or(BountiesError::SubmissionNotFound)?;
+        if submission.status != SubmissionStatus::Rejected {
+            return Err(BountiesError::NotRejected);
+        }
+        let window = BountiesStorage::get_dispute_window(&env).ok_or(BountiesError::NotInitialized)?;
+        if Clock::now(&env) > submission.decided_at + window {
+            return Err(BountiesError::DisputeWindowClosed);
+        }
+
+        submission.status = SubmissionStatus::Disputed;
+        BountiesStorage::set_submission(&env, &submission);
+        bounty.open_submissions += 1;
+        BountiesStorage::set_bounty(&env, &bounty);
+        BountiesEvents::emit_disputed(&env, bounty_id, &contributor);
+
+        Ok(())
+    }
+
+    /// Decide a disputed submission (admin only): upholding it pays the
+    /// reward, dismissing it closes the submission
+    pub fn resolve_dispute(
+        env: Env,
+        admin: Address,
+        bounty_id: u64,
+        contributor: Address,
+        uphold: bool,
+    ) -> Result<(), BountiesError> {
+        let _guard = ReentrancyLock::new(&env);
+        admin.require_auth();
+        if !AccessControl::is_admin(&env, &admin) {
+            return Err(BountiesError::Unauthorized);
+        }
+        let mut bounty = Self::load_open(&env, bounty_id)?;
+        let mut submission = BountiesStorage::get_submission(&env, bounty_id, &contributor)
+            .ok_or(BountiesError::SubmissionNotFound)?;
+        if submission.status != SubmissionStatus::Disputed {
+            return Err(BountiesError::NotDisputed);
+        }
+
+        if uphold {
+            Self::award(&env, &mut bounty, &mut submission);
+        } else {
+            submission.status = SubmissionStatus::Closed;
+            bounty.open_submissions -= 1;
+            BountiesStorage::set_bounty(&env, &bounty);
+        }
+        BountiesStorage::set_submission(&env, &submission);
+
+        Ok(())
+    }
+
+    /// Withdraw an unawarded bounty and refund its reward (poster or
+    /// admin). Not possible while a submission is pending, disputed or
+    /// still inside its dispute window.
+    pub fn cancel_bounty(env: Env, caller: Address, bounty_id: u64) -> Result<(), BountiesError> {
+        let _guard = ReentrancyLock::new(&env);
+        caller.require_auth();
+        let mut bounty = Self::load_open(&env, bounty_id)?;
+        if caller != bounty.poster && !AccessControl::is_admin(&env, &caller) {
+            return Err(BountiesError::Unauthorized);
+        }
+        if bounty.open_submissions > 0 || Self::has_disputable(&env, bounty_id) {
+            return Err(BountiesError::SubmissionsOpen);
+        }
+
+        bounty.status = BountyStatus::Cancelled;
+        BountiesStorage::set_bounty(&env, &bounty);
+        token::Client::new(&env, &bounty.terms.token).transfer(
+            &env.current_contract_address(),
+            &bounty.poster,
+            &bounty.terms.reward,
+        );
+        BountiesEvents::emit_cancelled(&env, bounty_id);
+
+        Ok(())
+    }
+
+    /// Get a bounty
+    pub fn get_bounty(env: Env, bounty_id: u64) -> Option<Bounty> {
+        BountiesStorage::get_bounty(&env, bounty_id)
+    }
+
+    /// Get a contributor's submission to a bounty
+    pub fn get_submission(env: Env, bounty_id: u64, contributor: Address) -> Option<Submission> {
+        BountiesStorage::get_submission(&env, bounty_id, &contributor)
+    }
+
+    /// Get everyone who submitted to a bounty, in submission order
+    pub fn get_contributors(env: Env, bounty_id: u64) -> Vec<Address> {
+        BountiesStorage::get_contributors(&env, bounty_id)
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        BountiesStorage::get_admin(&env)
+    }
+}
+
+impl Bounties {
+    fn validate_terms(env: &Env, terms: &BountyTerms) -> Result<(), BountiesError> {
+        let reviewers = terms.reviewers.len();
+        if terms.reward <= 0
+            || reviewers == 0
+            || reviewers > MAX_REVIEWERS
+            || terms.approvals_required == 0
+            || terms.approvals_required > reviewers
+            || terms.deadline <= Clock::now(env)
+        {
+            return Err(BountiesError::InvalidBounty);
+        }
+        for (index, reviewer) in terms.reviewers.iter().enumerate() {
+            if terms.reviewers.first_index_of(&reviewer) != Some(index as u32) {
+                return Err(BountiesError::InvalidBounty);
+            }
+        }
+        Ok(())
+    }
+
+    fn load_open(env: &Env, bounty_id: u64) -> Result<Bounty, BountiesError> {
+        let bounty = BountiesStorage::get_bounty(env, bounty_id).ok_or(BountiesError::BountyNotFound)?;
+        if bounty.status != BountyStatus::Open {
+            return Err(BountiesError::BountyClosed);
+        }
+        Ok(bounty)
+    }
+
+    fn load_submission(
+        env: &Env,
+        bounty_id: u64,
+        contributor: &Address,
+        status: SubmissionStatus,
+    ) -> Result<Submission, BountiesError> {
+        let submission = BountiesStorage::get_submission(env, bounty_id, contributor)
+            .ok_or(BountiesError::SubmissionNotFound)?;
+        if submission.status != status {
+            return Err(BountiesError::SubmissionClosed);
+        }
+        Ok(submission)
+    }
+
+    /// Whether a rejected submission could still be disputed
+    fn has_disputable(env: &Env, bounty_id: u64) -> bool {
+        let window = BountiesStorage::get_dispute_window(env).unwrap_or(0);
+        let now = Clock::now(env);
+        BountiesStorage::get_contributors(env, bounty_id).iter().any(|contributor| {
+            matches!(
+                BountiesStorage::get_submission(env, bounty_id, &contributor),
+                Some(submission) if submission.status == SubmissionStatus::Rejected
+                    && now <= submission.decided_at + window
+            )
+        })
+    }
+
+    /// Pay the locked reward to a submission and close the bounty
+    fn award(env: &Env, bounty: &mut Bounty, submission: &mut Submission) {
+        submission.status = SubmissionStatus::Approved;
+        submission.decided_at = Clock::now(env);
+        bounty.status = BountyStatus::Awarded;
+        bounty.winner = Some(submission.contributor.clone());
+        bounty.open_submissions -= 1;
+        BountiesStorage::set_bounty(env, bounty);
+
+        token::Client::new(env, &bounty.terms.token).transfer(
+            &env.current_contract_address(),
+            &submission.contributor,
+            &bounty.terms.reward,
+        );
+        BountiesEvents::emit_awarded(env, bounty.id, &submission.contributor, bounty.terms.reward);
+    }
+}
@@ -0,0 +1,74 @@
+use soroban_sdk::{Address, Env, Vec};
+use crate::types::{Bounty, DataKey, Submission};
+
+/// Storage utilities for the bounties contract
+pub struct BountiesStorage;
+
+impl BountiesStorage {
+    /// Get the contract admin
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Set the contract admin
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Get how long a rejected contributor has to dispute
+    pub fn get_dispute_window(env: &Env) -> Option<u64> {
+        env.storage().instance().get(&DataKey::DisputeWindow)
+    }
+
+    /// Set how long a rejected contributor has to dispute
+    pub fn set_dispute_window(env: &Env, window: u64) {
+        env.storage().instance().set(&DataKey::DisputeWindow, &window);
+    }
+
+    /// Allocate the next bounty id
+    pub fn next_bounty_id(env: &Env) -> u64 {
+        let id: u64 = env.storage().instance().get(&DataKey::BountyCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::BountyCount, &id);
+        id
+    }
+
+    /// Get a bounty
+    pub fn get_bounty(env: &Env, bounty_id: u64) -> Option<Bounty> {
+        env.storage().persistent().get(&DataKey::Bounty(bounty_id))
+    }
+
+    /// Store a bounty
+    pub fn set_bounty(env: &Env, bounty: &Bounty) {
+        env.storage().persistent().set(&DataKey::Bounty(bounty.id), bounty);
+    }
+
+    /// Get a contributor's submission to a bounty
+    pub fn get_submission(env: &Env, bounty_id: u64, contributor: &Address) -> Option<Submission> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Submission(bounty_id, contributor.clone()))
+    }
+
+    /// Store a submission
+    pub fn set_submission(env: &Env, submission: &Submission) {
+        env.storage().persistent().set(
+            &DataKey::Submission(submission.bounty_id, submission.contributor.clone()),
+            submission,
+        );
+    }
+
+    /// Get everyone who submitted to a bounty, in submission order
+    pub fn get_contributors(env: &Env, bounty_id: u64) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Contributors(bounty_id))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Set everyone who submitted to a bounty
+    pub fn set_contributors(env: &Env, bounty_id: u64, contributors: &Vec<Address>) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Contributors(bounty_id), contributors);
+    }
+}
@@ -0,0 +1,216 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger}, token, vec, Address, BytesN, Env, String,
+};
+use shared::{roles::RoleLevel, time::DAY};
+
+use crate::{
+    Bounties, BountiesClient, BountiesError, BountyKind, BountyStatus, BountyTerms, SubmissionStatus,
+};
+
+const REWARD: i128 = 5_000;
+
+fn setup_test_env() -> (
+    Env,
+    BountiesClient<'static>,
+    token::Client<'static>,
+    Address,
+    Address,
+    [Address; 3],
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let poster = Address::generate(&env);
+    let contract_id = env.register(Bounties, ());
+    let client = BountiesClient::new(&env, &contract_id);
+    client.initialize(&admin, &(3 * DAY));
+    client.grant_role(&admin, &poster, &RoleLevel::Instructor);
+
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &asset.address()).mint(&poster, &(10 * REWARD));
+    let token = token::Client::new(&env, &asset.address());
+    let reviewers = core::array::from_fn(|_| Address::generate(&env));
+    (env, client, token, admin, poster, reviewers)
+}
+
+fn post(
+    env: &Env,
+    client: &BountiesClient,
+    token: &token::Client,
+    poster: &Address,
+    reviewers: &[Address; 3],
+    approvals_required: u32,
+) -> u64 {
+    let terms = BountyTerms {
+        kind: BountyKind::Translation,
+        uri: String::from_str(env, "ipfs://translate-rust101-es"),
+        token: token.address.clone(),
+        reward: REWARD,
+        reviewers: vec![env, reviewers[0].clone(), reviewers[1].clone(), reviewers[2].clone()],
+        approvals_required,
+        deadline: 7 * DAY,
+    };
+    client.post_bounty(poster, &terms)
+}
+
+fn submit(env: &Env, client: &BountiesClient, bounty_id: u64) -> Address {
+    let contributor = Address::generate(env);
+    let work = BytesN::from_array(env, &[1; 32]);
+    client.submit(&contributor, &bounty_id, &work, &String::from_str(env, "ipfs://work"));
+    contributor
+}
+
+#[test]
+fn test_approvals_release_reward_automatically() {
+    let (env, client, token, _admin, poster, reviewers) = setup_test_env();
+    let terms = BountyTerms {
+        kind: BountyKind::CourseModule,
+        uri: String::from_str(&env, "ipfs://module"),
+        token: token.address.clone(),
+        reward: REWARD,
+        reviewers: vec![&env, reviewers[0].clone()],
+        approvals_required: 2,
+        deadline: 7 * DAY,
+    };
+    assert_eq!(client.try_post_bounty(&poster, &terms), Err(Ok(BountiesError::InvalidBounty)));
+    assert_eq!(
+        client.try_post_bounty(&reviewers[0], &terms),
+        Err(Ok(BountiesError::Unauthorized))
+    );
+
+    let id = post(&env, &client, &token, &poster, &reviewers, 2);
+    assert_eq!(token.balance(&client.address), REWARD);
+    let contributor = submit(&env, &client, id);
+    let work = BytesN::from_array(&env, &[2; 32]);
+    let uri = String::from_str(&env, "ipfs://mine");
+    assert_eq!(
+        client.try_submit(&reviewers[0], &id, &work, &uri),
+        Err(Ok(BountiesError::Unauthorized))
+    );
+
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_review(&outsider, &id, &contributor, &true),
+        Err(Ok(BountiesError::NotReviewer))
+    );
+    assert_eq!(client.review(&reviewers[0], &id, &contributor, &true), SubmissionStatus::Pending);
+    assert_eq!(
+        client.try_review(&reviewers[0], &id, &contributor, &true),
+        Err(Ok(BountiesError::AlreadyReviewed))
+    );
+    assert_eq!(client.review(&reviewers[1], &id, &contributor, &true), SubmissionStatus::Approved);
+
+    assert_eq!(token.balance(&contributor), REWARD);
+    let bounty = client.get_bounty(&id).unwrap();
+    assert_eq!(bounty.status, BountyStatus::Awarded);
+    assert_eq!(bounty.winner, Some(contributor));
+    assert_eq!(
+        client.try_submit(&outsider, &id, &work, &uri),
+        Err(Ok(BountiesError::BountyClosed))
+    );
+}
+
+#[test]
+fn test_rejected_work_can_be_disputed() {
+    let (env, client, token, admin, poster, reviewers) = setup_test_env();
+    let id = post(&env, &client, &token, &poster, &reviewers, 2);
+    let contributor = submit(&env, &client, id);
+
+    client.review(&reviewers[0], &id, &contributor, &false);
+    assert_eq!(client.review(&reviewers[1], &id, &contributor, &false), SubmissionStatus::Rejected);
+    // The poster cannot pull the reward while the rejection can be disputed
+    assert_eq!(
+        client.try_cancel_bounty(&poster, &id),
+        Err(Ok(BountiesError::SubmissionsOpen))
+    );
+
+    client.dispute(&contributor, &id);
+    assert_eq!(client.try_dispute(&contributor, &id), Err(Ok(BountiesError::NotRejected)));
+    assert_eq!(
+        client.try_resolve_dispute(&poster, &id, &contributor, &true),
+        Err(Ok(BountiesError::Unauthorized))
+    );
+    client.resolve_dispute(&admin, &id, &contributor, &true);
+    assert_eq!(token.balance(&contributor), REWARD);
+    assert_eq!(client.get_bounty(&id).unwrap().status, BountyStatus::Awarded);
+}
+
+#[test]
+fn test_deadlines_windows_and_cancellation() {
+    let (env, client, token, _admin, poster, reviewers) = setup_test_env();
+    let id = post(&env, &client, &token, &poster, &reviewers, 1);
+    let contributor = submit(&env, &client, id);
+    client.review(&reviewers[0], &id, &contributor, &false);
+    client.review(&reviewers[1], &id, &contributor, &false);
+    client.review(&reviewers[2], &id, &contributor, &false);
+
+    env.ledger().set_timestamp(7 * DAY + 1);
+    let work = BytesN::from_array(&env, &[3; 32]);
+    let late = Address::generate(&env);
+    assert_eq!(
+        client.try_submit(&late, &id, &work, &String::from_str(&env, "ipfs://late")),
+        Err(Ok(BountiesError::DeadlinePassed))
+    );
+    assert_eq!(client.try_dispute(&contributor, &id), Err(Ok(BountiesError::DisputeWindowClosed)));
+
+    client.cancel_bounty(&poster, &id);
+    assert_eq!(token.balance(&poster), 10 * REWARD);
+    assert_eq!(client.get_bounty(&id).unwrap().status, BountyStatus::Cancelled);
+}
+
+#[test]
+fn test_submission_review_and_cancellation_errors() {
+    let (env, client, token, admin, poster, reviewers) = setup_test_env();
+    assert_eq!(client.try_initialize(&admin, &DAY), Err(Ok(BountiesError::AlreadyInitialized)));
+    let id = post(&env, &client, &token, &poster, &reviewers, 2);
+    let contributor = submit(&env, &client, id);
+    let outsider = Address::generate(&env);
+    let work = BytesN::from_array(&env, &[4; 32]);
+    let uri = String::from_str(&env, "ipfs://again");
+
+    assert_eq!(
+        client.try_submit(&contributor, &id, &work, &uri),
+        Err(Ok(BountiesError::AlreadySubmitted))
+    );
+    assert_eq!(
+        client.try_submit(&outsider, &99, &work, &uri),
+        Err(Ok(BountiesError::BountyNotFound))
+    );
+    assert_eq!(
+        client.try_review(&reviewers[0], &id, &outsider, &true),
+        Err(Ok(BountiesError::SubmissionNotFound))
+    );
+
+    // Two rejections out of three reviewers leave the submission short of two approvals
+    client.review(&reviewers[0], &id, &contributor, &false);
+    assert_eq!(client.review(&reviewers[1], &id, &contributor, &false), SubmissionStatus::Rejected);
+    assert_eq!(
+        client.try_review(&reviewers[2], &id, &contributor, &true),
+        Err(Ok(BountiesError::SubmissionClosed))
+    );
+    assert_eq!(
+        client.try_resolve_dispute(&admin, &id, &contributor, &true),
+        Err(Ok(BountiesError::NotDisputed))
+    );
+    assert_eq!(client.try_dispute(&outsider, &id), Err(Ok(BountiesError::SubmissionNotFound)));
+    assert_eq!(client.try_cancel_bounty(&outsider, &id), Err(Ok(BountiesError::Unauthorized)));
+
+    // A dismissed dispute is final and frees the bounty for cancellation
+    client.dispute(&contributor, &id);
+    client.resolve_dispute(&admin, &id, &contributor, &false);
+    assert_eq!(
+        client.try_resolve_dispute(&admin, &id, &contributor, &true),
+        Err(Ok(BountiesError::NotDisputed))
+    );
+    assert_eq!(client.try_dispute(&contributor, &id), Err(Ok(BountiesError::NotRejected)));
+    assert_eq!(token.balance(&contributor), 0);
+
+    client.cancel_bounty(&poster, &id);
+    assert_eq!(token.balance(&poster), 10 * REWARD);
+    assert_eq!(client.try_cancel_bounty(&poster, &id), Err(Ok(BountiesError::BountyClosed)));
+    assert_eq!(
+        client.try_cancel_bounty(&poster, &99),
+        Err(Ok(BountiesError::BountyNotFound))
+    );
+}
@@ -0,0 +1,83 @@
+use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
+
+/// What a bounty asks for
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum BountyKind {
+    CourseModule,
+    Translation,
+    Other,
+}
+
+/// Terms a bounty is posted with
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct BountyTerms {
+    pub kind: BountyKind,
+    pub uri: String, // full task description
+    pub token: Address,
+    pub reward: i128,
+    pub reviewers: Vec<Address>,
+    pub approvals_required: u32,
+    pub deadline: u64, // last moment to submit
+}
+
+/// Lifecycle of a bounty
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum BountyStatus {
+    Open,
+    Awarded,
+    Cancelled,
+}
+
+/// A posted bounty and its locked reward
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Bounty {
+    pub id: u64,
+    pub poster: Address,
+    pub terms: BountyTerms,
+    pub status: BountyStatus,
+    pub winner: Option<Address>,
+    pub open_submissions: u32, // pending or disputed
+    pub created_at: u64,
+}
+
+/// Review state of a submission
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum SubmissionStatus {
+    Pending,
+    Approved,
+    Rejected,
+    Disputed,
+    Closed, // another submission won, or the dispute was dismissed
+}
+
+/// A contributor's work for a bounty
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Submission {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub work_hash: BytesN<32>,
+    pub uri: String,
+    pub submitted_at: u64,
+    pub approvals: Vec<Address>,
+    pub rejections: Vec<Address>,
+    pub status: SubmissionStatus,
+    pub decided_at: u64,
+}
+
+/// Storage keys for the bounties contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    DisputeWindow,
+    BountyCount,
+    Bounty(u64),
+    Submission(u64, Address),
+    Contributors(u64), // everyone who submitted to a bounty
+}
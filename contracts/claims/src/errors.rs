@@ -0,0 +1,26 @@
+use soroban_sdk::contracterror;
+
+/// Claims contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ClaimsError {
+    // Initialization errors
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+
+    // Authorization errors
+    Unauthorized = 3,
+
+    // Campaign errors
+    InvalidCampaign = 4,
+    CampaignNotFound = 5,
+    DeadlinePassed = 6,
+    DeadlineNotReached = 7,
+    AlreadySwept = 8,
+
+    // Claim errors
+    AlreadyClaimed = 9,
+    InvalidProof = 10,
+    InsufficientFunds = 11,
+}
@@ -0,0 +1,35 @@
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+use crate::types::Entitlement;
+
+/// Claims contract events
+pub struct ClaimsEvents;
+
+impl ClaimsEvents {
+    fn topic(env: &Env, name: &str) -> (Symbol, Symbol) {
+        (Symbol::new(env, "claims"), Symbol::new(env, name))
+    }
+
+    /// Emit event when a campaign's root is published
+    pub fn emit_campaign_created(env: &Env, campaign_id: u64, root: &BytesN<32>, funded: i128, deadline: u64) {
+        env.events().publish(
+            Self::topic(env, "campaign_created"),
+            (campaign_id, root.clone(), funded, deadline),
+        );
+    }
+
+    /// Emit event when an address claims its entitlement
+    pub fn emit_claimed(env: &Env, campaign_id: u64, claimant: &Address, entitlement: &Entitlement) {
+        env.events().publish(
+            Self::topic(env, "claimed"),
+            (campaign_id, claimant.clone(), entitlement.clone()),
+        );
+    }
+
+    /// Emit event when unclaimed tokens are swept to the treasury
+    pub fn emit_swept(env: &Env, campaign_id: u64, treasury: &Address, amount: i128) {
+        env.events().publish(
+            Self::topic(env, "swept"),
+            (campaign_id, treasury.clone(), amount),
+        );
+    }
+}
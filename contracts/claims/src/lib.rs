@@ -0,0 +1,188 @@
+#![no_std]
+
+mod types;
+mod errors;
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod tests;
+
+use soroban_sdk::{contract, contractimpl, token, xdr::ToXdr, Address, BytesN, Env, Vec};
+
+use shared::{
+    access_control::AccessControl,
+    merkle::Merkle,
+    reentrancy_guard::ReentrancyLock,
+    time::Clock,
+};
+
+pub use types::{Campaign, CampaignTerms, Entitlement};
+pub use errors::ClaimsError;
+use events::ClaimsEvents;
+use storage::ClaimsStorage;
+
+/// Leaf committed to in a campaign tree: the SHA-256 of the XDR encoding of
+/// the `(claimant, entitlement)` tuple
+pub fn claim_leaf(env: &Env, claimant: &Address, entitlement: &Entitlement) -> BytesN<32> {
+    Merkle::hash_leaf(env, &(claimant.clone(), entitlement.clone()).to_xdr(env))
+}
+
+#[contract]
+pub struct Claims;
+
+#[contractimpl]
+impl Claims {
+    /// Initialize the contract with the treasury unclaimed tokens go to
+    pub fn initialize(env: Env, admin: Address, treasury: Address) -> Result<(), ClaimsError> {
+        if ClaimsStorage::get_admin(&env).is_some() {
+            return Err(ClaimsError::AlreadyInitialized);
+        }
+
+        AccessControl::initialize(&env, &admin).map_err(|_| ClaimsError::AlreadyInitialized)?;
+        ClaimsStorage::set_admin(&env, &admin);
+        ClaimsStorage::set_treasury(&env, &treasury);
+
+        Ok(())
+    }
+
+    /// Change the treasury (admin only)
+    pub fn set_treasury(env: Env, admin: Address, treasury: Address) -> Result<(), ClaimsError> {
+        Self::require_admin(&env, &admin)?;
+        ClaimsStorage::set_treasury(&env, &treasury);
+        Ok(())
+    }
+
+    /// Publish a Merkle root and deposit the tokens it distributes
+    pub fn create_campaign(env: Env, admin: Address, terms: CampaignTerms) -> Result<u64, ClaimsError> {
+        let _guard = ReentrancyLock::new(&env);
+        Self::require_admin(&env, &admin)?;
+
+        if terms.amount < 0 || Clock::has_passed(&env, terms.deadline) {
+            return Err(ClaimsError::InvalidCampaign);
+        }
+        if terms.amount > 0 {
+            token::Client::new(&env, &terms.token).transfer(&admin, &env.current_contract_address(), &terms.amount);
+        }
+
+        let campaign = Campaign {
+            id: ClaimsStorage::next_campaign_id(&env),
+            token: terms.token,
+            root: terms.root,
+            funded: terms.amount,
+            claimed: 0,
+            claim_count: 0,
+            deadline: terms.deadline,
+            swept: false,
+        };
+        ClaimsStorage::set_campaign(&env, &campaign);
+        ClaimsEvents::emit_campaign_created(&env, campaign.id, &campaign.root, campaign.funded, campaign.deadline);
+
+        Ok(campaign.id)
+    }
+
+    /// Claim an entitlement by proving its leaf is in the campaign tree.
+    /// Each address claims once per campaign, before the deadline.
+    pub fn claim(
+        env: Env,
+        claimant: Address,
+        campaign_id: u64,
+        entitlement: Entitlement,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<(), ClaimsError> {
+        let _guard = ReentrancyLock::new(&env);
+        claimant.require_auth();
+
+        let mut campaign = Self::load_campaign(&env, campaign_id)?;
+        if Clock::has_passed(&env, campaign.deadline) {
+            return Err(ClaimsError::DeadlinePassed);
+        }
+        if ClaimsStorage::get_claim(&env, campaign_id, &claimant).is_some() {
+            return Err(ClaimsError::AlreadyClaimed);
+        }
+        if !Merkle::verify(&env, &campaign.root, &claim_leaf(&env, &claimant, &entitlement), &proof) {
+            return Err(ClaimsError::InvalidProof);
+        }
+
+        if let Entitlement::Tokens(amount) = entitlement {
+            if amount <= 0 || amount > campaign.funded - campaign.claimed {
+                return Err(ClaimsError::InsufficientFunds);
+            }
+            campaign.claimed += amount;
+            token::Client::new(&env, &campaign.token).transfer(&env.current_contract_address(), &claimant, &amount);
+        }
+
+        campaign.claim_count += 1;
+        ClaimsStorage::set_campaign(&env, &campaign);
+        ClaimsStorage::set_claim(&env, campaign_id, &claimant, &entitlement);
+        ClaimsEvents::emit_claimed(&env, campaign_id, &claimant, &entitlement);
+
+        Ok(())
+    }
+
+    /// After the deadline, send whatever was not claimed to the treasury.
+    /// Callable by anyone.
+    pub fn sweep(env: Env, campaign_id: u64) -> Result<i128, ClaimsError> {
+        let _guard = ReentrancyLock::new(&env);
+
+        let mut campaign = Self::load_campaign(&env, campaign_id)?;
+        if campaign.swept {
+            return Err(ClaimsError::AlreadySwept);
+        }
+        if !Clock::has_passed(&env, campaign.deadline) {
+            return Err(ClaimsError::DeadlineNotReached);
+        }
+        let treasury = ClaimsStorage::get_treasury(&env).ok_or(ClaimsError::NotInitialized)?;
+
+        let remainder = campaign.funded - campaign.claimed;
+        if remainder > 0 {
+            token::Client::new(&env, &campaign.token).transfer(&env.current_contract_address(), &treasury, &remainder);
+        }
+
+        campaign.swept = true;
+        ClaimsStorage::set_campaign(&env, &campaign);
+        ClaimsEvents::emit_swept(&env, campaign_id, &treasury, remainder);
+
+        Ok(remainder)
+    }
+
+    /// Get what an address claimed from a campaign, if anything. The
+    /// certificate issuer can check certificate entitlements here.
+    pub fn get_claim(env: Env, campaign_id: u64, claimant: Address) -> Option<Entitlement> {
+        ClaimsStorage::get_claim(&env, campaign_id, &claimant)
+    }
+
+    /// Whether an address has claimed from a campaign
+    pub fn has_claimed(env: Env, campaign_id: u64, claimant: Address) -> bool {
+        ClaimsStorage::get_claim(&env, campaign_id, &claimant).is_some()
+    }
+
+    /// Get a campaign by id
+    pub fn get_campaign(env: Env, campaign_id: u64) -> Option<Campaign> {
+        ClaimsStorage::get_campaign(&env, campaign_id)
+    }
+
+    /// Get the treasury unclaimed tokens are swept to
+    pub fn get_treasury(env: Env) -> Option<Address> {
+        ClaimsStorage::get_treasury(&env)
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        ClaimsStorage::get_admin(&env)
+    }
+}
+
+impl Claims {
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), ClaimsError> {
+        caller.require_auth();
+        if !AccessControl::is_admin(env, caller) {
+            return Err(ClaimsError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    fn load_campaign(env: &Env, campaign_id: u64) -> Result<Campaign, ClaimsError> {
+        ClaimsStorage::get_campaign(env, campaign_id).ok_or(ClaimsError::CampaignNotFound)
+    }
+}
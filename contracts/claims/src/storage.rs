@@ -0,0 +1,58 @@
+use soroban_sdk::{Address, Env};
+use crate::types::{Campaign, DataKey, Entitlement};
+
+/// Storage utilities for the claims contract
+pub struct ClaimsStorage;
+
+impl ClaimsStorage {
+    /// Get the contract admin
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Set the contract admin
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Get the treasury unclaimed tokens are swept to
+    pub fn get_treasury(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Treasury)
+    }
+
+    /// Set the treasury unclaimed tokens are swept to
+    pub fn set_treasury(env: &Env, treasury: &Address) {
+        env.storage().instance().set(&DataKey::Treasury, treasury);
+    }
+
+    /// Allocate the next campaign id
+    pub fn next_campaign_id(env: &Env) -> u64 {
+        let id: u64 = env.storage().instance().get(&DataKey::CampaignCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::CampaignCount, &id);
+        id
+    }
+
+    /// Get a campaign by id
+    pub fn get_campaign(env: &Env, campaign_id: u64) -> Option<Campaign> {
+        env.storage().persistent().get(&DataKey::Campaign(campaign_id))
+    }
+
+    /// Store a campaign
+    pub fn set_campaign(env: &Env, campaign: &Campaign) {
+        env.storage().persistent().set(&DataKey::Campaign(campaign.id), campaign);
+    }
+
+    /// Get what an address claimed from a campaign
+    pub fn get_claim(env: &Env, campaign_id: u64, claimant: &Address) -> Option<Entitlement> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Claimed(campaign_id, claimant.clone()))
+    }
+
+    /// Record an address's claim on a campaign
+    pub fn set_claim(env: &Env, campaign_id: u64, claimant: &Address, entitlement: &Entitlement) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Claimed(campaign_id, claimant.clone()), entitlement);
+    }
+}
@@ -0,0 +1,168 @@
+use soroban_sdk::{
+    symbol_short, testutils::{Address as _, Ledger}, token, vec, Address, BytesN, Env, Vec,
+};
+use shared::{merkle::Merkle, time::DAY};
+
+use crate::{claim_leaf, CampaignTerms, Claims, ClaimsClient, ClaimsError, Entitlement};
+
+fn setup_test_env() -> (Env, ClaimsClient<'static>, Address, Address, token::Client<'static>) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Claims, ());
+    let client = ClaimsClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    client.initialize(&admin, &treasury);
+
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &asset.address());
+    token::StellarAssetClient::new(&env, &asset.address()).mint(&admin, &10_000);
+
+    (env, client, admin, treasury, token)
+}
+
+/// Four-leaf tree over two token and two certificate entitlements
+struct Tree {
+    claimants: [Address; 4],
+    entitlements: [Entitlement; 4],
+    leaves: [BytesN<32>; 4],
+    root: BytesN<32>,
+}
+
+fn tree(env: &Env, contract_id: &Address) -> Tree {
+    let claimants = core::array::from_fn(|_| Address::generate(env));
+    let entitlements = [
+        Entitlement::Tokens(300),
+        Entitlement::Tokens(500),
+        Entitlement::Certificate(symbol_short!("RUST101")),
+        Entitlement::Certificate(symbol_short!("ML301")),
+    ];
+    let leaves: [BytesN<32>; 4] = core::array::from_fn(|i| {
+        env.as_contract(contract_id, || claim_leaf(env, &claimants[i], &entitlements[i]))
+    });
+    let root = Merkle::compute_root(env, &Vec::from_array(env, leaves.clone())).unwrap();
+    Tree { claimants, entitlements, leaves, root }
+}
+
+/// Sibling path for a leaf of the four-leaf tree
+fn proof(env: &Env, tree: &Tree, index: usize) -> Vec<BytesN<32>> {
+    let sibling = tree.leaves[index ^ 1].clone();
+    let other_pair = if index < 2 {
+        Merkle::hash_pair(env, &tree.leaves[2], &tree.leaves[3])
+    } else {
+        Merkle::hash_pair(env, &tree.leaves[0], &tree.leaves[1])
+    };
+    vec![env, sibling, other_pair]
+}
+
+fn campaign(client: &ClaimsClient, admin: &Address, token: &token::Client, tree: &Tree) -> u64 {
+    let terms = CampaignTerms {
+        token: token.address.clone(),
+        root: tree.root.clone(),
+        amount: 1_000,
+        deadline: 30 * DAY,
+    };
+    client.create_campaign(admin, &terms)
+}
+
+#[test]
+fn test_claims_with_valid_proofs() {
+    let (env, client, admin, _treasury, token) = setup_test_env();
+    let t = tree(&env, &client.address);
+    let campaign_id = campaign(&client, &admin, &token, &t);
+
+    for i in 0..4 {
+        client.claim(&t.claimants[i], &campaign_id, &t.entitlements[i], &proof(&env, &t, i));
+    }
+    assert_eq!(token.balance(&t.claimants[0]), 300);
+    assert_eq!(token.balance(&t.claimants[1]), 500);
+    assert_eq!(
+        client.get_claim(&campaign_id, &t.claimants[2]),
+        Some(Entitlement::Certificate(symbol_short!("RUST101")))
+    );
+
+    let campaign = client.get_campaign(&campaign_id).unwrap();
+    assert_eq!(campaign.claimed, 800);
+    assert_eq!(campaign.claim_count, 4);
+    assert_eq!(
+        client.try_claim(&t.claimants[0], &campaign_id, &t.entitlements[0], &proof(&env, &t, 0)),
+        Err(Ok(ClaimsError::AlreadyClaimed))
+    );
+}
+
+#[test]
+fn test_forged_claims_are_rejected() {
+    let (env, client, admin, _treasury, token) = setup_test_env();
+    let t = tree(&env, &client.address);
+    let campaign_id = campaign(&client, &admin, &token, &t);
+
+    // Inflated amount, someone else's leaf, and a stranger
+    assert_eq!(
+        client.try_claim(&t.claimants[0], &campaign_id, &Entitlement::Tokens(900), &proof(&env, &t, 0)),
+        Err(Ok(ClaimsError::InvalidProof))
+    );
+    assert_eq!(
+        client.try_claim(&t.claimants[0], &campaign_id, &t.entitlements[1], &proof(&env, &t, 1)),
+        Err(Ok(ClaimsError::InvalidProof))
+    );
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_claim(&stranger, &campaign_id, &t.entitlements[0], &proof(&env, &t, 0)),
+        Err(Ok(ClaimsError::InvalidProof))
+    );
+    assert!(!client.has_claimed(&campaign_id, &t.claimants[0]));
+}
+
+#[test]
+fn test_unclaimed_remainder_swept_after_deadline() {
+    let (env, client, admin, treasury, token) = setup_test_env();
+    let t = tree(&env, &client.address);
+    let campaign_id = campaign(&client, &admin, &token, &t);
+    client.claim(&t.claimants[0], &campaign_id, &t.entitlements[0], &proof(&env, &t, 0));
+
+    assert_eq!(client.try_sweep(&campaign_id), Err(Ok(ClaimsError::DeadlineNotReached)));
+
+    env.ledger().set_timestamp(30 * DAY + 1);
+    assert_eq!(
+        client.try_claim(&t.claimants[1], &campaign_id, &t.entitlements[1], &proof(&env, &t, 1)),
+        Err(Ok(ClaimsError::DeadlinePassed))
+    );
+    assert_eq!(client.sweep(&campaign_id), 700);
+    assert_eq!(token.balance(&treasury), 700);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(client.try_sweep(&campaign_id), Err(Ok(ClaimsError::AlreadySwept)));
+}
+
+#[test]
+fn test_campaign_funding_error_paths() {
+    let (env, client, admin, _treasury, token) = setup_test_env();
+    let t = tree(&env, &client.address);
+    let mut terms = CampaignTerms {
+        token: token.address.clone(),
+        root: t.root.clone(),
+        amount: 400,
+        deadline: 30 * DAY,
+    };
+
+    assert_eq!(client.try_create_campaign(&t.claimants[0], &terms), Err(Ok(ClaimsError::Unauthorized)));
+    assert_eq!(client.try_set_treasury(&t.claimants[0], &t.claimants[0]), Err(Ok(ClaimsError::Unauthorized)));
+    env.ledger().set_timestamp(DAY);
+    terms.deadline = DAY - 1;
+    assert_eq!(client.try_create_campaign(&admin, &terms), Err(Ok(ClaimsError::InvalidCampaign)));
+    terms.deadline = 30 * DAY;
+    assert_eq!(
+        client.try_claim(&t.claimants[0], &99, &t.entitlements[0], &proof(&env, &t, 0)),
+        Err(Ok(ClaimsError::CampaignNotFound))
+    );
+
+    // An underfunded campaign pays claims until its deposit runs out
+    let campaign_id = client.create_campaign(&admin, &terms);
+    client.claim(&t.claimants[0], &campaign_id, &t.entitlements[0], &proof(&env, &t, 0));
+    assert_eq!(
+        client.try_claim(&t.claimants[1], &campaign_id, &t.entitlements[1], &proof(&env, &t, 1)),
+        Err(Ok(ClaimsError::InsufficientFunds))
+    );
+    assert!(!client.has_claimed(&campaign_id, &t.claimants[1]));
+    assert_eq!(token.balance(&client.address), 100);
+}
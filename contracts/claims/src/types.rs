@@ -0,0 +1,44 @@
+use soroban_sdk::{contracttype, Address, BytesN, Symbol};
+
+/// What a Merkle leaf entitles its address to
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum Entitlement {
+    Tokens(i128),
+    Certificate(Symbol), // recorded on-chain for the certificate issuer to honour
+}
+
+/// Parameters for a new campaign
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CampaignTerms {
+    pub token: Address,
+    pub root: BytesN<32>,
+    pub amount: i128, // tokens deposited to cover token entitlements
+    pub deadline: u64,
+}
+
+/// A published Merkle distribution
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Campaign {
+    pub id: u64,
+    pub token: Address,
+    pub root: BytesN<32>,
+    pub funded: i128,
+    pub claimed: i128,
+    pub claim_count: u32,
+    pub deadline: u64,
+    pub swept: bool,
+}
+
+/// Storage keys for the claims contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Treasury,
+    CampaignCount,
+    Campaign(u64),
+    Claimed(u64, Address),
+}
@@ -0,0 +1,39 @@
+use soroban_sdk::contracterror;
+
+/// Competition contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CompetitionError {
+    // Initialization errors
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+
+    // Authorization errors
+    Unauthorized = 3,
+
+    // Competition errors
+    InvalidTerms = 4,
+    CompetitionNotFound = 5,
+    InvalidAmount = 6,
+    AlreadyFinalized = 7,
+
+    // Registration errors
+    RegistrationClosed = 8,
+    AlreadyRegistered = 9,
+    TooManyTeams = 10,
+    TeamNotFound = 11,
+    TeamFull = 12,
+    NotInvited = 13,
+
+    // Submission errors
+    SubmissionClosed = 14,
+    NotSubmitted = 15,
+
+    // Judging errors
+    NotJudge = 16,
+    JudgingClosed = 17,
+    AlreadyScored = 18,
+    InvalidScores = 19,
+    JudgingOpen = 20,
+}
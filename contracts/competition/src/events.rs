@@ -0,0 +1,58 @@
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+/// Competition contract events
+pub struct CompetitionEvents;
+
+impl CompetitionEvents {
+    fn topic(env: &Env, name: &str) -> (Symbol, Symbol) {
+        (Symbol::new(env, "competition"), Symbol::new(env, name))
+    }
+
+    /// Emit event when a competition is created
+    pub fn emit_created(env: &Env, competition_id: u64, organizer: &Address) {
+        env.events().publish(
+            Self::topic(env, "created"),
+            (competition_id, organizer.clone()),
+        );
+    }
+
+    /// Emit event when the prize pool is topped up
+    pub fn emit_funded(env: &Env, competition_id: u64, funder: &Address, prize_pool: i128) {
+        env.events().publish(
+            Self::topic(env, "funded"),
+            (competition_id, funder.clone(), prize_pool),
+        );
+    }
+
+    /// Emit event when a participant creates or joins a team
+    pub fn emit_joined(env: &Env, competition_id: u64, team_id: u32, participant: &Address) {
+        env.events().publish(
+            Self::topic(env, "joined"),
+            (competition_id, team_id, participant.clone()),
+        );
+    }
+
+    /// Emit event when a team commits to its submission
+    pub fn emit_submitted(env: &Env, competition_id: u64, team_id: u32, commitment: &BytesN<32>) {
+        env.events().publish(
+            Self::topic(env, "submitted"),
+            (competition_id, team_id, commitment.clone()),
+        );
+    }
+
+    /// Emit event when a judge scores a team
+    pub fn emit_scored(env: &Env, competition_id: u64, team_id: u32, judge: &Address, weighted: u64) {
+        env.events().publish(
+            Self::topic(env, "scored"),
+            (competition_id, team_id, judge.clone(), weighted),
+        );
+    }
+
+    /// Emit event when a team places and receives its prize
+    pub fn emit_placed(env: &Env, competition_id: u64, place: u32, team_id: u32, prize: i128) {
+        env.events().publish(
+            Self::topic(env, "placed"),
+            (competition_id, place, team_id, prize),
+        );
+    }
+}
@@ -0,0 +1,447 @@
+#![no_std]
+
+mod types;
+mod errors;
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod tests;
+
+use soroban_sdk::{contract, contractimpl, token, vec, Address, BytesN, Env, IntoVal, Symbol, Vec};
+
+use shared::{
+    access_control::AccessControl,
+    cross_contract::CrossContract,
+    fees::{Fees, BASIS_POINTS},
+    reentrancy_guard::ReentrancyLock,
+    roles::{Permission, RoleLevel},
+    time::Clock,
+};
+
+pub use types::{Competition, CompetitionTerms, Criterion, Placement, Team};
+pub use errors::CompetitionError;
+use events::CompetitionEvents;
+use storage::CompetitionStorage;
+
+/// Highest score a judge can give on one criterion
+pub const MAX_SCORE: u32 = 100;
+
+const MAX_TEAM_SIZE: u32 = 10;
+const MAX_TEAMS: u32 = 100;
+const MAX_JUDGES: u32 = 10;
+const MAX_CRITERIA: u32 = 10;
+const MAX_PLACES: u32 = 10;
+
+#[contract]
+pub struct CompetitionContract;
+
+#[contractimpl]
+impl CompetitionContract {
+    /// Initialize the contract
+    pub fn initialize(env: Env, admin: Address) -> Result<(), CompetitionError> {
+        if CompetitionStorage::get_admin(&env).is_some() {
+            return Err(CompetitionError::AlreadyInitialized);
+        }
+
+        AccessControl::initialize(&env, &admin).map_err(|_| CompetitionError::AlreadyInitialized)?;
+        CompetitionStorage::set_admin(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Grant a role in this contract (requires GrantRole)
+    pub fn grant_role(env: Env, admin: Address, user: Address, level: RoleLevel) -> Result<(), CompetitionError> {
+        admin.require_auth();
+        AccessControl::grant_role(&env, &admin, &user, level).map_err(|_| CompetitionError::Unauthorized)
+    }
+
+    /// Revoke a user's role in this contract (requires RevokeRole)
+    pub fn revoke_role(env: Env, admin: Address, user: Address) -> Result<(), CompetitionError> {
+        admin.require_auth();
+        AccessControl::revoke_role(&env, &admin, &user).map_err(|_| CompetitionError::Unauthorized)
+    }
+
+    /// Set the Badges contract winners are awarded through (admin only).
+    /// This contract must be an issuer there.
+    pub fn set_badges(env: Env, admin: Address, badges: Address) -> Result<(), CompetitionError> {
+        admin.require_auth();
+        if !AccessControl::is_admin(&env, &admin) {
+            return Err(CompetitionError::Unauthorized);
+        }
+        CompetitionStorage::set_badges(&env, &badges);
+        Ok(())
+    }
+
+    /// Create a competition (requires CreateCourse). Returns its id.
+    pub fn create_competition(
+        env: Env,
+        organizer: Address,
+        terms: CompetitionTerms,
+        judges: Vec<Address>,
+    ) -> Result<u64, CompetitionError> {
+        organizer.require_auth();
+        AccessControl::require_permission(&env, &organizer, &Permission::CreateCourse)
+            .map_err(|_| CompetitionError::Unauthorized)?;
+        Self::validate_terms(&env, &terms, &judges)?;
+
+        let competition = Competition {
+            id: CompetitionStorage::next_competition_id(&env),
+            organizer: organizer.clone(),
+            terms,
+            judges,
+            prize_pool: 0,
+            team_count: 0,
+            finalized: false,
+        };
+        CompetitionStorage::set_competition(&env, &competition);
+        CompetitionEvents::emit_created(&env, competition.id, &organizer);
+
+        Ok(competition.id)
+    }
+
+    /// Add to a competition's prize pool. Anyone may fund it, such as the
+    /// organizer or a sponsor.
+    pub fn fund(env: Env, funder: Address, competition_id: u64, amount: i128) -> Result<(), CompetitionError> {
+        funder.require_auth();
+        if amount <= 0 {
+            return Err(CompetitionError::InvalidAmount);
+        }
+        let mut competition = Self::load(&env, competition_id)?;
+
+        token::Client::new(&env, &competition.terms.token).transfer(&funder, &env.current_contract_address(), &amount);
+        competition.prize_pool += amount;
+        CompetitionStorage::set_competition(&env, &competition);
+        CompetitionEvents::emit_funded(&env, competition_id, &funder, competition.prize_pool);
+
+        Ok(())
+    }
+
+    /// Register a new team led by the caller. Returns the team id.
+    pub fn create_team(env: Env, leader: Address, competition_id: u64, name: Symbol) -> Result<u32, CompetitionError> {
+        leader.require_auth();
+        let mut competition = Self::load(&env, competition_id)?;
+        Self::require_can_register(&env, &competition, &leader)?;
+        if competition.team_count >= MAX_TEAMS {
+            return Err(CompetitionError::TooManyTeams);
+        }
+
+        competition.team_count += 1;
+        let team = Team {
+            id: competition.team_count,
+            name,
+            leader: leader.clone(),
+            members: vec![&env, leader.clone()],
+            submitted: false,
+            score_total: 0,
+            judge_count: 0,
+        };
+        CompetitionStorage::set_team(&env, competition_id, &team);
+        CompetitionStorage::set_membership(&env, competition_id, &leader, team.id);
+        CompetitionStorage::set_competition(&env, &competition);
+        CompetitionEvents::emit_joined(&env, competition_id, team.id, &leader);
+
+        Ok(team.id)
+    }
+
+    /// Invite a participant to the caller's team (team leader only)
+    pub fn invite(
+        env: Env,
+        leader: Address,
+        competition_id: u64,
+        team_id: u32,
+        participant: Address,
+    ) -> Result<(), CompetitionError> {
+        leader.require_auth();
+        let competition = Self::load(&env, competition_id)?;
+        Self::require_registration_open(&env, &competition)?;
+        let team = Self::load_team(&env, competition_id, team_id)?;
+        if team.leader != leader {
+            return Err(CompetitionError::Unauthorized);
+        }
+
+        CompetitionStorage::set_invited(&env, competition_id, team_id, &participant, true);
+        Ok(())
+    }
+
+    /// Accept an invitation and join a team
+    pub fn join_team(env: Env, participant: Address, competition_id: u64, team_id: u32) -> Result<(), CompetitionError> {
+        participant.require_auth();
+        let competition = Self::load(&env, competition_id)?;
+        Self::require_can_register(&env, &competition, &participant)?;
+        let mut team = Self::load_team(&env, competition_id, team_id)?;
+        if !CompetitionStorage::is_invited(&env, competition_id, team_id, &participant) {
+            return Err(CompetitionError::NotInvited);
+        }
+        if team.members.len() >= competition.terms.max_team_size {
+            return Err(CompetitionError::TeamFull);
+        }
+
+        team.members.push_back(participant.clone());
+        CompetitionStorage::set_team(&env, competition_id, &team);
+        CompetitionStorage::set_membership(&env, competition_id, &participant, team_id);
+        CompetitionStorage::set_invited(&env, competition_id, team_id, &participant, false);
+        CompetitionEvents::emit_joined(&env, competition_id, team_id, &participant);
+
+        Ok(())
+    }
+
+    /// Commit to the team's submission by its hash (team leader only). The
+    /// commitment can be replaced until submissions close.
+    pub fn submit(
+        env: Env,
+        leader: Address,
+        competition_id: u64,
+        team_id: u32,
+        commitment: BytesN<32>,
+    ) -> Result<(), CompetitionError> {
+        leader.require_auth();
+        let competition = Self::load(&env, competition_id)?;
+        if Clock::now(&env) > competition.terms.submission_ends {
+            return Err(CompetitionError::SubmissionClosed);
+        }
+        let mut team = Self::load_team(&env, competition_id, team_id)?;
+        if team.leader != leader {
+            return Err(CompetitionError::Unauthorized);
+        }
+
+        team.submitted = true;
+        CompetitionStorage::set_team(&env, competition_id, &team);
+        CompetitionStorage::set_commitment(&env, competition_id, team_id, &commitment);
+        CompetitionEvents::emit_submitted(&env, competition_id, team_id, &commitment);
+
+        Ok(())
+    }
+
+    /// Score a submitted team on every criterion, from 0 to `MAX_SCORE`
+    /// each (judges only, once per team, between the submission and
+    /// judging deadlines)
+    pub fn score(
+        env: Env,
+        judge: Address,
+        competition_id: u64,
+        team_id: u32,
+        scores: Vec<u32>,
+    ) -> Result<(), CompetitionError> {
+        judge.require_auth();
+        let competition = Self::load(&env, competition_id)?;
+        if !competition.judges.contains(&judge) {
+            return Err(CompetitionError::NotJudge);
+        }
+        let now = Clock::now(&env);
+        if now <= competition.terms.submission_ends || now > competition.terms.judging_ends {
+            return Err(CompetitionError::JudgingClosed);
+        }
+        let mut team = Self::load_team(&env, competition_id, team_id)?;
+        if !team.submitted {
+            return Err(CompetitionError::NotSubmitted);
+        }
+        if CompetitionStorage::has_scored(&env, competition_id, team_id, &judge) {
+            return Err(CompetitionError::AlreadyScored);
+        }
+
+        let criteria = &competition.terms.criteria;
+        if scores.len() != criteria.len() {
+            return Err(CompetitionError::InvalidScores);
+        }
+        let mut weighted = 0u64;
+        for (criterion, score) in criteria.iter().zip(scores.iter()) {
+            if score > MAX_SCORE {
+                return Err(CompetitionError::InvalidScores);
+            }
+            weighted += criterion.weight as u64 * score as u64;
+        }
+
+        team.score_total += weighted;
+        team.judge_count += 1;
+        CompetitionStorage::set_team(&env, competition_id, &team);
+        CompetitionStorage::set_scored(&env, competition_id, team_id, &judge);
+        CompetitionEvents::emit_scored(&env, competition_id, team_id, &judge, weighted);
+
+        Ok(())
+    }
+
+    /// Rank the scored teams once judging has closed, pay each placed team
+    /// its share of the prize pool split evenly between its members, and
+    /// award the competition badge to them. Shares of places nobody took
+    /// return to the organizer. Callable by anyone.
+    pub fn finalize(env: Env, competition_id: u64) -> Result<Vec<Placement>, CompetitionError> {
+        let _guard = ReentrancyLock::new(&env);
+        let mut competition = Self::load(&env, competition_id)?;
+        if Clock::now(&env) <= competition.terms.judging_ends {
+            return Err(CompetitionError::JudgingOpen);
+        }
+
+        let token = token::Client::new(&env, &competition.terms.token);
+        let contract = env.current_contract_address();
+        let ranking = Self::rank(&env, &competition);
+        let mut results = Vec::new(&env);
+        let mut paid = 0;
+        for (place, (team, score)) in ranking.iter().enumerate() {
+            let share = competition.terms.prize_bps.get(place as u32).unwrap_or(0);
+            let prize = Fees::portion(competition.prize_pool, share);
+            let per_member = prize / team.members.len() as i128;
+            if per_member > 0 {
+                for member in team.members.iter() {
+                    token.transfer(&contract, &member, &per_member);
+                }
+            }
+            paid += per_member * team.members.len() as i128;
+            Self::award_badges(&env, &competition, &team);
+
+            results.push_back(Placement { team_id: team.id, score, prize });
+            CompetitionEvents::emit_placed(&env, competition_id, place as u32 + 1, team.id, prize);
+        }
+
+        let remainder = competition.prize_pool - paid;
+        if remainder > 0 {
+            token.transfer(&contract, &competition.organizer, &remainder);
+        }
+        competition.finalized = true;
+        CompetitionStorage::set_competition(&env, &competition);
+        CompetitionStorage::set_results(&env, competition_id, &results);
+
+        Ok(results)
+    }
+
+    /// Get a competition
+    pub fn get_competition(env: Env, competition_id: u64) -> Option<Competition> {
+        CompetitionStorage::get_competition(&env, competition_id)
+    }
+
+    /// Get a team
+    pub fn get_team(env: Env, competition_id: u64, team_id: u32) -> Option<Team> {
+        CompetitionStorage::get_team(&env, competition_id, team_id)
+    }
+
+    /// Get a team's submission commitment
+    pub fn get_commitment(env: Env, competition_id: u64, team_id: u32) -> Option<BytesN<32>> {
+        CompetitionStorage::get_commitment(&env, competition_id, team_id)
+    }
+
+    /// Get the team a participant belongs to
+    pub fn get_membership(env: Env, competition_id: u64, participant: Address) -> Option<u32> {
+        CompetitionStorage::get_membership(&env, competition_id, &participant)
+    }
+
+    /// Get a finalized competition's placements, first place first
+    pub fn get_results(env: Env, competition_id: u64) -> Vec<Placement> {
+        CompetitionStorage::get_results(&env, competition_id)
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        CompetitionStorage::get_admin(&env)
+    }
+}
+
+impl CompetitionContract {
+    fn validate_terms(env: &Env, terms: &CompetitionTerms, judges: &Vec<Address>) -> Result<(), CompetitionError> {
+        if terms.registration_ends <= Clock::now(env)
+            || terms.submission_ends < terms.registration_ends
+            || terms.judging_ends <= terms.submission_ends
+            || terms.max_team_size == 0
+            || terms.max_team_size > MAX_TEAM_SIZE
+            || terms.criteria.is_empty()
+            || terms.criteria.len() > MAX_CRITERIA
+            || terms.prize_bps.is_empty()
+            || terms.prize_bps.len() > MAX_PLACES
+            || judges.is_empty()
+            || judges.len() > MAX_JUDGES
+        {
+            return Err(CompetitionError::InvalidTerms);
+        }
+        if terms.criteria.iter().any(|criterion| criterion.weight == 0) {
+            return Err(CompetitionError::InvalidTerms);
+        }
+        if terms.prize_bps.iter().any(|bps| bps == 0) || terms.prize_bps.iter().sum::<u32>() != BASIS_POINTS {
+            return Err(CompetitionError::InvalidTerms);
+        }
+        for (index, judge) in judges.iter().enumerate() {
+            if judges.first_index_of(&judge) != Some(index as u32) {
+                return Err(CompetitionError::InvalidTerms);
+            }
+        }
+        if terms.badge.is_some() && CompetitionStorage::get_badges(env).is_none() {
+            return Err(CompetitionError::InvalidTerms);
+        }
+        Ok(())
+    }
+
+    /// Load a competition that has not been finalized
+    fn load(env: &Env, competition_id: u64) -> Result<Competition, CompetitionError> {
+        let competition = CompetitionStorage::get_competition(env, competition_id)
+            .ok_or(CompetitionError::CompetitionNotFound)?;
+        if competition.finalized {
+            return Err(CompetitionError::AlreadyFinalized);
+        }
+        Ok(competition)
+    }
+
+    fn load_team(env: &Env, competition_id: u64, team_id: u32) -> Result<Team, CompetitionError> {
+        CompetitionStorage::get_team(env, competition_id, team_id).ok_or(CompetitionError::TeamNotFound)
+    }
+
+    fn require_registration_open(env: &Env, competition: &Competition) -> Result<(), CompetitionError> {
+        if Clock::now(env) > competition.terms.registration_ends {
+            return Err(CompetitionError::RegistrationClosed);
+        }
+        Ok(())
+    }
+
+    /// Participants register once per competition, and judges not at all
+    fn require_can_register(env: &Env, competition: &Competition, participant: &Address) -> Result<(), CompetitionError> {
+        Self::require_registration_open(env, competition)?;
+        if competition.judges.contains(participant) {
+            return Err(CompetitionError::Unauthorized);
+        }
+        if CompetitionStorage::get_membership(env, competition.id, participant).is_some() {
+            return Err(CompetitionError::AlreadyRegistered);
+        }
+        Ok(())
+    }
+
+    /// Scored teams in place order with their average weighted score, as
+    /// many as there are prizes. Ties go to the team registered first.
+    fn rank(env: &Env, competition: &Competition) -> Vec<(Team, u64)> {
+        let mut scored = Vec::new(env);
+        for team_id in 1..=competition.team_count {
+            if let Some(team) = CompetitionStorage::get_team(env, competition.id, team_id) {
+                if team.judge_count > 0 {
+                    let average = team.score_total / team.judge_count as u64;
+                    scored.push_back((team, average));
+                }
+            }
+        }
+
+        let mut ranking = Vec::new(env);
+        while ranking.len() < competition.terms.prize_bps.len() && !scored.is_empty() {
+            let mut best = 0;
+            for (index, (_, score)) in scored.iter().enumerate() {
+                if score > scored.get_unchecked(best).1 {
+                    best = index as u32;
+                }
+            }
+            ranking.push_back(scored.get_unchecked(best));
+            scored.remove(best);
+        }
+        ranking
+    }
+
+    /// Award the competition badge to a placed team. Members who already
+    /// hold the badge are skipped rather than failing finalization.
+    fn award_badges(env: &Env, competition: &Competition, team: &Team) {
+        let (Some(badge), Some(badges)) = (&competition.terms.badge, CompetitionStorage::get_badges(env)) else {
+            return;
+        };
+        let contract = env.current_contract_address();
+        for member in team.members.iter() {
+            CrossContract::try_invoke::<()>(
+                env,
+                &badges,
+                &Symbol::new(env, "award"),
+                vec![env, contract.into_val(env), member.into_val(env), badge.into_val(env)],
+            );
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use soroban_sdk::{Address, BytesN, Env, Vec};
+use crate::types::{Competition, DataKey, Placement, Team};
+
+/// Storage utilities for the competition contract
+pub struct CompetitionStorage;
+
+impl CompetitionStorage {
+    /// Get the contract admin
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Set the contract admin
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Get the Badges contract winners are awarded through
+    pub fn get_badges(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Badges)
+    }
+
+    /// Set the Badges contract winners are awarded through
+    pub fn set_badges(env: &Env, badges: &Address) {
+        env.storage().instance().set(&DataKey::Badges, badges);
+    }
+
+    /// Allocate the next competition id
+    pub fn next_competition_id(env: &Env) -> u64 {
+        let id: u64 = env.storage().instance().get(&DataKey::CompetitionCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::CompetitionCount, &id);
+        id
+    }
+
+    /// Get a competition
+    pub fn get_competition(env: &Env, competition_id: u64) -> Option<Competition> {
+        env.storage().persistent().get(&DataKey::Competition(competition_id))
+    }
+
+    /// Store a competition
+    pub fn set_competition(env: &Env, competition: &Competition) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Competition(competition.id), competition);
+    }
+
+    /// Get a team
+    pub fn get_team(env: &Env, competition_id: u64, team_id: u32) -> Option<Team> {
+        env.storage().persistent().get(&DataKey::Team(competition_id, team_id))
+    }
+
+    /// Store a team
+    pub fn set_team(env: &Env, competition_id: u64, team: &Team) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Team(competition_id, team.id), team);
+    }
+
+    /// Get the team a participant belongs to
+    pub fn get_membership(env: &Env, competition_id: u64, participant: &Address) -> Option<u32> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Membership(competition_id, participant.clone()))
+    }
+
+    /// Record the team a participant belongs to
+    pub fn set_membership(env: &Env, competition_id: u64, participant: &Address, team_id: u32) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Membership(competition_id, participant.clone()), &team_id);
+    }
+
+    /// Whether a participant has been invited to a team
+    pub fn is_invited(env: &Env, competition_id: u64, team_id: u32, participant: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Invite(competition_id, team_id, participant.clone()))
+    }
+
+    /// Record or clear an invitation
+    pub fn set_invited(env: &Env, competition_id: u64, team_id: u32, participant: &Address, invited: bool) {
+        let key = DataKey::Invite(competition_id, team_id, participant.clone());
+        if invited {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    /// Get a team's submission commitment
+    pub fn get_commitment(env: &Env, competition_id: u64, team_id: u32) -> Option<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Commitment(competition_id, team_id))
+    }
+
+    /// Set a team's submission commitment
+    pub fn set_commitment(env: &Env, competition_id: u64, team_id: u32, commitment: &BytesN<32>) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Commitment(competition_id, team_id), commitment);
+    }
+
+    /// Whether a judge has scored a team
+    pub fn has_scored(env: &Env, competition_id: u64, team_id: u32, judge: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Scored(competition_id, team_id, judge.clone()))
+    }
+
+    /// Record that a judge has scored a team
+    pub fn set_scored(env: &Env, competition_id: u64, team_id: u32, judge: &Address) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Scored(competition_id, team_id, judge.clone()), &true);
+    }
+
+    /// Get a finalized competition's placements
+    pub fn get_results(env: &Env, competition_id: u64) -> Vec<Placement> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Results(competition_id))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Store a finalized competition's placements
+    pub fn set_results(env: &Env, competition_id: u64, results: &Vec<Placement>) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Results(competition_id), results);
+    }
+}
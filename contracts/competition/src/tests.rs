@@ -0,0 +1,266 @@
+use soroban_sdk::{
+    symbol_short, testutils::{Address as _, Ledger}, token, vec, Address, BytesN, Env, String, Symbol, Vec,
+};
+use shared::{roles::RoleLevel, time::DAY};
+
+use badges::{AwardRule, Badges, BadgesClient};
+
+use crate::{
+    CompetitionContract, CompetitionContractClient, CompetitionError, CompetitionTerms, Criterion,
+};
+
+const POOL: i128 = 10_000;
+
+fn setup_test_env() -> (
+    Env,
+    CompetitionContractClient<'static>,
+    token::Client<'static>,
+    BadgesClient<'static>,
+    Address,
+    [Address; 2],
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let contract_id = env.register(CompetitionContract, ());
+    let client = CompetitionContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+    client.grant_role(&admin, &organizer, &RoleLevel::Instructor);
+
+    // Winners' badges are awarded by this contract as a Badges issuer
+    let badges_id = env.register(Badges, ());
+    let badges = BadgesClient::new(&env, &badges_id);
+    badges.initialize(&admin);
+    badges.grant_role(&admin, &contract_id, &RoleLevel::Moderator);
+    badges.define_badge(
+        &admin,
+        &winner_badge(),
+        &String::from_str(&env, "Hackathon Winner"),
+        &String::from_str(&env, "ipfs://hack-winner"),
+        &AwardRule::Manual,
+    );
+    client.set_badges(&admin, &badges_id);
+
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &asset.address()).mint(&organizer, &POOL);
+    let token = token::Client::new(&env, &asset.address());
+    let judges = core::array::from_fn(|_| Address::generate(&env));
+    (env, client, token, badges, organizer, judges)
+}
+
+fn winner_badge() -> Symbol {
+    symbol_short!("HACKWIN")
+}
+
+fn create(
+    env: &Env,
+    client: &CompetitionContractClient,
+    token: &token::Client,
+    organizer: &Address,
+    judges: &[Address; 2],
+    prize_bps: Vec<u32>,
+) -> u64 {
+    let terms = CompetitionTerms {
+        token: token.address.clone(),
+        registration_ends: DAY,
+        submission_ends: 3 * DAY,
+        judging_ends: 5 * DAY,
+        max_team_size: 2,
+        criteria: vec![
+            env,
+            Criterion { name: symbol_short!("impact"), weight: 3 },
+            Criterion { name: symbol_short!("polish"), weight: 1 },
+        ],
+        prize_bps,
+        badge: Some(winner_badge()),
+    };
+    let judges = vec![env, judges[0].clone(), judges[1].clone()];
+    let id = client.create_competition(organizer, &terms, &judges);
+    client.fund(organizer, &id, &POOL);
+    id
+}
+
+fn team(
+    env: &Env,
+    client: &CompetitionContractClient,
+    id: u64,
+    name: Symbol,
+    size: u32,
+) -> (u32, Vec<Address>) {
+    let leader = Address::generate(env);
+    let team_id = client.create_team(&leader, &id, &name);
+    let mut members = vec![env, leader.clone()];
+    for _ in 1..size {
+        let member = Address::generate(env);
+        client.invite(&leader, &id, &team_id, &member);
+        client.join_team(&member, &id, &team_id);
+        members.push_back(member);
+    }
+    client.submit(&leader, &id, &team_id, &BytesN::from_array(env, &[team_id as u8; 32]));
+    (team_id, members)
+}
+
+#[test]
+fn test_team_formation_rules() {
+    let (env, client, token, _badges, organizer, judges) = setup_test_env();
+    let id = create(&env, &client, &token, &organizer, &judges, vec![&env, 10_000]);
+    let leader = Address::generate(&env);
+    let team_id = client.create_team(&leader, &id, &symbol_short!("rustace"));
+
+    let member = Address::generate(&env);
+    assert_eq!(client.try_join_team(&member, &id, &team_id), Err(Ok(CompetitionError::NotInvited)));
+    client.invite(&leader, &id, &team_id, &member);
+    client.join_team(&member, &id, &team_id);
+    assert_eq!(
+        client.try_create_team(&member, &id, &symbol_short!("solo")),
+        Err(Ok(CompetitionError::AlreadyRegistered))
+    );
+
+    let third = Address::generate(&env);
+    client.invite(&leader, &id, &team_id, &third);
+    assert_eq!(client.try_join_team(&third, &id, &team_id), Err(Ok(CompetitionError::TeamFull)));
+    assert_eq!(
+        client.try_create_team(&judges[0], &id, &symbol_short!("judges")),
+        Err(Ok(CompetitionError::Unauthorized))
+    );
+
+    env.ledger().set_timestamp(DAY + 1);
+    assert_eq!(
+        client.try_create_team(&third, &id, &symbol_short!("late")),
+        Err(Ok(CompetitionError::RegistrationClosed))
+    );
+}
+
+#[test]
+fn test_weighted_judging_pays_prizes_and_awards_badges() {
+    let (env, client, token, badges, organizer, judges) = setup_test_env();
+    let id = create(&env, &client, &token, &organizer, &judges, vec![&env, 7_000, 3_000]);
+    let (first, first_members) = team(&env, &client, id, symbol_short!("alpha"), 2);
+    let (second, second_members) = team(&env, &client, id, symbol_short!("beta"), 1);
+    let (third, third_members) = team(&env, &client, id, symbol_short!("gamma"), 1);
+
+    let scores = vec![&env, 50, 50];
+    assert_eq!(
+        client.try_score(&judges[0], &id, &first, &scores),
+        Err(Ok(CompetitionError::JudgingClosed))
+    );
+    env.ledger().set_timestamp(3 * DAY + 1);
+    for judge in judges.iter() {
+        // Impact counts three times as much as polish
+        client.score(judge, &id, &first, &vec![&env, 90, 10]);
+        client.score(judge, &id, &second, &vec![&env, 20, 100]);
+        client.score(judge, &id, &third, &vec![&env, 50, 50]);
+    }
+    assert_eq!(
+        client.try_score(&judges[0], &id, &first, &scores),
+        Err(Ok(CompetitionError::AlreadyScored))
+    );
+    assert_eq!(
+        client.try_score(&organizer, &id, &first, &scores),
+        Err(Ok(CompetitionError::NotJudge))
+    );
+    assert_eq!(client.try_finalize(&id), Err(Ok(CompetitionError::JudgingOpen)));
+
+    env.ledger().set_timestamp(5 * DAY + 1);
+    let results = client.finalize(&id);
+    assert_eq!(results.len(), 2);
+    assert_eq!(results.get(0).unwrap().team_id, first);
+    assert_eq!(results.get(0).unwrap().score, 280);
+    assert_eq!(results.get(1).unwrap().team_id, third);
+
+    for member in first_members.iter() {
+        assert_eq!(token.balance(&member), 3_500);
+        assert!(badges.has_badge(&member, &winner_badge()));
+    }
+    assert_eq!(token.balance(&third_members.get(0).unwrap()), 3_000);
+    let unplaced = second_members.get(0).unwrap();
+    assert_eq!(token.balance(&unplaced), 0);
+    assert!(!badges.has_badge(&unplaced, &winner_badge()));
+    assert_eq!(client.try_finalize(&id), Err(Ok(CompetitionError::AlreadyFinalized)));
+}
+
+#[test]
+fn test_unclaimed_places_return_to_organizer() {
+    let (env, client, token, _badges, organizer, judges) = setup_test_env();
+    let id = create(&env, &client, &token, &organizer, &judges, vec![&env, 5_000, 3_000, 2_000]);
+    let (only, _) = team(&env, &client, id, symbol_short!("alpha"), 1);
+    let leader = Address::generate(&env);
+    let unsubmitted = client.create_team(&leader, &id, &symbol_short!("idle"));
+
+    env.ledger().set_timestamp(3 * DAY + 1);
+    client.score(&judges[0], &id, &only, &vec![&env, 10, 10]);
+    assert_eq!(
+        client.try_score(&judges[0], &id, &unsubmitted, &vec![&env, 10, 10]),
+        Err(Ok(CompetitionError::NotSubmitted))
+    );
+
+    env.ledger().set_timestamp(5 * DAY + 1);
+    assert_eq!(client.finalize(&id).len(), 1);
+    assert_eq!(token.balance(&organizer), 5_000);
+}
+
+#[test]
+fn test_setup_funding_submission_and_scoring_errors() {
+    let (env, client, token, _badges, organizer, judges) = setup_test_env();
+    let stranger = Address::generate(&env);
+    assert_eq!(client.try_set_badges(&organizer, &stranger), Err(Ok(CompetitionError::Unauthorized)));
+
+    let mut terms = CompetitionTerms {
+        token: token.address.clone(),
+        registration_ends: DAY,
+        submission_ends: 3 * DAY,
+        judging_ends: 5 * DAY,
+        max_team_size: 2,
+        criteria: vec![&env, Criterion { name: symbol_short!("impact"), weight: 1 }],
+        prize_bps: vec![&env, 6_000, 3_000],
+        badge: None,
+    };
+    let panel = vec![&env, judges[0].clone()];
+    assert_eq!(
+        client.try_create_competition(&organizer, &terms, &panel),
+        Err(Ok(CompetitionError::InvalidTerms))
+    );
+    terms.prize_bps = vec![&env, 10_000];
+    assert_eq!(
+        client.try_create_competition(&stranger, &terms, &panel),
+        Err(Ok(CompetitionError::Unauthorized))
+    );
+
+    let id = create(&env, &client, &token, &organizer, &judges, vec![&env, 10_000]);
+    assert_eq!(client.try_fund(&organizer, &id, &0), Err(Ok(CompetitionError::InvalidAmount)));
+    assert_eq!(client.try_fund(&organizer, &99, &1), Err(Ok(CompetitionError::CompetitionNotFound)));
+
+    let (team_id, members) = team(&env, &client, id, symbol_short!("alpha"), 2);
+    let commitment = BytesN::from_array(&env, &[9; 32]);
+    assert_eq!(
+        client.try_submit(&members.get(1).unwrap(), &id, &team_id, &commitment),
+        Err(Ok(CompetitionError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_invite(&members.get(0).unwrap(), &id, &7, &stranger),
+        Err(Ok(CompetitionError::TeamNotFound))
+    );
+
+    env.ledger().set_timestamp(3 * DAY + 1);
+    assert_eq!(
+        client.try_submit(&members.get(0).unwrap(), &id, &team_id, &commitment),
+        Err(Ok(CompetitionError::SubmissionClosed))
+    );
+    assert_eq!(
+        client.try_score(&judges[0], &id, &team_id, &vec![&env, 10]),
+        Err(Ok(CompetitionError::InvalidScores))
+    );
+    assert_eq!(
+        client.try_score(&judges[0], &id, &team_id, &vec![&env, 101, 10]),
+        Err(Ok(CompetitionError::InvalidScores))
+    );
+    // A rejected score sheet does not use up the judge's turn
+    client.score(&judges[0], &id, &team_id, &vec![&env, 100, 10]);
+
+    env.ledger().set_timestamp(5 * DAY + 1);
+    client.finalize(&id);
+    assert_eq!(token.balance(&members.get(0).unwrap()), POOL / 2);
+    assert_eq!(client.try_fund(&organizer, &id, &1), Err(Ok(CompetitionError::AlreadyFinalized)));
+}
@@ -0,0 +1,74 @@
+use soroban_sdk::{contracttype, Address, Symbol, Vec};
+
+/// One judging criterion and its weight in the final score
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Criterion {
+    pub name: Symbol,
+    pub weight: u32,
+}
+
+/// Schedule, judging and prize rules of a competition
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CompetitionTerms {
+    pub token: Address, // prize token
+    pub registration_ends: u64,
+    pub submission_ends: u64,
+    pub judging_ends: u64,
+    pub max_team_size: u32,
+    pub criteria: Vec<Criterion>,
+    pub prize_bps: Vec<u32>,   // share of the pool per place, first place first
+    pub badge: Option<Symbol>,   // badge awarded to every placed team member
+}
+
+/// A competition and its prize pool
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Competition {
+    pub id: u64,
+    pub organizer: Address,
+    pub terms: CompetitionTerms,
+    pub judges: Vec<Address>,
+    pub prize_pool: i128,
+    pub team_count: u32,
+    pub finalized: bool,
+}
+
+/// A registered team
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Team {
+    pub id: u32,
+    pub name: Symbol,
+    pub leader: Address,
+    pub members: Vec<Address>,
+    pub submitted: bool,
+    pub score_total: u64, // sum of each judge's weighted score
+    pub judge_count: u32,
+}
+
+/// A placed team and what it won
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Placement {
+    pub team_id: u32,
+    pub score: u64, // average weighted score across judges
+    pub prize: i128,
+}
+
+/// Storage keys for the competition contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Badges,
+    CompetitionCount,
+    Competition(u64),
+    Team(u64, u32),
+    Membership(u64, Address), // team a participant belongs to
+    Invite(u64, u32, Address),
+    Commitment(u64, u32),      // hash of a team's final submission
+    Scored(u64, u32, Address), // judge has scored a team
+    Results(u64),
+}
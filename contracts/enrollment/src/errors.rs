@@ -0,0 +1,39 @@
+use soroban_sdk::contracterror;
+
+/// Enrollment contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum EnrollmentError {
+    // Initialization errors
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+
+    // Authorization errors
+    Unauthorized = 3,
+
+    // Course errors
+    CourseNotFound = 4,
+    CourseAlreadyExists = 5,
+    InvalidCapacity = 6,
+    InvalidFee = 7,
+
+    // Enrollment errors
+    AlreadyEnrolled = 8,
+    NotEnrolled = 9,
+    NotActive = 10,
+
+    // Refund errors
+    InvalidRefundPolicy = 11,
+    RefundWindowOpen = 12,
+    NothingToRelease = 13,
+
+    // Premium access errors
+    StakingNotConfigured = 14,
+    InsufficientStakeTier = 15,
+
+    // USD pricing errors
+    OracleNotConfigured = 16,
+    PriceUnavailable = 17,
+    FeeAboveMaximum = 18,
+}
@@ -0,0 +1,98 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+/// Enrollment contract events
+pub struct EnrollmentEvents;
+
+impl EnrollmentEvents {
+    fn topic(env: &Env, name: &str) -> (Symbol, Symbol) {
+        (Symbol::new(env, "enrollment"), Symbol::new(env, name))
+    }
+
+    /// Emit event when a course is opened for enrollment
+    pub fn emit_course_registered(env: &Env, course_id: &Symbol, instructor: &Address, capacity: u32) {
+        env.events().publish(
+            Self::topic(env, "course_registered"),
+            (course_id.clone(), instructor.clone(), capacity),
+        );
+    }
+
+    /// Emit event when a course's capacity changes
+    pub fn emit_capacity_updated(env: &Env, course_id: &Symbol, old_capacity: u32, new_capacity: u32) {
+        env.events().publish(
+            Self::topic(env, "capacity_updated"),
+            (course_id.clone(), old_capacity, new_capacity),
+        );
+    }
+
+    /// Emit event when a course's stake requirement changes
+    pub fn emit_stake_requirement_updated(env: &Env, course_id: &Symbol, min_tier: u32) {
+        env.events().publish(
+            Self::topic(env, "stake_requirement_updated"),
+            (course_id.clone(), min_tier),
+        );
+    }
+
+    /// Emit event when a course's USD price changes (0 = priced in its token)
+    pub fn emit_usd_price_updated(env: &Env, course_id: &Symbol, usd_cents: i128) {
+        env.events().publish(
+            Self::topic(env, "usd_price_updated"),
+            (course_id.clone(), usd_cents),
+        );
+    }
+
+    /// Emit event when a student takes a seat
+    pub fn emit_enrolled(env: &Env, student: &Address, course_id: &Symbol, amount_paid: i128) {
+        env.events().publish(
+            Self::topic(env, "enrolled"),
+            (student.clone(), course_id.clone(), amount_paid),
+        );
+    }
+
+    /// Emit event when a student joins the waitlist
+    pub fn emit_waitlisted(env: &Env, student: &Address, course_id: &Symbol, position: u32) {
+        env.events().publish(
+            Self::topic(env, "waitlisted"),
+            (student.clone(), course_id.clone(), position),
+        );
+    }
+
+    /// Emit event when a waitlisted student is moved into a free seat
+    pub fn emit_promoted(env: &Env, student: &Address, course_id: &Symbol) {
+        env.events().publish(
+            Self::topic(env, "promoted"),
+            (student.clone(), course_id.clone()),
+        );
+    }
+
+    /// Emit event when a student leaves a course or its waitlist
+    pub fn emit_withdrawn(env: &Env, student: &Address, course_id: &Symbol, refunded: i128) {
+        env.events().publish(
+            Self::topic(env, "withdrawn"),
+            (student.clone(), course_id.clone(), refunded),
+        );
+    }
+
+    /// Emit event when a course's refund policy changes
+    pub fn emit_refund_policy_updated(env: &Env, course_id: &Symbol, version: u32) {
+        env.events().publish(
+            Self::topic(env, "refund_policy_updated"),
+            (course_id.clone(), version),
+        );
+    }
+
+    /// Emit event when escrowed fees are released to the instructor
+    pub fn emit_fees_released(env: &Env, student: &Address, course_id: &Symbol, amount: i128) {
+        env.events().publish(
+            Self::topic(env, "fees_released"),
+            (student.clone(), course_id.clone(), amount),
+        );
+    }
+
+    /// Emit event when an enrollment is marked completed
+    pub fn emit_completed(env: &Env, student: &Address, course_id: &Symbol, marked_by: &Address) {
+        env.events().publish(
+            Self::topic(env, "completed"),
+            (student.clone(), course_id.clone(), marked_by.clone()),
+        );
+    }
+}
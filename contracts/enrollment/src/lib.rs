@@ -0,0 +1,644 @@
+#![no_std]
+
+mod types;
+mod errors;
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod tests;
+
+use soroban_sdk::{contract, contractimpl, symbol_short, token, vec, Address, Env, IntoVal, Symbol, Vec};
+
+use shared::{
+    access_control::AccessControl,
+    cross_contract::CrossContract,
+    roles::{Permission, RoleLevel},
+    reentrancy_guard::ReentrancyLock,
+    time::Clock,
+    fees::{Fees, BASIS_POINTS},
+};
+
+pub use types::{Course, CourseFee, Enrollment, EnrollmentStatus, RefundPolicy, RefundTier};
+pub use errors::EnrollmentError;
+use events::EnrollmentEvents;
+use storage::EnrollmentStorage;
+
+/// Upper bound on refund tiers per policy
+const MAX_REFUND_TIERS: u32 = 10;
+
+#[contract]
+pub struct EnrollmentContract;
+
+#[contractimpl]
+impl EnrollmentContract {
+    /// Initialize the contract and the shared RBAC system
+    pub fn initialize(env: Env, admin: Address) -> Result<(), EnrollmentError> {
+        if EnrollmentStorage::get_admin(&env).is_some() {
+            return Err(EnrollmentError::AlreadyInitialized);
+        }
+
+        AccessControl::initialize(&env, &admin).map_err(|_| EnrollmentError::AlreadyInitialized)?;
+        EnrollmentStorage::set_admin(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Grant a role in this contract (requires GrantRole)
+    pub fn grant_role(env: Env, admin: Address, user: Address, level: RoleLevel) -> Result<(), EnrollmentError> {
+        admin.require_auth();
+        AccessControl::grant_role(&env, &admin, &user, level).map_err(|_| EnrollmentError::Unauthorized)
+    }
+
+    /// Revoke a user's role in this contract (requires RevokeRole)
+    pub fn revoke_role(env: Env, admin: Address, user: Address) -> Result<(), EnrollmentError> {
+        admin.require_auth();
+        AccessControl::revoke_role(&env, &admin, &user).map_err(|_| EnrollmentError::Unauthorized)
+    }
+
+    /// Open a course for enrollment. The caller becomes the course instructor
+    /// and receives its enrollment fees.
+    pub fn register_course(
+        env: Env,
+        instructor: Address,
+        course_id: Symbol,
+        capacity: u32,
+        fee: Option<CourseFee>,
+    ) -> Result<(), EnrollmentError> {
+        Self::require_initialized(&env)?;
+        instructor.require_auth();
+        AccessControl::require_permission(&env, &instructor, &Permission::CreateCourse)
+            .map_err(|_| EnrollmentError::Unauthorized)?;
+
+        if EnrollmentStorage::get_course(&env, &course_id).is_some() {
+            return Err(EnrollmentError::CourseAlreadyExists);
+        }
+        if capacity == 0 {
+            return Err(EnrollmentError::InvalidCapacity);
+        }
+        if let Some(fee) = &fee {
+            if fee.amount <= 0 {
+                return Err(EnrollmentError::InvalidFee);
+            }
+            EnrollmentStorage::set_course_fee(&env, &course_id, fee);
+        }
+
+        let course = Course {
+            course_id: course_id.clone(),
+            instructor: instructor.clone(),
+            capacity,
+            enrolled_count: 0,
+            refund_policy_version: 0,
+            created_at: Clock::now(&env),
+        };
+        EnrollmentStorage::set_course(&env, &course);
+        EnrollmentEvents::emit_course_registered(&env, &course_id, &instructor, capacity);
+
+        Ok(())
+    }
+
+    /// Change a course's capacity. Raising it promotes waitlisted students
+    /// into the new seats; lowering it below the current head count only
+    /// stops further enrollments.
+    pub fn set_capacity(
+        env: Env,
+        caller: Address,
+        course_id: Symbol,
+        capacity: u32,
+    ) -> Result<(), EnrollmentError> {
+        let mut course = Self::require_course_owner(&env, &caller, &course_id)?;
+        if capacity == 0 {
+            return Err(EnrollmentError::InvalidCapacity);
+        }
+
+        let old_capacity = course.capacity;
+        course.capacity = capacity;
+        Self::fill_open_seats(&env, &mut course);
+        EnrollmentStorage::set_course(&env, &course);
+        EnrollmentEvents::emit_capacity_updated(&env, &course_id, old_capacity, capacity);
+
+        Ok(())
+    }
+
+    /// Set the Staking contract premium courses are gated on (admin only)
+    pub fn set_staking(env: Env, admin: Address, staking: Address) -> Result<(), EnrollmentError> {
+        admin.require_auth();
+        if !AccessControl::is_admin(&env, &admin) {
+            return Err(EnrollmentError::Unauthorized);
+        }
+
+        EnrollmentStorage::set_staking(&env, &staking);
+
+        Ok(())
+    }
+
+    /// Make a course premium: students must hold a stake of at least
+    /// `min_tier` in the Staking contract to enroll. 0 lifts the requirement.
+    pub fn set_stake_requirement(
+        env: Env,
+        caller: Address,
+        course_id: Symbol,
+        min_tier: u32,
+    ) -> Result<(), EnrollmentError> {
+        Self::require_course_owner(&env, &caller, &course_id)?;
+        if min_tier > 0 && EnrollmentStorage::get_staking(&env).is_none() {
+            return Err(EnrollmentError::StakingNotConfigured);
+        }
+
+        EnrollmentStorage::set_stake_requirement(&env, &course_id, min_tier);
+        EnrollmentEvents::emit_stake_requirement_updated(&env, &course_id, min_tier);
+
+        Ok(())
+    }
+
+    /// Set the OracleAdapter used to convert USD course prices (admin only)
+    pub fn set_oracle(env: Env, admin: Address, oracle: Address) -> Result<(), EnrollmentError> {
+        admin.require_auth();
+        if !AccessControl::is_admin(&env, &admin) {
+            return Err(EnrollmentError::Unauthorized);
+        }
+
+        EnrollmentStorage::set_oracle(&env, &oracle);
+
+        Ok(())
+    }
+
+    /// Set the Metrics contract enrollments, completions and fee revenue are
+    /// reported to (admin only). This contract must be one of its reporters.
+    pub fn set_metrics(env: Env, admin: Address, metrics: Address) -> Result<(), EnrollmentError> {
+        admin.require_auth();
+        if !AccessControl::is_admin(&env, &admin) {
+            return Err(EnrollmentError::Unauthorized);
+        }
+
+        EnrollmentStorage::set_metrics(&env, &metrics);
+
+        Ok(())
+    }
+
+    /// Price a course's fee in USD cents. Students still pay in the course's
+    /// fee token, converted at the oracle price when they enroll. 0 goes back
+    /// to the fixed token amount.
+    pub fn set_usd_price(
+        env: Env,
+        caller: Address,
+        course_id: Symbol,
+        usd_cents: i128,
+    ) -> Result<(), EnrollmentError> {
+        Self::require_course_owner(&env, &caller, &course_id)?;
+        if usd_cents < 0 || EnrollmentStorage::get_course_fee(&env, &course_id).is_none() {
+            return Err(EnrollmentError::InvalidFee);
+        }
+
+        if usd_cents == 0 {
+            EnrollmentStorage::remove_usd_price(&env, &course_id);
+        } else {
+            if EnrollmentStorage::get_oracle(&env).is_none() {
+                return Err(EnrollmentError::OracleNotConfigured);
+            }
+            EnrollmentStorage::set_usd_price(&env, &course_id, usd_cents);
+        }
+        EnrollmentEvents::emit_usd_price_updated(&env, &course_id, usd_cents);
+
+        Ok(())
+    }
+
+    /// Enroll in a course, or join its waitlist when it is full. Any course
+    /// fee is collected up front and held until the student takes a seat.
+    /// Fails if the fee, after any USD conversion, exceeds `max_amount`.
+    pub fn enroll(
+        env: Env,
+        student: Address,
+        course_id: Symbol,
+        max_amount: i128,
+    ) -> Result<EnrollmentStatus, EnrollmentError> {
+        let _guard = ReentrancyLock::new(&env);
+        student.require_auth();
+
+        let mut course = Self::load_course(&env, &course_id)?;
+        if let Some(existing) = EnrollmentStorage::get_enrollment(&env, &student, &course_id) {
+            if existing.status != EnrollmentStatus::Withdrawn {
+                return Err(EnrollmentError::AlreadyEnrolled);
+            }
+        }
+        Self::require_stake_tier(&env, &student, &course_id)?;
+
+        let amount_paid = match EnrollmentStorage::get_course_fee(&env, &course_id) {
+            Some(fee) => {
+                let amount = Self::fee_amount(&env, &course_id, &fee)?;
+                if amount > max_amount {
+                    return Err(EnrollmentError::FeeAboveMaximum);
+                }
+                token::Client::new(&env, &fee.token).transfer(
+                    &student,
+                    &env.current_contract_address(),
+                    &amount,
+                );
+                amount
+            }
+            None => 0,
+        };
+
+        let mut enrollment = Enrollment {
+            student: student.clone(),
+            course_id: course_id.clone(),
+            status: EnrollmentStatus::Waitlisted,
+            amount_paid,
+            escrowed: amount_paid,
+            refund_policy_version: course.refund_policy_version,
+            requested_at: Clock::now(&env),
+            enrolled_at: None,
+            completed_at: None,
+        };
+
+        if course.enrolled_count < course.capacity {
+            Self::take_seat(&env, &mut course, &mut enrollment);
+            EnrollmentEvents::emit_enrolled(&env, &student, &course_id, amount_paid);
+        } else {
+            let mut waitlist = EnrollmentStorage::get_waitlist(&env, &course_id);
+            waitlist.push_back(student.clone());
+            EnrollmentStorage::set_waitlist(&env, &course_id, &waitlist);
+            EnrollmentEvents::emit_waitlisted(&env, &student, &course_id, waitlist.len());
+        }
+
+        EnrollmentStorage::set_enrollment(&env, &enrollment);
+        EnrollmentStorage::set_course(&env, &course);
+
+        Ok(enrollment.status)
+    }
+
+    /// Replace a course's refund schedule. The new version only applies to
+    /// students who enroll after the change.
+    pub fn set_refund_policy(
+        env: Env,
+        caller: Address,
+        course_id: Symbol,
+        tiers: Vec<RefundTier>,
+    ) -> Result<u32, EnrollmentError> {
+        let mut course = Self::require_course_owner(&env, &caller, &course_id)?;
+        Self::validate_refund_tiers(&tiers)?;
+
+        course.refund_policy_version += 1;
+        let policy = RefundPolicy {
+            version: course.refund_policy_version,
+            tiers,
+        };
+        EnrollmentStorage::set_refund_policy(&env, &course_id, &policy);
+        EnrollmentStorage::set_course(&env, &course);
+        EnrollmentEvents::emit_refund_policy_updated(&env, &course_id, policy.version);
+
+        Ok(policy.version)
+    }
+
+    /// Leave a course or its waitlist. Waitlisted students get their fee
+    /// back in full; enrolled students get the refund their policy allows
+    /// at this point, and the rest goes to the instructor. A freed seat goes
+    /// to the head of the waitlist.
+    pub fn withdraw(env: Env, student: Address, course_id: Symbol) -> Result<(), EnrollmentError> {
+        let _guard = ReentrancyLock::new(&env);
+        student.require_auth();
+
+        let mut course = Self::load_course(&env, &course_id)?;
+        let mut enrollment = EnrollmentStorage::get_enrollment(&env, &student, &course_id)
+            .ok_or(EnrollmentError::NotEnrolled)?;
+
+        let refunded = match enrollment.status {
+            EnrollmentStatus::Enrolled => {
+                course.enrolled_count -= 1;
+                Self::refund_due(&env, &enrollment)
+            }
+            EnrollmentStatus::Waitlisted => {
+                let mut waitlist = EnrollmentStorage::get_waitlist(&env, &course_id);
+                if let Some(index) = waitlist.first_index_of(&student) {
+                    waitlist.remove(index);
+                }
+                EnrollmentStorage::set_waitlist(&env, &course_id, &waitlist);
+                enrollment.escrowed
+            }
+            _ => return Err(EnrollmentError::NotActive),
+        };
+
+        Self::pay_out(&env, &course_id, &student, refunded);
+        Self::pay_instructor(&env, &course, enrollment.escrowed - refunded);
+        enrollment.escrowed = 0;
+
+        enrollment.status = EnrollmentStatus::Withdrawn;
+        EnrollmentStorage::set_enrollment(&env, &enrollment);
+        EnrollmentEvents::emit_withdrawn(&env, &student, &course_id, refunded);
+
+        Self::fill_open_seats(&env, &mut course);
+        EnrollmentStorage::set_course(&env, &course);
+
+        Ok(())
+    }
+
+    /// Release a student's escrowed fee to the instructor once no refund can
+    /// be due any more: after the last refund window closes, or once the
+    /// enrollment is completed. Callable by anyone.
+    pub fn release_fees(env: Env, student: Address, course_id: Symbol) -> Result<i128, EnrollmentError> {
+        let _guard = ReentrancyLock::new(&env);
+        let course = Self::load_course(&env, &course_id)?;
+        let mut enrollment = EnrollmentStorage::get_enrollment(&env, &student, &course_id)
+            .ok_or(EnrollmentError::NotEnrolled)?;
+
+        if enrollment.escrowed == 0 {
+            return Err(EnrollmentError::NothingToRelease);
+        }
+        match enrollment.status {
+            EnrollmentStatus::Completed => {}
+            EnrollmentStatus::Enrolled if Self::refund_due(&env, &enrollment) == 0 => {}
+            EnrollmentStatus::Enrolled => return Err(EnrollmentError::RefundWindowOpen),
+            _ => return Err(EnrollmentError::NotActive),
+        }
+
+        let amount = enrollment.escrowed;
+        Self::pay_instructor(&env, &course, amount);
+        enrollment.escrowed = 0;
+        EnrollmentStorage::set_enrollment(&env, &enrollment);
+        EnrollmentEvents::emit_fees_released(&env, &student, &course_id, amount);
+
+        Ok(amount)
+    }
+
+    /// Mark an enrolled student as having completed the course (its
+    /// instructor or an admin)
+    pub fn mark_completed(
+        env: Env,
+        caller: Address,
+        student: Address,
+        course_id: Symbol,
+    ) -> Result<(), EnrollmentError> {
+        Self::require_course_owner(&env, &caller, &course_id)?;
+
+        let mut enrollment = EnrollmentStorage::get_enrollment(&env, &student, &course_id)
+            .ok_or(EnrollmentError::NotEnrolled)?;
+        if enrollment.status != EnrollmentStatus::Enrolled {
+            return Err(EnrollmentError::NotActive);
+        }
+
+        enrollment.status = EnrollmentStatus::Completed;
+        enrollment.completed_at = Some(Clock::now(&env));
+        EnrollmentStorage::set_enrollment(&env, &enrollment);
+        EnrollmentEvents::emit_completed(&env, &student, &course_id, &caller);
+        Self::report(&env, symbol_short!("completed"), 1);
+
+        Ok(())
+    }
+
+    /// Get a course
+    pub fn get_course(env: Env, course_id: Symbol) -> Option<Course> {
+        EnrollmentStorage::get_course(&env, &course_id)
+    }
+
+    /// Get the stake tier a course requires, 0 for none
+    pub fn get_stake_requirement(env: Env, course_id: Symbol) -> u32 {
+        EnrollmentStorage::get_stake_requirement(&env, &course_id)
+    }
+
+    /// Get a course's enrollment fee, if it charges one
+    pub fn get_course_fee(env: Env, course_id: Symbol) -> Option<CourseFee> {
+        EnrollmentStorage::get_course_fee(&env, &course_id)
+    }
+
+    /// Get a course's fee in USD cents, if it is priced in USD
+    pub fn get_usd_price(env: Env, course_id: Symbol) -> Option<i128> {
+        EnrollmentStorage::get_usd_price(&env, &course_id)
+    }
+
+    /// Get the token amount enrolling would cost right now
+    pub fn quote_fee(env: Env, course_id: Symbol) -> Result<i128, EnrollmentError> {
+        match EnrollmentStorage::get_course_fee(&env, &course_id) {
+            Some(fee) => Self::fee_amount(&env, &course_id, &fee),
+            None => Ok(0),
+        }
+    }
+
+    /// Get the refund policy that applies to new enrollments in a course
+    pub fn get_refund_policy(env: Env, course_id: Symbol) -> Option<RefundPolicy> {
+        let course = EnrollmentStorage::get_course(&env, &course_id)?;
+        EnrollmentStorage::get_refund_policy(&env, &course_id, course.refund_policy_version)
+    }
+
+    /// Amount a student would get back by withdrawing now
+    pub fn get_refund_quote(env: Env, student: Address, course_id: Symbol) -> i128 {
+        match EnrollmentStorage::get_enrollment(&env, &student, &course_id) {
+            Some(enrollment) => match enrollment.status {
+                EnrollmentStatus::Waitlisted => enrollment.escrowed,
+                EnrollmentStatus::Enrolled => Self::refund_due(&env, &enrollment),
+                _ => 0,
+            },
+            None => 0,
+        }
+    }
+
+    /// Get a student's enrollment record for a course
+    pub fn get_enrollment(env: Env, student: Address, course_id: Symbol) -> Option<Enrollment> {
+        EnrollmentStorage::get_enrollment(&env, &student, &course_id)
+    }
+
+    /// Get the ordered waitlist for a course
+    pub fn get_waitlist(env: Env, course_id: Symbol) -> Vec<Address> {
+        EnrollmentStorage::get_waitlist(&env, &course_id)
+    }
+
+    /// Get every course a student has joined, including past ones
+    pub fn get_student_courses(env: Env, student: Address) -> Vec<Symbol> {
+        EnrollmentStorage::get_student_courses(&env, &student)
+    }
+
+    /// Whether the student currently holds a seat (or has completed)
+    pub fn is_enrolled(env: Env, student: Address, course_id: Symbol) -> bool {
+        matches!(
+            EnrollmentStorage::get_enrollment(&env, &student, &course_id).map(|e| e.status),
+            Some(EnrollmentStatus::Enrolled) | Some(EnrollmentStatus::Completed)
+        )
+    }
+
+    /// Whether the student completed the course. Certificate issuance can
+    /// gate on this.
+    pub fn has_completed(env: Env, student: Address, course_id: Symbol) -> bool {
+        matches!(
+            EnrollmentStorage::get_enrollment(&env, &student, &course_id).map(|e| e.status),
+            Some(EnrollmentStatus::Completed)
+        )
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        EnrollmentStorage::get_admin(&env)
+    }
+}
+
+impl EnrollmentContract {
+    fn require_initialized(env: &Env) -> Result<(), EnrollmentError> {
+        if EnrollmentStorage::get_admin(env).is_none() {
+            return Err(EnrollmentError::NotInitialized);
+        }
+        Ok(())
+    }
+
+    fn load_course(env: &Env, course_id: &Symbol) -> Result<Course, EnrollmentError> {
+        EnrollmentStorage::get_course(env, course_id).ok_or(EnrollmentError::CourseNotFound)
+    }
+
+    /// Load a course the caller may manage: its instructor or an admin
+    fn require_course_owner(env: &Env, caller: &Address, course_id: &Symbol) -> Result<Course, EnrollmentError> {
+        caller.require_auth();
+        let course = Self::load_course(env, course_id)?;
+        if course.instructor != *caller && !AccessControl::is_admin(env, caller) {
+            return Err(EnrollmentError::Unauthorized);
+        }
+        Ok(course)
+    }
+
+    /// Check a premium course's stake requirement with the Staking contract
+    fn require_stake_tier(env: &Env, student: &Address, course_id: &Symbol) -> Result<(), EnrollmentError> {
+        let min_tier = EnrollmentStorage::get_stake_requirement(env, course_id);
+        if min_tier == 0 {
+            return Ok(());
+        }
+
+        let staking = EnrollmentStorage::get_staking(env).ok_or(EnrollmentError::StakingNotConfigured)?;
+        let tier: u32 = CrossContract::invoke(
+            env,
+            &staking,
+            &Symbol::new(env, "get_stake_tier"),
+            vec![env, student.into_val(env)],
+        )
+        .map_err(|_| EnrollmentError::InsufficientStakeTier)?;
+        if tier < min_tier {
+            return Err(EnrollmentError::InsufficientStakeTier);
+        }
+
+        Ok(())
+    }
+
+    /// The token amount due for a course: the fixed fee, or its USD price
+    /// converted by the OracleAdapter
+    fn fee_amount(env: &Env, course_id: &Symbol, fee: &CourseFee) -> Result<i128, EnrollmentError> {
+        let Some(usd_cents) = EnrollmentStorage::get_usd_price(env, course_id) else {
+            return Ok(fee.amount);
+        };
+
+        let oracle = EnrollmentStorage::get_oracle(env).ok_or(EnrollmentError::OracleNotConfigured)?;
+        CrossContract::invoke(
+            env,
+            &oracle,
+            &Symbol::new(env, "usd_to_tokens"),
+            vec![env, fee.token.into_val(env), usd_cents.into_val(env)],
+        )
+        .map_err(|_| EnrollmentError::PriceUnavailable)
+    }
+
+    fn validate_refund_tiers(tiers: &Vec<RefundTier>) -> Result<(), EnrollmentError> {
+        if tiers.is_empty() || tiers.len() > MAX_REFUND_TIERS {
+            return Err(EnrollmentError::InvalidRefundPolicy);
+        }
+
+        let mut previous: Option<RefundTier> = None;
+        for tier in tiers.iter() {
+            if tier.refund_bps > BASIS_POINTS {
+                return Err(EnrollmentError::InvalidRefundPolicy);
+            }
+            // Windows must grow and refunds must not grow with them
+            if let Some(previous) = previous {
+                if tier.within <= previous.within || tier.refund_bps > previous.refund_bps {
+                    return Err(EnrollmentError::InvalidRefundPolicy);
+                }
+            }
+            previous = Some(tier);
+        }
+
+        Ok(())
+    }
+
+    /// Refund owed to an enrolled student under the policy they enrolled with
+    fn refund_due(env: &Env, enrollment: &Enrollment) -> i128 {
+        let Some(enrolled_at) = enrollment.enrolled_at else {
+            return 0;
+        };
+        let Some(policy) = EnrollmentStorage::get_refund_policy(
+            env,
+            &enrollment.course_id,
+            enrollment.refund_policy_version,
+        ) else {
+            return 0;
+        };
+
+        let elapsed = Clock::now(env).saturating_sub(enrolled_at);
+        match policy.tiers.iter().find(|tier| elapsed <= tier.within) {
+            Some(tier) => Fees::portion(enrollment.amount_paid, tier.refund_bps).min(enrollment.escrowed),
+            None => 0,
+        }
+    }
+
+    /// Transfer `amount` of a course's fee token out of escrow
+    fn pay_out(env: &Env, course_id: &Symbol, to: &Address, amount: i128) {
+        if amount <= 0 {
+            return;
+        }
+        if let Some(fee) = EnrollmentStorage::get_course_fee(env, course_id) {
+            token::Client::new(env, &fee.token).transfer(&env.current_contract_address(), to, &amount);
+        }
+    }
+
+    /// Pay fees out of escrow to a course's instructor, counting them as
+    /// `revenue` in the fee token's units
+    fn pay_instructor(env: &Env, course: &Course, amount: i128) {
+        if amount <= 0 {
+            return;
+        }
+        Self::pay_out(env, &course.course_id, &course.instructor, amount);
+        Self::report(env, symbol_short!("revenue"), amount);
+    }
+
+    /// Seat a student. Without a refund policy their held fee goes straight
+    /// to the instructor; otherwise it stays in escrow until released.
+    fn take_seat(env: &Env, course: &mut Course, enrollment: &mut Enrollment) {
+        course.enrolled_count += 1;
+        enrollment.status = EnrollmentStatus::Enrolled;
+        enrollment.enrolled_at = Some(Clock::now(env));
+        Self::report(env, symbol_short!("enrolled"), 1);
+
+        if enrollment.refund_policy_version == 0 {
+            Self::pay_instructor(env, course, enrollment.escrowed);
+            enrollment.escrowed = 0;
+        }
+    }
+
+    /// Add `amount` to a metric in the Metrics contract, if one is set.
+    /// Reporting is best-effort and never blocks enrollment.
+    fn report(env: &Env, metric: Symbol, amount: i128) {
+        if let Some(metrics) = EnrollmentStorage::get_metrics(env) {
+            let args = vec![
+                env,
+                env.current_contract_address().into_val(env),
+                metric.into_val(env),
+                amount.into_val(env),
+            ];
+            let _ = CrossContract::try_invoke::<()>(env, &metrics, &Symbol::new(env, "record"), args);
+        }
+    }
+
+    /// Promote waitlisted students, in order, until the course is full
+    fn fill_open_seats(env: &Env, course: &mut Course) {
+        let mut waitlist = EnrollmentStorage::get_waitlist(env, &course.course_id);
+        let mut promoted = false;
+
+        while course.enrolled_count < course.capacity {
+            let Some(student) = waitlist.pop_front() else {
+                break;
+            };
+            if let Some(mut enrollment) = EnrollmentStorage::get_enrollment(env, &student, &course.course_id) {
+                Self::take_seat(env, course, &mut enrollment);
+                EnrollmentStorage::set_enrollment(env, &enrollment);
+                EnrollmentEvents::emit_promoted(env, &student, &course.course_id);
+            }
+            promoted = true;
+        }
+
+        if promoted {
+            EnrollmentStorage::set_waitlist(env, &course.course_id, &waitlist);
+        }
+    }
+}
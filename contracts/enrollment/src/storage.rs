@@ -0,0 +1,160 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+use crate::types::{Course, CourseFee, DataKey, Enrollment, RefundPolicy};
+
+/// Storage utilities for the enrollment contract
+pub struct EnrollmentStorage;
+
+impl EnrollmentStorage {
+    /// Get the contract admin
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Set the contract admin
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Get the Staking contract premium courses are gated on
+    pub fn get_staking(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Staking)
+    }
+
+    /// Set the Staking contract premium courses are gated on
+    pub fn set_staking(env: &Env, staking: &Address) {
+        env.storage().instance().set(&DataKey::Staking, staking);
+    }
+
+    /// Get the OracleAdapter USD-priced courses are converted with
+    pub fn get_oracle(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Oracle)
+    }
+
+    /// Set the OracleAdapter USD-priced courses are converted with
+    pub fn set_oracle(env: &Env, oracle: &Address) {
+        env.storage().instance().set(&DataKey::Oracle, oracle);
+    }
+
+    /// Get the Metrics contract enrollments and completions are reported to
+    pub fn get_metrics(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Metrics)
+    }
+
+    /// Set the Metrics contract enrollments and completions are reported to
+    pub fn set_metrics(env: &Env, metrics: &Address) {
+        env.storage().instance().set(&DataKey::Metrics, metrics);
+    }
+
+    /// Get a course's fee in USD cents, if it is priced in USD
+    pub fn get_usd_price(env: &Env, course_id: &Symbol) -> Option<i128> {
+        env.storage().persistent().get(&DataKey::UsdPrice(course_id.clone()))
+    }
+
+    /// Set a course's fee in USD cents
+    pub fn set_usd_price(env: &Env, course_id: &Symbol, usd_cents: i128) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::UsdPrice(course_id.clone()), &usd_cents);
+    }
+
+    /// Price a course in its fee token again
+    pub fn remove_usd_price(env: &Env, course_id: &Symbol) {
+        env.storage().persistent().remove(&DataKey::UsdPrice(course_id.clone()));
+    }
+
+    /// Get the stake tier a course requires, 0 for none
+    pub fn get_stake_requirement(env: &Env, course_id: &Symbol) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::StakeRequirement(course_id.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Set the stake tier a course requires
+    pub fn set_stake_requirement(env: &Env, course_id: &Symbol, min_tier: u32) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::StakeRequirement(course_id.clone()), &min_tier);
+    }
+
+    /// Get a course by id
+    pub fn get_course(env: &Env, course_id: &Symbol) -> Option<Course> {
+        env.storage().persistent().get(&DataKey::Course(course_id.clone()))
+    }
+
+    /// Store a course
+    pub fn set_course(env: &Env, course: &Course) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Course(course.course_id.clone()), course);
+    }
+
+    /// Get the enrollment fee for a course, if it charges one
+    pub fn get_course_fee(env: &Env, course_id: &Symbol) -> Option<CourseFee> {
+        env.storage().persistent().get(&DataKey::CourseFee(course_id.clone()))
+    }
+
+    /// Store the enrollment fee for a course
+    pub fn set_course_fee(env: &Env, course_id: &Symbol, fee: &CourseFee) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::CourseFee(course_id.clone()), fee);
+    }
+
+    /// Get a specific version of a course's refund policy
+    pub fn get_refund_policy(env: &Env, course_id: &Symbol, version: u32) -> Option<RefundPolicy> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RefundPolicy(course_id.clone(), version))
+    }
+
+    /// Store a refund policy version. Older versions are kept for the
+    /// enrollments that were made under them.
+    pub fn set_refund_policy(env: &Env, course_id: &Symbol, policy: &RefundPolicy) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::RefundPolicy(course_id.clone(), policy.version), policy);
+    }
+
+    /// Get a student's enrollment in a course
+    pub fn get_enrollment(env: &Env, student: &Address, course_id: &Symbol) -> Option<Enrollment> {
+        let key = DataKey::Enrollment(student.clone(), course_id.clone());
+        env.storage().persistent().get(&key)
+    }
+
+    /// Store an enrollment, indexing the course under the student on first write
+    pub fn set_enrollment(env: &Env, enrollment: &Enrollment) {
+        let key = DataKey::Enrollment(enrollment.student.clone(), enrollment.course_id.clone());
+        if !env.storage().persistent().has(&key) {
+            let mut courses = Self::get_student_courses(env, &enrollment.student);
+            courses.push_back(enrollment.course_id.clone());
+            env.storage()
+                .persistent()
+                .set(&DataKey::StudentCourses(enrollment.student.clone()), &courses);
+        }
+        env.storage().persistent().set(&key, enrollment);
+    }
+
+    /// Get every course a student has ever joined
+    pub fn get_student_courses(env: &Env, student: &Address) -> Vec<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::StudentCourses(student.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Get the ordered waitlist for a course
+    pub fn get_waitlist(env: &Env, course_id: &Symbol) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Waitlist(course_id.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Store the waitlist for a course
+    pub fn set_waitlist(env: &Env, course_id: &Symbol, waitlist: &Vec<Address>) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Waitlist(course_id.clone()), waitlist);
+    }
+}
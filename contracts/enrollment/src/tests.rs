@@ -0,0 +1,441 @@
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::{Address as _, Ledger}, token, vec, Address, Env, Symbol, Vec,
+};
+use shared::roles::RoleLevel;
+
+use shared::time::DAY;
+
+use staking::{StakeTier, Staking, StakingClient, StakingConfig};
+
+use oracle_adapter::{Asset, OracleAdapter, OracleAdapterClient, PriceData};
+
+use metrics::{MetricsContract, MetricsContractClient};
+
+use crate::{
+    CourseFee, EnrollmentContract, EnrollmentContractClient, EnrollmentError, EnrollmentStatus,
+    RefundTier,
+};
+
+fn setup_test_env() -> (Env, EnrollmentContractClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EnrollmentContract, ());
+    let client = EnrollmentContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let instructor = Address::generate(&env);
+
+    client.initialize(&admin);
+    client.grant_role(&admin, &instructor, &RoleLevel::Instructor);
+
+    (env, client, admin, instructor)
+}
+
+fn course() -> Symbol {
+    symbol_short!("RUST101")
+}
+
+/// An instructor who doesn't teach `course()`
+fn other_instructor(env: &Env, client: &EnrollmentContractClient, admin: &Address) -> Address {
+    let instructor = Address::generate(env);
+    client.grant_role(admin, &instructor, &RoleLevel::Instructor);
+    instructor
+}
+
+#[test]
+fn test_enroll_until_full_then_waitlist() {
+    let (env, client, _admin, instructor) = setup_test_env();
+    client.register_course(&instructor, &course(), &2, &None);
+
+    let students: [Address; 3] = core::array::from_fn(|_| Address::generate(&env));
+    assert_eq!(client.enroll(&students[0], &course(), &0), EnrollmentStatus::Enrolled);
+    assert_eq!(client.enroll(&students[1], &course(), &0), EnrollmentStatus::Enrolled);
+    assert_eq!(client.enroll(&students[2], &course(), &0), EnrollmentStatus::Waitlisted);
+
+    assert_eq!(client.get_course(&course()).unwrap().enrolled_count, 2);
+    assert_eq!(client.get_waitlist(&course()).len(), 1);
+    assert!(client.is_enrolled(&students[1], &course()));
+    assert!(!client.is_enrolled(&students[2], &course()));
+
+    assert_eq!(
+        client.try_enroll(&students[0], &course(), &0),
+        Err(Ok(EnrollmentError::AlreadyEnrolled))
+    );
+}
+
+#[test]
+fn test_withdraw_promotes_waitlist_in_order() {
+    let (env, client, _admin, instructor) = setup_test_env();
+    client.register_course(&instructor, &course(), &1, &None);
+
+    let students: [Address; 3] = core::array::from_fn(|_| Address::generate(&env));
+    for student in students.iter() {
+        client.enroll(student, &course(), &0);
+    }
+
+    client.withdraw(&students[0], &course());
+
+    assert_eq!(
+        client.get_enrollment(&students[0], &course()).unwrap().status,
+        EnrollmentStatus::Withdrawn
+    );
+    assert!(client.is_enrolled(&students[1], &course()));
+    assert_eq!(client.get_waitlist(&course()).len(), 1);
+    assert_eq!(client.get_waitlist(&course()).get(0), Some(students[2].clone()));
+}
+
+#[test]
+fn test_raising_capacity_promotes_waitlist() {
+    let (env, client, admin, instructor) = setup_test_env();
+    client.register_course(&instructor, &course(), &1, &None);
+
+    let students: [Address; 3] = core::array::from_fn(|_| Address::generate(&env));
+    for student in students.iter() {
+        client.enroll(student, &course(), &0);
+    }
+
+    client.set_capacity(&admin, &course(), &3);
+
+    assert_eq!(client.get_course(&course()).unwrap().enrolled_count, 3);
+    assert!(client.get_waitlist(&course()).is_empty());
+}
+
+#[test]
+fn test_fees_are_held_for_waitlist_and_forwarded_on_seat() {
+    let (env, client, admin, instructor) = setup_test_env();
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    let token_client = token::Client::new(&env, &asset.address());
+    let fee = CourseFee { token: asset.address(), amount: 100 };
+    client.register_course(&instructor, &course(), &1, &Some(fee));
+
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let third = Address::generate(&env);
+    for student in [&first, &second, &third] {
+        token::StellarAssetClient::new(&env, &asset.address()).mint(student, &100);
+        client.enroll(student, &course(), &100);
+    }
+
+    // One seat paid out, two fees held for the waitlist
+    assert_eq!(token_client.balance(&instructor), 100);
+    assert_eq!(token_client.balance(&client.address), 200);
+
+    // Leaving the waitlist refunds in full
+    client.withdraw(&third, &course());
+    assert_eq!(token_client.balance(&third), 100);
+
+    // A promoted student's held fee goes to the instructor
+    client.withdraw(&first, &course());
+    assert_eq!(token_client.balance(&instructor), 200);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_completion_requires_course_instructor_and_seat() {
+    let (env, client, admin, instructor) = setup_test_env();
+    client.register_course(&instructor, &course(), &1, &None);
+
+    let student = Address::generate(&env);
+    let waitlisted = Address::generate(&env);
+    let other_instructor = other_instructor(&env, &client, &admin);
+    client.grant_role(&admin, &student, &RoleLevel::Student);
+    client.enroll(&student, &course(), &0);
+    client.enroll(&waitlisted, &course(), &0);
+
+    // Holding MarkCompletion or UpdateCourse is not enough on someone else's course
+    assert_eq!(
+        client.try_mark_completed(&student, &student, &course()),
+        Err(Ok(EnrollmentError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_mark_completed(&other_instructor, &student, &course()),
+        Err(Ok(EnrollmentError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_capacity(&other_instructor, &course(), &5),
+        Err(Ok(EnrollmentError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_mark_completed(&instructor, &waitlisted, &course()),
+        Err(Ok(EnrollmentError::NotActive))
+    );
+
+    client.mark_completed(&instructor, &student, &course());
+    assert!(client.has_completed(&student, &course()));
+    assert!(!client.has_completed(&waitlisted, &course()));
+}
+
+#[test]
+fn test_register_course_validation() {
+    let (env, client, _admin, instructor) = setup_test_env();
+    let outsider = Address::generate(&env);
+
+    assert_eq!(
+        client.try_register_course(&outsider, &course(), &10, &None),
+        Err(Ok(EnrollmentError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_register_course(&instructor, &course(), &0, &None),
+        Err(Ok(EnrollmentError::InvalidCapacity))
+    );
+
+    client.register_course(&instructor, &course(), &10, &None);
+    assert_eq!(
+        client.try_register_course(&instructor, &course(), &10, &None),
+        Err(Ok(EnrollmentError::CourseAlreadyExists))
+    );
+    assert_eq!(
+        client.try_enroll(&outsider, &symbol_short!("NOPE"), &0),
+        Err(Ok(EnrollmentError::CourseNotFound))
+    );
+}
+
+fn paid_course<'a>(
+    env: &Env,
+    client: &EnrollmentContractClient<'a>,
+    admin: &Address,
+    instructor: &Address,
+    capacity: u32,
+) -> token::Client<'a> {
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    let fee = CourseFee { token: asset.address(), amount: 1_000 };
+    client.register_course(instructor, &course(), &capacity, &Some(fee));
+    token::Client::new(env, &asset.address())
+}
+
+fn enroll_paying<'a>(
+    env: &Env,
+    client: &EnrollmentContractClient<'a>,
+    token_client: &token::Client<'a>,
+) -> Address {
+    let student = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_client.address).mint(&student, &1_000);
+    client.enroll(&student, &course(), &1_000);
+    student
+}
+
+fn tiered_policy(env: &Env) -> Vec<RefundTier> {
+    vec![
+        env,
+        RefundTier { within: 7 * DAY, refund_bps: 10_000 },
+        RefundTier { within: 14 * DAY, refund_bps: 5_000 },
+    ]
+}
+
+#[test]
+fn test_withdraw_refunds_by_schedule() {
+    let (env, client, admin, instructor) = setup_test_env();
+    let token_client = paid_course(&env, &client, &admin, &instructor, 10);
+    client.set_refund_policy(&admin, &course(), &tiered_policy(&env));
+
+    let early = enroll_paying(&env, &client, &token_client);
+    let mid = enroll_paying(&env, &client, &token_client);
+    let late = enroll_paying(&env, &client, &token_client);
+
+    env.ledger().set_timestamp(3 * DAY);
+    assert_eq!(client.get_refund_quote(&early, &course()), 1_000);
+    client.withdraw(&early, &course());
+    assert_eq!(token_client.balance(&early), 1_000);
+
+    env.ledger().set_timestamp(10 * DAY);
+    client.withdraw(&mid, &course());
+    assert_eq!(token_client.balance(&mid), 500);
+    assert_eq!(token_client.balance(&instructor), 500);
+
+    env.ledger().set_timestamp(20 * DAY);
+    assert_eq!(client.get_refund_quote(&late, &course()), 0);
+    client.withdraw(&late, &course());
+    assert_eq!(token_client.balance(&late), 0);
+    assert_eq!(token_client.balance(&instructor), 1_500);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_policy_changes_only_affect_future_enrollments() {
+    let (env, client, admin, instructor) = setup_test_env();
+    let token_client = paid_course(&env, &client, &admin, &instructor, 10);
+    client.set_refund_policy(&admin, &course(), &tiered_policy(&env));
+    let before = enroll_paying(&env, &client, &token_client);
+
+    let stricter = vec![&env, RefundTier { within: DAY, refund_bps: 2_000 }];
+    assert_eq!(client.set_refund_policy(&admin, &course(), &stricter), 2);
+    let after = enroll_paying(&env, &client, &token_client);
+
+    env.ledger().set_timestamp(2 * DAY);
+    assert_eq!(client.get_refund_quote(&before, &course()), 1_000);
+    assert_eq!(client.get_refund_quote(&after, &course()), 0);
+    assert_eq!(client.get_refund_policy(&course()).unwrap().version, 2);
+}
+
+#[test]
+fn test_release_fees_after_refund_window() {
+    let (env, client, admin, instructor) = setup_test_env();
+    let token_client = paid_course(&env, &client, &admin, &instructor, 10);
+    client.set_refund_policy(&admin, &course(), &tiered_policy(&env));
+    let student = enroll_paying(&env, &client, &token_client);
+    let finisher = enroll_paying(&env, &client, &token_client);
+
+    assert_eq!(
+        client.try_release_fees(&student, &course()),
+        Err(Ok(EnrollmentError::RefundWindowOpen))
+    );
+
+    // Completion releases immediately
+    client.mark_completed(&instructor, &finisher, &course());
+    assert_eq!(client.release_fees(&finisher, &course()), 1_000);
+
+    env.ledger().set_timestamp(15 * DAY);
+    assert_eq!(client.release_fees(&student, &course()), 1_000);
+    assert_eq!(token_client.balance(&instructor), 2_000);
+    assert_eq!(
+        client.try_release_fees(&student, &course()),
+        Err(Ok(EnrollmentError::NothingToRelease))
+    );
+}
+
+#[test]
+fn test_refund_policy_validation() {
+    let (env, client, admin, instructor) = setup_test_env();
+    client.register_course(&instructor, &course(), &10, &None);
+
+    let increasing_refund = vec![
+        &env,
+        RefundTier { within: DAY, refund_bps: 5_000 },
+        RefundTier { within: 2 * DAY, refund_bps: 8_000 },
+    ];
+    let unordered = vec![
+        &env,
+        RefundTier { within: 2 * DAY, refund_bps: 5_000 },
+        RefundTier { within: DAY, refund_bps: 1_000 },
+    ];
+    let over_full = vec![&env, RefundTier { within: DAY, refund_bps: 10_001 }];
+
+    for tiers in [increasing_refund, unordered, over_full, Vec::new(&env)] {
+        assert_eq!(
+            client.try_set_refund_policy(&admin, &course(), &tiers),
+            Err(Ok(EnrollmentError::InvalidRefundPolicy))
+        );
+    }
+
+    assert_eq!(
+        client.try_set_refund_policy(&other_instructor(&env, &client, &admin), &course(), &tiered_policy(&env)),
+        Err(Ok(EnrollmentError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_premium_course_requires_stake_tier() {
+    let (env, client, admin, instructor) = setup_test_env();
+    client.register_course(&instructor, &course(), &10, &None);
+    assert_eq!(
+        client.try_set_stake_requirement(&instructor, &course(), &2),
+        Err(Ok(EnrollmentError::StakingNotConfigured))
+    );
+
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    let staking_id = env.register(Staking, ());
+    let staking = StakingClient::new(&env, &staking_id);
+    staking.initialize(&admin, &StakingConfig { token: asset.address(), early_unstake_penalty_bps: 0 });
+    staking.set_tiers(&admin, &vec![
+        &env,
+        StakeTier { lock_period: 30 * DAY, min_amount: 100, reward_bps: 0 },
+        StakeTier { lock_period: 90 * DAY, min_amount: 500, reward_bps: 0 },
+    ]);
+
+    client.set_staking(&admin, &staking_id);
+    assert_eq!(
+        client.try_set_stake_requirement(&other_instructor(&env, &client, &admin), &course(), &0),
+        Err(Ok(EnrollmentError::Unauthorized))
+    );
+    client.set_stake_requirement(&instructor, &course(), &2);
+    assert_eq!(client.get_stake_requirement(&course()), 2);
+
+    let student = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &asset.address()).mint(&student, &1_000);
+    assert_eq!(
+        client.try_enroll(&student, &course(), &0),
+        Err(Ok(EnrollmentError::InsufficientStakeTier))
+    );
+
+    staking.stake(&student, &500, &2);
+    assert_eq!(client.enroll(&student, &course(), &0), EnrollmentStatus::Enrolled);
+}
+
+/// Price feed quoting every asset at $2 with 14 decimals
+#[contract]
+pub struct FlatFeed;
+
+#[contractimpl]
+impl FlatFeed {
+    pub fn decimals(_env: Env) -> u32 {
+        14
+    }
+
+    pub fn lastprice(env: Env, _asset: Asset) -> Option<PriceData> {
+        Some(PriceData { price: 200_000_000_000_000, timestamp: env.ledger().timestamp() })
+    }
+}
+
+#[test]
+fn test_usd_priced_course_converts_at_enrollment() {
+    let (env, client, admin, instructor) = setup_test_env();
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    let fee = CourseFee { token: asset.address(), amount: 1 };
+    client.register_course(&instructor, &course(), &10, &Some(fee));
+    assert_eq!(
+        client.try_set_usd_price(&instructor, &course(), &5_000),
+        Err(Ok(EnrollmentError::OracleNotConfigured))
+    );
+
+    let oracle_id = env.register(OracleAdapter, ());
+    OracleAdapterClient::new(&env, &oracle_id).initialize(&admin, &env.register(FlatFeed, ()), &600);
+    client.set_oracle(&admin, &oracle_id);
+    assert_eq!(
+        client.try_set_usd_price(&other_instructor(&env, &client, &admin), &course(), &5_000),
+        Err(Ok(EnrollmentError::Unauthorized))
+    );
+    client.set_usd_price(&instructor, &course(), &5_000);
+
+    // $50 at $2 per token = 25 tokens of 7 decimals
+    assert_eq!(client.quote_fee(&course()), 250_000_000);
+    let student = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &asset.address()).mint(&student, &250_000_000);
+    // The student's bound is checked against the converted amount
+    assert_eq!(
+        client.try_enroll(&student, &course(), &249_999_999),
+        Err(Ok(EnrollmentError::FeeAboveMaximum))
+    );
+    client.enroll(&student, &course(), &250_000_000);
+    assert_eq!(client.get_enrollment(&student, &course()).unwrap().amount_paid, 250_000_000);
+    assert_eq!(token::Client::new(&env, &asset.address()).balance(&instructor), 250_000_000);
+}
+
+#[test]
+fn test_enrollments_completions_and_revenue_are_reported_to_metrics() {
+    let (env, client, admin, instructor) = setup_test_env();
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    let fee = CourseFee { token: asset.address(), amount: 100 };
+    client.register_course(&instructor, &course(), &1, &Some(fee));
+    let metrics = MetricsContractClient::new(&env, &env.register(MetricsContract, ()));
+    metrics.initialize(&admin);
+    client.set_metrics(&admin, &metrics.address);
+
+    // Not yet a reporter: enrollment still succeeds
+    let student = Address::generate(&env);
+    let waitlisted = Address::generate(&env);
+    for student in [&student, &waitlisted] {
+        token::StellarAssetClient::new(&env, &asset.address()).mint(student, &100);
+    }
+    client.enroll(&student, &course(), &100);
+    assert_eq!(metrics.get_total(&symbol_short!("enrolled")), 0);
+
+    metrics.set_reporter(&admin, &client.address, &true);
+    client.enroll(&waitlisted, &course(), &100);
+    client.mark_completed(&instructor, &student, &course());
+    assert_eq!(metrics.get_total(&symbol_short!("revenue")), 0);
+    // A seat taken from the waitlist counts as an enrollment and its fee as revenue
+    client.set_capacity(&instructor, &course(), &2);
+    assert_eq!(metrics.get_total(&symbol_short!("completed")), 1);
+    assert_eq!(metrics.get_total(&symbol_short!("enrolled")), 1);
+    assert_eq!(metrics.get_total(&symbol_short!("revenue")), 100);
+}
@@ -0,0 +1,83 @@
+use soroban_sdk::{contracttype, Address, Symbol, Vec};
+
+/// Token-denominated fee charged when a student joins a course
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CourseFee {
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Enrollment settings and live counters for a course
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Course {
+    pub course_id: Symbol,
+    pub instructor: Address, // receives enrollment fees
+    pub capacity: u32,
+    pub enrolled_count: u32,
+    pub refund_policy_version: u32, // 0 = no refund policy
+    pub created_at: u64,
+}
+
+/// Share of the fee refunded when a student withdraws within `within`
+/// seconds of taking their seat
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RefundTier {
+    pub within: u64,
+    pub refund_bps: u32,
+}
+
+/// Refund schedule for a course. Tiers are ordered by `within`; the first
+/// tier whose window has not closed applies, and no refund is due once the
+/// last window has closed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RefundPolicy {
+    pub version: u32,
+    pub tiers: Vec<RefundTier>,
+}
+
+/// Where a student stands in a course
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum EnrollmentStatus {
+    Waitlisted,
+    Enrolled,
+    Completed,
+    Withdrawn,
+}
+
+/// A student's enrollment record for one course
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Enrollment {
+    pub student: Address,
+    pub course_id: Symbol,
+    pub status: EnrollmentStatus,
+    pub amount_paid: i128,
+    pub escrowed: i128, // part of amount_paid still held by the contract
+    pub refund_policy_version: u32,
+    pub requested_at: u64,
+    pub enrolled_at: Option<u64>,
+    pub completed_at: Option<u64>,
+}
+
+/// Storage keys for the enrollment contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Staking,
+    Oracle,
+    Metrics,
+    Course(Symbol),
+    CourseFee(Symbol),
+    RefundPolicy(Symbol, u32),
+    Enrollment(Address, Symbol),
+    Waitlist(Symbol),
+    StudentCourses(Address),
+    StakeRequirement(Symbol),
+    UsdPrice(Symbol), // course fee in USD cents, converted at enrollment
+}
@@ -0,0 +1,30 @@
+use soroban_sdk::contracterror;
+
+/// Federation contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum FederationError {
+    // Initialization errors
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+
+    // Authorization errors
+    Unauthorized = 3,
+
+    // Partner errors
+    InvalidPartner = 4,
+    PartnerExists = 5,
+    PartnerNotFound = 6,
+    TooManyPartners = 7,
+
+    // Issuer errors
+    IssuerAlreadyRegistered = 8,
+    IssuerNotFound = 9,
+    TooManyIssuers = 10,
+
+    // Mapping errors
+    MappingExists = 11,
+    MappingNotFound = 12,
+    TooManyMappings = 13,
+}
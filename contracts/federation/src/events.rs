@@ -0,0 +1,66 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+/// Federation contract events
+pub struct FederationEvents;
+
+impl FederationEvents {
+    fn topic(env: &Env, name: &str) -> (Symbol, Symbol) {
+        (Symbol::new(env, "federation"), Symbol::new(env, name))
+    }
+
+    /// Emit event when a partner institution is registered
+    pub fn emit_partner_registered(env: &Env, partner_id: &Symbol, manager: &Address) {
+        env.events().publish(
+            Self::topic(env, "partner_registered"),
+            (partner_id.clone(), manager.clone()),
+        );
+    }
+
+    /// Emit event when a partner is added to or removed from the trust list
+    pub fn emit_trust_updated(env: &Env, partner_id: &Symbol, trusted: bool) {
+        env.events().publish(
+            Self::topic(env, "trust_updated"),
+            (partner_id.clone(), trusted),
+        );
+    }
+
+    /// Emit event when a partner registers an issuer contract
+    pub fn emit_issuer_added(env: &Env, partner_id: &Symbol, issuer: &Address) {
+        env.events().publish(
+            Self::topic(env, "issuer_added"),
+            (partner_id.clone(), issuer.clone()),
+        );
+    }
+
+    /// Emit event when a partner removes an issuer contract
+    pub fn emit_issuer_removed(env: &Env, partner_id: &Symbol, issuer: &Address) {
+        env.events().publish(
+            Self::topic(env, "issuer_removed"),
+            (partner_id.clone(), issuer.clone()),
+        );
+    }
+
+    /// Emit event when a partner proposes a credential mapping
+    pub fn emit_mapping_proposed(env: &Env, issuer: &Address, external_course: &Symbol, course: &Symbol) {
+        env.events().publish(
+            Self::topic(env, "mapping_proposed"),
+            (issuer.clone(), external_course.clone(), course.clone()),
+        );
+    }
+
+    /// Emit event when an admin accepts a credential mapping
+    pub fn emit_mapping_accepted(env: &Env, issuer: &Address, external_course: &Symbol, course: &Symbol) {
+        env.events().publish(
+            Self::topic(env, "mapping_accepted"),
+            (issuer.clone(), external_course.clone(), course.clone()),
+        );
+    }
+
+    /// Emit event when a credential mapping is removed
+    pub fn emit_mapping_removed(env: &Env, issuer: &Address, external_course: &Symbol, course: &Symbol) {
+        env.events().publish(
+            Self::topic(env, "mapping_removed"),
+            (issuer.clone(), external_course.clone(), course.clone()),
+        );
+    }
+}
@@ -0,0 +1,298 @@
+#![no_std]
+
+mod types;
+mod errors;
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod tests;
+
+use soroban_sdk::{contract, contractimpl, vec, Address, Env, IntoVal, String, Symbol, Vec};
+
+use shared::{access_control::AccessControl, cross_contract::CrossContract, time::Clock};
+
+pub use types::{CredentialMapping, Partner};
+pub use errors::FederationError;
+use events::FederationEvents;
+use storage::FederationStorage;
+
+/// Upper bound on partner institutions
+const MAX_PARTNERS: u32 = 100;
+
+/// Upper bound on issuer contracts per partner
+const MAX_ISSUERS: u32 = 10;
+
+/// Upper bound on mappings onto one course, which `satisfies` walks
+const MAX_MAPPINGS_PER_COURSE: u32 = 20;
+
+#[contract]
+pub struct FederationContract;
+
+#[contractimpl]
+impl FederationContract {
+    /// Initialize the contract
+    pub fn initialize(env: Env, admin: Address) -> Result<(), FederationError> {
+        if FederationStorage::get_admin(&env).is_some() {
+            return Err(FederationError::AlreadyInitialized);
+        }
+
+        AccessControl::initialize(&env, &admin).map_err(|_| FederationError::AlreadyInitialized)?;
+        FederationStorage::set_admin(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Register a partner institution on the trust list (admin only).
+    /// `manager` is the partner's account for its issuers and mappings.
+    pub fn register_partner(
+        env: Env,
+        admin: Address,
+        partner_id: Symbol,
+        name: String,
+        manager: Address,
+    ) -> Result<(), FederationError> {
+        Self::require_admin(&env, &admin)?;
+        if name.is_empty() {
+            return Err(FederationError::InvalidPartner);
+        }
+        if FederationStorage::get_partner(&env, &partner_id).is_some() {
+            return Err(FederationError::PartnerExists);
+        }
+        let mut partner_ids = FederationStorage::get_partner_ids(&env);
+        if partner_ids.len() >= MAX_PARTNERS {
+            return Err(FederationError::TooManyPartners);
+        }
+
+        let partner = Partner {
+            id: partner_id.clone(),
+            name,
+            manager: manager.clone(),
+            issuers: Vec::new(&env),
+            trusted: true,
+            registered_at: Clock::now(&env),
+        };
+        FederationStorage::set_partner(&env, &partner);
+        partner_ids.push_back(partner_id.clone());
+        FederationStorage::set_partner_ids(&env, &partner_ids);
+        FederationEvents::emit_partner_registered(&env, &partner_id, &manager);
+
+        Ok(())
+    }
+
+    /// Add a partner to or remove it from the trust list (admin only).
+    /// Its issuers and mappings are kept but satisfy nothing while untrusted.
+    pub fn set_trusted(env: Env, admin: Address, partner_id: Symbol, trusted: bool) -> Result<(), FederationError> {
+        Self::require_admin(&env, &admin)?;
+        let mut partner = FederationStorage::get_partner(&env, &partner_id).ok_or(FederationError::PartnerNotFound)?;
+
+        partner.trusted = trusted;
+        FederationStorage::set_partner(&env, &partner);
+        FederationEvents::emit_trust_updated(&env, &partner_id, trusted);
+
+        Ok(())
+    }
+
+    /// Register one of the partner's issuer contracts (partner manager only)
+    pub fn add_issuer(env: Env, manager: Address, partner_id: Symbol, issuer: Address) -> Result<(), FederationError> {
+        let mut partner = Self::load_managed(&env, &manager, &partner_id)?;
+        if FederationStorage::get_issuer_partner(&env, &issuer).is_some() {
+            return Err(FederationError::IssuerAlreadyRegistered);
+        }
+        if partner.issuers.len() >= MAX_ISSUERS {
+            return Err(FederationError::TooManyIssuers);
+        }
+
+        partner.issuers.push_back(issuer.clone());
+        FederationStorage::set_partner(&env, &partner);
+        FederationStorage::set_issuer_partner(&env, &issuer, &partner_id);
+        FederationEvents::emit_issuer_added(&env, &partner_id, &issuer);
+
+        Ok(())
+    }
+
+    /// Remove one of the partner's issuer contracts (partner manager only).
+    /// Mappings from it stop counting.
+    pub fn remove_issuer(env: Env, manager: Address, partner_id: Symbol, issuer: Address) -> Result<(), FederationError> {
+        let mut partner = Self::load_managed(&env, &manager, &partner_id)?;
+        let index = partner.issuers.first_index_of(&issuer).ok_or(FederationError::IssuerNotFound)?;
+
+        partner.issuers.remove(index);
+        FederationStorage::set_partner(&env, &partner);
+        FederationStorage::remove_issuer_partner(&env, &issuer);
+        FederationEvents::emit_issuer_removed(&env, &partner_id, &issuer);
+
+        Ok(())
+    }
+
+    /// Propose that a credential from one of the partner's issuers satisfies
+    /// a StrellerMinds course (partner manager only). It counts once an
+    /// admin accepts it.
+    pub fn propose_mapping(
+        env: Env,
+        manager: Address,
+        partner_id: Symbol,
+        issuer: Address,
+        external_course: Symbol,
+        course: Symbol,
+    ) -> Result<(), FederationError> {
+        let partner = Self::load_managed(&env, &manager, &partner_id)?;
+        if !partner.issuers.contains(&issuer) {
+            return Err(FederationError::IssuerNotFound);
+        }
+        let mut mappings = FederationStorage::get_course_mappings(&env, &course);
+        if Self::find_mapping(&mappings, &issuer, &external_course).is_some() {
+            return Err(FederationError::MappingExists);
+        }
+        if mappings.len() >= MAX_MAPPINGS_PER_COURSE {
+            return Err(FederationError::TooManyMappings);
+        }
+
+        mappings.push_back(CredentialMapping {
+            partner_id,
+            issuer: issuer.clone(),
+            external_course: external_course.clone(),
+            course: course.clone(),
+            accepted: false,
+            proposed_at: Clock::now(&env),
+        });
+        FederationStorage::set_course_mappings(&env, &course, &mappings);
+        FederationEvents::emit_mapping_proposed(&env, &issuer, &external_course, &course);
+
+        Ok(())
+    }
+
+    /// Accept a proposed mapping (admin only)
+    pub fn accept_mapping(
+        env: Env,
+        admin: Address,
+        course: Symbol,
+        issuer: Address,
+        external_course: Symbol,
+    ) -> Result<(), FederationError> {
+        Self::require_admin(&env, &admin)?;
+        let mut mappings = FederationStorage::get_course_mappings(&env, &course);
+        let index = Self::find_mapping(&mappings, &issuer, &external_course).ok_or(FederationError::MappingNotFound)?;
+        let mut mapping = mappings.get_unchecked(index);
+        if mapping.accepted {
+            return Err(FederationError::MappingExists);
+        }
+
+        mapping.accepted = true;
+        mappings.set(index, mapping);
+        FederationStorage::set_course_mappings(&env, &course, &mappings);
+        FederationEvents::emit_mapping_accepted(&env, &issuer, &external_course, &course);
+
+        Ok(())
+    }
+
+    /// Remove a mapping, proposed or accepted (an admin, or the manager of
+    /// the partner that proposed it)
+    pub fn remove_mapping(
+        env: Env,
+        caller: Address,
+        course: Symbol,
+        issuer: Address,
+        external_course: Symbol,
+    ) -> Result<(), FederationError> {
+        caller.require_auth();
+        let mut mappings = FederationStorage::get_course_mappings(&env, &course);
+        let index = Self::find_mapping(&mappings, &issuer, &external_course).ok_or(FederationError::MappingNotFound)?;
+        let mapping = mappings.get_unchecked(index);
+        let is_manager = FederationStorage::get_partner(&env, &mapping.partner_id)
+            .is_some_and(|partner| partner.manager == caller);
+        if !is_manager && !AccessControl::is_admin(&env, &caller) {
+            return Err(FederationError::Unauthorized);
+        }
+
+        mappings.remove(index);
+        FederationStorage::set_course_mappings(&env, &course, &mappings);
+        FederationEvents::emit_mapping_removed(&env, &issuer, &external_course, &course);
+
+        Ok(())
+    }
+
+    /// Get a partner
+    pub fn get_partner(env: Env, partner_id: Symbol) -> Option<Partner> {
+        FederationStorage::get_partner(&env, &partner_id)
+    }
+
+    /// Get every registered partner id
+    pub fn get_partner_ids(env: Env) -> Vec<Symbol> {
+        FederationStorage::get_partner_ids(&env)
+    }
+
+    /// Get the partner an issuer contract belongs to
+    pub fn get_issuer_partner(env: Env, issuer: Address) -> Option<Symbol> {
+        FederationStorage::get_issuer_partner(&env, &issuer)
+    }
+
+    /// Get every mapping onto a course, proposed or accepted
+    pub fn get_mappings(env: Env, course: Symbol) -> Vec<CredentialMapping> {
+        FederationStorage::get_course_mappings(&env, &course)
+    }
+
+    /// Whether an issuer contract belongs to a partner on the trust list
+    pub fn is_trusted_issuer(env: Env, issuer: Address) -> bool {
+        Self::is_trusted(&env, &issuer)
+    }
+
+    /// Get the trusted partner issuer whose credential satisfies a course
+    /// for a student, if any. Each accepted mapping from a trusted issuer is
+    /// checked by calling `has_credential(student, external_course)` on the
+    /// issuer contract; issuers that fail or lack the function are skipped.
+    pub fn find_satisfying_issuer(env: Env, student: Address, course: Symbol) -> Option<Address> {
+        let func = Symbol::new(&env, "has_credential");
+        FederationStorage::get_course_mappings(&env, &course)
+            .iter()
+            .filter(|mapping| mapping.accepted && Self::is_trusted(&env, &mapping.issuer))
+            .find(|mapping| {
+                let args = vec![&env, student.into_val(&env), mapping.external_course.into_val(&env)];
+                CrossContract::try_invoke::<bool>(&env, &mapping.issuer, &func, args).unwrap_or(false)
+            })
+            .map(|mapping| mapping.issuer)
+    }
+
+    /// Whether a student holds a trusted partner credential that satisfies a
+    /// course, for prerequisite checks
+    pub fn satisfies(env: Env, student: Address, course: Symbol) -> bool {
+        Self::find_satisfying_issuer(env, student, course).is_some()
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        FederationStorage::get_admin(&env)
+    }
+}
+
+impl FederationContract {
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), FederationError> {
+        caller.require_auth();
+        if !AccessControl::is_admin(env, caller) {
+            return Err(FederationError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    fn load_managed(env: &Env, manager: &Address, partner_id: &Symbol) -> Result<Partner, FederationError> {
+        manager.require_auth();
+        let partner = FederationStorage::get_partner(env, partner_id).ok_or(FederationError::PartnerNotFound)?;
+        if partner.manager != *manager {
+            return Err(FederationError::Unauthorized);
+        }
+        Ok(partner)
+    }
+
+    fn find_mapping(mappings: &Vec<CredentialMapping>, issuer: &Address, external_course: &Symbol) -> Option<u32> {
+        mappings
+            .iter()
+            .position(|mapping| mapping.issuer == *issuer && mapping.external_course == *external_course)
+            .map(|index| index as u32)
+    }
+
+    fn is_trusted(env: &Env, issuer: &Address) -> bool {
+        FederationStorage::get_issuer_partner(env, issuer)
+            .and_then(|partner_id| FederationStorage::get_partner(env, &partner_id))
+            .is_some_and(|partner| partner.trusted && partner.issuers.contains(issuer))
+    }
+}
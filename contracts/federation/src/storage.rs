@@ -0,0 +1,74 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+use crate::types::{CredentialMapping, DataKey, Partner};
+
+/// Storage utilities for the federation contract
+pub struct FederationStorage;
+
+impl FederationStorage {
+    /// Get the contract admin
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Set the contract admin
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Get every registered partner id
+    pub fn get_partner_ids(env: &Env) -> Vec<Symbol> {
+        env.storage()
+            .instance()
+            .get(&DataKey::PartnerIds)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Set every registered partner id
+    pub fn set_partner_ids(env: &Env, partner_ids: &Vec<Symbol>) {
+        env.storage().instance().set(&DataKey::PartnerIds, partner_ids);
+    }
+
+    /// Get a partner
+    pub fn get_partner(env: &Env, partner_id: &Symbol) -> Option<Partner> {
+        env.storage().persistent().get(&DataKey::Partner(partner_id.clone()))
+    }
+
+    /// Store a partner
+    pub fn set_partner(env: &Env, partner: &Partner) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Partner(partner.id.clone()), partner);
+    }
+
+    /// Get the partner an issuer contract belongs to
+    pub fn get_issuer_partner(env: &Env, issuer: &Address) -> Option<Symbol> {
+        env.storage().persistent().get(&DataKey::IssuerPartner(issuer.clone()))
+    }
+
+    /// Record the partner an issuer contract belongs to
+    pub fn set_issuer_partner(env: &Env, issuer: &Address, partner_id: &Symbol) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::IssuerPartner(issuer.clone()), partner_id);
+    }
+
+    /// Forget an issuer contract
+    pub fn remove_issuer_partner(env: &Env, issuer: &Address) {
+        env.storage().persistent().remove(&DataKey::IssuerPartner(issuer.clone()));
+    }
+
+    /// Get the mappings onto a course
+    pub fn get_course_mappings(env: &Env, course: &Symbol) -> Vec<CredentialMapping> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::CourseMappings(course.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Set the mappings onto a course
+    pub fn set_course_mappings(env: &Env, course: &Symbol, mappings: &Vec<CredentialMapping>) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::CourseMappings(course.clone()), mappings);
+    }
+}
@@ -0,0 +1,168 @@
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::Address as _, Address, Env, String, Symbol,
+};
+
+use crate::{FederationContract, FederationContractClient, FederationError};
+
+/// Stand-in for a partner university's certificate contract
+#[contract]
+pub struct PartnerIssuer;
+
+#[contractimpl]
+impl PartnerIssuer {
+    pub fn grant(env: Env, holder: Address, course: Symbol) {
+        env.storage().persistent().set(&(holder, course), &true);
+    }
+
+    pub fn has_credential(env: Env, holder: Address, course: Symbol) -> bool {
+        env.storage().persistent().has(&(holder, course))
+    }
+}
+
+fn setup_test_env() -> (Env, FederationContractClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(FederationContract, ());
+    let client = FederationContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+
+    let manager = Address::generate(&env);
+    let issuer = env.register(PartnerIssuer, ());
+    client.register_partner(&admin, &symbol_short!("uon"), &String::from_str(&env, "University of Nairobi"), &manager);
+    client.add_issuer(&manager, &symbol_short!("uon"), &issuer);
+
+    (env, client, admin, manager, issuer)
+}
+
+#[test]
+fn test_partner_credential_satisfies_course_once_accepted() {
+    let (env, client, admin, manager, issuer) = setup_test_env();
+    let student = Address::generate(&env);
+    PartnerIssuerClient::new(&env, &issuer).grant(&student, &symbol_short!("cs101"));
+    let course = symbol_short!("rust101");
+
+    client.propose_mapping(&manager, &symbol_short!("uon"), &issuer, &symbol_short!("cs101"), &course);
+    assert!(!client.satisfies(&student, &course));
+
+    client.accept_mapping(&admin, &course, &issuer, &symbol_short!("cs101"));
+    assert_eq!(client.find_satisfying_issuer(&student, &course), Some(issuer.clone()));
+    assert!(!client.satisfies(&Address::generate(&env), &course));
+}
+
+#[test]
+fn test_untrusted_partner_satisfies_nothing() {
+    let (env, client, admin, manager, issuer) = setup_test_env();
+    let student = Address::generate(&env);
+    PartnerIssuerClient::new(&env, &issuer).grant(&student, &symbol_short!("cs101"));
+    let course = symbol_short!("rust101");
+    client.propose_mapping(&manager, &symbol_short!("uon"), &issuer, &symbol_short!("cs101"), &course);
+    client.accept_mapping(&admin, &course, &issuer, &symbol_short!("cs101"));
+
+    client.set_trusted(&admin, &symbol_short!("uon"), &false);
+    assert!(!client.is_trusted_issuer(&issuer));
+    assert!(!client.satisfies(&student, &course));
+
+    client.set_trusted(&admin, &symbol_short!("uon"), &true);
+    client.remove_issuer(&manager, &symbol_short!("uon"), &issuer);
+    assert!(!client.satisfies(&student, &course));
+}
+
+#[test]
+fn test_only_manager_registers_issuers_and_mappings() {
+    let (env, client, _admin, manager, issuer) = setup_test_env();
+    let outsider = Address::generate(&env);
+    let other_issuer = Address::generate(&env);
+    assert_eq!(
+        client.try_add_issuer(&outsider, &symbol_short!("uon"), &other_issuer),
+        Err(Ok(FederationError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_add_issuer(&manager, &symbol_short!("uon"), &issuer),
+        Err(Ok(FederationError::IssuerAlreadyRegistered))
+    );
+    assert_eq!(
+        client.try_propose_mapping(&manager, &symbol_short!("uon"), &other_issuer, &symbol_short!("cs101"), &symbol_short!("rust101")),
+        Err(Ok(FederationError::IssuerNotFound))
+    );
+
+    client.propose_mapping(&manager, &symbol_short!("uon"), &issuer, &symbol_short!("cs101"), &symbol_short!("rust101"));
+    assert_eq!(
+        client.try_remove_mapping(&outsider, &symbol_short!("rust101"), &issuer, &symbol_short!("cs101")),
+        Err(Ok(FederationError::Unauthorized))
+    );
+    client.remove_mapping(&manager, &symbol_short!("rust101"), &issuer, &symbol_short!("cs101"));
+    assert!(client.get_mappings(&symbol_short!("rust101")).is_empty());
+}
+
+#[test]
+fn test_partner_and_mapping_errors() {
+    let (env, client, admin, manager, issuer) = setup_test_env();
+    let uon = symbol_short!("uon");
+    let other = symbol_short!("mit");
+    let other_manager = Address::generate(&env);
+    let name = String::from_str(&env, "Partner");
+
+    assert_eq!(
+        client.try_register_partner(&manager, &other, &name, &manager),
+        Err(Ok(FederationError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_register_partner(&admin, &other, &String::from_str(&env, ""), &other_manager),
+        Err(Ok(FederationError::InvalidPartner))
+    );
+    assert_eq!(
+        client.try_register_partner(&admin, &uon, &name, &other_manager),
+        Err(Ok(FederationError::PartnerExists))
+    );
+    assert_eq!(client.try_set_trusted(&admin, &other, &false), Err(Ok(FederationError::PartnerNotFound)));
+    assert_eq!(client.try_set_trusted(&manager, &uon, &false), Err(Ok(FederationError::Unauthorized)));
+    assert_eq!(
+        client.try_add_issuer(&other_manager, &other, &issuer),
+        Err(Ok(FederationError::PartnerNotFound))
+    );
+
+    // An issuer contract belongs to one partner only
+    client.register_partner(&admin, &other, &name, &other_manager);
+    assert_eq!(
+        client.try_add_issuer(&other_manager, &other, &issuer),
+        Err(Ok(FederationError::IssuerAlreadyRegistered))
+    );
+    assert_eq!(
+        client.try_remove_issuer(&other_manager, &other, &issuer),
+        Err(Ok(FederationError::IssuerNotFound))
+    );
+
+    let external = symbol_short!("cs101");
+    let course = symbol_short!("rust101");
+    client.propose_mapping(&manager, &uon, &issuer, &external, &course);
+    assert_eq!(
+        client.try_propose_mapping(&manager, &uon, &issuer, &external, &course),
+        Err(Ok(FederationError::MappingExists))
+    );
+    assert_eq!(
+        client.try_accept_mapping(&manager, &course, &issuer, &external),
+        Err(Ok(FederationError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_accept_mapping(&admin, &course, &issuer, &symbol_short!("cs999")),
+        Err(Ok(FederationError::MappingNotFound))
+    );
+    client.accept_mapping(&admin, &course, &issuer, &external);
+    assert_eq!(
+        client.try_accept_mapping(&admin, &course, &issuer, &external),
+        Err(Ok(FederationError::MappingExists))
+    );
+
+    // Another partner's manager cannot drop the mapping, an admin can
+    assert_eq!(
+        client.try_remove_mapping(&other_manager, &course, &issuer, &external),
+        Err(Ok(FederationError::Unauthorized))
+    );
+    client.remove_mapping(&admin, &course, &issuer, &external);
+    assert_eq!(
+        client.try_remove_mapping(&admin, &course, &issuer, &external),
+        Err(Ok(FederationError::MappingNotFound))
+    );
+}
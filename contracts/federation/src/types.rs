@@ -0,0 +1,36 @@
+use soroban_sdk::{contracttype, Address, String, Symbol, Vec};
+
+/// A partner institution and the contracts that issue its credentials
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Partner {
+    pub id: Symbol,
+    pub name: String,
+    pub manager: Address, // the partner's own account for issuers and mappings
+    pub issuers: Vec<Address>,
+    pub trusted: bool, // on the trust list; untrusted partners satisfy nothing
+    pub registered_at: u64,
+}
+
+/// A partner credential accepted in place of a StrellerMinds course
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CredentialMapping {
+    pub partner_id: Symbol,
+    pub issuer: Address,
+    pub external_course: Symbol, // course id in the partner's issuer contract
+    pub course: Symbol,          // StrellerMinds course it satisfies
+    pub accepted: bool,          // proposed by the partner, accepted by an admin
+    pub proposed_at: u64,
+}
+
+/// Storage keys for the federation contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    PartnerIds,
+    Partner(Symbol),
+    IssuerPartner(Address),
+    CourseMappings(Symbol), // mappings onto a StrellerMinds course
+}
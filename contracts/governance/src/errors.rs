@@ -0,0 +1,40 @@
+use soroban_sdk::contracterror;
+
+/// Governance contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum GovernanceError {
+    // Initialization errors
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+
+    // Authorization errors
+    Unauthorized = 3,
+
+    // Configuration errors
+    InvalidConfig = 4,
+
+    // Proposal errors
+    InvalidProposal = 5,
+    ProposalNotFound = 6,
+    BelowProposalThreshold = 7,
+    InvalidProposalState = 8,
+    TimelockActive = 9,
+    ExecutionFailed = 10,
+
+    // Voting errors
+    VotingClosed = 11,
+    AlreadyVoted = 12,
+    NoVotingPower = 13,
+
+    // Token lock errors
+    InvalidAmount = 14,
+    InsufficientLocked = 15,
+    TokensLocked = 16,
+
+    // Delegation errors
+    InvalidDelegate = 17,
+    NotDelegated = 18,
+    TooManyDelegators = 19,
+}
@@ -0,0 +1,66 @@
+use soroban_sdk::{Address, Env, Symbol};
+use crate::types::VoteChoice;
+
+/// Governance contract events
+pub struct GovernanceEvents;
+
+impl GovernanceEvents {
+    fn topic(env: &Env, name: &str) -> (Symbol, Symbol) {
+        (Symbol::new(env, "governance"), Symbol::new(env, name))
+    }
+
+    /// Emit event when voting tokens are locked or unlocked
+    pub fn emit_lock_changed(env: &Env, voter: &Address, locked: i128) {
+        env.events().publish(
+            Self::topic(env, "lock_changed"),
+            (voter.clone(), locked),
+        );
+    }
+
+    /// Emit event when an address delegates its voting power
+    pub fn emit_delegated(env: &Env, delegator: &Address, delegate: &Address) {
+        env.events().publish(
+            Self::topic(env, "delegated"),
+            (delegator.clone(), delegate.clone()),
+        );
+    }
+
+    /// Emit event when an address takes back its voting power
+    pub fn emit_delegation_revoked(env: &Env, delegator: &Address, delegate: &Address) {
+        env.events().publish(
+            Self::topic(env, "delegation_revoked"),
+            (delegator.clone(), delegate.clone()),
+        );
+    }
+
+    /// Emit event when a proposal is created
+    pub fn emit_proposed(env: &Env, proposal_id: u64, proposer: &Address, voting_ends: u64) {
+        env.events().publish(
+            Self::topic(env, "proposed"),
+            (proposal_id, proposer.clone(), voting_ends),
+        );
+    }
+
+    /// Emit event when a vote is cast
+    pub fn emit_voted(env: &Env, proposal_id: u64, voter: &Address, choice: VoteChoice, weight: i128) {
+        env.events().publish(
+            Self::topic(env, "voted"),
+            (proposal_id, voter.clone(), choice, weight),
+        );
+    }
+
+    /// Emit event when a passed proposal enters the timelock
+    pub fn emit_queued(env: &Env, proposal_id: u64, eta: u64) {
+        env.events().publish(Self::topic(env, "queued"), (proposal_id, eta));
+    }
+
+    /// Emit event when a proposal's calls have been made
+    pub fn emit_executed(env: &Env, proposal_id: u64) {
+        env.events().publish(Self::topic(env, "executed"), proposal_id);
+    }
+
+    /// Emit event when a proposal is cancelled
+    pub fn emit_cancelled(env: &Env, proposal_id: u64) {
+        env.events().publish(Self::topic(env, "cancelled"), proposal_id);
+    }
+}
@@ -0,0 +1,463 @@
+#![no_std]
+
+mod types;
+mod errors;
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod tests;
+
+use soroban_sdk::{contract, contractimpl, token, Address, Env, String, Val, Vec};
+
+use shared::{
+    access_control::AccessControl,
+    cross_contract::CrossContract,
+    reentrancy_guard::ReentrancyLock,
+    roles::RoleLevel,
+    time::Clock,
+};
+
+pub use types::{
+    GovernanceConfig, Proposal, ProposalCall, ProposalState, ProposalStatus, VoteChoice, VoteRecord,
+    VoteWeighting,
+};
+pub use errors::GovernanceError;
+use events::GovernanceEvents;
+use storage::GovernanceStorage;
+
+/// Upper bound on calls in one proposal
+const MAX_CALLS: u32 = 10;
+
+/// Upper bound on addresses delegating to one delegate
+const MAX_DELEGATORS: u32 = 50;
+
+#[contract]
+pub struct Governance;
+
+#[contractimpl]
+impl Governance {
+    /// Initialize the contract with its voting parameters
+    pub fn initialize(env: Env, admin: Address, config: GovernanceConfig) -> Result<(), GovernanceError> {
+        if GovernanceStorage::get_admin(&env).is_some() {
+            return Err(GovernanceError::AlreadyInitialized);
+        }
+        Self::validate_config(&config)?;
+
+        AccessControl::initialize(&env, &admin).map_err(|_| GovernanceError::AlreadyInitialized)?;
+        GovernanceStorage::set_admin(&env, &admin);
+        GovernanceStorage::set_config(&env, &config);
+
+        Ok(())
+    }
+
+    /// Grant a role in this contract (requires GrantRole)
+    pub fn grant_role(env: Env, admin: Address, user: Address, level: RoleLevel) -> Result<(), GovernanceError> {
+        admin.require_auth();
+        AccessControl::grant_role(&env, &admin, &user, level).map_err(|_| GovernanceError::Unauthorized)
+    }
+
+    /// Revoke a user's role in this contract (requires RevokeRole)
+    pub fn revoke_role(env: Env, admin: Address, user: Address) -> Result<(), GovernanceError> {
+        admin.require_auth();
+        AccessControl::revoke_role(&env, &admin, &user).map_err(|_| GovernanceError::Unauthorized)
+    }
+
+    /// Change the voting parameters (admin only). Open proposals keep the
+    /// weighting and quorum they were created with. The voting token
+    /// cannot change while tokens are locked against it.
+    pub fn set_config(env: Env, admin: Address, config: GovernanceConfig) -> Result<(), GovernanceError> {
+        admin.require_auth();
+        if !AccessControl::is_admin(&env, &admin) {
+            return Err(GovernanceError::Unauthorized);
+        }
+        let current = Self::load_config(&env)?;
+        if config.voting_token != current.voting_token {
+            return Err(GovernanceError::InvalidConfig);
+        }
+        Self::validate_config(&config)?;
+
+        GovernanceStorage::set_config(&env, &config);
+
+        Ok(())
+    }
+
+    /// Lock voting tokens to gain token-weighted voting power
+    pub fn lock_tokens(env: Env, voter: Address, amount: i128) -> Result<i128, GovernanceError> {
+        let _guard = ReentrancyLock::new(&env);
+        voter.require_auth();
+
+        let config = Self::load_config(&env)?;
+        if amount <= 0 {
+            return Err(GovernanceError::InvalidAmount);
+        }
+
+        token::Client::new(&env, &config.voting_token).transfer(&voter, &env.current_contract_address(), &amount);
+        let locked = GovernanceStorage::get_locked(&env, &voter) + amount;
+        GovernanceStorage::set_locked(&env, &voter, locked);
+        GovernanceEvents::emit_lock_changed(&env, &voter, locked);
+
+        Ok(locked)
+    }
+
+    /// Withdraw locked voting tokens. Tokens that voted stay locked until
+    /// the last of those votes closes, so they cannot vote twice.
+    pub fn unlock_tokens(env: Env, voter: Address, amount: i128) -> Result<i128, GovernanceError> {
+        let _guard = ReentrancyLock::new(&env);
+        voter.require_auth();
+
+        let config = Self::load_config(&env)?;
+        if amount <= 0 {
+            return Err(GovernanceError::InvalidAmount);
+        }
+        if !Clock::has_passed(&env, GovernanceStorage::get_locked_until(&env, &voter)) {
+            return Err(GovernanceError::TokensLocked);
+        }
+        let locked = GovernanceStorage::get_locked(&env, &voter);
+        if amount > locked {
+            return Err(GovernanceError::InsufficientLocked);
+        }
+
+        GovernanceStorage::set_locked(&env, &voter, locked - amount);
+        token::Client::new(&env, &config.voting_token).transfer(&env.current_contract_address(), &voter, &amount);
+        GovernanceEvents::emit_lock_changed(&env, &voter, locked - amount);
+
+        Ok(locked - amount)
+    }
+
+    /// Hand the delegator's voting power to `delegate` until revoked. The
+    /// delegator cannot vote while delegating. Delegation is one level
+    /// deep: weight delegated to a delegate is not passed further on.
+    pub fn delegate(env: Env, delegator: Address, delegate: Address) -> Result<(), GovernanceError> {
+        delegator.require_auth();
+        Self::load_config(&env)?;
+        if delegator == delegate {
+            return Err(GovernanceError::InvalidDelegate);
+        }
+
+        if let Some(previous) = GovernanceStorage::get_delegate(&env, &delegator) {
+            Self::remove_delegator(&env, &delegator, &previous);
+        }
+        let mut delegators = GovernanceStorage::get_delegators(&env, &delegate);
+        if delegators.len() >= MAX_DELEGATORS {
+            return Err(GovernanceError::TooManyDelegators);
+        }
+        delegators.push_back(delegator.clone());
+        GovernanceStorage::set_delegators(&env, &delegate, &delegators);
+        GovernanceStorage::set_delegate(&env, &delegator, &delegate);
+        GovernanceEvents::emit_delegated(&env, &delegator, &delegate);
+
+        Ok(())
+    }
+
+    /// Take back delegated voting power. Votes the delegate already cast
+    /// with it stand.
+    pub fn revoke_delegation(env: Env, delegator: Address) -> Result<(), GovernanceError> {
+        delegator.require_auth();
+
+        let delegate = GovernanceStorage::get_delegate(&env, &delegator).ok_or(GovernanceError::NotDelegated)?;
+        Self::remove_delegator(&env, &delegator, &delegate);
+        GovernanceEvents::emit_delegation_revoked(&env, &delegator, &delegate);
+
+        Ok(())
+    }
+
+    /// Put a set of calls to a vote. The proposer needs at least
+    /// `proposal_threshold` voting power.
+    pub fn propose(
+        env: Env,
+        proposer: Address,
+        description: String,
+        calls: Vec<ProposalCall>,
+    ) -> Result<u64, GovernanceError> {
+        proposer.require_auth();
+
+        let config = Self::load_config(&env)?;
+        if calls.is_empty() || calls.len() > MAX_CALLS {
+            return Err(GovernanceError::InvalidProposal);
+        }
+        if Self::total_power(&env, config.weighting, &proposer) < config.proposal_threshold {
+            return Err(GovernanceError::BelowProposalThreshold);
+        }
+
+        let proposal = Proposal {
+            id: GovernanceStorage::next_proposal_id(&env),
+            proposer: proposer.clone(),
+            description,
+            calls,
+            weighting: config.weighting,
+            quorum: config.quorum,
+            voting_ends: Clock::after(&env, config.voting_period),
+            for_votes: 0,
+            against_votes: 0,
+            abstain_votes: 0,
+            status: ProposalStatus::Active,
+            eta: 0,
+            expires_at: 0,
+        };
+        GovernanceStorage::set_proposal(&env, &proposal);
+        GovernanceEvents::emit_proposed(&env, proposal.id, &proposer, proposal.voting_ends);
+
+        Ok(proposal.id)
+    }
+
+    /// Cast a vote with the voter's current weight plus that of everyone
+    /// delegating to them who has not voted on this proposal. Votes are
+    /// final, and each address's weight counts once per proposal.
+    pub fn vote(env: Env, voter: Address, proposal_id: u64, choice: VoteChoice) -> Result<i128, GovernanceError> {
+        voter.require_auth();
+
+        let mut proposal = Self::load_proposal(&env, proposal_id)?;
+        if Self::state_of(&env, &proposal) != ProposalState::Active {
+            return Err(GovernanceError::VotingClosed);
+        }
+        if GovernanceStorage::get_vote(&env, proposal_id, &voter).is_some() {
+            return Err(GovernanceError::AlreadyVoted);
+        }
+
+        let mut weight = 0;
+        if GovernanceStorage::get_delegate(&env, &voter).is_none() {
+            weight += Self::use_weight(&env, &proposal, &voter);
+        }
+        for delegator in GovernanceStorage::get_delegators(&env, &voter).iter() {
+            if GovernanceStorage::get_vote(&env, proposal_id, &delegator).is_some() {
+                continue;
+            }
+            let delegated = Self::use_weight(&env, &proposal, &delegator);
+            if delegated > 0 {
+                let record = VoteRecord { choice, weight: delegated, via: Some(voter.clone()) };
+                GovernanceStorage::set_vote(&env, proposal_id, &delegator, &record);
+                weight += delegated;
+            }
+        }
+        if weight <= 0 {
+            return Err(GovernanceError::NoVotingPower);
+        }
+
+        match choice {
+            VoteChoice::For => proposal.for_votes += weight,
+            VoteChoice::Against => proposal.against_votes += weight,
+            VoteChoice::Abstain => proposal.abstain_votes += weight,
+        }
+        GovernanceStorage::set_proposal(&env, &proposal);
+        GovernanceStorage::set_vote(&env, proposal_id, &voter, &VoteRecord { choice, weight, via: None });
+        GovernanceEvents::emit_voted(&env, proposal_id, &voter, choice, weight);
+
+        Ok(weight)
+    }
+
+    /// Start the timelock on a proposal that passed. Callable by anyone.
+    pub fn queue(env: Env, proposal_id: u64) -> Result<u64, GovernanceError> {
+        let config = Self::load_config(&env)?;
+        let mut proposal = Self::load_proposal(&env, proposal_id)?;
+        if Self::state_of(&env, &proposal) != ProposalState::Succeeded {
+            return Err(GovernanceError::InvalidProposalState);
+        }
+
+        proposal.status = ProposalStatus::Queued;
+        proposal.eta = Clock::after(&env, config.timelock);
+        proposal.expires_at = proposal.eta.saturating_add(config.execution_window);
+        GovernanceStorage::set_proposal(&env, &proposal);
+        GovernanceEvents::emit_queued(&env, proposal_id, proposal.eta);
+
+        Ok(proposal.eta)
+    }
+
+    /// Make a queued proposal's calls once its timelock has run out.
+    /// Callable by anyone. If any call fails, none of them take effect.
+    pub fn execute(env: Env, proposal_id: u64) -> Result<(), GovernanceError> {
+        let _guard = ReentrancyLock::new(&env);
+
+        let mut proposal = Self::load_proposal(&env, proposal_id)?;
+        if Self::state_of(&env, &proposal) != ProposalState::Queued {
+            return Err(GovernanceError::InvalidProposalState);
+        }
+        if Clock::now(&env) < proposal.eta {
+            return Err(GovernanceError::TimelockActive);
+        }
+
+        proposal.status = ProposalStatus::Executed;
+        GovernanceStorage::set_proposal(&env, &proposal);
+
+        for call in proposal.calls.iter() {
+            CrossContract::invoke::<Val>(&env, &call.contract, &call.function, call.args)
+                .map_err(|_| GovernanceError::ExecutionFailed)?;
+        }
+        GovernanceEvents::emit_executed(&env, proposal_id);
+
+        Ok(())
+    }
+
+    /// Withdraw a proposal before it executes (proposer or admin)
+    pub fn cancel(env: Env, caller: Address, proposal_id: u64) -> Result<(), GovernanceError> {
+        caller.require_auth();
+
+        let mut proposal = Self::load_proposal(&env, proposal_id)?;
+        if caller != proposal.proposer && !AccessControl::is_admin(&env, &caller) {
+            return Err(GovernanceError::Unauthorized);
+        }
+        if matches!(proposal.status, ProposalStatus::Executed | ProposalStatus::Cancelled) {
+            return Err(GovernanceError::InvalidProposalState);
+        }
+
+        proposal.status = ProposalStatus::Cancelled;
+        GovernanceStorage::set_proposal(&env, &proposal);
+        GovernanceEvents::emit_cancelled(&env, proposal_id);
+
+        Ok(())
+    }
+
+    /// Get where a proposal stands now
+    pub fn get_state(env: Env, proposal_id: u64) -> Option<ProposalState> {
+        GovernanceStorage::get_proposal(&env, proposal_id).map(|proposal| Self::state_of(&env, &proposal))
+    }
+
+    /// Get a voter's current voting power under the configured weighting,
+    /// including power delegated to them
+    pub fn get_voting_power(env: Env, voter: Address) -> i128 {
+        match GovernanceStorage::get_config(&env) {
+            Some(config) => Self::total_power(&env, config.weighting, &voter),
+            None => 0,
+        }
+    }
+
+    /// Get who an address has delegated its voting power to
+    pub fn get_delegate(env: Env, delegator: Address) -> Option<Address> {
+        GovernanceStorage::get_delegate(&env, &delegator)
+    }
+
+    /// Get the addresses delegating to a delegate
+    pub fn get_delegators(env: Env, delegate: Address) -> Vec<Address> {
+        GovernanceStorage::get_delegators(&env, &delegate)
+    }
+
+    /// Get a proposal by id
+    pub fn get_proposal(env: Env, proposal_id: u64) -> Option<Proposal> {
+        GovernanceStorage::get_proposal(&env, proposal_id)
+    }
+
+    /// Get a voter's vote on a proposal
+    pub fn get_vote(env: Env, proposal_id: u64, voter: Address) -> Option<VoteRecord> {
+        GovernanceStorage::get_vote(&env, proposal_id, &voter)
+    }
+
+    /// Get the voting tokens a voter has locked
+    pub fn get_locked(env: Env, voter: Address) -> i128 {
+        GovernanceStorage::get_locked(&env, &voter)
+    }
+
+    /// Get the governance configuration
+    pub fn get_config(env: Env) -> Option<GovernanceConfig> {
+        GovernanceStorage::get_config(&env)
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        GovernanceStorage::get_admin(&env)
+    }
+}
+
+impl Governance {
+    fn load_config(env: &Env) -> Result<GovernanceConfig, GovernanceError> {
+        GovernanceStorage::get_config(env).ok_or(GovernanceError::NotInitialized)
+    }
+
+    fn load_proposal(env: &Env, proposal_id: u64) -> Result<Proposal, GovernanceError> {
+        GovernanceStorage::get_proposal(env, proposal_id).ok_or(GovernanceError::ProposalNotFound)
+    }
+
+    fn validate_config(config: &GovernanceConfig) -> Result<(), GovernanceError> {
+        if config.quorum <= 0
+            || config.proposal_threshold < 0
+            || config.voting_period == 0
+            || config.execution_window == 0
+        {
+            return Err(GovernanceError::InvalidConfig);
+        }
+        Ok(())
+    }
+
+    /// Take an address's own weight for a vote, keeping any locked tokens
+    /// behind it locked until voting closes
+    fn use_weight(env: &Env, proposal: &Proposal, holder: &Address) -> i128 {
+        let weight = Self::voting_power(env, proposal.weighting, holder);
+        if weight > 0 && proposal.weighting != VoteWeighting::Role {
+            let until = GovernanceStorage::get_locked_until(env, holder).max(proposal.voting_ends);
+            GovernanceStorage::set_locked_until(env, holder, until);
+        }
+        weight
+    }
+
+    fn remove_delegator(env: &Env, delegator: &Address, delegate: &Address) {
+        let mut delegators = GovernanceStorage::get_delegators(env, delegate);
+        if let Some(index) = delegators.first_index_of(delegator) {
+            delegators.remove(index);
+        }
+        GovernanceStorage::set_delegators(env, delegate, &delegators);
+        GovernanceStorage::remove_delegate(env, delegator);
+    }
+
+    /// Own weight, unless delegated away, plus weight delegated in
+    fn total_power(env: &Env, weighting: VoteWeighting, voter: &Address) -> i128 {
+        let mut power = 0;
+        if GovernanceStorage::get_delegate(env, voter).is_none() {
+            power += Self::voting_power(env, weighting, voter);
+        }
+        for delegator in GovernanceStorage::get_delegators(env, voter).iter() {
+            power += Self::voting_power(env, weighting, &delegator);
+        }
+        power
+    }
+
+    /// Weight an address carries on its own under `weighting`
+    fn voting_power(env: &Env, weighting: VoteWeighting, voter: &Address) -> i128 {
+        match weighting {
+            VoteWeighting::Token => GovernanceStorage::get_locked(env, voter),
+            VoteWeighting::Quadratic => Self::integer_sqrt(GovernanceStorage::get_locked(env, voter)),
+            VoteWeighting::Role => match AccessControl::get_role(env, voter) {
+                Some(role) if !role.is_expired(Clock::now(env)) => role.level as i128,
+                _ => 0,
+            },
+        }
+    }
+
+    /// Largest `r` with `r * r <= value`
+    fn integer_sqrt(value: i128) -> i128 {
+        if value <= 0 {
+            return 0;
+        }
+        let mut root = value;
+        let mut next = (root + 1) / 2;
+        while next < root {
+            root = next;
+            next = (root + value / root) / 2;
+        }
+        root
+    }
+
+    /// Derive a proposal's state from its stored status and the clock
+    fn state_of(env: &Env, proposal: &Proposal) -> ProposalState {
+        match proposal.status {
+            ProposalStatus::Executed => ProposalState::Executed,
+            ProposalStatus::Cancelled => ProposalState::Cancelled,
+            ProposalStatus::Queued => {
+                if Clock::has_passed(env, proposal.expires_at) {
+                    ProposalState::Expired
+                } else {
+                    ProposalState::Queued
+                }
+            }
+            ProposalStatus::Active => {
+                if !Clock::has_passed(env, proposal.voting_ends) {
+                    ProposalState::Active
+                } else if proposal.for_votes + proposal.against_votes + proposal.abstain_votes >= proposal.quorum
+                    && proposal.for_votes > proposal.against_votes
+                {
+                    ProposalState::Succeeded
+                } else {
+                    ProposalState::Defeated
+                }
+            }
+        }
+    }
+}
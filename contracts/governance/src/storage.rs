@@ -0,0 +1,124 @@
+use soroban_sdk::{Address, Env, Vec};
+use crate::types::{DataKey, GovernanceConfig, Proposal, VoteRecord};
+
+/// Storage utilities for the governance contract
+pub struct GovernanceStorage;
+
+impl GovernanceStorage {
+    /// Get the contract admin
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Set the contract admin
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Get the governance configuration
+    pub fn get_config(env: &Env) -> Option<GovernanceConfig> {
+        env.storage().instance().get(&DataKey::Config)
+    }
+
+    /// Set the governance configuration
+    pub fn set_config(env: &Env, config: &GovernanceConfig) {
+        env.storage().instance().set(&DataKey::Config, config);
+    }
+
+    /// Allocate the next proposal id
+    pub fn next_proposal_id(env: &Env) -> u64 {
+        let id: u64 = env.storage().instance().get(&DataKey::ProposalCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::ProposalCount, &id);
+        id
+    }
+
+    /// Get a proposal by id
+    pub fn get_proposal(env: &Env, proposal_id: u64) -> Option<Proposal> {
+        env.storage().persistent().get(&DataKey::Proposal(proposal_id))
+    }
+
+    /// Store a proposal
+    pub fn set_proposal(env: &Env, proposal: &Proposal) {
+        env.storage().persistent().set(&DataKey::Proposal(proposal.id), proposal);
+    }
+
+    /// Get a voter's vote on a proposal
+    pub fn get_vote(env: &Env, proposal_id: u64, voter: &Address) -> Option<VoteRecord> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Vote(proposal_id, voter.clone()))
+    }
+
+    /// Record a voter's vote on a proposal
+    pub fn set_vote(env: &Env, proposal_id: u64, voter: &Address, vote: &VoteRecord) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Vote(proposal_id, voter.clone()), vote);
+    }
+
+    /// Get the voting tokens a voter has locked
+    pub fn get_locked(env: &Env, voter: &Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Locked(voter.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Set the voting tokens a voter has locked
+    pub fn set_locked(env: &Env, voter: &Address, amount: i128) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Locked(voter.clone()), &amount);
+    }
+
+    /// Get when a voter's locked tokens can next be withdrawn
+    pub fn get_locked_until(env: &Env, voter: &Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::LockedUntil(voter.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Get who an address has delegated its voting power to
+    pub fn get_delegate(env: &Env, delegator: &Address) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Delegate(delegator.clone()))
+    }
+
+    /// Set who an address has delegated its voting power to
+    pub fn set_delegate(env: &Env, delegator: &Address, delegate: &Address) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Delegate(delegator.clone()), delegate);
+    }
+
+    /// Clear an address's delegation
+    pub fn remove_delegate(env: &Env, delegator: &Address) {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Delegate(delegator.clone()));
+    }
+
+    /// Get the addresses that have delegated to a delegate
+    pub fn get_delegators(env: &Env, delegate: &Address) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Delegators(delegate.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Set the addresses that have delegated to a delegate
+    pub fn set_delegators(env: &Env, delegate: &Address, delegators: &Vec<Address>) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Delegators(delegate.clone()), delegators);
+    }
+
+    /// Set when a voter's locked tokens can next be withdrawn
+    pub fn set_locked_until(env: &Env, voter: &Address, until: u64) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::LockedUntil(voter.clone()), &until);
+    }
+}
@@ -0,0 +1,250 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger}, token, vec, Address, Env, IntoVal, String, Symbol, Vec,
+};
+use shared::{roles::RoleLevel, time::DAY};
+
+use treasury::{Treasury, TreasuryClient, TreasuryConfig};
+
+use crate::{
+    Governance, GovernanceClient, GovernanceConfig, GovernanceError, ProposalCall, ProposalState, VoteChoice,
+    VoteWeighting,
+};
+
+fn setup_test_env(weighting: VoteWeighting) -> (
+    Env,
+    GovernanceClient<'static>,
+    TreasuryClient<'static>,
+    Address,
+    token::StellarAssetClient<'static>,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::StellarAssetClient::new(&env, &asset.address());
+
+    let contract_id = env.register(Governance, ());
+    let client = GovernanceClient::new(&env, &contract_id);
+    let config = GovernanceConfig {
+        voting_token: asset.address(),
+        weighting,
+        proposal_threshold: 100,
+        quorum: 1_000,
+        voting_period: 3 * DAY,
+        timelock: 2 * DAY,
+        execution_window: 7 * DAY,
+    };
+    client.initialize(&admin, &config);
+
+    let treasury_id = env.register(Treasury, ());
+    let treasury = TreasuryClient::new(&env, &treasury_id);
+    treasury.initialize(&admin, &treasury_config(&env, &admin, 1));
+    treasury.set_governance(&vec![&env, admin.clone()], &contract_id);
+
+    (env, client, treasury, admin, token)
+}
+
+fn treasury_config(env: &Env, signer: &Address, threshold: u32) -> TreasuryConfig {
+    TreasuryConfig {
+        signers: vec![env, signer.clone(), Address::generate(env)],
+        threshold,
+        proposal_ttl: 7 * DAY,
+    }
+}
+
+fn voter(
+    env: &Env,
+    client: &GovernanceClient,
+    token: &token::StellarAssetClient,
+    locked: i128,
+) -> Address {
+    let voter = Address::generate(env);
+    token.mint(&voter, &locked);
+    client.lock_tokens(&voter, &locked);
+    voter
+}
+
+/// A proposal raising the treasury multisig threshold to 2
+fn raise_threshold(
+    env: &Env,
+    client: &GovernanceClient,
+    treasury: &TreasuryClient,
+    admin: &Address,
+    proposer: &Address,
+) -> u64 {
+    let config = treasury_config(env, admin, 2);
+    let call = ProposalCall {
+        contract: treasury.address.clone(),
+        function: Symbol::new(env, "set_config"),
+        args: vec![env, vec![env, client.address.clone()].into_val(env), config.into_val(env)],
+    };
+    client.propose(proposer, &String::from_str(env, "Require 2 treasury signers"), &vec![env, call])
+}
+
+#[test]
+fn test_passed_proposal_executes_after_timelock() {
+    let (env, client, treasury, admin, token) = setup_test_env(VoteWeighting::Token);
+    let alice = voter(&env, &client, &token, 800);
+    let bob = voter(&env, &client, &token, 400);
+    let carol = voter(&env, &client, &token, 300);
+
+    let proposal_id = raise_threshold(&env, &client, &treasury, &admin, &alice);
+    assert_eq!(client.vote(&alice, &proposal_id, &VoteChoice::For), 800);
+    client.vote(&bob, &proposal_id, &VoteChoice::Against);
+    client.vote(&carol, &proposal_id, &VoteChoice::Abstain);
+    assert_eq!(
+        client.try_vote(&bob, &proposal_id, &VoteChoice::For),
+        Err(Ok(GovernanceError::AlreadyVoted))
+    );
+
+    // Tokens that voted stay locked until voting closes
+    assert_eq!(client.try_unlock_tokens(&bob, &400), Err(Ok(GovernanceError::TokensLocked)));
+    assert_eq!(client.try_queue(&proposal_id), Err(Ok(GovernanceError::InvalidProposalState)));
+
+    env.ledger().set_timestamp(3 * DAY + 1);
+    assert_eq!(client.get_state(&proposal_id), Some(ProposalState::Succeeded));
+    assert_eq!(client.unlock_tokens(&bob, &400), 0);
+    assert_eq!(client.queue(&proposal_id), 5 * DAY + 1);
+    assert_eq!(client.try_execute(&proposal_id), Err(Ok(GovernanceError::TimelockActive)));
+
+    env.ledger().set_timestamp(5 * DAY + 1);
+    client.execute(&proposal_id);
+    assert_eq!(client.get_state(&proposal_id), Some(ProposalState::Executed));
+    assert_eq!(treasury.get_config().unwrap().threshold, 2);
+}
+
+#[test]
+fn test_quorum_threshold_and_defeat() {
+    let (env, client, treasury, admin, token) = setup_test_env(VoteWeighting::Token);
+    let small = voter(&env, &client, &token, 50);
+    let large = voter(&env, &client, &token, 600);
+    let other = voter(&env, &client, &token, 500);
+
+    assert_eq!(
+        client.try_propose(&small, &String::from_str(&env, ""), &Vec::new(&env)),
+        Err(Ok(GovernanceError::InvalidProposal))
+    );
+    let config = treasury_config(&env, &admin, 2);
+    let call = ProposalCall {
+        contract: treasury.address.clone(),
+        function: Symbol::new(&env, "set_config"),
+        args: vec![&env, vec![&env, client.address.clone()].into_val(&env), config.into_val(&env)],
+    };
+    assert_eq!(
+        client.try_propose(&small, &String::from_str(&env, ""), &vec![&env, call]),
+        Err(Ok(GovernanceError::BelowProposalThreshold))
+    );
+
+    // 600 For alone misses the 1,000 quorum
+    let missed_quorum = raise_threshold(&env, &client, &treasury, &admin, &large);
+    client.vote(&large, &missed_quorum, &VoteChoice::For);
+
+    // Quorum reached but more against than for
+    let voted_down = raise_threshold(&env, &client, &treasury, &admin, &large);
+    client.vote(&large, &voted_down, &VoteChoice::Against);
+    client.vote(&other, &voted_down, &VoteChoice::For);
+
+    env.ledger().set_timestamp(3 * DAY + 1);
+    assert_eq!(client.get_state(&missed_quorum), Some(ProposalState::Defeated));
+    assert_eq!(client.get_state(&voted_down), Some(ProposalState::Defeated));
+    assert_eq!(
+        client.try_vote(&small, &voted_down, &VoteChoice::For),
+        Err(Ok(GovernanceError::VotingClosed))
+    );
+    assert_eq!(client.try_queue(&voted_down), Err(Ok(GovernanceError::InvalidProposalState)));
+}
+
+#[test]
+fn test_role_weighted_votes_and_failed_execution() {
+    let (env, client, treasury, admin, _token) = setup_test_env(VoteWeighting::Role);
+    let instructor = Address::generate(&env);
+    let student = Address::generate(&env);
+    client.grant_role(&admin, &instructor, &RoleLevel::Instructor);
+    client.grant_role(&admin, &student, &RoleLevel::Student);
+    let config = GovernanceConfig { proposal_threshold: 3, quorum: 8, ..client.get_config().unwrap() };
+    client.set_config(&admin, &config);
+
+    assert_eq!(client.get_voting_power(&instructor), 3);
+    assert_eq!(client.get_voting_power(&Address::generate(&env)), 0);
+
+    // A call the treasury rejects: an empty signer set
+    let bad_config = TreasuryConfig { signers: Vec::new(&env), threshold: 1, proposal_ttl: DAY };
+    let call = ProposalCall {
+        contract: treasury.address.clone(),
+        function: Symbol::new(&env, "set_config"),
+        args: vec![&env, vec![&env, client.address.clone()].into_val(&env), bad_config.into_val(&env)],
+    };
+    let proposal_id = client.propose(&instructor, &String::from_str(&env, "Bad"), &vec![&env, call]);
+    assert_eq!(
+        client.try_propose(&student, &String::from_str(&env, ""), &client.get_proposal(&proposal_id).unwrap().calls),
+        Err(Ok(GovernanceError::BelowProposalThreshold))
+    );
+
+    client.vote(&admin, &proposal_id, &VoteChoice::For);
+    client.vote(&instructor, &proposal_id, &VoteChoice::For);
+    assert_eq!(client.get_proposal(&proposal_id).unwrap().for_votes, 8);
+
+    env.ledger().set_timestamp(3 * DAY + 1);
+    client.queue(&proposal_id);
+    env.ledger().set_timestamp(5 * DAY + 1);
+    assert_eq!(client.try_execute(&proposal_id), Err(Ok(GovernanceError::ExecutionFailed)));
+    assert_eq!(client.get_state(&proposal_id), Some(ProposalState::Queued));
+
+    env.ledger().set_timestamp(12 * DAY + 2);
+    assert_eq!(client.get_state(&proposal_id), Some(ProposalState::Expired));
+}
+
+#[test]
+fn test_quadratic_weighting_dampens_large_holders() {
+    let (env, client, treasury, admin, token) = setup_test_env(VoteWeighting::Quadratic);
+    let config = GovernanceConfig { proposal_threshold: 10, quorum: 50, ..client.get_config().unwrap() };
+    client.set_config(&admin, &config);
+
+    let whale = voter(&env, &client, &token, 2_500);
+    let teachers: [Address; 3] = core::array::from_fn(|_| voter(&env, &client, &token, 400));
+    assert_eq!(client.get_voting_power(&whale), 50);
+    assert_eq!(client.get_voting_power(&teachers[0]), 20);
+
+    // 100x the tokens of a teacher, but outvoted by three of them
+    let proposal_id = raise_threshold(&env, &client, &treasury, &admin, &teachers[0]);
+    client.vote(&whale, &proposal_id, &VoteChoice::Against);
+    for teacher in teachers.iter() {
+        client.vote(teacher, &proposal_id, &VoteChoice::For);
+    }
+    let proposal = client.get_proposal(&proposal_id).unwrap();
+    assert_eq!((proposal.for_votes, proposal.against_votes), (60, 50));
+}
+
+#[test]
+fn test_delegation_and_revocation() {
+    let (env, client, treasury, admin, token) = setup_test_env(VoteWeighting::Token);
+    let delegate = voter(&env, &client, &token, 200);
+    let first = voter(&env, &client, &token, 500);
+    let second = voter(&env, &client, &token, 300);
+
+    client.delegate(&first, &delegate);
+    client.delegate(&second, &delegate);
+    assert_eq!(client.get_voting_power(&delegate), 1_000);
+    assert_eq!(client.get_voting_power(&first), 0);
+    assert_eq!(client.try_delegate(&first, &first), Err(Ok(GovernanceError::InvalidDelegate)));
+
+    // Second votes directly before the delegate does; first is represented
+    let proposal_id = raise_threshold(&env, &client, &treasury, &admin, &delegate);
+    client.revoke_delegation(&second);
+    assert_eq!(client.try_revoke_delegation(&second), Err(Ok(GovernanceError::NotDelegated)));
+    client.vote(&second, &proposal_id, &VoteChoice::Against);
+    assert_eq!(client.vote(&delegate, &proposal_id, &VoteChoice::For), 700);
+
+    let record = client.get_vote(&proposal_id, &first).unwrap();
+    assert_eq!((record.weight, record.via), (500, Some(delegate.clone())));
+
+    // Revoking afterwards does not let first vote again, and their tokens stay locked
+    client.revoke_delegation(&first);
+    assert_eq!(
+        client.try_vote(&first, &proposal_id, &VoteChoice::Against),
+        Err(Ok(GovernanceError::AlreadyVoted))
+    );
+    assert_eq!(client.try_unlock_tokens(&first, &500), Err(Ok(GovernanceError::TokensLocked)));
+    assert!(client.get_delegators(&delegate).is_empty());
+}
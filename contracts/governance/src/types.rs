@@ -0,0 +1,108 @@
+use soroban_sdk::{contracttype, Address, String, Symbol, Val, Vec};
+
+/// How a voter's weight is measured
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum VoteWeighting {
+    Token,     // voting tokens locked in this contract, one vote each
+    Quadratic, // square root of the voting tokens locked
+    Role,      // RBAC role level in this contract
+}
+
+/// Voting and execution parameters
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct GovernanceConfig {
+    pub voting_token: Address,
+    pub weighting: VoteWeighting,
+    pub proposal_threshold: i128, // weight needed to propose
+    pub quorum: i128,             // total weight that must vote
+    pub voting_period: u64,
+    pub timelock: u64,         // delay between queueing and execution
+    pub execution_window: u64, // how long a queued proposal stays executable
+}
+
+/// A call made when a proposal executes
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ProposalCall {
+    pub contract: Address,
+    pub function: Symbol,
+    pub args: Vec<Val>,
+}
+
+/// A voter's position on a proposal
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum VoteChoice {
+    For,
+    Against,
+    Abstain,
+}
+
+/// Stored progress of a proposal. See `ProposalState` for the derived view.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum ProposalStatus {
+    Active,
+    Queued,
+    Executed,
+    Cancelled,
+}
+
+/// Where a proposal stands now
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum ProposalState {
+    Active,
+    Defeated,
+    Succeeded,
+    Queued,
+    Executed,
+    Cancelled,
+    Expired,
+}
+
+/// A parameter change put to a vote
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Proposal {
+    pub id: u64,
+    pub proposer: Address,
+    pub description: String,
+    pub calls: Vec<ProposalCall>,
+    pub weighting: VoteWeighting,
+    pub quorum: i128,
+    pub voting_ends: u64,
+    pub for_votes: i128,
+    pub against_votes: i128,
+    pub abstain_votes: i128,
+    pub status: ProposalStatus,
+    pub eta: u64,        // earliest execution time once queued
+    pub expires_at: u64, // end of the execution window once queued
+}
+
+/// A vote as cast. A delegator whose weight a delegate used gets a record
+/// too, with `via` naming the delegate.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct VoteRecord {
+    pub choice: VoteChoice,
+    pub weight: i128,
+    pub via: Option<Address>,
+}
+
+/// Storage keys for the governance contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Config,
+    ProposalCount,
+    Proposal(u64),
+    Vote(u64, Address),
+    Locked(Address),
+    LockedUntil(Address),
+    Delegate(Address),
+    Delegators(Address),
+}
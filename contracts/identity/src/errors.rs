@@ -0,0 +1,33 @@
+use soroban_sdk::contracterror;
+
+/// Identity contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum IdentityError {
+    // Initialization errors
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+
+    // Authorization errors
+    Unauthorized = 3,
+
+    // Identity errors
+    IdentityNotFound = 4,
+    IdentityRevoked = 5,
+    InvalidDocument = 6,
+
+    // Key errors
+    AddressAlreadyBound = 7,
+    KeyNotFound = 8,
+    TooManyKeys = 9,
+    HistoryFull = 10,
+
+    // Recovery errors
+    InvalidRecoveryConfig = 11,
+    NotGuardian = 12,
+    RecoveryPending = 13,
+    NoRecoveryRequest = 14,
+    AlreadyApproved = 15,
+    RecoveryLocked = 16,
+}
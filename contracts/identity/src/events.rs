@@ -0,0 +1,87 @@
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+/// Identity contract events
+pub struct IdentityEvents;
+
+impl IdentityEvents {
+    fn topic(env: &Env, name: &str) -> (Symbol, Symbol) {
+        (Symbol::new(env, "identity"), Symbol::new(env, name))
+    }
+
+    /// Emit event when an identity is created
+    pub fn emit_created(env: &Env, identity_id: u64, controller: &Address) {
+        env.events().publish(
+            Self::topic(env, "created"),
+            (identity_id, controller.clone()),
+        );
+    }
+
+    /// Emit event when an identity's DID document changes
+    pub fn emit_document_updated(env: &Env, identity_id: u64, version: u32, document_hash: &BytesN<32>) {
+        env.events().publish(
+            Self::topic(env, "document_updated"),
+            (identity_id, version, document_hash.clone()),
+        );
+    }
+
+    /// Emit event when an address starts acting for an identity
+    pub fn emit_key_added(env: &Env, identity_id: u64, key: &Address) {
+        env.events().publish(
+            Self::topic(env, "key_added"),
+            (identity_id, key.clone()),
+        );
+    }
+
+    /// Emit event when an address stops acting for an identity
+    pub fn emit_key_removed(env: &Env, identity_id: u64, key: &Address) {
+        env.events().publish(
+            Self::topic(env, "key_removed"),
+            (identity_id, key.clone()),
+        );
+    }
+
+    /// Emit event when control of an identity moves to a new address
+    pub fn emit_controller_changed(env: &Env, identity_id: u64, old_controller: &Address, new_controller: &Address) {
+        env.events().publish(
+            Self::topic(env, "controller_changed"),
+            (identity_id, old_controller.clone(), new_controller.clone()),
+        );
+    }
+
+    /// Emit event when an identity's guardians change
+    pub fn emit_guardians_updated(env: &Env, identity_id: u64, guardians: u32, threshold: u32) {
+        env.events().publish(
+            Self::topic(env, "guardians_updated"),
+            (identity_id, guardians, threshold),
+        );
+    }
+
+    /// Emit event when a guardian starts or approves a recovery
+    pub fn emit_recovery_approved(env: &Env, identity_id: u64, guardian: &Address, new_address: &Address) {
+        env.events().publish(
+            Self::topic(env, "recovery_approved"),
+            (identity_id, guardian.clone(), new_address.clone()),
+        );
+    }
+
+    /// Emit event when the controller cancels a pending recovery
+    pub fn emit_recovery_cancelled(env: &Env, identity_id: u64) {
+        env.events().publish(Self::topic(env, "recovery_cancelled"), identity_id);
+    }
+
+    /// Emit event when an identity is moved to a recovered address
+    pub fn emit_recovered(env: &Env, identity_id: u64, new_address: &Address) {
+        env.events().publish(
+            Self::topic(env, "recovered"),
+            (identity_id, new_address.clone()),
+        );
+    }
+
+    /// Emit event when an identity is revoked
+    pub fn emit_revoked(env: &Env, identity_id: u64, revoked_by: &Address) {
+        env.events().publish(
+            Self::topic(env, "revoked"),
+            (identity_id, revoked_by.clone()),
+        );
+    }
+}
@@ -0,0 +1,382 @@
+#![no_std]
+
+mod types;
+mod errors;
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod tests;
+
+use soroban_sdk::{contract, contractimpl, vec, Address, BytesN, Env, String, Vec};
+
+use shared::{access_control::AccessControl, time::Clock};
+
+pub use types::{Identity, KeyRecord, RecoveryConfig, RecoveryRequest};
+pub use errors::IdentityError;
+use events::IdentityEvents;
+use storage::IdentityStorage;
+
+/// Upper bound on addresses acting for an identity at once
+const MAX_KEYS: u32 = 10;
+
+/// Upper bound on key history entries, so it fits in one storage entry
+const MAX_KEY_HISTORY: u32 = 100;
+
+/// Upper bound on guardians per identity
+const MAX_GUARDIANS: u32 = 10;
+
+#[contract]
+pub struct IdentityContract;
+
+#[contractimpl]
+impl IdentityContract {
+    /// Initialize the contract
+    pub fn initialize(env: Env, admin: Address) -> Result<(), IdentityError> {
+        if IdentityStorage::get_admin(&env).is_some() {
+            return Err(IdentityError::AlreadyInitialized);
+        }
+
+        AccessControl::initialize(&env, &admin).map_err(|_| IdentityError::AlreadyInitialized)?;
+        IdentityStorage::set_admin(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Create an identity controlled by, and first keyed to, the caller.
+    /// Returns its id, which DID documents and other contracts refer to.
+    pub fn create_identity(
+        env: Env,
+        controller: Address,
+        document_hash: BytesN<32>,
+        document_uri: String,
+    ) -> Result<u64, IdentityError> {
+        controller.require_auth();
+        if document_uri.is_empty() {
+            return Err(IdentityError::InvalidDocument);
+        }
+        if IdentityStorage::get_binding(&env, &controller).is_some() {
+            return Err(IdentityError::AddressAlreadyBound);
+        }
+
+        let now = Clock::now(&env);
+        let identity = Identity {
+            id: IdentityStorage::next_identity_id(&env),
+            controller: controller.clone(),
+            keys: vec![&env, controller.clone()],
+            document_hash,
+            document_uri,
+            version: 1,
+            created_at: now,
+            updated_at: now,
+            revoked: false,
+        };
+        IdentityStorage::set_identity(&env, &identity);
+        IdentityStorage::set_binding(&env, &controller, identity.id);
+        let history = vec![&env, KeyRecord { key: controller.clone(), added_at: now, removed_at: None }];
+        IdentityStorage::set_key_history(&env, identity.id, &history);
+        IdentityEvents::emit_created(&env, identity.id, &controller);
+        IdentityEvents::emit_key_added(&env, identity.id, &controller);
+
+        Ok(identity.id)
+    }
+
+    /// Point the identity at a new DID document (controller only)
+    pub fn update_document(
+        env: Env,
+        controller: Address,
+        identity_id: u64,
+        document_hash: BytesN<32>,
+        document_uri: String,
+    ) -> Result<u32, IdentityError> {
+        let mut identity = Self::load_controlled(&env, &controller, identity_id)?;
+        if document_uri.is_empty() {
+            return Err(IdentityError::InvalidDocument);
+        }
+
+        identity.document_hash = document_hash;
+        identity.document_uri = document_uri;
+        identity.version += 1;
+        identity.updated_at = Clock::now(&env);
+        IdentityStorage::set_identity(&env, &identity);
+        IdentityEvents::emit_document_updated(&env, identity_id, identity.version, &identity.document_hash);
+
+        Ok(identity.version)
+    }
+
+    /// Let another address act for the identity (controller only). The
+    /// address must authorize too, proving it is held by the learner.
+    pub fn add_key(env: Env, controller: Address, identity_id: u64, key: Address) -> Result<(), IdentityError> {
+        let mut identity = Self::load_controlled(&env, &controller, identity_id)?;
+        Self::bind_key(&env, &mut identity, &key)?;
+        IdentityStorage::set_identity(&env, &identity);
+        Ok(())
+    }
+
+    /// Stop an address acting for the identity (controller only)
+    pub fn remove_key(env: Env, controller: Address, identity_id: u64, key: Address) -> Result<(), IdentityError> {
+        let mut identity = Self::load_controlled(&env, &controller, identity_id)?;
+        Self::unbind_key(&env, &mut identity, &key)?;
+        IdentityStorage::set_identity(&env, &identity);
+        Ok(())
+    }
+
+    /// Replace one address with another in a single step, e.g. when moving
+    /// to a new wallet (controller only; the new address must authorize)
+    pub fn rotate_key(
+        env: Env,
+        controller: Address,
+        identity_id: u64,
+        old_key: Address,
+        new_key: Address,
+    ) -> Result<(), IdentityError> {
+        let mut identity = Self::load_controlled(&env, &controller, identity_id)?;
+        Self::unbind_key(&env, &mut identity, &old_key)?;
+        Self::bind_key(&env, &mut identity, &new_key)?;
+        IdentityStorage::set_identity(&env, &identity);
+        Ok(())
+    }
+
+    /// Hand control of the identity to another address (controller only;
+    /// the new controller must authorize)
+    pub fn change_controller(
+        env: Env,
+        controller: Address,
+        identity_id: u64,
+        new_controller: Address,
+    ) -> Result<(), IdentityError> {
+        let mut identity = Self::load_controlled(&env, &controller, identity_id)?;
+        new_controller.require_auth();
+
+        identity.controller = new_controller.clone();
+        identity.updated_at = Clock::now(&env);
+        IdentityStorage::set_identity(&env, &identity);
+        IdentityEvents::emit_controller_changed(&env, identity_id, &controller, &new_controller);
+
+        Ok(())
+    }
+
+    /// Permanently revoke an identity (its controller, or an admin for
+    /// fraud). Its addresses are released and its history is kept.
+    pub fn revoke(env: Env, caller: Address, identity_id: u64) -> Result<(), IdentityError> {
+        caller.require_auth();
+        let mut identity = Self::load_active(&env, identity_id)?;
+        if caller != identity.controller && !AccessControl::is_admin(&env, &caller) {
+            return Err(IdentityError::Unauthorized);
+        }
+
+        for key in identity.keys.clone().iter() {
+            Self::unbind_key(&env, &mut identity, &key)?;
+        }
+        identity.revoked = true;
+        identity.updated_at = Clock::now(&env);
+        IdentityStorage::set_identity(&env, &identity);
+        IdentityEvents::emit_revoked(&env, identity_id, &caller);
+
+        Ok(())
+    }
+
+    /// Choose the guardians who can recover the identity, how many must
+    /// approve, and how long a recovery waits before it can execute
+    /// (controller only). Cancels any pending recovery.
+    pub fn set_guardians(env: Env, controller: Address, identity_id: u64, config: RecoveryConfig) -> Result<(), IdentityError> {
+        Self::load_controlled(&env, &controller, identity_id)?;
+        let guardians = config.guardians.len();
+        if guardians == 0
+            || guardians > MAX_GUARDIANS
+            || config.threshold == 0
+            || config.threshold > guardians
+            || config.guardians.contains(&controller)
+        {
+            return Err(IdentityError::InvalidRecoveryConfig);
+        }
+        for (index, guardian) in config.guardians.iter().enumerate() {
+            if config.guardians.first_index_of(&guardian) != Some(index as u32) {
+                return Err(IdentityError::InvalidRecoveryConfig);
+            }
+        }
+
+        IdentityStorage::set_recovery(&env, identity_id, &config);
+        IdentityStorage::remove_recovery_request(&env, identity_id);
+        IdentityEvents::emit_guardians_updated(&env, identity_id, guardians, config.threshold);
+
+        Ok(())
+    }
+
+    /// Start a recovery to `new_address`, or approve the pending one
+    /// (guardians only). Once enough guardians approve, the recovery can be
+    /// executed after the delay.
+    pub fn approve_recovery(env: Env, guardian: Address, identity_id: u64, new_address: Address) -> Result<(), IdentityError> {
+        guardian.require_auth();
+        Self::load_active(&env, identity_id)?;
+        let config = IdentityStorage::get_recovery(&env, identity_id).ok_or(IdentityError::NotGuardian)?;
+        if !config.guardians.contains(&guardian) {
+            return Err(IdentityError::NotGuardian);
+        }
+
+        let mut request = match IdentityStorage::get_recovery_request(&env, identity_id) {
+            Some(request) if request.new_address != new_address => return Err(IdentityError::RecoveryPending),
+            Some(request) => request,
+            None => RecoveryRequest {
+                new_address: new_address.clone(),
+                approvals: Vec::new(&env),
+                initiated_at: Clock::now(&env),
+                executable_at: None,
+            },
+        };
+        if request.approvals.contains(&guardian) {
+            return Err(IdentityError::AlreadyApproved);
+        }
+
+        request.approvals.push_back(guardian.clone());
+        if request.executable_at.is_none() && request.approvals.len() >= config.threshold {
+            request.executable_at = Some(Clock::now(&env) + config.delay);
+        }
+        IdentityStorage::set_recovery_request(&env, identity_id, &request);
+        IdentityEvents::emit_recovery_approved(&env, identity_id, &guardian, &new_address);
+
+        Ok(())
+    }
+
+    /// Cancel a pending recovery (controller only), e.g. one started
+    /// without the learner's knowledge
+    pub fn cancel_recovery(env: Env, controller: Address, identity_id: u64) -> Result<(), IdentityError> {
+        Self::load_controlled(&env, &controller, identity_id)?;
+        if IdentityStorage::get_recovery_request(&env, identity_id).is_none() {
+            return Err(IdentityError::NoRecoveryRequest);
+        }
+
+        IdentityStorage::remove_recovery_request(&env, identity_id);
+        IdentityEvents::emit_recovery_cancelled(&env, identity_id);
+
+        Ok(())
+    }
+
+    /// Move the identity to the recovered address once the guardians have
+    /// approved and the delay has passed. Every current key is removed and
+    /// the new address becomes the only key and the controller; it must
+    /// authorize.
+    pub fn execute_recovery(env: Env, identity_id: u64) -> Result<(), IdentityError> {
+        let mut identity = Self::load_active(&env, identity_id)?;
+        let request = IdentityStorage::get_recovery_request(&env, identity_id).ok_or(IdentityError::NoRecoveryRequest)?;
+        match request.executable_at {
+            Some(executable_at) if Clock::now(&env) >= executable_at => {}
+            _ => return Err(IdentityError::RecoveryLocked),
+        }
+
+        for key in identity.keys.clone().iter() {
+            Self::unbind_key(&env, &mut identity, &key)?;
+        }
+        Self::bind_key(&env, &mut identity, &request.new_address)?;
+        identity.controller = request.new_address.clone();
+        IdentityStorage::set_identity(&env, &identity);
+        IdentityStorage::remove_recovery_request(&env, identity_id);
+        IdentityEvents::emit_recovered(&env, identity_id, &request.new_address);
+
+        Ok(())
+    }
+
+    /// Get an identity
+    pub fn get_identity(env: Env, identity_id: u64) -> Option<Identity> {
+        IdentityStorage::get_identity(&env, identity_id)
+    }
+
+    /// Get the identity an address currently acts for
+    pub fn resolve(env: Env, key: Address) -> Option<u64> {
+        IdentityStorage::get_binding(&env, &key)
+    }
+
+    /// Get an identity's guardians
+    pub fn get_guardians(env: Env, identity_id: u64) -> Option<RecoveryConfig> {
+        IdentityStorage::get_recovery(&env, identity_id)
+    }
+
+    /// Get an identity's pending recovery
+    pub fn get_recovery_request(env: Env, identity_id: u64) -> Option<RecoveryRequest> {
+        IdentityStorage::get_recovery_request(&env, identity_id)
+    }
+
+    /// Get every address that has acted for an identity, oldest first
+    pub fn get_key_history(env: Env, identity_id: u64) -> Vec<KeyRecord> {
+        IdentityStorage::get_key_history(&env, identity_id)
+    }
+
+    /// Whether an address acted for an identity at a point in time, e.g.
+    /// when a credential was issued to a wallet the learner has since
+    /// rotated away from
+    pub fn was_key_at(env: Env, identity_id: u64, key: Address, timestamp: u64) -> bool {
+        IdentityStorage::get_key_history(&env, identity_id).iter().any(|record| {
+            record.key == key
+                && record.added_at <= timestamp
+                && record.removed_at.is_none_or(|removed_at| timestamp < removed_at)
+        })
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        IdentityStorage::get_admin(&env)
+    }
+}
+
+impl IdentityContract {
+    fn load_active(env: &Env, identity_id: u64) -> Result<Identity, IdentityError> {
+        let identity = IdentityStorage::get_identity(env, identity_id).ok_or(IdentityError::IdentityNotFound)?;
+        if identity.revoked {
+            return Err(IdentityError::IdentityRevoked);
+        }
+        Ok(identity)
+    }
+
+    fn load_controlled(env: &Env, controller: &Address, identity_id: u64) -> Result<Identity, IdentityError> {
+        controller.require_auth();
+        let identity = Self::load_active(env, identity_id)?;
+        if identity.controller != *controller {
+            return Err(IdentityError::Unauthorized);
+        }
+        Ok(identity)
+    }
+
+    fn bind_key(env: &Env, identity: &mut Identity, key: &Address) -> Result<(), IdentityError> {
+        key.require_auth();
+        if IdentityStorage::get_binding(env, key).is_some() {
+            return Err(IdentityError::AddressAlreadyBound);
+        }
+        if identity.keys.len() >= MAX_KEYS {
+            return Err(IdentityError::TooManyKeys);
+        }
+        let mut history = IdentityStorage::get_key_history(env, identity.id);
+        if history.len() >= MAX_KEY_HISTORY {
+            return Err(IdentityError::HistoryFull);
+        }
+
+        let now = Clock::now(env);
+        identity.keys.push_back(key.clone());
+        identity.updated_at = now;
+        history.push_back(KeyRecord { key: key.clone(), added_at: now, removed_at: None });
+        IdentityStorage::set_key_history(env, identity.id, &history);
+        IdentityStorage::set_binding(env, key, identity.id);
+        IdentityEvents::emit_key_added(env, identity.id, key);
+
+        Ok(())
+    }
+
+    fn unbind_key(env: &Env, identity: &mut Identity, key: &Address) -> Result<(), IdentityError> {
+        let index = identity.keys.first_index_of(key).ok_or(IdentityError::KeyNotFound)?;
+        let now = Clock::now(env);
+        identity.keys.remove(index);
+        identity.updated_at = now;
+
+        let mut history = IdentityStorage::get_key_history(env, identity.id);
+        for (position, mut record) in history.clone().iter().enumerate() {
+            if record.key == *key && record.removed_at.is_none() {
+                record.removed_at = Some(now);
+                history.set(position as u32, record);
+            }
+        }
+        IdentityStorage::set_key_history(env, identity.id, &history);
+        IdentityStorage::remove_binding(env, key);
+        IdentityEvents::emit_key_removed(env, identity.id, key);
+
+        Ok(())
+    }
+}
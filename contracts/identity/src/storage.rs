@@ -0,0 +1,93 @@
+use soroban_sdk::{Address, Env, Vec};
+use crate::types::{DataKey, Identity, KeyRecord, RecoveryConfig, RecoveryRequest};
+
+/// Storage utilities for the identity contract
+pub struct IdentityStorage;
+
+impl IdentityStorage {
+    /// Get the contract admin
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Set the contract admin
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Allocate the next identity id
+    pub fn next_identity_id(env: &Env) -> u64 {
+        let id: u64 = env.storage().instance().get(&DataKey::IdentityCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::IdentityCount, &id);
+        id
+    }
+
+    /// Get an identity
+    pub fn get_identity(env: &Env, identity_id: u64) -> Option<Identity> {
+        env.storage().persistent().get(&DataKey::Identity(identity_id))
+    }
+
+    /// Store an identity
+    pub fn set_identity(env: &Env, identity: &Identity) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Identity(identity.id), identity);
+    }
+
+    /// Get every address that has acted for an identity
+    pub fn get_key_history(env: &Env, identity_id: u64) -> Vec<KeyRecord> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::KeyHistory(identity_id))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Set every address that has acted for an identity
+    pub fn set_key_history(env: &Env, identity_id: u64, history: &Vec<KeyRecord>) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::KeyHistory(identity_id), history);
+    }
+
+    /// Get the identity an address currently acts for
+    pub fn get_binding(env: &Env, key: &Address) -> Option<u64> {
+        env.storage().persistent().get(&DataKey::Binding(key.clone()))
+    }
+
+    /// Bind an address to an identity
+    pub fn set_binding(env: &Env, key: &Address, identity_id: u64) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Binding(key.clone()), &identity_id);
+    }
+
+    /// Unbind an address
+    pub fn remove_binding(env: &Env, key: &Address) {
+        env.storage().persistent().remove(&DataKey::Binding(key.clone()));
+    }
+
+    /// Get an identity's guardians
+    pub fn get_recovery(env: &Env, identity_id: u64) -> Option<RecoveryConfig> {
+        env.storage().persistent().get(&DataKey::Recovery(identity_id))
+    }
+
+    /// Set an identity's guardians
+    pub fn set_recovery(env: &Env, identity_id: u64, config: &RecoveryConfig) {
+        env.storage().persistent().set(&DataKey::Recovery(identity_id), config);
+    }
+
+    /// Get an identity's pending recovery
+    pub fn get_recovery_request(env: &Env, identity_id: u64) -> Option<RecoveryRequest> {
+        env.storage().persistent().get(&DataKey::RecoveryRequest(identity_id))
+    }
+
+    /// Set an identity's pending recovery
+    pub fn set_recovery_request(env: &Env, identity_id: u64, request: &RecoveryRequest) {
+        env.storage().persistent().set(&DataKey::RecoveryRequest(identity_id), request);
+    }
+
+    /// Clear an identity's pending recovery
+    pub fn remove_recovery_request(env: &Env, identity_id: u64) {
+        env.storage().persistent().remove(&DataKey::RecoveryRequest(identity_id));
+    }
+}
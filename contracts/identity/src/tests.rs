@@ -0,0 +1,147 @@
+use shared::time::DAY;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger}, vec, Address, BytesN, Env, String,
+};
+
+use crate::{IdentityContract, IdentityContractClient, IdentityError, RecoveryConfig};
+
+fn setup_test_env() -> (Env, IdentityContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(IdentityContract, ());
+    let client = IdentityContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+
+    (env, client, admin)
+}
+
+fn create(env: &Env, client: &IdentityContractClient, controller: &Address) -> u64 {
+    let hash = BytesN::from_array(env, &[1; 32]);
+    client.create_identity(controller, &hash, &String::from_str(env, "ipfs://did-doc"))
+}
+
+#[test]
+fn test_identity_survives_wallet_rotation() {
+    let (env, client, _admin) = setup_test_env();
+    let old_wallet = Address::generate(&env);
+    let id = create(&env, &client, &old_wallet);
+    assert_eq!(client.resolve(&old_wallet), Some(id));
+
+    env.ledger().set_timestamp(100);
+    let new_wallet = Address::generate(&env);
+    client.rotate_key(&old_wallet, &id, &old_wallet, &new_wallet);
+    client.change_controller(&old_wallet, &id, &new_wallet);
+
+    assert_eq!(client.resolve(&old_wallet), None);
+    assert_eq!(client.resolve(&new_wallet), Some(id));
+    // Credentials issued to the old wallet still trace back to the identity
+    assert!(client.was_key_at(&id, &old_wallet, &50));
+    assert!(!client.was_key_at(&id, &old_wallet, &100));
+    assert!(client.was_key_at(&id, &new_wallet, &100));
+
+    let hash = BytesN::from_array(&env, &[2; 32]);
+    let uri = String::from_str(&env, "ipfs://did-doc-v2");
+    assert_eq!(
+        client.try_update_document(&old_wallet, &id, &hash, &uri),
+        Err(Ok(IdentityError::Unauthorized))
+    );
+    assert_eq!(client.update_document(&new_wallet, &id, &hash, &uri), 2);
+}
+
+#[test]
+fn test_addresses_bind_to_one_identity() {
+    let (env, client, _admin) = setup_test_env();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let alice_id = create(&env, &client, &alice);
+    let bob_id = create(&env, &client, &bob);
+
+    assert_eq!(
+        client.try_add_key(&alice, &alice_id, &bob),
+        Err(Ok(IdentityError::AddressAlreadyBound))
+    );
+    assert_eq!(
+        client.try_remove_key(&alice, &bob_id, &bob),
+        Err(Ok(IdentityError::Unauthorized))
+    );
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_remove_key(&alice, &alice_id, &stranger),
+        Err(Ok(IdentityError::KeyNotFound))
+    );
+
+    let phone = Address::generate(&env);
+    client.add_key(&alice, &alice_id, &phone);
+    assert_eq!(client.get_identity(&alice_id).unwrap().keys.len(), 2);
+    client.remove_key(&alice, &alice_id, &phone);
+    assert_eq!(client.resolve(&phone), None);
+    assert_eq!(client.get_key_history(&alice_id).len(), 2);
+}
+
+#[test]
+fn test_revocation_releases_addresses() {
+    let (env, client, admin) = setup_test_env();
+    let learner = Address::generate(&env);
+    let id = create(&env, &client, &learner);
+    let outsider = Address::generate(&env);
+    assert_eq!(client.try_revoke(&outsider, &id), Err(Ok(IdentityError::Unauthorized)));
+
+    client.revoke(&admin, &id);
+    assert!(client.get_identity(&id).unwrap().revoked);
+    assert_eq!(client.resolve(&learner), None);
+    assert_eq!(client.try_revoke(&learner, &id), Err(Ok(IdentityError::IdentityRevoked)));
+
+    // The learner can start over with a fresh identity
+    assert_eq!(create(&env, &client, &learner), id + 1);
+}
+
+#[test]
+fn test_guardians_recover_lost_wallet_after_delay() {
+    let (env, client, _admin) = setup_test_env();
+    let lost = Address::generate(&env);
+    let id = create(&env, &client, &lost);
+    let guardians: [Address; 3] = core::array::from_fn(|_| Address::generate(&env));
+    let config = RecoveryConfig {
+        guardians: vec![&env, guardians[0].clone(), guardians[1].clone(), guardians[2].clone()],
+        threshold: 2,
+        delay: 3 * DAY,
+    };
+    client.set_guardians(&lost, &id, &config);
+
+    let recovered = Address::generate(&env);
+    let attacker = Address::generate(&env);
+    assert_eq!(client.try_approve_recovery(&attacker, &id, &attacker), Err(Ok(IdentityError::NotGuardian)));
+    client.approve_recovery(&guardians[0], &id, &recovered);
+    assert_eq!(client.try_approve_recovery(&guardians[1], &id, &attacker), Err(Ok(IdentityError::RecoveryPending)));
+    assert_eq!(client.try_execute_recovery(&id), Err(Ok(IdentityError::RecoveryLocked)));
+    client.approve_recovery(&guardians[1], &id, &recovered);
+    assert_eq!(client.try_execute_recovery(&id), Err(Ok(IdentityError::RecoveryLocked)));
+
+    env.ledger().set_timestamp(3 * DAY);
+    client.execute_recovery(&id);
+    let identity = client.get_identity(&id).unwrap();
+    assert_eq!(identity.controller, recovered);
+    assert_eq!(identity.keys, vec![&env, recovered.clone()]);
+    assert_eq!(client.resolve(&lost), None);
+    assert!(client.was_key_at(&id, &lost, &0));
+}
+
+#[test]
+fn test_controller_can_cancel_recovery() {
+    let (env, client, _admin) = setup_test_env();
+    let learner = Address::generate(&env);
+    let id = create(&env, &client, &learner);
+    let guardian = Address::generate(&env);
+    let config = RecoveryConfig { guardians: vec![&env, learner.clone()], threshold: 1, delay: DAY };
+    assert_eq!(client.try_set_guardians(&learner, &id, &config), Err(Ok(IdentityError::InvalidRecoveryConfig)));
+    let config = RecoveryConfig { guardians: vec![&env, guardian.clone()], threshold: 1, delay: DAY };
+    client.set_guardians(&learner, &id, &config);
+
+    client.approve_recovery(&guardian, &id, &guardian);
+    client.cancel_recovery(&learner, &id);
+    env.ledger().set_timestamp(2 * DAY);
+    assert_eq!(client.try_execute_recovery(&id), Err(Ok(IdentityError::NoRecoveryRequest)));
+    assert_eq!(client.resolve(&learner), Some(id));
+}
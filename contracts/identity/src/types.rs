@@ -0,0 +1,57 @@
+use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
+
+/// A persistent identity and its current DID document
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Identity {
+    pub id: u64,
+    pub controller: Address, // may update the document, keys and controller
+    pub keys: Vec<Address>,  // addresses currently acting for the identity
+    pub document_hash: BytesN<32>,
+    pub document_uri: String,
+    pub version: u32, // bumped on every document change
+    pub created_at: u64,
+    pub updated_at: u64,
+    pub revoked: bool,
+}
+
+/// When an address acted for an identity
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct KeyRecord {
+    pub key: Address,
+    pub added_at: u64,
+    pub removed_at: Option<u64>,
+}
+
+/// Guardians who can move an identity to a new address if its keys are lost
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RecoveryConfig {
+    pub guardians: Vec<Address>,
+    pub threshold: u32, // approvals needed
+    pub delay: u64,     // seconds between reaching the threshold and executing
+}
+
+/// A pending move of an identity to a new address
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RecoveryRequest {
+    pub new_address: Address,
+    pub approvals: Vec<Address>,
+    pub initiated_at: u64,
+    pub executable_at: Option<u64>, // set once the threshold is reached
+}
+
+/// Storage keys for the identity contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    IdentityCount,
+    Identity(u64),
+    KeyHistory(u64),
+    Binding(Address), // identity an address currently acts for
+    Recovery(u64),
+    RecoveryRequest(u64),
+}
@@ -0,0 +1,34 @@
+use soroban_sdk::contracterror;
+
+/// Mentorship contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum MentorshipError {
+    // Initialization errors
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+
+    // Authorization errors
+    Unauthorized = 3,
+
+    // Configuration errors
+    InvalidConfig = 4,
+
+    // Listing errors
+    InvalidTerms = 5,
+    MentorNotFound = 6,
+    MentorUnlisted = 7,
+    InvalidAvailability = 8,
+
+    // Booking errors
+    SlotUnavailable = 9,
+    SelfBooking = 10,
+    SessionNotFound = 11,
+    SessionClosed = 12,
+    SessionNotStarted = 13,
+    AlreadyReported = 14,
+    ConfirmWindowOpen = 15,
+    NotDisputed = 16,
+    InvalidRefund = 17,
+}
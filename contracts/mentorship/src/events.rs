@@ -0,0 +1,56 @@
+use soroban_sdk::{Address, Env, Symbol};
+use crate::types::SessionStatus;
+
+/// Mentorship contract events
+pub struct MentorshipEvents;
+
+impl MentorshipEvents {
+    fn topic(env: &Env, name: &str) -> (Symbol, Symbol) {
+        (Symbol::new(env, "mentorship"), Symbol::new(env, name))
+    }
+
+    /// Emit event when a mentor lists or changes their terms
+    pub fn emit_mentor_listed(env: &Env, mentor: &Address, rate: i128, session_length: u64) {
+        env.events().publish(
+            Self::topic(env, "mentor_listed"),
+            (mentor.clone(), rate, session_length),
+        );
+    }
+
+    /// Emit event when a mentor replaces their open slots
+    pub fn emit_availability_updated(env: &Env, mentor: &Address, slots: u32) {
+        env.events().publish(
+            Self::topic(env, "availability_updated"),
+            (mentor.clone(), slots),
+        );
+    }
+
+    /// Emit event when a mentee books and pays for a session
+    pub fn emit_booked(env: &Env, session_id: u64, mentor: &Address, mentee: &Address, starts_at: u64) {
+        env.events().publish(
+            Self::topic(env, "booked"),
+            (session_id, mentor.clone(), mentee.clone(), starts_at),
+        );
+    }
+
+    /// Emit event when a party confirms a session or reports a no-show
+    pub fn emit_reported(env: &Env, session_id: u64, party: &Address, confirmed: bool) {
+        env.events().publish(
+            Self::topic(env, "reported"),
+            (session_id, party.clone(), confirmed),
+        );
+    }
+
+    /// Emit event when a session's escrow is paid out
+    pub fn emit_settled(env: &Env, session_id: u64, status: SessionStatus, paid: i128, refunded: i128) {
+        env.events().publish(
+            Self::topic(env, "settled"),
+            (session_id, status, paid, refunded),
+        );
+    }
+
+    /// Emit event when conflicting reports leave a session for an admin
+    pub fn emit_disputed(env: &Env, session_id: u64) {
+        env.events().publish(Self::topic(env, "disputed"), session_id);
+    }
+}
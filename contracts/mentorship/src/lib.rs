@@ -0,0 +1,366 @@
+#![no_std]
+
+mod types;
+mod errors;
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod tests;
+
+use soroban_sdk::{contract, contractimpl, token, Address, Env, Vec};
+
+use shared::{
+    access_control::AccessControl,
+    fees::{Fees, BASIS_POINTS},
+    reentrancy_guard::ReentrancyLock,
+    time::Clock,
+};
+
+pub use types::{Attendance, Mentor, MentorTerms, MentorshipConfig, Session, SessionStatus};
+pub use errors::MentorshipError;
+use events::MentorshipEvents;
+use storage::MentorshipStorage;
+
+/// Upper bound on open slots per mentor
+const MAX_SLOTS: u32 = 50;
+
+#[contract]
+pub struct Mentorship;
+
+#[contractimpl]
+impl Mentorship {
+    /// Initialize the contract with the cancellation and no-show rules
+    pub fn initialize(env: Env, admin: Address, config: MentorshipConfig) -> Result<(), MentorshipError> {
+        if MentorshipStorage::get_admin(&env).is_some() {
+            return Err(MentorshipError::AlreadyInitialized);
+        }
+        Self::validate_config(&config)?;
+
+        AccessControl::initialize(&env, &admin).map_err(|_| MentorshipError::AlreadyInitialized)?;
+        MentorshipStorage::set_admin(&env, &admin);
+        MentorshipStorage::set_config(&env, &config);
+
+        Ok(())
+    }
+
+    /// Replace the cancellation and no-show rules (admin only). Sessions
+    /// already booked settle under the new rules.
+    pub fn set_config(env: Env, admin: Address, config: MentorshipConfig) -> Result<(), MentorshipError> {
+        Self::require_admin(&env, &admin)?;
+        Self::validate_config(&config)?;
+        MentorshipStorage::set_config(&env, &config);
+        Ok(())
+    }
+
+    /// List as a mentor, or change rate and session length. Sessions
+    /// already booked keep the price they were booked at.
+    pub fn list_mentor(env: Env, mentor: Address, terms: MentorTerms) -> Result<(), MentorshipError> {
+        mentor.require_auth();
+        if terms.rate <= 0 || terms.session_length == 0 {
+            return Err(MentorshipError::InvalidTerms);
+        }
+
+        let listing = match MentorshipStorage::get_mentor(&env, &mentor) {
+            Some(mut listing) => {
+                listing.terms = terms;
+                listing.listed = true;
+                listing
+            }
+            None => Mentor {
+                mentor: mentor.clone(),
+                terms,
+                listed: true,
+                sessions_completed: 0,
+            },
+        };
+        MentorshipStorage::set_mentor(&env, &listing);
+        MentorshipEvents::emit_mentor_listed(&env, &mentor, listing.terms.rate, listing.terms.session_length);
+
+        Ok(())
+    }
+
+    /// Stop or resume taking bookings. Booked sessions are unaffected.
+    pub fn set_listed(env: Env, mentor: Address, listed: bool) -> Result<(), MentorshipError> {
+        mentor.require_auth();
+        let mut listing = MentorshipStorage::get_mentor(&env, &mentor).ok_or(MentorshipError::MentorNotFound)?;
+        listing.listed = listed;
+        MentorshipStorage::set_mentor(&env, &listing);
+        Ok(())
+    }
+
+    /// Replace the mentor's open slots with future session start times in
+    /// increasing order
+    pub fn set_availability(env: Env, mentor: Address, slots: Vec<u64>) -> Result<(), MentorshipError> {
+        mentor.require_auth();
+        MentorshipStorage::get_mentor(&env, &mentor).ok_or(MentorshipError::MentorNotFound)?;
+        if slots.len() > MAX_SLOTS {
+            return Err(MentorshipError::InvalidAvailability);
+        }
+        let mut previous = Clock::now(&env);
+        for slot in slots.iter() {
+            if slot <= previous {
+                return Err(MentorshipError::InvalidAvailability);
+            }
+            previous = slot;
+        }
+
+        MentorshipStorage::set_availability(&env, &mentor, &slots);
+        MentorshipEvents::emit_availability_updated(&env, &mentor, slots.len());
+
+        Ok(())
+    }
+
+    /// Book one of a mentor's open slots, escrowing the mentor's rate.
+    /// Returns the session id.
+    pub fn book(env: Env, mentee: Address, mentor: Address, starts_at: u64) -> Result<u64, MentorshipError> {
+        let _guard = ReentrancyLock::new(&env);
+        mentee.require_auth();
+        if mentee == mentor {
+            return Err(MentorshipError::SelfBooking);
+        }
+        let listing = MentorshipStorage::get_mentor(&env, &mentor).ok_or(MentorshipError::MentorNotFound)?;
+        if !listing.listed {
+            return Err(MentorshipError::MentorUnlisted);
+        }
+
+        let mut slots = MentorshipStorage::get_availability(&env, &mentor);
+        let index = slots.first_index_of(starts_at).ok_or(MentorshipError::SlotUnavailable)?;
+        if starts_at <= Clock::now(&env) {
+            return Err(MentorshipError::SlotUnavailable);
+        }
+        slots.remove(index);
+        MentorshipStorage::set_availability(&env, &mentor, &slots);
+
+        let terms = listing.terms;
+        token::Client::new(&env, &terms.token).transfer(&mentee, &env.current_contract_address(), &terms.rate);
+
+        let session = Session {
+            id: MentorshipStorage::next_session_id(&env),
+            mentor: mentor.clone(),
+            mentee: mentee.clone(),
+            token: terms.token,
+            price: terms.rate,
+            starts_at,
+            ends_at: starts_at + terms.session_length,
+            mentor_report: Attendance::Unreported,
+            mentee_report: Attendance::Unreported,
+            status: SessionStatus::Booked,
+            refunded: 0,
+        };
+        MentorshipStorage::set_session(&env, &session);
+        MentorshipStorage::add_session_ids(&env, &session);
+        MentorshipEvents::emit_booked(&env, session.id, &mentor, &mentee, starts_at);
+
+        Ok(session.id)
+    }
+
+    /// Cancel a session before it starts. A mentor cancellation refunds the
+    /// mentee in full; a mentee cancellation is refunded in full with
+    /// enough notice and by the late-cancellation rate otherwise.
+    pub fn cancel(env: Env, caller: Address, session_id: u64) -> Result<(), MentorshipError> {
+        let _guard = ReentrancyLock::new(&env);
+        caller.require_auth();
+        let session = Self::load_booked(&env, session_id)?;
+        let now = Clock::now(&env);
+        if now >= session.starts_at {
+            return Err(MentorshipError::SessionClosed);
+        }
+
+        let refund_bps = if caller == session.mentor {
+            BASIS_POINTS
+        } else if caller == session.mentee {
+            let config = MentorshipStorage::get_config(&env).ok_or(MentorshipError::NotInitialized)?;
+            if now + config.cancel_notice <= session.starts_at {
+                BASIS_POINTS
+            } else {
+                config.late_cancel_refund_bps
+            }
+        } else {
+            return Err(MentorshipError::Unauthorized);
+        };
+
+        Self::close(&env, session, SessionStatus::Cancelled, refund_bps);
+        Ok(())
+    }
+
+    /// Confirm that the session took place. Once both parties confirm, the
+    /// mentor is paid immediately.
+    pub fn confirm(env: Env, party: Address, session_id: u64) -> Result<(), MentorshipError> {
+        Self::report(&env, &party, session_id, Attendance::Confirmed)
+    }
+
+    /// Report that the other party did not attend
+    pub fn report_no_show(env: Env, party: Address, session_id: u64) -> Result<(), MentorshipError> {
+        Self::report(&env, &party, session_id, Attendance::NoShow)
+    }
+
+    /// Settle a session once its confirmation window has closed. Silence
+    /// counts as confirmation; an unanswered no-show report applies the
+    /// no-show rules; conflicting reports go to dispute. Callable by anyone.
+    pub fn settle(env: Env, session_id: u64) -> Result<SessionStatus, MentorshipError> {
+        let _guard = ReentrancyLock::new(&env);
+        let session = Self::load_booked(&env, session_id)?;
+        let config = MentorshipStorage::get_config(&env).ok_or(MentorshipError::NotInitialized)?;
+        if Clock::now(&env) <= session.ends_at + config.confirm_window {
+            return Err(MentorshipError::ConfirmWindowOpen);
+        }
+
+        Ok(Self::apply_outcome(&env, session, &config))
+    }
+
+    /// Rule on a disputed session (admin only), refunding `refund_bps` of
+    /// the price to the mentee and paying the rest to the mentor
+    pub fn resolve(env: Env, admin: Address, session_id: u64, refund_bps: u32) -> Result<(), MentorshipError> {
+        let _guard = ReentrancyLock::new(&env);
+        Self::require_admin(&env, &admin)?;
+        let session = MentorshipStorage::get_session(&env, session_id).ok_or(MentorshipError::SessionNotFound)?;
+        if session.status != SessionStatus::Disputed {
+            return Err(MentorshipError::NotDisputed);
+        }
+        if refund_bps > BASIS_POINTS {
+            return Err(MentorshipError::InvalidRefund);
+        }
+
+        Self::close(&env, session, SessionStatus::Resolved, refund_bps);
+        Ok(())
+    }
+
+    /// Get a mentor's listing
+    pub fn get_mentor(env: Env, mentor: Address) -> Option<Mentor> {
+        MentorshipStorage::get_mentor(&env, &mentor)
+    }
+
+    /// Get a mentor's open slots
+    pub fn get_availability(env: Env, mentor: Address) -> Vec<u64> {
+        MentorshipStorage::get_availability(&env, &mentor)
+    }
+
+    /// Get a session
+    pub fn get_session(env: Env, session_id: u64) -> Option<Session> {
+        MentorshipStorage::get_session(&env, session_id)
+    }
+
+    /// Get the ids of a mentor's sessions
+    pub fn get_mentor_sessions(env: Env, mentor: Address) -> Vec<u64> {
+        MentorshipStorage::get_mentor_sessions(&env, &mentor)
+    }
+
+    /// Get the ids of a mentee's sessions
+    pub fn get_mentee_sessions(env: Env, mentee: Address) -> Vec<u64> {
+        MentorshipStorage::get_mentee_sessions(&env, &mentee)
+    }
+
+    /// Get the cancellation and no-show rules
+    pub fn get_config(env: Env) -> Option<MentorshipConfig> {
+        MentorshipStorage::get_config(&env)
+    }
+}
+
+impl Mentorship {
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), MentorshipError> {
+        caller.require_auth();
+        if !AccessControl::is_admin(env, caller) {
+            return Err(MentorshipError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    fn validate_config(config: &MentorshipConfig) -> Result<(), MentorshipError> {
+        if config.confirm_window == 0
+            || config.late_cancel_refund_bps > BASIS_POINTS
+            || config.mentee_no_show_refund_bps > BASIS_POINTS
+        {
+            return Err(MentorshipError::InvalidConfig);
+        }
+        Ok(())
+    }
+
+    fn load_booked(env: &Env, session_id: u64) -> Result<Session, MentorshipError> {
+        let session = MentorshipStorage::get_session(env, session_id).ok_or(MentorshipError::SessionNotFound)?;
+        if session.status != SessionStatus::Booked {
+            return Err(MentorshipError::SessionClosed);
+        }
+        Ok(session)
+    }
+
+    /// Record one party's account of a session, settling straight away once
+    /// both parties have reported
+    fn report(env: &Env, party: &Address, session_id: u64, attendance: Attendance) -> Result<(), MentorshipError> {
+        let _guard = ReentrancyLock::new(env);
+        party.require_auth();
+        let mut session = Self::load_booked(env, session_id)?;
+        let config = MentorshipStorage::get_config(env).ok_or(MentorshipError::NotInitialized)?;
+        let now = Clock::now(env);
+        if now < session.starts_at {
+            return Err(MentorshipError::SessionNotStarted);
+        }
+        if now > session.ends_at + config.confirm_window {
+            return Err(MentorshipError::SessionClosed);
+        }
+
+        let report = if *party == session.mentor {
+            &mut session.mentor_report
+        } else if *party == session.mentee {
+            &mut session.mentee_report
+        } else {
+            return Err(MentorshipError::Unauthorized);
+        };
+        if *report != Attendance::Unreported {
+            return Err(MentorshipError::AlreadyReported);
+        }
+        *report = attendance;
+        MentorshipEvents::emit_reported(env, session_id, party, attendance == Attendance::Confirmed);
+
+        if session.mentor_report != Attendance::Unreported && session.mentee_report != Attendance::Unreported {
+            Self::apply_outcome(env, session, &config);
+        } else {
+            MentorshipStorage::set_session(env, &session);
+        }
+        Ok(())
+    }
+
+    /// Settle a session according to both parties' reports
+    fn apply_outcome(env: &Env, mut session: Session, config: &MentorshipConfig) -> SessionStatus {
+        let (status, refund_bps) = match (session.mentor_report, session.mentee_report) {
+            (Attendance::NoShow, Attendance::Unreported) => {
+                (SessionStatus::MenteeNoShow, config.mentee_no_show_refund_bps)
+            }
+            (Attendance::Unreported, Attendance::NoShow) => (SessionStatus::MentorNoShow, BASIS_POINTS),
+            (Attendance::NoShow, _) | (_, Attendance::NoShow) => {
+                session.status = SessionStatus::Disputed;
+                MentorshipStorage::set_session(env, &session);
+                MentorshipEvents::emit_disputed(env, session.id);
+                return SessionStatus::Disputed;
+            }
+            _ => (SessionStatus::Completed, 0),
+        };
+
+        Self::close(env, session, status, refund_bps);
+        status
+    }
+
+    /// Pay out a session's escrow, `refund_bps` of it back to the mentee
+    fn close(env: &Env, mut session: Session, status: SessionStatus, refund_bps: u32) {
+        let refunded = Fees::portion(session.price, refund_bps);
+        let paid = session.price - refunded;
+        let token = token::Client::new(env, &session.token);
+        if refunded > 0 {
+            token.transfer(&env.current_contract_address(), &session.mentee, &refunded);
+        }
+        if paid > 0 {
+            token.transfer(&env.current_contract_address(), &session.mentor, &paid);
+        }
+
+        if status == SessionStatus::Completed {
+            if let Some(mut listing) = MentorshipStorage::get_mentor(env, &session.mentor) {
+                listing.sessions_completed += 1;
+                MentorshipStorage::set_mentor(env, &listing);
+            }
+        }
+        session.status = status;
+        session.refunded = refunded;
+        MentorshipStorage::set_session(env, &session);
+        MentorshipEvents::emit_settled(env, session.id, status, paid, refunded);
+    }
+}
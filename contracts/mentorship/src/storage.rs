@@ -0,0 +1,104 @@
+use soroban_sdk::{Address, Env, Vec};
+use crate::types::{DataKey, Mentor, MentorshipConfig, Session};
+
+/// Storage utilities for the mentorship contract
+pub struct MentorshipStorage;
+
+impl MentorshipStorage {
+    /// Get the contract admin
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Set the contract admin
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Get the cancellation and no-show rules
+    pub fn get_config(env: &Env) -> Option<MentorshipConfig> {
+        env.storage().instance().get(&DataKey::Config)
+    }
+
+    /// Set the cancellation and no-show rules
+    pub fn set_config(env: &Env, config: &MentorshipConfig) {
+        env.storage().instance().set(&DataKey::Config, config);
+    }
+
+    /// Get a mentor's listing
+    pub fn get_mentor(env: &Env, mentor: &Address) -> Option<Mentor> {
+        env.storage().persistent().get(&DataKey::Mentor(mentor.clone()))
+    }
+
+    /// Store a mentor's listing
+    pub fn set_mentor(env: &Env, mentor: &Mentor) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Mentor(mentor.mentor.clone()), mentor);
+    }
+
+    /// Get a mentor's open slots
+    pub fn get_availability(env: &Env, mentor: &Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Availability(mentor.clone()))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Set a mentor's open slots
+    pub fn set_availability(env: &Env, mentor: &Address, slots: &Vec<u64>) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Availability(mentor.clone()), slots);
+    }
+
+    /// Allocate the next session id
+    pub fn next_session_id(env: &Env) -> u64 {
+        let id: u64 = env.storage().instance().get(&DataKey::SessionCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::SessionCount, &id);
+        id
+    }
+
+    /// Get a session
+    pub fn get_session(env: &Env, session_id: u64) -> Option<Session> {
+        env.storage().persistent().get(&DataKey::Session(session_id))
+    }
+
+    /// Store a session
+    pub fn set_session(env: &Env, session: &Session) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Session(session.id), session);
+    }
+
+    /// Get the ids of a mentor's sessions
+    pub fn get_mentor_sessions(env: &Env, mentor: &Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MentorSessions(mentor.clone()))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Get the ids of a mentee's sessions
+    pub fn get_mentee_sessions(env: &Env, mentee: &Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MenteeSessions(mentee.clone()))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Index a new session under both parties
+    pub fn add_session_ids(env: &Env, session: &Session) {
+        let mut ids = Self::get_mentor_sessions(env, &session.mentor);
+        ids.push_back(session.id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::MentorSessions(session.mentor.clone()), &ids);
+
+        let mut ids = Self::get_mentee_sessions(env, &session.mentee);
+        ids.push_back(session.id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::MenteeSessions(session.mentee.clone()), &ids);
+    }
+}
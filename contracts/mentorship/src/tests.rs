@@ -0,0 +1,172 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger}, token, vec, Address, Env,
+};
+use shared::time::{DAY, HOUR};
+
+use crate::{Mentorship, MentorshipClient, MentorshipConfig, MentorshipError, MentorTerms, SessionStatus};
+
+const RATE: i128 = 1_000;
+
+fn setup_test_env() -> (
+    Env,
+    MentorshipClient<'static>,
+    token::Client<'static>,
+    Address,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(Mentorship, ());
+    let client = MentorshipClient::new(&env, &contract_id);
+    client.initialize(
+        &admin,
+        &MentorshipConfig {
+            confirm_window: DAY,
+            cancel_notice: DAY,
+            late_cancel_refund_bps: 5_000,
+            mentee_no_show_refund_bps: 2_000,
+        },
+    );
+
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    let mentor = Address::generate(&env);
+    let mentee = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &asset.address()).mint(&mentee, &(10 * RATE));
+    client.list_mentor(&mentor, &MentorTerms { token: asset.address(), rate: RATE, session_length: HOUR });
+    client.set_availability(&mentor, &vec![&env, 2 * DAY, 3 * DAY, 4 * DAY]);
+
+    let token = token::Client::new(&env, &asset.address());
+    (env, client, token, admin, mentor, mentee)
+}
+
+fn at(env: &Env, timestamp: u64) {
+    env.ledger().set_timestamp(timestamp);
+}
+
+#[test]
+fn test_book_and_confirm_releases_payment() {
+    let (env, client, token, _admin, mentor, mentee) = setup_test_env();
+    assert_eq!(
+        client.try_book(&mentor, &mentor, &(2 * DAY)),
+        Err(Ok(MentorshipError::SelfBooking))
+    );
+    let id = client.book(&mentee, &mentor, &(2 * DAY));
+    assert_eq!(token.balance(&client.address), RATE);
+    assert_eq!(client.get_availability(&mentor), vec![&env, 3 * DAY, 4 * DAY]);
+    assert_eq!(
+        client.try_book(&mentee, &mentor, &(2 * DAY)),
+        Err(Ok(MentorshipError::SlotUnavailable))
+    );
+    assert_eq!(
+        client.try_confirm(&mentee, &id),
+        Err(Ok(MentorshipError::SessionNotStarted))
+    );
+
+    at(&env, 2 * DAY + HOUR);
+    client.confirm(&mentor, &id);
+    assert_eq!(client.try_confirm(&mentor, &id), Err(Ok(MentorshipError::AlreadyReported)));
+    client.confirm(&mentee, &id);
+
+    assert_eq!(client.get_session(&id).unwrap().status, SessionStatus::Completed);
+    assert_eq!(token.balance(&mentor), RATE);
+    assert_eq!(client.get_mentor(&mentor).unwrap().sessions_completed, 1);
+}
+
+#[test]
+fn test_no_show_rules_apply_after_confirm_window() {
+    let (env, client, token, _admin, mentor, mentee) = setup_test_env();
+    let missed_by_mentee = client.book(&mentee, &mentor, &(2 * DAY));
+    let missed_by_mentor = client.book(&mentee, &mentor, &(3 * DAY));
+
+    at(&env, 3 * DAY + HOUR);
+    client.report_no_show(&mentor, &missed_by_mentee);
+    client.report_no_show(&mentee, &missed_by_mentor);
+    assert_eq!(
+        client.try_settle(&missed_by_mentor),
+        Err(Ok(MentorshipError::ConfirmWindowOpen))
+    );
+
+    at(&env, 4 * DAY + HOUR + 1);
+    assert_eq!(client.settle(&missed_by_mentee), SessionStatus::MenteeNoShow);
+    assert_eq!(client.settle(&missed_by_mentor), SessionStatus::MentorNoShow);
+    // 20% back for the missed session, everything back for the mentor's
+    assert_eq!(token.balance(&mentee), 10 * RATE - 2 * RATE + 200 + RATE);
+    assert_eq!(token.balance(&mentor), 800);
+    assert_eq!(
+        client.try_report_no_show(&mentor, &missed_by_mentee),
+        Err(Ok(MentorshipError::SessionClosed))
+    );
+}
+
+#[test]
+fn test_cancellations_and_disputes() {
+    let (env, client, token, admin, mentor, mentee) = setup_test_env();
+    let early = client.book(&mentee, &mentor, &(2 * DAY));
+    let late = client.book(&mentee, &mentor, &(3 * DAY));
+    let disputed = client.book(&mentee, &mentor, &(4 * DAY));
+
+    let stranger = Address::generate(&env);
+    assert_eq!(client.try_cancel(&stranger, &early), Err(Ok(MentorshipError::Unauthorized)));
+    client.cancel(&mentee, &early);
+    at(&env, 2 * DAY + 12 * HOUR);
+    client.cancel(&mentee, &late);
+    assert_eq!(client.get_session(&late).unwrap().refunded, 500);
+    assert_eq!(token.balance(&mentor), 500);
+
+    // Conflicting reports wait for an admin ruling
+    at(&env, 4 * DAY + HOUR);
+    client.confirm(&mentor, &disputed);
+    client.report_no_show(&mentee, &disputed);
+    assert_eq!(client.get_session(&disputed).unwrap().status, SessionStatus::Disputed);
+    assert_eq!(
+        client.try_resolve(&mentor, &disputed, &0),
+        Err(Ok(MentorshipError::Unauthorized))
+    );
+    client.resolve(&admin, &disputed, &10_000);
+    assert_eq!(token.balance(&mentee), 10 * RATE - 500);
+    assert_eq!(
+        client.try_resolve(&admin, &disputed, &0),
+        Err(Ok(MentorshipError::NotDisputed))
+    );
+}
+
+#[test]
+fn test_booking_and_settlement_error_paths() {
+    let (env, client, token, admin, mentor, mentee) = setup_test_env();
+    let stranger = Address::generate(&env);
+    let terms = MentorTerms { token: token.address.clone(), rate: 0, session_length: HOUR };
+
+    assert_eq!(client.try_list_mentor(&stranger, &terms), Err(Ok(MentorshipError::InvalidTerms)));
+    assert_eq!(client.try_book(&mentee, &stranger, &(2 * DAY)), Err(Ok(MentorshipError::MentorNotFound)));
+    assert_eq!(
+        client.try_set_availability(&mentor, &vec![&env, 3 * DAY, 2 * DAY]),
+        Err(Ok(MentorshipError::InvalidAvailability))
+    );
+    client.set_listed(&mentor, &false);
+    assert_eq!(client.try_book(&mentee, &mentor, &(2 * DAY)), Err(Ok(MentorshipError::MentorUnlisted)));
+    client.set_listed(&mentor, &true);
+
+    // A cancelled session cannot be cancelled again or reported on
+    let cancelled = client.book(&mentee, &mentor, &(2 * DAY));
+    client.cancel(&mentor, &cancelled);
+    assert_eq!(client.try_cancel(&mentee, &cancelled), Err(Ok(MentorshipError::SessionClosed)));
+    assert_eq!(client.try_settle(&cancelled), Err(Ok(MentorshipError::SessionClosed)));
+    assert_eq!(client.try_cancel(&mentee, &99), Err(Ok(MentorshipError::SessionNotFound)));
+
+    // Once started a session cannot be cancelled, and only its parties report
+    let session_id = client.book(&mentee, &mentor, &(3 * DAY));
+    at(&env, 3 * DAY);
+    assert_eq!(client.try_cancel(&mentee, &session_id), Err(Ok(MentorshipError::SessionClosed)));
+    assert_eq!(client.try_confirm(&stranger, &session_id), Err(Ok(MentorshipError::Unauthorized)));
+    assert_eq!(client.try_resolve(&admin, &session_id, &20_000), Err(Ok(MentorshipError::NotDisputed)));
+
+    // Reports close with the confirm window, and a session settles once
+    at(&env, 4 * DAY + HOUR + 1);
+    assert_eq!(client.try_confirm(&mentor, &session_id), Err(Ok(MentorshipError::SessionClosed)));
+    assert_eq!(client.settle(&session_id), SessionStatus::Completed);
+    assert_eq!(client.try_settle(&session_id), Err(Ok(MentorshipError::SessionClosed)));
+    assert_eq!(token.balance(&mentor), RATE);
+}
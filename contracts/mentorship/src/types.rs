@@ -0,0 +1,83 @@
+use soroban_sdk::{contracttype, Address};
+
+/// Platform-wide cancellation and no-show rules
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MentorshipConfig {
+    pub confirm_window: u64,            // seconds after a session ends to confirm or report
+    pub cancel_notice: u64,             // mentee cancellations this far ahead are refunded in full
+    pub late_cancel_refund_bps: u32,    // refund for later mentee cancellations
+    pub mentee_no_show_refund_bps: u32, // refund when the mentee misses the session
+}
+
+/// What a mentor charges
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MentorTerms {
+    pub token: Address,
+    pub rate: i128,          // per session
+    pub session_length: u64, // seconds
+}
+
+/// A mentor's listing
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Mentor {
+    pub mentor: Address,
+    pub terms: MentorTerms,
+    pub listed: bool,
+    pub sessions_completed: u32,
+}
+
+/// One party's account of a session
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum Attendance {
+    Unreported,
+    Confirmed, // the session took place
+    NoShow,    // the other party did not attend
+}
+
+/// Lifecycle of a booked session
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum SessionStatus {
+    Booked,
+    Completed,    // paid to the mentor
+    Cancelled,
+    MentorNoShow, // refunded to the mentee
+    MenteeNoShow, // split by the no-show rule
+    Disputed,     // awaiting an admin ruling
+    Resolved,
+}
+
+/// A booked session and its escrowed payment
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Session {
+    pub id: u64,
+    pub mentor: Address,
+    pub mentee: Address,
+    pub token: Address,
+    pub price: i128,
+    pub starts_at: u64,
+    pub ends_at: u64,
+    pub mentor_report: Attendance,
+    pub mentee_report: Attendance,
+    pub status: SessionStatus,
+    pub refunded: i128, // returned to the mentee on settlement
+}
+
+/// Storage keys for the mentorship contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Config,
+    Mentor(Address),
+    Availability(Address), // open slot start times
+    SessionCount,
+    Session(u64),
+    MentorSessions(Address),
+    MenteeSessions(Address),
+}
@@ -0,0 +1,18 @@
+use soroban_sdk::contracterror;
+
+/// Metrics contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum MetricsError {
+    // Initialization errors
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+
+    // Authorization errors
+    Unauthorized = 3,
+
+    // Recording errors
+    InvalidAmount = 4,
+    TooManyMetrics = 5,
+}
@@ -0,0 +1,26 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+/// Metrics contract events
+pub struct MetricsEvents;
+
+impl MetricsEvents {
+    fn topic(env: &Env, name: &str) -> (Symbol, Symbol) {
+        (Symbol::new(env, "metrics"), Symbol::new(env, name))
+    }
+
+    /// Emit event when an address is allowed or stopped from reporting
+    pub fn emit_reporter_updated(env: &Env, reporter: &Address, allowed: bool) {
+        env.events().publish(
+            Self::topic(env, "reporter_updated"),
+            (reporter.clone(), allowed),
+        );
+    }
+
+    /// Emit event when a metric is recorded
+    pub fn emit_recorded(env: &Env, metric: &Symbol, reporter: &Address, amount: i128) {
+        env.events().publish(
+            Self::topic(env, "recorded"),
+            (metric.clone(), reporter.clone(), amount),
+        );
+    }
+}
@@ -0,0 +1,168 @@
+#![no_std]
+
+mod types;
+mod errors;
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod tests;
+
+use soroban_sdk::{contract, contractimpl, Address, Env, Symbol, Vec};
+
+use shared::{
+    access_control::AccessControl,
+    time::{Clock, DAY, WEEK},
+};
+
+pub use types::{Bucket, Period};
+pub use errors::MetricsError;
+use events::MetricsEvents;
+use storage::MetricsStorage;
+
+/// Days kept in each metric's daily series
+const DAILY_SLOTS: u32 = 90;
+
+/// Weeks kept in each metric's weekly series
+const WEEKLY_SLOTS: u32 = 104;
+
+/// Upper bound on distinct metrics, so storage stays bounded
+const MAX_METRICS: u32 = 20;
+
+/// Upper bound on buckets returned by one `get_series` call
+const MAX_SERIES_PAGE: u32 = 50;
+
+#[contract]
+pub struct MetricsContract;
+
+#[contractimpl]
+impl MetricsContract {
+    /// Initialize the contract
+    pub fn initialize(env: Env, admin: Address) -> Result<(), MetricsError> {
+        if MetricsStorage::get_admin(&env).is_some() {
+            return Err(MetricsError::AlreadyInitialized);
+        }
+
+        AccessControl::initialize(&env, &admin).map_err(|_| MetricsError::AlreadyInitialized)?;
+        MetricsStorage::set_admin(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Allow a contract to report metrics, or stop it (admin only)
+    pub fn set_reporter(env: Env, admin: Address, reporter: Address, allowed: bool) -> Result<(), MetricsError> {
+        Self::require_admin(&env, &admin)?;
+
+        MetricsStorage::set_reporter(&env, &reporter, allowed);
+        MetricsEvents::emit_reporter_updated(&env, &reporter, allowed);
+
+        Ok(())
+    }
+
+    /// Add `amount` to a metric's total and to the current day's and week's
+    /// buckets (reporters only)
+    pub fn record(env: Env, reporter: Address, metric: Symbol, amount: i128) -> Result<(), MetricsError> {
+        reporter.require_auth();
+        if !MetricsStorage::is_reporter(&env, &reporter) {
+            return Err(MetricsError::Unauthorized);
+        }
+        if amount <= 0 {
+            return Err(MetricsError::InvalidAmount);
+        }
+
+        let mut metrics = MetricsStorage::get_metrics(&env);
+        if !metrics.contains(&metric) {
+            if metrics.len() >= MAX_METRICS {
+                return Err(MetricsError::TooManyMetrics);
+            }
+            metrics.push_back(metric.clone());
+            MetricsStorage::set_metrics(&env, &metrics);
+        }
+
+        MetricsStorage::set_total(&env, &metric, MetricsStorage::get_total(&env, &metric) + amount);
+        for period in [Period::Daily, Period::Weekly] {
+            let index = Clock::now(&env) / Self::period_length(period);
+            let mut bucket = Self::bucket_at(&env, &metric, period, index);
+            bucket.value += amount;
+            MetricsStorage::set_slot(&env, &metric, period, Self::slot_of(period, index), &bucket);
+        }
+        MetricsEvents::emit_recorded(&env, &metric, &reporter, amount);
+
+        Ok(())
+    }
+
+    /// Get a metric's all-time total
+    pub fn get_total(env: Env, metric: Symbol) -> i128 {
+        MetricsStorage::get_total(&env, &metric)
+    }
+
+    /// Get every metric reported so far
+    pub fn get_metrics(env: Env) -> Vec<Symbol> {
+        MetricsStorage::get_metrics(&env)
+    }
+
+    /// Get a page of a metric's series, oldest first, starting with the
+    /// period containing `from` and ending no later than the current one.
+    /// Periods with nothing recorded are returned as zero. Periods older
+    /// than the retention window (90 days, 104 weeks) are skipped. At most
+    /// 50 buckets are returned per call.
+    pub fn get_series(env: Env, metric: Symbol, period: Period, from: u64, limit: u32) -> Vec<Bucket> {
+        let mut series = Vec::new(&env);
+        let current = Clock::now(&env) / Self::period_length(period);
+        let oldest = (current + 1).saturating_sub(Self::slot_count(period) as u64);
+        let mut index = (from / Self::period_length(period)).max(oldest);
+        while index <= current && series.len() < limit.min(MAX_SERIES_PAGE) {
+            series.push_back(Self::bucket_at(&env, &metric, period, index));
+            index += 1;
+        }
+        series
+    }
+
+    /// Whether an address may report metrics
+    pub fn is_reporter(env: Env, reporter: Address) -> bool {
+        MetricsStorage::is_reporter(&env, &reporter)
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        MetricsStorage::get_admin(&env)
+    }
+}
+
+impl MetricsContract {
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), MetricsError> {
+        caller.require_auth();
+        if !AccessControl::is_admin(env, caller) {
+            return Err(MetricsError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    fn period_length(period: Period) -> u64 {
+        match period {
+            Period::Daily => DAY,
+            Period::Weekly => WEEK,
+        }
+    }
+
+    fn slot_count(period: Period) -> u32 {
+        match period {
+            Period::Daily => DAILY_SLOTS,
+            Period::Weekly => WEEKLY_SLOTS,
+        }
+    }
+
+    fn slot_of(period: Period, index: u64) -> u32 {
+        (index % Self::slot_count(period) as u64) as u32
+    }
+
+    /// The bucket for the `index`th period since the epoch. A slot still
+    /// holding an older period is read as empty.
+    fn bucket_at(env: &Env, metric: &Symbol, period: Period, index: u64) -> Bucket {
+        let start = index * Self::period_length(period);
+        match MetricsStorage::get_slot(env, metric, period, Self::slot_of(period, index)) {
+            Some(bucket) if bucket.start == start => bucket,
+            _ => Bucket { start, value: 0 },
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+use crate::types::{Bucket, DataKey, Period};
+
+/// Storage utilities for the metrics contract
+pub struct MetricsStorage;
+
+impl MetricsStorage {
+    /// Get the contract admin
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Set the contract admin
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Whether an address may report metrics
+    pub fn is_reporter(env: &Env, reporter: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Reporter(reporter.clone()))
+            .unwrap_or(false)
+    }
+
+    /// Allow an address to report metrics, or stop it
+    pub fn set_reporter(env: &Env, reporter: &Address, allowed: bool) {
+        let key = DataKey::Reporter(reporter.clone());
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    /// Get every metric reported so far
+    pub fn get_metrics(env: &Env) -> Vec<Symbol> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Metrics)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Set every metric reported so far
+    pub fn set_metrics(env: &Env, metrics: &Vec<Symbol>) {
+        env.storage().instance().set(&DataKey::Metrics, metrics);
+    }
+
+    /// Get a metric's all-time total
+    pub fn get_total(env: &Env, metric: &Symbol) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Total(metric.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Set a metric's all-time total
+    pub fn set_total(env: &Env, metric: &Symbol, total: i128) {
+        env.storage().persistent().set(&DataKey::Total(metric.clone()), &total);
+    }
+
+    /// Get a ring buffer slot of a metric's series
+    pub fn get_slot(env: &Env, metric: &Symbol, period: Period, slot: u32) -> Option<Bucket> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Slot(metric.clone(), period, slot))
+    }
+
+    /// Set a ring buffer slot of a metric's series
+    pub fn set_slot(env: &Env, metric: &Symbol, period: Period, slot: u32, bucket: &Bucket) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Slot(metric.clone(), period, slot), bucket);
+    }
+}
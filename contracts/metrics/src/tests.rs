@@ -0,0 +1,105 @@
+use soroban_sdk::{
+    symbol_short, testutils::{Address as _, Ledger}, Address, Env, Symbol,
+};
+
+use shared::time::{DAY, WEEK};
+
+use crate::{Bucket, MetricsContract, MetricsContractClient, MetricsError, Period};
+
+fn setup_test_env() -> (Env, MetricsContractClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(MetricsContract, ());
+    let client = MetricsContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+
+    let reporter = Address::generate(&env);
+    client.set_reporter(&admin, &reporter, &true);
+
+    (env, client, admin, reporter)
+}
+
+#[test]
+fn test_records_aggregate_by_day_and_week() {
+    let (env, client, _admin, reporter) = setup_test_env();
+    let enrolled = symbol_short!("enrolled");
+    env.ledger().set_timestamp(10 * DAY);
+    client.record(&reporter, &enrolled, &2);
+    env.ledger().set_timestamp(12 * DAY);
+    client.record(&reporter, &enrolled, &3);
+
+    assert_eq!(client.get_total(&enrolled), 5);
+    assert_eq!(client.get_metrics().len(), 1);
+    let days = client.get_series(&enrolled, &Period::Daily, &(10 * DAY), &10);
+    assert_eq!(days.len(), 3);
+    assert_eq!(days.get_unchecked(0), Bucket { start: 10 * DAY, value: 2 });
+    assert_eq!(days.get_unchecked(1).value, 0);
+    assert_eq!(days.get_unchecked(2).value, 3);
+    // Days 10 and 12 fall in the same epoch-aligned week
+    let weeks = client.get_series(&enrolled, &Period::Weekly, &0, &10);
+    assert_eq!(weeks.len(), 2);
+    assert_eq!(weeks.get_unchecked(1), Bucket { start: WEEK, value: 5 });
+}
+
+#[test]
+fn test_ring_buffer_drops_periods_past_retention() {
+    let (env, client, _admin, reporter) = setup_test_env();
+    let revenue = symbol_short!("revenue");
+    client.record(&reporter, &revenue, &100);
+
+    // Day 90 reuses day 0's slot
+    env.ledger().set_timestamp(90 * DAY);
+    client.record(&reporter, &revenue, &7);
+    let days = client.get_series(&revenue, &Period::Daily, &0, &100);
+    assert_eq!(days.len(), 50);
+    assert_eq!(days.get_unchecked(0).start, DAY);
+    let latest = client.get_series(&revenue, &Period::Daily, &(90 * DAY), &1);
+    assert_eq!(latest.get_unchecked(0).value, 7);
+    assert_eq!(client.get_total(&revenue), 107);
+}
+
+#[test]
+fn test_only_reporters_record() {
+    let (env, client, admin, reporter) = setup_test_env();
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_record(&outsider, &symbol_short!("enrolled"), &1),
+        Err(Ok(MetricsError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_record(&reporter, &symbol_short!("enrolled"), &0),
+        Err(Ok(MetricsError::InvalidAmount))
+    );
+
+    client.set_reporter(&admin, &reporter, &false);
+    assert_eq!(
+        client.try_record(&reporter, &symbol_short!("enrolled"), &1),
+        Err(Ok(MetricsError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_admin_and_metric_limit_errors() {
+    let (env, client, admin, reporter) = setup_test_env();
+    assert_eq!(client.try_initialize(&admin), Err(Ok(MetricsError::AlreadyInitialized)));
+    assert_eq!(
+        client.try_set_reporter(&reporter, &Address::generate(&env), &true),
+        Err(Ok(MetricsError::Unauthorized))
+    );
+
+    for letter in b'a'..b'a' + 20 {
+        let name = [b'm', letter];
+        client.record(&reporter, &Symbol::new(&env, core::str::from_utf8(&name).unwrap()), &1);
+    }
+    let extra = symbol_short!("extra");
+    assert_eq!(client.try_record(&reporter, &extra, &1), Err(Ok(MetricsError::TooManyMetrics)));
+    assert_eq!(client.get_total(&extra), 0);
+    assert!(client.get_series(&extra, &Period::Daily, &0, &10).iter().all(|bucket| bucket.value == 0));
+
+    // Metrics already tracked keep accepting records at the limit
+    client.record(&reporter, &symbol_short!("ma"), &4);
+    assert_eq!(client.get_total(&symbol_short!("ma")), 5);
+    assert_eq!(client.get_metrics().len(), 20);
+}
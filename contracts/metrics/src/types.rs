@@ -0,0 +1,28 @@
+use soroban_sdk::{contracttype, Address, Symbol};
+
+/// Aggregation window of a time series
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum Period {
+    Daily,
+    Weekly,
+}
+
+/// A metric's total over one period
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Bucket {
+    pub start: u64, // first second of the period
+    pub value: i128,
+}
+
+/// Storage keys for the metrics contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Reporter(Address),
+    Metrics,
+    Total(Symbol),
+    Slot(Symbol, Period, u32), // ring buffer slot of a metric's series
+}
@@ -0,0 +1,23 @@
+use soroban_sdk::contracterror;
+
+/// Oracle adapter contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum OracleError {
+    // Initialization errors
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+
+    // Authorization errors
+    Unauthorized = 3,
+
+    // Configuration errors
+    InvalidConfig = 4,
+    FeedUnavailable = 5,
+
+    // Pricing errors
+    InvalidPrice = 6,
+    PriceUnavailable = 7,
+    InvalidAmount = 8,
+}
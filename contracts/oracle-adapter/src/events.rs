@@ -0,0 +1,31 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+/// Oracle adapter contract events
+pub struct OracleEvents;
+
+impl OracleEvents {
+    fn topic(env: &Env, name: &str) -> (Symbol, Symbol) {
+        (Symbol::new(env, "oracle"), Symbol::new(env, name))
+    }
+
+    /// Emit event when the price feed changes
+    pub fn emit_feed_updated(env: &Env, feed: &Address, decimals: u32, max_age: u64) {
+        env.events().publish(
+            Self::topic(env, "feed_updated"),
+            (feed.clone(), decimals, max_age),
+        );
+    }
+
+    /// Emit event when a token's fallback price is set
+    pub fn emit_fallback_set(env: &Env, token: &Address, price: i128, expires_at: u64) {
+        env.events().publish(
+            Self::topic(env, "fallback_set"),
+            (token.clone(), price, expires_at),
+        );
+    }
+
+    /// Emit event when a token's fallback price is removed
+    pub fn emit_fallback_cleared(env: &Env, token: &Address) {
+        env.events().publish(Self::topic(env, "fallback_cleared"), token.clone());
+    }
+}
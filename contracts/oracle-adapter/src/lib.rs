@@ -0,0 +1,170 @@
+#![no_std]
+
+mod types;
+mod errors;
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod tests;
+
+use soroban_sdk::{contract, contractimpl, token, vec, Address, Env, IntoVal, Symbol};
+
+use shared::{access_control::AccessControl, cross_contract::CrossContract, time::Clock};
+
+pub use types::{Asset, FallbackPrice, OracleConfig, PriceData, PriceSource, TokenPrice};
+pub use errors::OracleError;
+use events::OracleEvents;
+use storage::OracleStorage;
+
+/// USD amounts are quoted in cents
+const USD_DECIMALS: u32 = 2;
+
+#[contract]
+pub struct OracleAdapter;
+
+#[contractimpl]
+impl OracleAdapter {
+    /// Initialize the contract with the price feed to read from
+    pub fn initialize(env: Env, admin: Address, feed: Address, max_age: u64) -> Result<(), OracleError> {
+        if OracleStorage::get_admin(&env).is_some() {
+            return Err(OracleError::AlreadyInitialized);
+        }
+
+        AccessControl::initialize(&env, &admin).map_err(|_| OracleError::AlreadyInitialized)?;
+        OracleStorage::set_admin(&env, &admin);
+        Self::configure_feed(&env, feed, max_age)
+    }
+
+    /// Switch to another price feed or change how old its prices may be
+    /// (admin only)
+    pub fn set_feed(env: Env, admin: Address, feed: Address, max_age: u64) -> Result<(), OracleError> {
+        Self::require_admin(&env, &admin)?;
+        Self::configure_feed(&env, feed, max_age)
+    }
+
+    /// Set the USD price used for a token when the feed has no fresh price
+    /// (admin only). The price uses the feed's decimals and lapses after
+    /// `valid_for` seconds, so a stale manual price cannot linger.
+    pub fn set_fallback_price(
+        env: Env,
+        admin: Address,
+        token: Address,
+        price: i128,
+        valid_for: u64,
+    ) -> Result<(), OracleError> {
+        Self::require_admin(&env, &admin)?;
+        if price <= 0 || valid_for == 0 {
+            return Err(OracleError::InvalidPrice);
+        }
+
+        let fallback = FallbackPrice {
+            price,
+            set_at: Clock::now(&env),
+            expires_at: Clock::after(&env, valid_for),
+        };
+        OracleStorage::set_fallback(&env, &token, &fallback);
+        OracleEvents::emit_fallback_set(&env, &token, price, fallback.expires_at);
+
+        Ok(())
+    }
+
+    /// Remove a token's fallback price (admin only)
+    pub fn clear_fallback_price(env: Env, admin: Address, token: Address) -> Result<(), OracleError> {
+        Self::require_admin(&env, &admin)?;
+        OracleStorage::remove_fallback(&env, &token);
+        OracleEvents::emit_fallback_cleared(&env, &token);
+
+        Ok(())
+    }
+
+    /// Get the USD price of one whole token. The feed's price is used while
+    /// it is no older than `max_age`; otherwise an unexpired fallback price.
+    pub fn get_price(env: Env, token: Address) -> Result<TokenPrice, OracleError> {
+        let config = OracleStorage::get_config(&env).ok_or(OracleError::NotInitialized)?;
+        let now = Clock::now(&env);
+
+        let args = vec![&env, Asset::Stellar(token.clone()).into_val(&env)];
+        let reported = CrossContract::try_invoke::<Option<PriceData>>(&env, &config.feed, &Symbol::new(&env, "lastprice"), args)
+            .flatten();
+        if let Some(data) = reported {
+            if data.price > 0 && data.timestamp <= now && now - data.timestamp <= config.max_age {
+                return Ok(TokenPrice {
+                    price: data.price,
+                    decimals: config.feed_decimals,
+                    timestamp: data.timestamp,
+                    source: PriceSource::Feed,
+                });
+            }
+        }
+
+        match OracleStorage::get_fallback(&env, &token) {
+            Some(fallback) if now < fallback.expires_at => Ok(TokenPrice {
+                price: fallback.price,
+                decimals: config.feed_decimals,
+                timestamp: fallback.set_at,
+                source: PriceSource::Fallback,
+            }),
+            _ => Err(OracleError::PriceUnavailable),
+        }
+    }
+
+    /// Convert a USD amount in cents to the token amount (in the token's
+    /// smallest unit) at the current price, rounding up so the payee is
+    /// never short
+    pub fn usd_to_tokens(env: Env, token: Address, usd_cents: i128) -> Result<i128, OracleError> {
+        if usd_cents <= 0 {
+            return Err(OracleError::InvalidAmount);
+        }
+        let price = Self::get_price(env.clone(), token.clone())?;
+        let token_decimals = token::Client::new(&env, &token).decimals();
+
+        let numerator = 10i128
+            .checked_pow(token_decimals + price.decimals)
+            .and_then(|scale| usd_cents.checked_mul(scale))
+            .ok_or(OracleError::InvalidAmount)?;
+        let denominator = price
+            .price
+            .checked_mul(10i128.pow(USD_DECIMALS))
+            .ok_or(OracleError::InvalidAmount)?;
+        Ok((numerator + denominator - 1) / denominator)
+    }
+
+    /// Get the feed configuration
+    pub fn get_config(env: Env) -> Option<OracleConfig> {
+        OracleStorage::get_config(&env)
+    }
+
+    /// Get a token's fallback price, expired or not
+    pub fn get_fallback_price(env: Env, token: Address) -> Option<FallbackPrice> {
+        OracleStorage::get_fallback(&env, &token)
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        OracleStorage::get_admin(&env)
+    }
+}
+
+impl OracleAdapter {
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), OracleError> {
+        caller.require_auth();
+        if !AccessControl::is_admin(env, caller) {
+            return Err(OracleError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    fn configure_feed(env: &Env, feed: Address, max_age: u64) -> Result<(), OracleError> {
+        if max_age == 0 {
+            return Err(OracleError::InvalidConfig);
+        }
+        let feed_decimals = CrossContract::invoke::<u32>(env, &feed, &Symbol::new(env, "decimals"), vec![env])
+            .map_err(|_| OracleError::FeedUnavailable)?;
+
+        OracleStorage::set_config(env, &OracleConfig { feed: feed.clone(), feed_decimals, max_age });
+        OracleEvents::emit_feed_updated(env, &feed, feed_decimals, max_age);
+
+        Ok(())
+    }
+}
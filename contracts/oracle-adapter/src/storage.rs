@@ -0,0 +1,44 @@
+use soroban_sdk::{Address, Env};
+use crate::types::{DataKey, FallbackPrice, OracleConfig};
+
+/// Storage utilities for the oracle adapter contract
+pub struct OracleStorage;
+
+impl OracleStorage {
+    /// Get the contract admin
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Set the contract admin
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Get the feed configuration
+    pub fn get_config(env: &Env) -> Option<OracleConfig> {
+        env.storage().instance().get(&DataKey::Config)
+    }
+
+    /// Set the feed configuration
+    pub fn set_config(env: &Env, config: &OracleConfig) {
+        env.storage().instance().set(&DataKey::Config, config);
+    }
+
+    /// Get a token's fallback price
+    pub fn get_fallback(env: &Env, token: &Address) -> Option<FallbackPrice> {
+        env.storage().persistent().get(&DataKey::Fallback(token.clone()))
+    }
+
+    /// Set a token's fallback price
+    pub fn set_fallback(env: &Env, token: &Address, fallback: &FallbackPrice) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Fallback(token.clone()), fallback);
+    }
+
+    /// Remove a token's fallback price
+    pub fn remove_fallback(env: &Env, token: &Address) {
+        env.storage().persistent().remove(&DataKey::Fallback(token.clone()));
+    }
+}
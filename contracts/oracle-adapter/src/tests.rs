@@ -0,0 +1,131 @@
+use soroban_sdk::{
+    contract, contractimpl, testutils::{Address as _, Ledger}, Address, Env,
+};
+
+use crate::{Asset, OracleAdapter, OracleAdapterClient, OracleError, PriceData, PriceSource};
+
+/// Stand-in for a Reflector price feed quoting in USD with 14 decimals
+#[contract]
+pub struct MockFeed;
+
+#[contractimpl]
+impl MockFeed {
+    pub fn decimals(_env: Env) -> u32 {
+        14
+    }
+
+    pub fn set_price(env: Env, asset: Asset, price: i128, timestamp: u64) {
+        env.storage().persistent().set(&asset, &PriceData { price, timestamp });
+    }
+
+    pub fn lastprice(env: Env, asset: Asset) -> Option<PriceData> {
+        env.storage().persistent().get(&asset)
+    }
+}
+
+/// $0.50 per token, in feed decimals
+const HALF_DOLLAR: i128 = 50_000_000_000_000;
+
+fn setup_test_env() -> (
+    Env,
+    OracleAdapterClient<'static>,
+    MockFeedClient<'static>,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(10_000);
+
+    let admin = Address::generate(&env);
+    let feed_id = env.register(MockFeed, ());
+    let contract_id = env.register(OracleAdapter, ());
+    let client = OracleAdapterClient::new(&env, &contract_id);
+    client.initialize(&admin, &feed_id, &600);
+
+    // Stellar asset contracts use 7 decimals
+    let token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let feed = MockFeedClient::new(&env, &feed_id);
+
+    (env, client, feed, admin, token)
+}
+
+#[test]
+fn test_usd_price_converts_at_feed_rate() {
+    let (_env, client, feed, _admin, token) = setup_test_env();
+    feed.set_price(&Asset::Stellar(token.clone()), &HALF_DOLLAR, &9_900);
+
+    let price = client.get_price(&token);
+    assert_eq!(price.source, PriceSource::Feed);
+    assert_eq!(price.decimals, 14);
+    // $49.99 at $0.50 = 99.98 tokens
+    assert_eq!(client.usd_to_tokens(&token, &4_999), 999_800_000);
+}
+
+#[test]
+fn test_stale_feed_uses_fallback_until_it_expires() {
+    let (env, client, feed, admin, token) = setup_test_env();
+    feed.set_price(&Asset::Stellar(token.clone()), &HALF_DOLLAR, &9_000);
+    assert_eq!(client.try_get_price(&token), Err(Ok(OracleError::PriceUnavailable)));
+
+    client.set_fallback_price(&admin, &token, &(HALF_DOLLAR * 2), &3_600);
+    let price = client.get_price(&token);
+    assert_eq!(price.source, PriceSource::Fallback);
+    assert_eq!(client.usd_to_tokens(&token, &100), 10_000_000);
+
+    env.ledger().set_timestamp(13_600);
+    assert_eq!(client.try_usd_to_tokens(&token, &100), Err(Ok(OracleError::PriceUnavailable)));
+}
+
+#[test]
+fn test_conversion_rounds_up() {
+    let (_env, client, feed, _admin, token) = setup_test_env();
+    // $3 per token: one cent buys 33_333.33 stroops
+    feed.set_price(&Asset::Stellar(token.clone()), &(HALF_DOLLAR * 6), &10_000);
+    assert_eq!(client.usd_to_tokens(&token, &1), 33_334);
+    assert_eq!(client.try_usd_to_tokens(&token, &0), Err(Ok(OracleError::InvalidAmount)));
+}
+
+#[test]
+fn test_admin_configuration_errors() {
+    let (env, client, feed, admin, token) = setup_test_env();
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_initialize(&admin, &feed.address, &600),
+        Err(Ok(OracleError::AlreadyInitialized))
+    );
+    assert_eq!(
+        client.try_set_feed(&stranger, &feed.address, &600),
+        Err(Ok(OracleError::Unauthorized))
+    );
+    assert_eq!(client.try_set_feed(&admin, &feed.address, &0), Err(Ok(OracleError::InvalidConfig)));
+    assert_eq!(
+        client.try_set_feed(&admin, &stranger, &600),
+        Err(Ok(OracleError::FeedUnavailable))
+    );
+    assert_eq!(client.get_config().unwrap().feed, feed.address);
+
+    assert_eq!(
+        client.try_set_fallback_price(&stranger, &token, &HALF_DOLLAR, &3_600),
+        Err(Ok(OracleError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_fallback_price(&admin, &token, &0, &3_600),
+        Err(Ok(OracleError::InvalidPrice))
+    );
+    assert_eq!(
+        client.try_set_fallback_price(&admin, &token, &HALF_DOLLAR, &0),
+        Err(Ok(OracleError::InvalidPrice))
+    );
+
+    // A feed price stamped in the future is not trusted
+    feed.set_price(&Asset::Stellar(token.clone()), &HALF_DOLLAR, &10_001);
+    client.set_fallback_price(&admin, &token, &HALF_DOLLAR, &3_600);
+    assert_eq!(client.get_price(&token).source, PriceSource::Fallback);
+    assert_eq!(
+        client.try_clear_fallback_price(&stranger, &token),
+        Err(Ok(OracleError::Unauthorized))
+    );
+    client.clear_fallback_price(&admin, &token);
+    assert_eq!(client.try_get_price(&token), Err(Ok(OracleError::PriceUnavailable)));
+}
@@ -0,0 +1,62 @@
+use soroban_sdk::{contracttype, Address, Symbol};
+
+/// Asset identifier used by Reflector-style price feeds
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum Asset {
+    Stellar(Address),
+    Other(Symbol),
+}
+
+/// A price reported by the feed, scaled by the feed's decimals
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PriceData {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+/// The price feed the adapter reads from
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct OracleConfig {
+    pub feed: Address,
+    pub feed_decimals: u32, // read from the feed when it is set
+    pub max_age: u64,       // seconds before a feed price counts as stale
+}
+
+/// A manually set USD price used when the feed has none or is stale
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct FallbackPrice {
+    pub price: i128, // scaled by the feed's decimals
+    pub set_at: u64,
+    pub expires_at: u64,
+}
+
+/// Where a quoted price came from
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum PriceSource {
+    Feed,
+    Fallback,
+}
+
+/// The USD price of one whole token
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TokenPrice {
+    pub price: i128,
+    pub decimals: u32,
+    pub timestamp: u64,
+    pub source: PriceSource,
+}
+
+/// Storage keys for the oracle adapter contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Config,
+    Fallback(Address),
+}
@@ -0,0 +1,49 @@
+use soroban_sdk::contracterror;
+
+/// Payments contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PaymentsError {
+    // Initialization errors
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+
+    // Authorization errors
+    Unauthorized = 3,
+
+    // Configuration errors
+    InvalidConfiguration = 4,
+    InvalidAmount = 5,
+    CourseNotPriced = 6,
+
+    // Escrow errors
+    EscrowNotFound = 7,
+    InvalidEscrowState = 8,
+    TimeoutNotReached = 9,
+
+    // Payout errors
+    InvalidSplit = 10,
+    NothingToClaim = 11,
+    SelfReferral = 12,
+
+    // Multi-token errors
+    TokenNotAccepted = 13,
+    OracleNotConfigured = 14,
+    PriceUnavailable = 15,
+
+    // Installment errors
+    InvalidInstallmentTerms = 16,
+    InstallmentsNotOffered = 17,
+    PlanNotFound = 18,
+    PlanAlreadyOpen = 19,
+    InvalidPlanState = 20,
+    InstallmentsOutstanding = 21,
+    NotInDefault = 22,
+
+    // Slippage errors
+    AmountAboveMaximum = 23,
+
+    // Completion errors
+    CompletionNotVerified = 24,
+}
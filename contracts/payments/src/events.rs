@@ -0,0 +1,115 @@
+use soroban_sdk::{Address, Env, Symbol};
+use crate::types::PlanStatus;
+
+/// Payments contract events
+pub struct PaymentsEvents;
+
+impl PaymentsEvents {
+    fn topic(env: &Env, name: &str) -> (Symbol, Symbol) {
+        (Symbol::new(env, "payments"), Symbol::new(env, name))
+    }
+
+    /// Emit event when a course price is set
+    pub fn emit_course_priced(env: &Env, course_id: &Symbol, token: &Address, amount: i128) {
+        env.events().publish(
+            Self::topic(env, "course_priced"),
+            (course_id.clone(), token.clone(), amount),
+        );
+    }
+
+    /// Emit event when the additional tokens a course accepts change
+    pub fn emit_accepted_tokens_updated(env: &Env, course_id: &Symbol, count: u32) {
+        env.events().publish(
+            Self::topic(env, "accepted_tokens_updated"),
+            (course_id.clone(), count),
+        );
+    }
+
+    /// Emit event when a course fee is escrowed
+    pub fn emit_escrow_created(env: &Env, escrow_id: u64, payer: &Address, course_id: &Symbol, amount: i128) {
+        env.events().publish(
+            Self::topic(env, "escrow_created"),
+            (escrow_id, payer.clone(), course_id.clone(), amount),
+        );
+    }
+
+    /// Emit event when an escrow is paid out to the instructor and platform
+    pub fn emit_escrow_released(env: &Env, escrow_id: u64, attester: &Address, amount: i128) {
+        env.events().publish(
+            Self::topic(env, "escrow_released"),
+            (escrow_id, attester.clone(), amount),
+        );
+    }
+
+    /// Emit event when a course's payout split changes
+    pub fn emit_split_updated(env: &Env, course_id: &Symbol, caller: &Address) {
+        env.events().publish(
+            Self::topic(env, "split_updated"),
+            (course_id.clone(), caller.clone()),
+        );
+    }
+
+    /// Emit event when a recipient withdraws their accrued payouts
+    pub fn emit_payout_claimed(env: &Env, recipient: &Address, token: &Address, amount: i128) {
+        env.events().publish(
+            Self::topic(env, "payout_claimed"),
+            (recipient.clone(), token.clone(), amount),
+        );
+    }
+
+    /// Emit event when a payer disputes an escrow
+    pub fn emit_dispute_opened(env: &Env, escrow_id: u64, payer: &Address, reason: &Symbol) {
+        env.events().publish(
+            Self::topic(env, "dispute_opened"),
+            (escrow_id, payer.clone(), reason.clone()),
+        );
+    }
+
+    /// Emit event when an arbiter settles a dispute
+    pub fn emit_dispute_resolved(env: &Env, escrow_id: u64, arbiter: &Address, refunded: i128, released: i128) {
+        env.events().publish(
+            Self::topic(env, "dispute_resolved"),
+            (escrow_id, arbiter.clone(), refunded, released),
+        );
+    }
+
+    /// Emit event when an escrow is refunded to its payer
+    pub fn emit_escrow_refunded(env: &Env, escrow_id: u64, payer: &Address, amount: i128) {
+        env.events().publish(
+            Self::topic(env, "escrow_refunded"),
+            (escrow_id, payer.clone(), amount),
+        );
+    }
+
+    /// Emit event when a course's installment terms change
+    pub fn emit_installment_terms_updated(env: &Env, course_id: &Symbol, installments: u32) {
+        env.events().publish(
+            Self::topic(env, "installment_terms_updated"),
+            (course_id.clone(), installments),
+        );
+    }
+
+    /// Emit event when an installment is paid
+    pub fn emit_installment_paid(env: &Env, plan_id: u64, index: u32, amount: i128, late_fee: i128) {
+        env.events().publish(
+            Self::topic(env, "installment_paid"),
+            (plan_id, index, amount, late_fee),
+        );
+    }
+
+    /// Emit event when an installment plan changes status
+    pub fn emit_plan_status(env: &Env, plan_id: u64, status: &PlanStatus) {
+        env.events().publish(
+            Self::topic(env, "plan_status"),
+            (plan_id, status.clone()),
+        );
+    }
+
+    /// Emit event when a receipt is issued
+    pub fn emit_receipt_issued(env: &Env, invoice_id: u64, payer: &Address, escrow_id: u64) {
+        env.events().publish(
+            Self::topic(env, "receipt_issued"),
+            (invoice_id, payer.clone(), escrow_id),
+        );
+    }
+}
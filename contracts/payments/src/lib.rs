@@ -0,0 +1,969 @@
+#![no_std]
+
+mod types;
+mod errors;
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod tests;
+
+use soroban_sdk::{contract, contractimpl, token, vec, Address, Env, IntoVal, Symbol, Vec};
+
+use shared::{
+    access_control::AccessControl,
+    cross_contract::CrossContract,
+    roles::{Permission, RoleLevel},
+    reentrancy_guard::ReentrancyLock,
+    time::Clock,
+    fees::{FeeShare, Fees, BASIS_POINTS},
+};
+
+pub use types::{
+    AcceptedToken, CoursePrice, Escrow, EscrowStatus, InstallmentDue, InstallmentPlan, InstallmentTerms, PaymentsConfig,
+    PayoutSplit, PlanStatus, Receipt, ReceiptKind, TokenPricing,
+};
+pub use errors::PaymentsError;
+use types::ReferralRecord;
+use events::PaymentsEvents;
+use storage::PaymentsStorage;
+
+/// Upper bound on co-instructors sharing a course's fees
+const MAX_CO_INSTRUCTORS: u32 = 5;
+
+/// Upper bound on additional tokens a course accepts
+const MAX_ACCEPTED_TOKENS: u32 = 5;
+
+/// Upper bound on receipts returned by one `get_receipts` call
+const MAX_RECEIPT_PAGE: u32 = 50;
+
+/// Upper bound on installments in a schedule
+const MAX_INSTALLMENTS: u32 = 12;
+
+#[contract]
+pub struct Payments;
+
+#[contractimpl]
+impl Payments {
+    /// Initialize the contract and the shared RBAC system
+    pub fn initialize(env: Env, admin: Address, config: PaymentsConfig) -> Result<(), PaymentsError> {
+        if PaymentsStorage::get_admin(&env).is_some() {
+            return Err(PaymentsError::AlreadyInitialized);
+        }
+        Self::validate_config(&config)?;
+
+        AccessControl::initialize(&env, &admin).map_err(|_| PaymentsError::AlreadyInitialized)?;
+        PaymentsStorage::set_admin(&env, &admin);
+        PaymentsStorage::set_config(&env, &config);
+
+        Ok(())
+    }
+
+    /// Grant a role in this contract (requires GrantRole)
+    pub fn grant_role(env: Env, admin: Address, user: Address, level: RoleLevel) -> Result<(), PaymentsError> {
+        admin.require_auth();
+        AccessControl::grant_role(&env, &admin, &user, level).map_err(|_| PaymentsError::Unauthorized)
+    }
+
+    /// Revoke a user's role in this contract (requires RevokeRole)
+    pub fn revoke_role(env: Env, admin: Address, user: Address) -> Result<(), PaymentsError> {
+        admin.require_auth();
+        AccessControl::revoke_role(&env, &admin, &user).map_err(|_| PaymentsError::Unauthorized)
+    }
+
+    /// Update the platform share and refund timeout. Existing escrows are
+    /// settled under the new settings.
+    pub fn update_config(env: Env, admin: Address, config: PaymentsConfig) -> Result<(), PaymentsError> {
+        Self::require_admin(&env, &admin)?;
+        Self::validate_config(&config)?;
+        PaymentsStorage::set_config(&env, &config);
+        Ok(())
+    }
+
+    /// Set the price of a course and the instructor who is paid for it. The
+    /// first caller to price a course becomes its owner; only the owner or an
+    /// admin can change it afterwards.
+    pub fn set_course_price(
+        env: Env,
+        caller: Address,
+        course_id: Symbol,
+        instructor: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), PaymentsError> {
+        let owner = match PaymentsStorage::get_course_price(&env, &course_id) {
+            Some(_) => Self::require_course_owner(&env, &caller, &course_id)?.owner,
+            None => {
+                caller.require_auth();
+                AccessControl::require_permission(&env, &caller, &Permission::UpdateCourse)
+                    .map_err(|_| PaymentsError::Unauthorized)?;
+                caller
+            }
+        };
+
+        if amount <= 0 {
+            return Err(PaymentsError::InvalidAmount);
+        }
+
+        let price = CoursePrice {
+            course_id: course_id.clone(),
+            owner,
+            instructor,
+            token: token.clone(),
+            amount,
+        };
+        PaymentsStorage::set_course_price(&env, &price);
+        PaymentsEvents::emit_course_priced(&env, &course_id, &token, amount);
+
+        Ok(())
+    }
+
+    /// Set the OracleAdapter used for USD-priced tokens (admin only)
+    pub fn set_oracle(env: Env, admin: Address, oracle: Address) -> Result<(), PaymentsError> {
+        Self::require_admin(&env, &admin)?;
+        PaymentsStorage::set_oracle(&env, &oracle);
+        Ok(())
+    }
+
+    /// Set the contract allowed to attest completion for any course, such as
+    /// a certificate issuer (admin only)
+    pub fn set_completion_oracle(env: Env, admin: Address, oracle: Address) -> Result<(), PaymentsError> {
+        Self::require_admin(&env, &admin)?;
+        PaymentsStorage::set_completion_oracle(&env, &oracle);
+        Ok(())
+    }
+
+    /// Set the contract that records course completions, such as
+    /// Enrollment. Instructors can only release an escrow once it reports
+    /// the payer's `has_completed` (admin only).
+    pub fn set_completion_registry(env: Env, admin: Address, registry: Address) -> Result<(), PaymentsError> {
+        Self::require_admin(&env, &admin)?;
+        PaymentsStorage::set_completion_registry(&env, &registry);
+        Ok(())
+    }
+
+    /// Set the Referrals contract whose recorded referrals decide who earns
+    /// a payment's referral share (admin only)
+    pub fn set_referrals(env: Env, admin: Address, referrals: Address) -> Result<(), PaymentsError> {
+        Self::require_admin(&env, &admin)?;
+        PaymentsStorage::set_referrals(&env, &referrals);
+        Ok(())
+    }
+
+    /// Replace the tokens a course accepts besides its price token, each
+    /// with a fixed amount or a USD price. An empty list accepts only the
+    /// price token again.
+    pub fn set_accepted_tokens(
+        env: Env,
+        caller: Address,
+        course_id: Symbol,
+        tokens: Vec<AcceptedToken>,
+    ) -> Result<(), PaymentsError> {
+        let price = Self::require_course_owner(&env, &caller, &course_id)?;
+        if tokens.len() > MAX_ACCEPTED_TOKENS {
+            return Err(PaymentsError::InvalidConfiguration);
+        }
+        for (index, accepted) in tokens.iter().enumerate() {
+            let repeated = tokens.iter().take(index).any(|earlier| earlier.token == accepted.token);
+            if repeated || accepted.token == price.token {
+                return Err(PaymentsError::InvalidConfiguration);
+            }
+            match accepted.pricing {
+                TokenPricing::Fixed(amount) | TokenPricing::Usd(amount) if amount <= 0 => {
+                    return Err(PaymentsError::InvalidAmount);
+                }
+                TokenPricing::Usd(_) if PaymentsStorage::get_oracle(&env).is_none() => {
+                    return Err(PaymentsError::OracleNotConfigured);
+                }
+                _ => {}
+            }
+        }
+
+        PaymentsStorage::set_accepted_tokens(&env, &course_id, &tokens);
+        PaymentsEvents::emit_accepted_tokens_updated(&env, &course_id, tokens.len());
+
+        Ok(())
+    }
+
+    /// Offer a course in installments, replacing any earlier terms. Plans
+    /// already started keep the terms they started with.
+    pub fn set_installment_terms(
+        env: Env,
+        caller: Address,
+        course_id: Symbol,
+        terms: InstallmentTerms,
+    ) -> Result<(), PaymentsError> {
+        Self::require_course_owner(&env, &caller, &course_id)?;
+        Self::validate_installment_terms(&terms)?;
+
+        PaymentsStorage::set_installment_terms(&env, &course_id, &terms);
+        PaymentsEvents::emit_installment_terms_updated(&env, &course_id, terms.schedule.len());
+
+        Ok(())
+    }
+
+    /// Stop offering installments for a course
+    pub fn clear_installment_terms(env: Env, caller: Address, course_id: Symbol) -> Result<(), PaymentsError> {
+        Self::require_course_owner(&env, &caller, &course_id)?;
+
+        PaymentsStorage::remove_installment_terms(&env, &course_id);
+        PaymentsEvents::emit_installment_terms_updated(&env, &course_id, 0);
+
+        Ok(())
+    }
+
+    /// Override how a course's released fees are divided
+    pub fn set_course_split(
+        env: Env,
+        caller: Address,
+        course_id: Symbol,
+        split: PayoutSplit,
+    ) -> Result<(), PaymentsError> {
+        Self::require_course_owner(&env, &caller, &course_id)?;
+        Self::validate_split(&split)?;
+
+        PaymentsStorage::set_course_split(&env, &course_id, &split);
+        PaymentsEvents::emit_split_updated(&env, &course_id, &caller);
+
+        Ok(())
+    }
+
+    /// Drop a course's split override so the platform default applies again
+    pub fn clear_course_split(env: Env, caller: Address, course_id: Symbol) -> Result<(), PaymentsError> {
+        Self::require_course_owner(&env, &caller, &course_id)?;
+
+        PaymentsStorage::remove_course_split(&env, &course_id);
+        PaymentsEvents::emit_split_updated(&env, &course_id, &caller);
+
+        Ok(())
+    }
+
+    /// Pay a course's fee into escrow, optionally crediting a referrer. The
+    /// referrer only earns the referral share if the Referrals contract
+    /// recorded them for this payer and course. Returns the escrow id.
+    pub fn pay(
+        env: Env,
+        payer: Address,
+        course_id: Symbol,
+        referrer: Option<Address>,
+    ) -> Result<u64, PaymentsError> {
+        let _guard = ReentrancyLock::new(&env);
+        payer.require_auth();
+
+        if referrer.as_ref() == Some(&payer) {
+            return Err(PaymentsError::SelfReferral);
+        }
+
+        let price = PaymentsStorage::get_course_price(&env, &course_id)
+            .ok_or(PaymentsError::CourseNotPriced)?;
+        Self::open_escrow(&env, &payer, &payer, &course_id, &price.token, price.amount, referrer)
+    }
+
+    /// Pay a course's fee on a beneficiary's behalf, such as a scholarship
+    /// award. The escrow, its receipts and completion checks name the
+    /// beneficiary, while the fee is pulled from `funder` and any refund
+    /// goes back to it. Returns the escrow id.
+    pub fn pay_for(
+        env: Env,
+        funder: Address,
+        beneficiary: Address,
+        course_id: Symbol,
+        referrer: Option<Address>,
+    ) -> Result<u64, PaymentsError> {
+        let _guard = ReentrancyLock::new(&env);
+        funder.require_auth();
+
+        if referrer.as_ref() == Some(&funder) || referrer.as_ref() == Some(&beneficiary) {
+            return Err(PaymentsError::SelfReferral);
+        }
+
+        let price = PaymentsStorage::get_course_price(&env, &course_id)
+            .ok_or(PaymentsError::CourseNotPriced)?;
+        Self::open_escrow(&env, &funder, &beneficiary, &course_id, &price.token, price.amount, referrer)
+    }
+
+    /// Pay a course's fee in any token it accepts. The escrow records the
+    /// token, so refunds and payouts stay in the asset that was paid.
+    /// `max_amount` bounds an oracle-converted fee against price moves
+    /// between quoting and paying.
+    pub fn pay_with_token(
+        env: Env,
+        payer: Address,
+        course_id: Symbol,
+        token: Address,
+        max_amount: i128,
+        referrer: Option<Address>,
+    ) -> Result<u64, PaymentsError> {
+        let _guard = ReentrancyLock::new(&env);
+        payer.require_auth();
+
+        if referrer.as_ref() == Some(&payer) {
+            return Err(PaymentsError::SelfReferral);
+        }
+
+        let amount = Self::quote_amount(&env, &course_id, &token)?;
+        if amount > max_amount {
+            return Err(PaymentsError::AmountAboveMaximum);
+        }
+        Self::open_escrow(&env, &payer, &payer, &course_id, &token, amount, referrer)
+    }
+
+    /// Start paying a course in installments. The first installment is
+    /// collected now into a new escrow. Returns the plan id.
+    pub fn start_installments(
+        env: Env,
+        payer: Address,
+        course_id: Symbol,
+        referrer: Option<Address>,
+    ) -> Result<u64, PaymentsError> {
+        let _guard = ReentrancyLock::new(&env);
+        payer.require_auth();
+
+        if referrer.as_ref() == Some(&payer) {
+            return Err(PaymentsError::SelfReferral);
+        }
+        let terms = PaymentsStorage::get_installment_terms(&env, &course_id)
+            .ok_or(PaymentsError::InstallmentsNotOffered)?;
+        if let Some(existing) = Self::find_plan(&env, &payer, &course_id) {
+            if matches!(existing.status, PlanStatus::Active | PlanStatus::Suspended) {
+                return Err(PaymentsError::PlanAlreadyOpen);
+            }
+        }
+
+        let price = PaymentsStorage::get_course_price(&env, &course_id).ok_or(PaymentsError::CourseNotPriced)?;
+        let first = terms.schedule.get_unchecked(0);
+        let escrow_id = Self::open_escrow(&env, &payer, &payer, &course_id, &price.token, first.amount, referrer)?;
+        // Not refundable by timeout until the plan completes
+        let mut escrow = Self::load_escrow(&env, escrow_id)?;
+        escrow.refundable_after = u64::MAX;
+        PaymentsStorage::set_escrow(&env, &escrow);
+
+        let plan = InstallmentPlan {
+            id: PaymentsStorage::next_plan_id(&env),
+            payer,
+            course_id,
+            escrow_id,
+            terms,
+            started_at: Clock::now(&env),
+            paid_count: 1,
+            late_fees: 0,
+            status: PlanStatus::Active,
+        };
+        PaymentsStorage::set_plan(&env, &plan);
+        PaymentsEvents::emit_installment_paid(&env, plan.id, 0, first.amount, 0);
+
+        Ok(plan.id)
+    }
+
+    /// Pay the next installment of a plan, plus a late fee once its grace
+    /// period has passed. Catching up lifts a suspension. Returns the amount
+    /// charged.
+    pub fn pay_installment(env: Env, payer: Address, plan_id: u64) -> Result<i128, PaymentsError> {
+        let _guard = ReentrancyLock::new(&env);
+        payer.require_auth();
+
+        let mut plan = Self::load_plan(&env, plan_id)?;
+        if plan.payer != payer {
+            return Err(PaymentsError::Unauthorized);
+        }
+        if !matches!(plan.status, PlanStatus::Active | PlanStatus::Suspended) {
+            return Err(PaymentsError::InvalidPlanState);
+        }
+        let mut escrow = Self::load_escrow(&env, plan.escrow_id)?;
+        if escrow.status != EscrowStatus::Held {
+            return Err(PaymentsError::InvalidEscrowState);
+        }
+
+        let index = plan.paid_count;
+        let due = plan.terms.schedule.get_unchecked(index);
+        let due_at = plan.started_at.saturating_add(due.offset);
+        let late_fee = if Clock::has_passed(&env, due_at.saturating_add(plan.terms.grace_period)) {
+            Fees::portion(due.amount, plan.terms.late_fee_bps)
+        } else {
+            0
+        };
+        let charged = due.amount + late_fee;
+        token::Client::new(&env, &escrow.token).transfer(&payer, &env.current_contract_address(), &charged);
+
+        escrow.amount += charged;
+        plan.paid_count += 1;
+        plan.late_fees += late_fee;
+        let status = if plan.paid_count == plan.terms.schedule.len() {
+            let config = Self::load_config(&env)?;
+            escrow.refundable_after = Clock::after(&env, config.release_timeout);
+            PlanStatus::Completed
+        } else if Self::is_in_default(&env, &plan) {
+            PlanStatus::Suspended
+        } else {
+            PlanStatus::Active
+        };
+        PaymentsStorage::set_escrow(&env, &escrow);
+        Self::issue_receipt(&env, &escrow, ReceiptKind::Payment, charged);
+        PaymentsEvents::emit_installment_paid(&env, plan_id, index, due.amount, late_fee);
+        if status != plan.status {
+            PaymentsEvents::emit_plan_status(&env, plan_id, &status);
+        }
+        plan.status = status;
+        PaymentsStorage::set_plan(&env, &plan);
+
+        Ok(charged)
+    }
+
+    /// Record that a plan has gone unpaid past its suspension window.
+    /// Anyone may call this; `has_access` already reports the lapse.
+    pub fn suspend_plan(env: Env, plan_id: u64) -> Result<(), PaymentsError> {
+        let mut plan = Self::load_plan(&env, plan_id)?;
+        if plan.status != PlanStatus::Active {
+            return Err(PaymentsError::InvalidPlanState);
+        }
+        if !Self::is_in_default(&env, &plan) {
+            return Err(PaymentsError::NotInDefault);
+        }
+
+        plan.status = PlanStatus::Suspended;
+        PaymentsStorage::set_plan(&env, &plan);
+        PaymentsEvents::emit_plan_status(&env, plan_id, &plan.status);
+
+        Ok(())
+    }
+
+    /// Close a suspended plan (admin only). What has been paid is released
+    /// to the course's payees for the access already given.
+    pub fn close_plan(env: Env, admin: Address, plan_id: u64) -> Result<(), PaymentsError> {
+        let _guard = ReentrancyLock::new(&env);
+        Self::require_admin(&env, &admin)?;
+
+        let mut plan = Self::load_plan(&env, plan_id)?;
+        if plan.status != PlanStatus::Suspended {
+            return Err(PaymentsError::InvalidPlanState);
+        }
+        let mut escrow = Self::load_escrow(&env, plan.escrow_id)?;
+        if escrow.status != EscrowStatus::Held {
+            return Err(PaymentsError::InvalidEscrowState);
+        }
+
+        Self::credit_split(&env, &escrow, escrow.amount)?;
+        escrow.status = EscrowStatus::Released;
+        PaymentsStorage::set_escrow(&env, &escrow);
+        PaymentsEvents::emit_escrow_released(&env, escrow.id, &admin, escrow.amount);
+        plan.status = PlanStatus::Closed;
+        PaymentsStorage::set_plan(&env, &plan);
+        PaymentsEvents::emit_plan_status(&env, plan_id, &plan.status);
+
+        Ok(())
+    }
+
+    /// Attest that the payer completed the course (or was issued its
+    /// certificate), crediting the escrow to its payees under the course's
+    /// payout split. The attester must be an admin, the completion oracle,
+    /// or the escrow's instructor once the completion registry confirms the
+    /// payer completed the course.
+    pub fn release(env: Env, attester: Address, escrow_id: u64) -> Result<(), PaymentsError> {
+        let _guard = ReentrancyLock::new(&env);
+        attester.require_auth();
+
+        let mut escrow = Self::load_escrow(&env, escrow_id)?;
+        let is_oracle = PaymentsStorage::get_completion_oracle(&env).as_ref() == Some(&attester);
+        let is_trusted = is_oracle || AccessControl::is_admin(&env, &attester);
+        if attester != escrow.instructor && !is_trusted {
+            return Err(PaymentsError::Unauthorized);
+        }
+        if escrow.status != EscrowStatus::Held {
+            return Err(PaymentsError::InvalidEscrowState);
+        }
+        // The instructor is paid by the release, so their word alone is not enough
+        if !is_trusted && !Self::is_completed(&env, &escrow) {
+            return Err(PaymentsError::CompletionNotVerified);
+        }
+        // Certificates are held until an installment plan is paid in full
+        if let Some(plan_id) = PaymentsStorage::get_escrow_plan(&env, escrow_id) {
+            if Self::load_plan(&env, plan_id)?.status != PlanStatus::Completed {
+                return Err(PaymentsError::InstallmentsOutstanding);
+            }
+        }
+
+        Self::credit_split(&env, &escrow, escrow.amount)?;
+        escrow.status = EscrowStatus::Released;
+        PaymentsStorage::set_escrow(&env, &escrow);
+        PaymentsEvents::emit_escrow_released(&env, escrow_id, &attester, escrow.amount);
+
+        Ok(())
+    }
+
+    /// Dispute a held escrow, as its payer or funder. Disputed escrows can
+    /// only be settled by an admin and no longer time out.
+    pub fn open_dispute(env: Env, payer: Address, escrow_id: u64, reason: Symbol) -> Result<(), PaymentsError> {
+        payer.require_auth();
+
+        let mut escrow = Self::load_escrow(&env, escrow_id)?;
+        if escrow.payer != payer && escrow.funder != payer {
+            return Err(PaymentsError::Unauthorized);
+        }
+        if escrow.status != EscrowStatus::Held {
+            return Err(PaymentsError::InvalidEscrowState);
+        }
+
+        escrow.status = EscrowStatus::Disputed;
+        PaymentsStorage::set_escrow(&env, &escrow);
+        PaymentsEvents::emit_dispute_opened(&env, escrow_id, &payer, &reason);
+
+        Ok(())
+    }
+
+    /// Settle a dispute by crediting `refund_bps` of the escrow back to the
+    /// funder and releasing the rest to the course's payees
+    pub fn resolve_dispute(
+        env: Env,
+        arbiter: Address,
+        escrow_id: u64,
+        refund_bps: u32,
+    ) -> Result<(), PaymentsError> {
+        let _guard = ReentrancyLock::new(&env);
+        Self::require_admin(&env, &arbiter)?;
+
+        let mut escrow = Self::load_escrow(&env, escrow_id)?;
+        if escrow.status != EscrowStatus::Disputed {
+            return Err(PaymentsError::InvalidEscrowState);
+        }
+        if refund_bps > BASIS_POINTS {
+            return Err(PaymentsError::InvalidAmount);
+        }
+
+        let refunded = Fees::portion(escrow.amount, refund_bps);
+        let released = escrow.amount - refunded;
+        if refunded > 0 {
+            Self::credit(&env, &escrow.funder, &escrow.token, refunded);
+            Self::issue_receipt(&env, &escrow, ReceiptKind::Refund, refunded);
+        }
+        if released > 0 {
+            Self::credit_split(&env, &escrow, released)?;
+        }
+
+        escrow.status = if released > 0 { EscrowStatus::Released } else { EscrowStatus::Refunded };
+        escrow.refunded = refunded;
+        PaymentsStorage::set_escrow(&env, &escrow);
+        // A ruling settles any unfinished installment plan behind the escrow
+        if let Some(mut plan) = PaymentsStorage::get_escrow_plan(&env, escrow_id)
+            .and_then(|plan_id| PaymentsStorage::get_plan(&env, plan_id))
+        {
+            if plan.status != PlanStatus::Completed {
+                plan.status = PlanStatus::Closed;
+                PaymentsStorage::set_plan(&env, &plan);
+                PaymentsEvents::emit_plan_status(&env, plan.id, &plan.status);
+            }
+        }
+        PaymentsEvents::emit_dispute_resolved(&env, escrow_id, &arbiter, refunded, released);
+
+        Ok(())
+    }
+
+    /// Reclaim a held escrow that was never released before its timeout.
+    /// The refund is paid to the escrow's funder.
+    pub fn claim_refund(env: Env, payer: Address, escrow_id: u64) -> Result<(), PaymentsError> {
+        let _guard = ReentrancyLock::new(&env);
+        payer.require_auth();
+
+        let mut escrow = Self::load_escrow(&env, escrow_id)?;
+        if escrow.payer != payer && escrow.funder != payer {
+            return Err(PaymentsError::Unauthorized);
+        }
+        if escrow.status != EscrowStatus::Held {
+            return Err(PaymentsError::InvalidEscrowState);
+        }
+        if !Clock::has_passed(&env, escrow.refundable_after) {
+            return Err(PaymentsError::TimeoutNotReached);
+        }
+
+        token::Client::new(&env, &escrow.token).transfer(
+            &env.current_contract_address(),
+            &escrow.funder,
+            &escrow.amount,
+        );
+        escrow.status = EscrowStatus::Refunded;
+        escrow.refunded = escrow.amount;
+        PaymentsStorage::set_escrow(&env, &escrow);
+        Self::issue_receipt(&env, &escrow, ReceiptKind::Refund, escrow.amount);
+        PaymentsEvents::emit_escrow_refunded(&env, escrow_id, &escrow.funder, escrow.amount);
+
+        Ok(())
+    }
+
+    /// Withdraw everything credited to `recipient` in `token`
+    pub fn claim_payout(env: Env, recipient: Address, token: Address) -> Result<i128, PaymentsError> {
+        let _guard = ReentrancyLock::new(&env);
+        recipient.require_auth();
+
+        let amount = PaymentsStorage::get_payout(&env, &recipient, &token);
+        if amount == 0 {
+            return Err(PaymentsError::NothingToClaim);
+        }
+
+        // Zero the balance before transferring
+        PaymentsStorage::set_payout(&env, &recipient, &token, 0);
+        token::Client::new(&env, &token).transfer(&env.current_contract_address(), &recipient, &amount);
+        PaymentsEvents::emit_payout_claimed(&env, &recipient, &token, amount);
+
+        Ok(amount)
+    }
+
+    /// Get a recipient's claimable balance in a token
+    pub fn get_payout_balance(env: Env, recipient: Address, token: Address) -> i128 {
+        PaymentsStorage::get_payout(&env, &recipient, &token)
+    }
+
+    /// Get the split that applies to a course: its override, or the
+    /// platform default
+    pub fn get_course_split(env: Env, course_id: Symbol) -> Option<PayoutSplit> {
+        PaymentsStorage::get_course_split(&env, &course_id)
+            .or_else(|| PaymentsStorage::get_config(&env).map(|config| Self::default_split(&env, &config)))
+    }
+
+    /// Get the payment settings
+    pub fn get_config(env: Env) -> Option<PaymentsConfig> {
+        PaymentsStorage::get_config(&env)
+    }
+
+    /// Get the price of a course
+    pub fn get_course_price(env: Env, course_id: Symbol) -> Option<CoursePrice> {
+        PaymentsStorage::get_course_price(&env, &course_id)
+    }
+
+    /// Get the tokens a course accepts besides its price token
+    pub fn get_accepted_tokens(env: Env, course_id: Symbol) -> Vec<AcceptedToken> {
+        PaymentsStorage::get_accepted_tokens(&env, &course_id)
+    }
+
+    /// Get what paying for a course in a token would cost right now
+    pub fn quote(env: Env, course_id: Symbol, token: Address) -> Result<i128, PaymentsError> {
+        Self::quote_amount(&env, &course_id, &token)
+    }
+
+    /// Get an escrow by id
+    pub fn get_escrow(env: Env, escrow_id: u64) -> Option<Escrow> {
+        PaymentsStorage::get_escrow(&env, escrow_id)
+    }
+
+    /// Get the ids of every escrow a payer has opened
+    pub fn get_payer_escrows(env: Env, payer: Address) -> Vec<u64> {
+        PaymentsStorage::get_payer_escrows(&env, &payer)
+    }
+
+    /// Get a course's installment terms, if it offers installments
+    pub fn get_installment_terms(env: Env, course_id: Symbol) -> Option<InstallmentTerms> {
+        PaymentsStorage::get_installment_terms(&env, &course_id)
+    }
+
+    /// Get an installment plan
+    pub fn get_plan(env: Env, plan_id: u64) -> Option<InstallmentPlan> {
+        PaymentsStorage::get_plan(&env, plan_id)
+    }
+
+    /// Get a payer's latest installment plan for a course
+    pub fn get_plan_for(env: Env, payer: Address, course_id: Symbol) -> Option<InstallmentPlan> {
+        Self::find_plan(&env, &payer, &course_id)
+    }
+
+    /// Whether a payer on an installment plan may currently use the course:
+    /// the plan is paid in full, or no installment is overdue past the
+    /// suspension window
+    pub fn has_access(env: Env, payer: Address, course_id: Symbol) -> bool {
+        Self::find_plan(&env, &payer, &course_id).is_some_and(|plan| match plan.status {
+            PlanStatus::Completed => true,
+            PlanStatus::Active => !Self::is_in_default(&env, &plan),
+            PlanStatus::Suspended | PlanStatus::Closed => false,
+        })
+    }
+
+    /// Whether a payer's installment plan for a course is paid in full, so
+    /// its certificate may be issued
+    pub fn is_paid_in_full(env: Env, payer: Address, course_id: Symbol) -> bool {
+        Self::find_plan(&env, &payer, &course_id).is_some_and(|plan| plan.status == PlanStatus::Completed)
+    }
+
+    /// Get a receipt by invoice id
+    pub fn get_receipt(env: Env, invoice_id: u64) -> Option<Receipt> {
+        PaymentsStorage::get_receipt(&env, invoice_id)
+    }
+
+    /// Get a page of a user's receipts, oldest first. At most 50 are
+    /// returned per call.
+    pub fn get_receipts(env: Env, user: Address, offset: u32, limit: u32) -> Vec<Receipt> {
+        let ids = PaymentsStorage::get_user_receipts(&env, &user);
+        let end = offset.saturating_add(limit.min(MAX_RECEIPT_PAGE)).min(ids.len());
+        let mut receipts = Vec::new(&env);
+        for index in offset..end {
+            if let Some(receipt) = PaymentsStorage::get_receipt(&env, ids.get_unchecked(index)) {
+                receipts.push_back(receipt);
+            }
+        }
+        receipts
+    }
+
+    /// Get how many receipts a user has
+    pub fn get_receipt_count(env: Env, user: Address) -> u32 {
+        PaymentsStorage::get_user_receipts(&env, &user).len()
+    }
+
+    /// Get the contract allowed to attest completion for any course
+    pub fn get_completion_oracle(env: Env) -> Option<Address> {
+        PaymentsStorage::get_completion_oracle(&env)
+    }
+
+    /// Get the registry instructors' completion attestations are checked against
+    pub fn get_completion_registry(env: Env) -> Option<Address> {
+        PaymentsStorage::get_completion_registry(&env)
+    }
+
+    /// Get the Referrals contract referrers are checked against
+    pub fn get_referrals(env: Env) -> Option<Address> {
+        PaymentsStorage::get_referrals(&env)
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        PaymentsStorage::get_admin(&env)
+    }
+}
+
+impl Payments {
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), PaymentsError> {
+        caller.require_auth();
+        if !AccessControl::is_admin(env, caller) {
+            return Err(PaymentsError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Load the price of a course the caller may manage: its owner or an admin
+    fn require_course_owner(env: &Env, caller: &Address, course_id: &Symbol) -> Result<CoursePrice, PaymentsError> {
+        caller.require_auth();
+        let price = PaymentsStorage::get_course_price(env, course_id).ok_or(PaymentsError::CourseNotPriced)?;
+        if price.owner != *caller && !AccessControl::is_admin(env, caller) {
+            return Err(PaymentsError::Unauthorized);
+        }
+        Ok(price)
+    }
+
+    fn validate_config(config: &PaymentsConfig) -> Result<(), PaymentsError> {
+        if config.platform_bps > BASIS_POINTS || config.release_timeout == 0 {
+            return Err(PaymentsError::InvalidConfiguration);
+        }
+        Ok(())
+    }
+
+    fn validate_split(split: &PayoutSplit) -> Result<(), PaymentsError> {
+        if split.co_instructors.len() > MAX_CO_INSTRUCTORS {
+            return Err(PaymentsError::InvalidSplit);
+        }
+
+        let mut total = split.platform_bps as u64 + split.referral_bps as u64;
+        for share in split.co_instructors.iter() {
+            if share.bps == 0 {
+                return Err(PaymentsError::InvalidSplit);
+            }
+            total += share.bps as u64;
+        }
+        if total > BASIS_POINTS as u64 {
+            return Err(PaymentsError::InvalidSplit);
+        }
+
+        Ok(())
+    }
+
+    fn validate_installment_terms(terms: &InstallmentTerms) -> Result<(), PaymentsError> {
+        let count = terms.schedule.len();
+        if !(2..=MAX_INSTALLMENTS).contains(&count)
+            || terms.late_fee_bps > BASIS_POINTS
+            || terms.suspend_after < terms.grace_period
+        {
+            return Err(PaymentsError::InvalidInstallmentTerms);
+        }
+
+        let mut previous: Option<u64> = None;
+        for due in terms.schedule.iter() {
+            let in_order = match previous {
+                None => due.offset == 0,
+                Some(offset) => due.offset > offset,
+            };
+            if !in_order || due.amount <= 0 {
+                return Err(PaymentsError::InvalidInstallmentTerms);
+            }
+            previous = Some(due.offset);
+        }
+
+        Ok(())
+    }
+
+    fn default_split(env: &Env, config: &PaymentsConfig) -> PayoutSplit {
+        PayoutSplit {
+            platform_bps: config.platform_bps,
+            referral_bps: 0,
+            co_instructors: Vec::new(env),
+        }
+    }
+
+    fn load_config(env: &Env) -> Result<PaymentsConfig, PaymentsError> {
+        PaymentsStorage::get_config(env).ok_or(PaymentsError::NotInitialized)
+    }
+
+    fn load_escrow(env: &Env, escrow_id: u64) -> Result<Escrow, PaymentsError> {
+        PaymentsStorage::get_escrow(env, escrow_id).ok_or(PaymentsError::EscrowNotFound)
+    }
+
+    fn load_plan(env: &Env, plan_id: u64) -> Result<InstallmentPlan, PaymentsError> {
+        PaymentsStorage::get_plan(env, plan_id).ok_or(PaymentsError::PlanNotFound)
+    }
+
+    fn find_plan(env: &Env, payer: &Address, course_id: &Symbol) -> Option<InstallmentPlan> {
+        PaymentsStorage::get_payer_plan(env, payer, course_id).and_then(|plan_id| PaymentsStorage::get_plan(env, plan_id))
+    }
+
+    /// Whether the next unpaid installment is overdue past the suspension
+    /// window
+    fn is_in_default(env: &Env, plan: &InstallmentPlan) -> bool {
+        match plan.terms.schedule.get(plan.paid_count) {
+            Some(due) => {
+                let due_at = plan.started_at.saturating_add(due.offset);
+                Clock::has_passed(env, due_at.saturating_add(plan.terms.suspend_after))
+            }
+            None => false,
+        }
+    }
+
+    /// The amount a course costs in `token`, converting USD prices with the
+    /// OracleAdapter
+    fn quote_amount(env: &Env, course_id: &Symbol, token: &Address) -> Result<i128, PaymentsError> {
+        let price = PaymentsStorage::get_course_price(env, course_id).ok_or(PaymentsError::CourseNotPriced)?;
+        if price.token == *token {
+            return Ok(price.amount);
+        }
+
+        let accepted = PaymentsStorage::get_accepted_tokens(env, course_id)
+            .iter()
+            .find(|accepted| accepted.token == *token)
+            .ok_or(PaymentsError::TokenNotAccepted)?;
+        match accepted.pricing {
+            TokenPricing::Fixed(amount) => Ok(amount),
+            TokenPricing::Usd(usd_cents) => {
+                let oracle = PaymentsStorage::get_oracle(env).ok_or(PaymentsError::OracleNotConfigured)?;
+                CrossContract::invoke(
+                    env,
+                    &oracle,
+                    &Symbol::new(env, "usd_to_tokens"),
+                    vec![env, token.into_val(env), usd_cents.into_val(env)],
+                )
+                .map_err(|_| PaymentsError::PriceUnavailable)
+            }
+        }
+    }
+
+    /// Whether the completion registry reports that the escrow's payer
+    /// completed its course. False without a registry or if it cannot answer.
+    fn is_completed(env: &Env, escrow: &Escrow) -> bool {
+        PaymentsStorage::get_completion_registry(env).is_some_and(|registry| {
+            let args = vec![env, escrow.payer.into_val(env), escrow.course_id.into_val(env)];
+            CrossContract::try_invoke::<bool>(env, &registry, &Symbol::new(env, "has_completed"), args).unwrap_or(false)
+        })
+    }
+
+    /// The named referrer, if the Referrals contract recorded them as the
+    /// payer's referrer for the course. A referrer that cannot be verified
+    /// earns nothing, and their share stays with the instructor.
+    fn verified_referrer(env: &Env, payer: &Address, course_id: &Symbol, referrer: Option<Address>) -> Option<Address> {
+        let referrer = referrer?;
+        let referrals = PaymentsStorage::get_referrals(env)?;
+        let args = vec![env, payer.into_val(env), course_id.into_val(env)];
+        let recorded =
+            CrossContract::try_invoke::<Option<ReferralRecord>>(env, &referrals, &Symbol::new(env, "get_referral"), args)
+                .flatten()?;
+        (recorded.referrer == referrer).then_some(referrer)
+    }
+
+    /// Collect `amount` of `token` from the funder into a new escrow for the payer
+    fn open_escrow(
+        env: &Env,
+        funder: &Address,
+        payer: &Address,
+        course_id: &Symbol,
+        token: &Address,
+        amount: i128,
+        referrer: Option<Address>,
+    ) -> Result<u64, PaymentsError> {
+        let config = Self::load_config(env)?;
+        let price = PaymentsStorage::get_course_price(env, course_id).ok_or(PaymentsError::CourseNotPriced)?;
+
+        token::Client::new(env, token).transfer(funder, &env.current_contract_address(), &amount);
+
+        let escrow = Escrow {
+            id: PaymentsStorage::next_escrow_id(env),
+            payer: payer.clone(),
+            funder: funder.clone(),
+            course_id: course_id.clone(),
+            instructor: price.instructor,
+            token: token.clone(),
+            amount,
+            referrer: Self::verified_referrer(env, payer, course_id, referrer),
+            status: EscrowStatus::Held,
+            created_at: Clock::now(env),
+            refundable_after: Clock::after(env, config.release_timeout),
+            refunded: 0,
+        };
+        PaymentsStorage::set_escrow(env, &escrow);
+        Self::issue_receipt(env, &escrow, ReceiptKind::Payment, amount);
+        PaymentsEvents::emit_escrow_created(env, escrow.id, payer, course_id, escrow.amount);
+
+        Ok(escrow.id)
+    }
+
+    /// Record a payment into or refund out of an escrow for its payer
+    fn issue_receipt(env: &Env, escrow: &Escrow, kind: ReceiptKind, amount: i128) {
+        let receipt = Receipt {
+            invoice_id: PaymentsStorage::next_invoice_id(env),
+            kind,
+            escrow_id: escrow.id,
+            payer: escrow.payer.clone(),
+            course_id: escrow.course_id.clone(),
+            token: escrow.token.clone(),
+            amount,
+            issued_at: Clock::now(env),
+        };
+        PaymentsStorage::add_receipt(env, &receipt);
+        PaymentsEvents::emit_receipt_issued(env, receipt.invoice_id, &receipt.payer, escrow.id);
+    }
+
+    /// Add to a recipient's claimable balance
+    fn credit(env: &Env, recipient: &Address, token: &Address, amount: i128) {
+        let balance = PaymentsStorage::get_payout(env, recipient, token);
+        PaymentsStorage::set_payout(env, recipient, token, balance + amount);
+    }
+
+    /// Credit `amount` of an escrow to its payees under the course's split.
+    /// Nothing is transferred here; payees withdraw with `claim_payout`.
+    fn credit_split(env: &Env, escrow: &Escrow, amount: i128) -> Result<(), PaymentsError> {
+        let config = Self::load_config(env)?;
+        let split = PaymentsStorage::get_course_split(env, &escrow.course_id)
+            .unwrap_or_else(|| Self::default_split(env, &config));
+
+        let mut shares = vec![env, FeeShare { recipient: config.platform, bps: split.platform_bps }];
+        let mut instructor_bps = BASIS_POINTS - split.platform_bps;
+        if let Some(referrer) = &escrow.referrer {
+            shares.push_back(FeeShare { recipient: referrer.clone(), bps: split.referral_bps });
+            instructor_bps -= split.referral_bps;
+        }
+        for share in split.co_instructors.iter() {
+            instructor_bps -= share.bps;
+            shares.push_back(share);
+        }
+        // Rounding dust lands on the first share, so put the instructor there
+        shares.push_front(FeeShare { recipient: escrow.instructor.clone(), bps: instructor_bps });
+
+        let parts = Fees::split(env, amount, &shares).map_err(|_| PaymentsError::InvalidSplit)?;
+        for (share, part) in shares.iter().zip(parts.iter()) {
+            if part > 0 {
+                Self::credit(env, &share.recipient, &escrow.token, part);
+            }
+        }
+
+        Ok(())
+    }
+}
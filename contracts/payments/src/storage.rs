@@ -0,0 +1,255 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+use crate::types::{
+    AcceptedToken, CoursePrice, DataKey, Escrow, InstallmentPlan, InstallmentTerms, PaymentsConfig, PayoutSplit, Receipt,
+};
+
+/// Storage utilities for the payments contract
+pub struct PaymentsStorage;
+
+impl PaymentsStorage {
+    /// Get the contract admin
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Set the contract admin
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Get the payment settings
+    pub fn get_config(env: &Env) -> Option<PaymentsConfig> {
+        env.storage().instance().get(&DataKey::Config)
+    }
+
+    /// Store the payment settings
+    pub fn set_config(env: &Env, config: &PaymentsConfig) {
+        env.storage().instance().set(&DataKey::Config, config);
+    }
+
+    /// Get the OracleAdapter USD prices are converted with
+    pub fn get_oracle(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Oracle)
+    }
+
+    /// Set the OracleAdapter USD prices are converted with
+    pub fn set_oracle(env: &Env, oracle: &Address) {
+        env.storage().instance().set(&DataKey::Oracle, oracle);
+    }
+
+    /// Get the contract allowed to attest completion for any course
+    pub fn get_completion_oracle(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::CompletionOracle)
+    }
+
+    /// Set the contract allowed to attest completion for any course
+    pub fn set_completion_oracle(env: &Env, oracle: &Address) {
+        env.storage().instance().set(&DataKey::CompletionOracle, oracle);
+    }
+
+    /// Get the registry instructors' completion attestations are checked against
+    pub fn get_completion_registry(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::CompletionRegistry)
+    }
+
+    /// Set the registry instructors' completion attestations are checked against
+    pub fn set_completion_registry(env: &Env, registry: &Address) {
+        env.storage().instance().set(&DataKey::CompletionRegistry, registry);
+    }
+
+    /// Get the Referrals contract referrers are checked against
+    pub fn get_referrals(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Referrals)
+    }
+
+    /// Set the Referrals contract referrers are checked against
+    pub fn set_referrals(env: &Env, referrals: &Address) {
+        env.storage().instance().set(&DataKey::Referrals, referrals);
+    }
+
+    /// Get the additional tokens a course accepts
+    pub fn get_accepted_tokens(env: &Env, course_id: &Symbol) -> Vec<AcceptedToken> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AcceptedTokens(course_id.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Store the additional tokens a course accepts
+    pub fn set_accepted_tokens(env: &Env, course_id: &Symbol, tokens: &Vec<AcceptedToken>) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::AcceptedTokens(course_id.clone()), tokens);
+    }
+
+    /// Get the price of a course
+    pub fn get_course_price(env: &Env, course_id: &Symbol) -> Option<CoursePrice> {
+        env.storage().persistent().get(&DataKey::CoursePrice(course_id.clone()))
+    }
+
+    /// Store the price of a course
+    pub fn set_course_price(env: &Env, price: &CoursePrice) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::CoursePrice(price.course_id.clone()), price);
+    }
+
+    /// Get a course's payout split override
+    pub fn get_course_split(env: &Env, course_id: &Symbol) -> Option<PayoutSplit> {
+        env.storage().persistent().get(&DataKey::CourseSplit(course_id.clone()))
+    }
+
+    /// Store a course's payout split override
+    pub fn set_course_split(env: &Env, course_id: &Symbol, split: &PayoutSplit) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::CourseSplit(course_id.clone()), split);
+    }
+
+    /// Remove a course's payout split override
+    pub fn remove_course_split(env: &Env, course_id: &Symbol) {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::CourseSplit(course_id.clone()));
+    }
+
+    /// Get a recipient's claimable balance in a token
+    pub fn get_payout(env: &Env, recipient: &Address, token: &Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Payout(recipient.clone(), token.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Set a recipient's claimable balance in a token
+    pub fn set_payout(env: &Env, recipient: &Address, token: &Address, amount: i128) {
+        let key = DataKey::Payout(recipient.clone(), token.clone());
+        if amount == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &amount);
+        }
+    }
+
+    /// Allocate the next escrow id
+    pub fn next_escrow_id(env: &Env) -> u64 {
+        let id: u64 = env.storage().instance().get(&DataKey::EscrowCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::EscrowCount, &id);
+        id
+    }
+
+    /// Get an escrow by id
+    pub fn get_escrow(env: &Env, escrow_id: u64) -> Option<Escrow> {
+        env.storage().persistent().get(&DataKey::Escrow(escrow_id))
+    }
+
+    /// Store an escrow, indexing it under its payer on first write
+    pub fn set_escrow(env: &Env, escrow: &Escrow) {
+        let key = DataKey::Escrow(escrow.id);
+        if !env.storage().persistent().has(&key) {
+            let mut escrows = Self::get_payer_escrows(env, &escrow.payer);
+            escrows.push_back(escrow.id);
+            env.storage()
+                .persistent()
+                .set(&DataKey::PayerEscrows(escrow.payer.clone()), &escrows);
+        }
+        env.storage().persistent().set(&key, escrow);
+    }
+
+    /// Get the ids of every escrow a payer has opened
+    pub fn get_payer_escrows(env: &Env, payer: &Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PayerEscrows(payer.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Allocate the next invoice id
+    pub fn next_invoice_id(env: &Env) -> u64 {
+        let id: u64 = env.storage().instance().get(&DataKey::ReceiptCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::ReceiptCount, &id);
+        id
+    }
+
+    /// Get a receipt by invoice id
+    pub fn get_receipt(env: &Env, invoice_id: u64) -> Option<Receipt> {
+        env.storage().persistent().get(&DataKey::Receipt(invoice_id))
+    }
+
+    /// Store a new receipt and index it under its payer
+    pub fn add_receipt(env: &Env, receipt: &Receipt) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Receipt(receipt.invoice_id), receipt);
+        let mut ids = Self::get_user_receipts(env, &receipt.payer);
+        ids.push_back(receipt.invoice_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::UserReceipts(receipt.payer.clone()), &ids);
+    }
+
+    /// Get the invoice ids of every receipt issued to a user, oldest first
+    pub fn get_user_receipts(env: &Env, user: &Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::UserReceipts(user.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Get a course's installment terms
+    pub fn get_installment_terms(env: &Env, course_id: &Symbol) -> Option<InstallmentTerms> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::InstallmentTerms(course_id.clone()))
+    }
+
+    /// Store a course's installment terms
+    pub fn set_installment_terms(env: &Env, course_id: &Symbol, terms: &InstallmentTerms) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::InstallmentTerms(course_id.clone()), terms);
+    }
+
+    /// Stop offering installments for a course
+    pub fn remove_installment_terms(env: &Env, course_id: &Symbol) {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::InstallmentTerms(course_id.clone()));
+    }
+
+    /// Allocate the next plan id
+    pub fn next_plan_id(env: &Env) -> u64 {
+        let id: u64 = env.storage().instance().get(&DataKey::PlanCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::PlanCount, &id);
+        id
+    }
+
+    /// Get an installment plan
+    pub fn get_plan(env: &Env, plan_id: u64) -> Option<InstallmentPlan> {
+        env.storage().persistent().get(&DataKey::Plan(plan_id))
+    }
+
+    /// Store an installment plan, indexing it by escrow and by payer and
+    /// course
+    pub fn set_plan(env: &Env, plan: &InstallmentPlan) {
+        env.storage().persistent().set(&DataKey::Plan(plan.id), plan);
+        env.storage()
+            .persistent()
+            .set(&DataKey::EscrowPlan(plan.escrow_id), &plan.id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::PayerPlan(plan.payer.clone(), plan.course_id.clone()), &plan.id);
+    }
+
+    /// Get the plan an escrow belongs to, if any
+    pub fn get_escrow_plan(env: &Env, escrow_id: u64) -> Option<u64> {
+        env.storage().persistent().get(&DataKey::EscrowPlan(escrow_id))
+    }
+
+    /// Get a payer's latest plan for a course
+    pub fn get_payer_plan(env: &Env, payer: &Address, course_id: &Symbol) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PayerPlan(payer.clone(), course_id.clone()))
+    }
+}
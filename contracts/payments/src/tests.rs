@@ -0,0 +1,594 @@
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::{Address as _, Ledger}, token, vec, Address, Env, Symbol, Vec,
+};
+use shared::{roles::RoleLevel, time::DAY};
+
+use shared::fees::FeeShare;
+
+use oracle_adapter::{Asset, OracleAdapter, OracleAdapterClient, PriceData};
+
+use crate::{
+    types::ReferralRecord, AcceptedToken, EscrowStatus, InstallmentDue, InstallmentTerms, Payments, PaymentsClient,
+    PaymentsConfig, PaymentsError, PayoutSplit, PlanStatus, ReceiptKind, TokenPricing,
+};
+
+fn setup_test_env() -> (
+    Env,
+    PaymentsClient<'static>,
+    Address,
+    Address,
+    Address,
+    token::Client<'static>,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Payments, ());
+    let client = PaymentsClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let instructor = Address::generate(&env);
+    let platform = Address::generate(&env);
+
+    let config = PaymentsConfig {
+        platform: platform.clone(),
+        platform_bps: 2_000,
+        release_timeout: 30 * DAY,
+    };
+    client.initialize(&admin, &config);
+    client.grant_role(&admin, &instructor, &RoleLevel::Instructor);
+
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &asset.address());
+    client.set_course_price(&instructor, &course(), &instructor, &asset.address(), &1_000);
+
+    (env, client, admin, instructor, platform, token)
+}
+
+fn course() -> Symbol {
+    symbol_short!("RUST101")
+}
+
+fn funded_student(env: &Env, token: &token::Client) -> Address {
+    let student = Address::generate(env);
+    token::StellarAssetClient::new(env, &token.address).mint(&student, &1_000);
+    student
+}
+
+#[test]
+fn test_release_splits_between_instructor_and_platform() {
+    let (env, client, admin, instructor, platform, token) = setup_test_env();
+    let student = funded_student(&env, &token);
+
+    let escrow_id = client.pay(&student, &course(), &None);
+    assert_eq!(token.balance(&client.address), 1_000);
+    assert_eq!(client.get_payer_escrows(&student).len(), 1);
+
+    client.release(&admin, &escrow_id);
+    assert_eq!(client.get_payout_balance(&instructor, &token.address), 800);
+    assert_eq!(client.get_payout_balance(&platform, &token.address), 200);
+
+    // Payees pull their share
+    assert_eq!(client.claim_payout(&instructor, &token.address), 800);
+    assert_eq!(client.claim_payout(&platform, &token.address), 200);
+    assert_eq!(token.balance(&instructor), 800);
+    assert_eq!(token.balance(&platform), 200);
+    assert_eq!(
+        client.try_claim_payout(&instructor, &token.address),
+        Err(Ok(PaymentsError::NothingToClaim))
+    );
+    assert_eq!(client.get_escrow(&escrow_id).unwrap().status, EscrowStatus::Released);
+    assert_eq!(
+        client.try_release(&instructor, &escrow_id),
+        Err(Ok(PaymentsError::InvalidEscrowState))
+    );
+}
+
+/// An instructor who doesn't own `course()`
+fn other_instructor(env: &Env, client: &PaymentsClient, admin: &Address) -> Address {
+    let instructor = Address::generate(env);
+    client.grant_role(admin, &instructor, &RoleLevel::Instructor);
+    instructor
+}
+
+/// Stand-in for the Enrollment contract's completion records
+#[contract]
+pub struct MockRegistry;
+
+#[contractimpl]
+impl MockRegistry {
+    pub fn complete(env: Env, student: Address, course_id: Symbol) {
+        env.storage().persistent().set(&(student, course_id), &true);
+    }
+
+    pub fn has_completed(env: Env, student: Address, course_id: Symbol) -> bool {
+        env.storage().persistent().has(&(student, course_id))
+    }
+}
+
+#[test]
+fn test_release_requires_instructor_admin_or_oracle() {
+    let (env, client, admin, _instructor, _platform, token) = setup_test_env();
+    let student = funded_student(&env, &token);
+    let escrow_id = client.pay(&student, &course(), &None);
+
+    // The default Student role holds MarkCompletion, which no longer suffices
+    client.grant_role(&admin, &student, &RoleLevel::Student);
+    for attester in [student.clone(), other_instructor(&env, &client, &admin)] {
+        assert_eq!(
+            client.try_release(&attester, &escrow_id),
+            Err(Ok(PaymentsError::Unauthorized))
+        );
+    }
+
+    let oracle = Address::generate(&env);
+    client.set_completion_oracle(&admin, &oracle);
+    client.release(&oracle, &escrow_id);
+    assert_eq!(client.get_escrow(&escrow_id).unwrap().status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_instructor_release_needs_verified_completion() {
+    let (env, client, admin, instructor, _platform, token) = setup_test_env();
+    let student = funded_student(&env, &token);
+    let escrow_id = client.pay(&student, &course(), &None);
+
+    // Without a registry the instructor cannot vouch for their own payout
+    assert_eq!(
+        client.try_release(&instructor, &escrow_id),
+        Err(Ok(PaymentsError::CompletionNotVerified))
+    );
+
+    let registry_id = env.register(MockRegistry, ());
+    client.set_completion_registry(&admin, &registry_id);
+    assert_eq!(client.get_completion_registry(), Some(registry_id.clone()));
+    assert_eq!(
+        client.try_release(&instructor, &escrow_id),
+        Err(Ok(PaymentsError::CompletionNotVerified))
+    );
+    assert_eq!(
+        client.try_set_completion_registry(&instructor, &instructor),
+        Err(Ok(PaymentsError::Unauthorized))
+    );
+
+    MockRegistryClient::new(&env, &registry_id).complete(&student, &course());
+    client.release(&instructor, &escrow_id);
+    assert_eq!(client.get_payout_balance(&instructor, &token.address), 800);
+
+    // A registry that cannot answer verifies nothing
+    client.set_completion_registry(&admin, &Address::generate(&env));
+    let unverified = client.pay(&funded_student(&env, &token), &course(), &None);
+    assert_eq!(
+        client.try_release(&instructor, &unverified),
+        Err(Ok(PaymentsError::CompletionNotVerified))
+    );
+}
+
+#[test]
+fn test_only_course_owner_reprices() {
+    let (env, client, admin, instructor, _platform, token) = setup_test_env();
+    let other = other_instructor(&env, &client, &admin);
+    assert_eq!(
+        client.try_set_course_price(&other, &course(), &other, &token.address, &1),
+        Err(Ok(PaymentsError::Unauthorized))
+    );
+
+    // The owner and admins can, and repricing keeps the owner
+    client.set_course_price(&admin, &course(), &other, &token.address, &2_000);
+    let price = client.get_course_price(&course()).unwrap();
+    assert_eq!(price.owner, instructor);
+    assert_eq!(price.instructor, other);
+}
+
+#[test]
+fn test_refund_after_timeout() {
+    let (env, client, _admin, _instructor, _platform, token) = setup_test_env();
+    let student = funded_student(&env, &token);
+    let escrow_id = client.pay(&student, &course(), &None);
+
+    assert_eq!(
+        client.try_claim_refund(&student, &escrow_id),
+        Err(Ok(PaymentsError::TimeoutNotReached))
+    );
+
+    env.ledger().set_timestamp(31 * DAY);
+    client.claim_refund(&student, &escrow_id);
+    assert_eq!(token.balance(&student), 1_000);
+    let escrow = client.get_escrow(&escrow_id).unwrap();
+    assert_eq!((escrow.status, escrow.refunded), (EscrowStatus::Refunded, 1_000));
+}
+
+#[test]
+fn test_pay_for_names_beneficiary_and_refunds_funder() {
+    let (env, client, _admin, _instructor, _platform, token) = setup_test_env();
+    let sponsor = funded_student(&env, &token);
+    let student = Address::generate(&env);
+    assert_eq!(
+        client.try_pay_for(&sponsor, &student, &course(), &Some(student.clone())),
+        Err(Ok(PaymentsError::SelfReferral))
+    );
+    let escrow_id = client.pay_for(&sponsor, &student, &course(), &None);
+
+    let escrow = client.get_escrow(&escrow_id).unwrap();
+    assert_eq!((escrow.payer, escrow.funder), (student.clone(), sponsor.clone()));
+    assert_eq!(client.get_payer_escrows(&student), vec![&env, escrow_id]);
+    assert_eq!(client.get_receipt_count(&student), 1);
+    assert_eq!(client.get_receipt_count(&sponsor), 0);
+
+    // The student may reclaim a lapsed escrow, but the fee returns to its funder
+    env.ledger().set_timestamp(31 * DAY);
+    client.claim_refund(&student, &escrow_id);
+    assert_eq!(token.balance(&sponsor), 1_000);
+    assert_eq!(token.balance(&student), 0);
+}
+
+#[test]
+fn test_dispute_blocks_timeout_and_is_settled_by_admin() {
+    let (env, client, admin, instructor, platform, token) = setup_test_env();
+    let student = funded_student(&env, &token);
+    let escrow_id = client.pay(&student, &course(), &None);
+
+    client.open_dispute(&student, &escrow_id, &symbol_short!("no_show"));
+
+    env.ledger().set_timestamp(31 * DAY);
+    assert_eq!(
+        client.try_claim_refund(&student, &escrow_id),
+        Err(Ok(PaymentsError::InvalidEscrowState))
+    );
+    assert_eq!(
+        client.try_resolve_dispute(&instructor, &escrow_id, &5_000),
+        Err(Ok(PaymentsError::Unauthorized))
+    );
+
+    client.resolve_dispute(&admin, &escrow_id, &5_000);
+    assert_eq!(client.get_escrow(&escrow_id).unwrap().refunded, 500);
+    assert_eq!(client.get_payout_balance(&student, &token.address), 500);
+    assert_eq!(client.get_payout_balance(&instructor, &token.address), 400);
+    assert_eq!(client.get_payout_balance(&platform, &token.address), 100);
+}
+
+#[test]
+fn test_configuration_validation() {
+    let (env, client, admin, _instructor, platform, token) = setup_test_env();
+    let student = funded_student(&env, &token);
+
+    let bad = PaymentsConfig {
+        platform: platform.clone(),
+        platform_bps: 10_001,
+        release_timeout: DAY,
+    };
+    assert_eq!(
+        client.try_update_config(&admin, &bad),
+        Err(Ok(PaymentsError::InvalidConfiguration))
+    );
+    assert_eq!(
+        client.try_pay(&student, &symbol_short!("NOPE"), &None),
+        Err(Ok(PaymentsError::CourseNotPriced))
+    );
+    assert_eq!(
+        client.try_open_dispute(&student, &42, &symbol_short!("x")),
+        Err(Ok(PaymentsError::EscrowNotFound))
+    );
+}
+
+/// Stand-in for the Referrals contract's recorded referrals
+#[contract]
+pub struct MockReferrals;
+
+#[contractimpl]
+impl MockReferrals {
+    pub fn refer(env: Env, student: Address, course_id: Symbol, referrer: Address) {
+        let referral = ReferralRecord {
+            student: student.clone(),
+            course_id: course_id.clone(),
+            referrer,
+            code: symbol_short!("FRIEND"),
+            commission_bps: 0,
+            commission: 0,
+            recorded_at: env.ledger().timestamp(),
+            settled: false,
+        };
+        env.storage().persistent().set(&(student, course_id), &referral);
+    }
+
+    pub fn get_referral(env: Env, student: Address, course_id: Symbol) -> Option<ReferralRecord> {
+        env.storage().persistent().get(&(student, course_id))
+    }
+}
+
+fn referrals(env: &Env, client: &PaymentsClient, admin: &Address) -> MockReferralsClient<'static> {
+    let referrals = MockReferralsClient::new(env, &env.register(MockReferrals, ()));
+    client.set_referrals(admin, &referrals.address);
+    referrals
+}
+
+#[test]
+fn test_course_split_with_co_instructor_and_referrer() {
+    let (env, client, admin, instructor, platform, token) = setup_test_env();
+    let student = funded_student(&env, &token);
+    let co_instructor = Address::generate(&env);
+    let referrer = Address::generate(&env);
+    referrals(&env, &client, &admin).refer(&student, &course(), &referrer);
+
+    let split = PayoutSplit {
+        platform_bps: 1_000,
+        referral_bps: 500,
+        co_instructors: vec![&env, FeeShare { recipient: co_instructor.clone(), bps: 2_500 }],
+    };
+    client.set_course_split(&instructor, &course(), &split);
+    assert_eq!(client.get_course_split(&course()), Some(split));
+
+    let escrow_id = client.pay(&student, &course(), &Some(referrer.clone()));
+    client.release(&admin, &escrow_id);
+
+    let token = &token.address;
+    assert_eq!(client.get_payout_balance(&instructor, token), 600);
+    assert_eq!(client.get_payout_balance(&co_instructor, token), 250);
+    assert_eq!(client.get_payout_balance(&platform, token), 100);
+    assert_eq!(client.get_payout_balance(&referrer, token), 50);
+}
+
+#[test]
+fn test_referral_share_goes_to_instructor_without_referrer() {
+    let (env, client, admin, instructor, _platform, token) = setup_test_env();
+    let student = funded_student(&env, &token);
+
+    let split = PayoutSplit { platform_bps: 1_000, referral_bps: 500, co_instructors: Vec::new(&env) };
+    client.set_course_split(&instructor, &course(), &split);
+
+    let escrow_id = client.pay(&student, &course(), &None);
+    client.release(&admin, &escrow_id);
+    assert_eq!(client.get_payout_balance(&instructor, &token.address), 900);
+
+    // Clearing the override restores the platform default (20%)
+    client.clear_course_split(&instructor, &course());
+    assert_eq!(client.get_course_split(&course()).unwrap().platform_bps, 2_000);
+}
+
+#[test]
+fn test_unrecorded_referrer_earns_nothing() {
+    let (env, client, admin, instructor, _platform, token) = setup_test_env();
+    let split = PayoutSplit { platform_bps: 1_000, referral_bps: 500, co_instructors: Vec::new(&env) };
+    client.set_course_split(&instructor, &course(), &split);
+
+    // Without a Referrals contract no referrer can be verified
+    let student = funded_student(&env, &token);
+    let second_wallet = Address::generate(&env);
+    let escrow_id = client.pay(&student, &course(), &Some(second_wallet.clone()));
+    assert_eq!(client.get_escrow(&escrow_id).unwrap().referrer, None);
+
+    // A referrer other than the recorded one gets no share either
+    let referrals = referrals(&env, &client, &admin);
+    let friend = Address::generate(&env);
+    let other = funded_student(&env, &token);
+    referrals.refer(&other, &course(), &friend);
+    let claimed = client.pay(&other, &course(), &Some(second_wallet.clone()));
+    assert_eq!(client.get_escrow(&claimed).unwrap().referrer, None);
+
+    client.release(&admin, &escrow_id);
+    client.release(&admin, &claimed);
+    assert_eq!(client.get_payout_balance(&second_wallet, &token.address), 0);
+    assert_eq!(client.get_payout_balance(&instructor, &token.address), 1_800);
+}
+
+#[test]
+fn test_split_ownership_validation_and_self_referral() {
+    let (env, client, admin, instructor, _platform, token) = setup_test_env();
+    let student = funded_student(&env, &token);
+
+    let over_allocated = PayoutSplit { platform_bps: 6_000, referral_bps: 5_000, co_instructors: Vec::new(&env) };
+    let other = other_instructor(&env, &client, &admin);
+    assert_eq!(
+        client.try_set_course_split(&other, &course(), &over_allocated),
+        Err(Ok(PaymentsError::Unauthorized))
+    );
+    assert_eq!(client.try_clear_course_split(&other, &course()), Err(Ok(PaymentsError::Unauthorized)));
+    assert_eq!(
+        client.try_set_course_split(&instructor, &course(), &over_allocated),
+        Err(Ok(PaymentsError::InvalidSplit))
+    );
+    assert_eq!(
+        client.try_pay(&student, &course(), &Some(student.clone())),
+        Err(Ok(PaymentsError::SelfReferral))
+    );
+}
+
+/// Price feed quoting every asset at $0.25 with 14 decimals
+#[contract]
+pub struct QuarterFeed;
+
+#[contractimpl]
+impl QuarterFeed {
+    pub fn decimals(_env: Env) -> u32 {
+        14
+    }
+
+    pub fn lastprice(env: Env, _asset: Asset) -> Option<PriceData> {
+        Some(PriceData { price: 25_000_000_000_000, timestamp: env.ledger().timestamp() })
+    }
+}
+
+#[test]
+fn test_pay_in_accepted_tokens_and_refund_in_same_asset() {
+    let (env, client, admin, instructor, _platform, token) = setup_test_env();
+    let usdc = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let xlm = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let accepted = vec![
+        &env,
+        AcceptedToken { token: usdc.clone(), pricing: TokenPricing::Fixed(500) },
+        AcceptedToken { token: xlm.clone(), pricing: TokenPricing::Usd(1_000) },
+    ];
+    assert_eq!(
+        client.try_set_accepted_tokens(&instructor, &course(), &accepted),
+        Err(Ok(PaymentsError::OracleNotConfigured))
+    );
+
+    assert_eq!(
+        client.try_set_accepted_tokens(&other_instructor(&env, &client, &admin), &course(), &Vec::new(&env)),
+        Err(Ok(PaymentsError::Unauthorized))
+    );
+
+    let oracle_id = env.register(OracleAdapter, ());
+    OracleAdapterClient::new(&env, &oracle_id).initialize(&admin, &env.register(QuarterFeed, ()), &600);
+    client.set_oracle(&admin, &oracle_id);
+    client.set_accepted_tokens(&instructor, &course(), &accepted);
+
+    // $10 at $0.25 = 40 tokens of 7 decimals
+    assert_eq!(client.quote(&course(), &xlm), 400_000_000);
+    assert_eq!(client.quote(&course(), &token.address), 1_000);
+    assert_eq!(
+        client.try_quote(&course(), &Address::generate(&env)),
+        Err(Ok(PaymentsError::TokenNotAccepted))
+    );
+
+    let student = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc).mint(&student, &500);
+    token::StellarAssetClient::new(&env, &xlm).mint(&student, &400_000_000);
+    let usdc_escrow = client.pay_with_token(&student, &course(), &usdc, &500, &None);
+    assert_eq!(
+        client.try_pay_with_token(&student, &course(), &xlm, &399_999_999, &None),
+        Err(Ok(PaymentsError::AmountAboveMaximum))
+    );
+    let xlm_escrow = client.pay_with_token(&student, &course(), &xlm, &400_000_000, &None);
+    assert_eq!(client.get_escrow(&xlm_escrow).unwrap().token, xlm);
+
+    client.release(&admin, &usdc_escrow);
+    assert_eq!(client.get_payout_balance(&instructor, &usdc), 400);
+
+    env.ledger().set_timestamp(31 * DAY);
+    client.claim_refund(&student, &xlm_escrow);
+    assert_eq!(token::Client::new(&env, &xlm).balance(&student), 400_000_000);
+}
+
+#[test]
+fn test_receipts_record_payments_and_refunds() {
+    let (env, client, admin, instructor, _platform, token) = setup_test_env();
+    let student = funded_student(&env, &token);
+    token::StellarAssetClient::new(&env, &token.address).mint(&student, &1_000);
+    env.ledger().set_timestamp(100);
+    let kept = client.pay(&student, &course(), &None);
+    let refunded = client.pay(&student, &course(), &None);
+    client.release(&admin, &kept);
+    client.open_dispute(&student, &refunded, &symbol_short!("absent"));
+    client.resolve_dispute(&admin, &refunded, &2_500);
+
+    assert_eq!(client.get_receipt_count(&student), 3);
+    let receipts = client.get_receipts(&student, &0, &10);
+    let first = receipts.get_unchecked(0);
+    assert_eq!((first.kind, first.escrow_id, first.amount, first.issued_at), (ReceiptKind::Payment, kept, 1_000, 100));
+    assert_eq!(first.token, token.address);
+    let last = receipts.get_unchecked(2);
+    assert_eq!((last.kind.clone(), last.escrow_id, last.amount), (ReceiptKind::Refund, refunded, 250));
+    assert_eq!(client.get_receipt(&last.invoice_id), Some(last));
+
+    assert_eq!(client.get_receipts(&student, &2, &10).len(), 1);
+    assert!(client.get_receipts(&student, &5, &10).is_empty());
+    assert!(client.get_receipts(&instructor, &0, &10).is_empty());
+}
+
+/// 400 now, then 300 after 30 and 60 days; 10% late after 3 days, suspended after 14
+fn installment_terms(env: &Env) -> InstallmentTerms {
+    InstallmentTerms {
+        schedule: vec![
+            env,
+            InstallmentDue { offset: 0, amount: 400 },
+            InstallmentDue { offset: 30 * DAY, amount: 300 },
+            InstallmentDue { offset: 60 * DAY, amount: 300 },
+        ],
+        grace_period: 3 * DAY,
+        late_fee_bps: 1_000,
+        suspend_after: 14 * DAY,
+    }
+}
+
+#[test]
+fn test_installments_hold_release_until_paid_in_full() {
+    let (env, client, admin, instructor, platform, token) = setup_test_env();
+    let student = funded_student(&env, &token);
+    token::StellarAssetClient::new(&env, &token.address).mint(&student, &100);
+    client.set_installment_terms(&instructor, &course(), &installment_terms(&env));
+
+    let plan_id = client.start_installments(&student, &course(), &None);
+    let escrow_id = client.get_plan(&plan_id).unwrap().escrow_id;
+    assert_eq!(client.try_start_installments(&student, &course(), &None), Err(Ok(PaymentsError::PlanAlreadyOpen)));
+    assert_eq!(client.try_release(&admin, &escrow_id), Err(Ok(PaymentsError::InstallmentsOutstanding)));
+    assert_eq!(client.try_claim_refund(&student, &escrow_id), Err(Ok(PaymentsError::TimeoutNotReached)));
+    assert!(client.has_access(&student, &course()));
+    assert!(!client.is_paid_in_full(&student, &course()));
+
+    env.ledger().set_timestamp(10 * DAY);
+    assert_eq!(client.pay_installment(&student, &plan_id), 300);
+    // Four days after the last due date is past the grace period
+    env.ledger().set_timestamp(64 * DAY);
+    assert_eq!(client.pay_installment(&student, &plan_id), 330);
+
+    let plan = client.get_plan(&plan_id).unwrap();
+    assert_eq!(plan.status, PlanStatus::Completed);
+    assert_eq!(plan.late_fees, 30);
+    assert!(client.is_paid_in_full(&student, &course()));
+    assert_eq!(client.get_receipts(&student, &0, &10).len(), 3);
+
+    client.release(&admin, &escrow_id);
+    assert_eq!(client.get_payout_balance(&instructor, &token.address), 824);
+    assert_eq!(client.get_payout_balance(&platform, &token.address), 206);
+}
+
+#[test]
+fn test_prolonged_default_suspends_plan() {
+    let (env, client, admin, instructor, _platform, token) = setup_test_env();
+    let student = funded_student(&env, &token);
+    token::StellarAssetClient::new(&env, &token.address).mint(&student, &100);
+    client.set_installment_terms(&instructor, &course(), &installment_terms(&env));
+    let plan_id = client.start_installments(&student, &course(), &None);
+
+    env.ledger().set_timestamp(40 * DAY);
+    assert_eq!(client.try_suspend_plan(&plan_id), Err(Ok(PaymentsError::NotInDefault)));
+    env.ledger().set_timestamp(45 * DAY);
+    assert!(!client.has_access(&student, &course()));
+    client.suspend_plan(&plan_id);
+    assert_eq!(client.get_plan(&plan_id).unwrap().status, PlanStatus::Suspended);
+
+    // Catching up restores access
+    assert_eq!(client.pay_installment(&student, &plan_id), 330);
+    assert!(client.has_access(&student, &course()));
+
+    env.ledger().set_timestamp(75 * DAY);
+    client.suspend_plan(&plan_id);
+    client.close_plan(&admin, &plan_id);
+    assert_eq!(client.get_plan(&plan_id).unwrap().status, PlanStatus::Closed);
+    assert_eq!(client.get_payout_balance(&instructor, &token.address), 584);
+    assert_eq!(client.try_pay_installment(&student, &plan_id), Err(Ok(PaymentsError::InvalidPlanState)));
+}
+
+#[test]
+fn test_installment_terms_ownership_and_validation() {
+    let (env, client, admin, instructor, _platform, token) = setup_test_env();
+    let mut terms = installment_terms(&env);
+    terms.schedule.set(1, InstallmentDue { offset: 0, amount: 300 });
+    assert_eq!(
+        client.try_set_installment_terms(&instructor, &course(), &terms),
+        Err(Ok(PaymentsError::InvalidInstallmentTerms))
+    );
+
+    let mut terms = installment_terms(&env);
+    terms.suspend_after = DAY;
+    assert_eq!(
+        client.try_set_installment_terms(&instructor, &course(), &terms),
+        Err(Ok(PaymentsError::InvalidInstallmentTerms))
+    );
+    assert_eq!(
+        client.try_set_installment_terms(&instructor, &symbol_short!("NOPRICE"), &installment_terms(&env)),
+        Err(Ok(PaymentsError::CourseNotPriced))
+    );
+    let other = other_instructor(&env, &client, &admin);
+    assert_eq!(
+        client.try_set_installment_terms(&other, &course(), &installment_terms(&env)),
+        Err(Ok(PaymentsError::Unauthorized))
+    );
+    assert_eq!(client.try_clear_installment_terms(&other, &course()), Err(Ok(PaymentsError::Unauthorized)));
+
+    let student = funded_student(&env, &token);
+    assert_eq!(
+        client.try_start_installments(&student, &course(), &None),
+        Err(Ok(PaymentsError::InstallmentsNotOffered))
+    );
+}
@@ -0,0 +1,187 @@
+use soroban_sdk::{contracttype, Address, Symbol, Vec};
+use shared::fees::FeeShare;
+
+/// Platform-wide payment settings
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PaymentsConfig {
+    pub platform: Address,   // receives the platform share of released fees
+    pub platform_bps: u32,   // platform share in basis points
+    pub release_timeout: u64, // seconds after which an unreleased escrow can be refunded
+}
+
+/// Price of a course and who is paid for it
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CoursePrice {
+    pub course_id: Symbol,
+    pub owner: Address,      // who first priced the course; may change its settings
+    pub instructor: Address, // paid the instructor share of released fees
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// How a course is priced in an additional payment token
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum TokenPricing {
+    Fixed(i128), // token amount
+    Usd(i128),   // USD cents, converted by the OracleAdapter at payment time
+}
+
+/// A token a course accepts besides the one in its `CoursePrice`
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AcceptedToken {
+    pub token: Address,
+    pub pricing: TokenPricing,
+}
+
+/// How a course's released fees are divided. The instructor receives
+/// whatever the platform, referral and co-instructor shares leave over; the
+/// referral share goes to the instructor when a payment had no referrer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PayoutSplit {
+    pub platform_bps: u32,
+    pub referral_bps: u32,
+    pub co_instructors: Vec<FeeShare>,
+}
+
+/// Lifecycle of an escrowed payment
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum EscrowStatus {
+    Held,
+    Disputed,
+    Released,
+    Refunded,
+}
+
+/// A course fee held by the contract until completion is attested
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Escrow {
+    pub id: u64,
+    pub payer: Address,
+    pub funder: Address, // who paid the fee and is owed refunds; the payer unless paid on their behalf
+    pub course_id: Symbol,
+    pub instructor: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub referrer: Option<Address>,
+    pub status: EscrowStatus,
+    pub created_at: u64,
+    pub refundable_after: u64,
+    pub refunded: i128, // returned to the payer by timeout or dispute
+}
+
+/// Whether a receipt records money paid in or returned
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum ReceiptKind {
+    Payment,
+    Refund,
+}
+
+/// A payment or refund record kept for reconciliation. The id doubles as
+/// the invoice number.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Receipt {
+    pub invoice_id: u64,
+    pub kind: ReceiptKind,
+    pub escrow_id: u64,
+    pub payer: Address,
+    pub course_id: Symbol,
+    pub token: Address,
+    pub amount: i128,
+    pub issued_at: u64,
+}
+
+/// One payment in an installment schedule, due `offset` seconds after the
+/// plan starts
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct InstallmentDue {
+    pub offset: u64,
+    pub amount: i128,
+}
+
+/// How a course may be paid in installments
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct InstallmentTerms {
+    pub schedule: Vec<InstallmentDue>, // the first is due immediately
+    pub grace_period: u64,             // seconds after a due date before a late fee applies
+    pub late_fee_bps: u32,             // late fee as a share of the installment
+    pub suspend_after: u64,            // seconds after a due date before access is suspended
+}
+
+/// Lifecycle of an installment plan
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum PlanStatus {
+    Active,
+    Completed,
+    Suspended, // an installment is overdue past `suspend_after`
+    Closed,    // settled by an admin after default
+}
+
+/// A payer's installment plan for a course. Payments accumulate in one
+/// escrow, which cannot be released until the plan is completed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct InstallmentPlan {
+    pub id: u64,
+    pub payer: Address,
+    pub course_id: Symbol,
+    pub escrow_id: u64,
+    pub terms: InstallmentTerms, // as offered when the plan started
+    pub started_at: u64,
+    pub paid_count: u32,
+    pub late_fees: i128,
+    pub status: PlanStatus,
+}
+
+/// Mirror of the Referrals contract's `Referral`, decoded from
+/// cross-contract calls
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ReferralRecord {
+    pub student: Address,
+    pub course_id: Symbol,
+    pub referrer: Address,
+    pub code: Symbol,
+    pub commission_bps: u32,
+    pub commission: i128,
+    pub recorded_at: u64,
+    pub settled: bool,
+}
+
+/// Storage keys for the payments contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Config,
+    Oracle,
+    CompletionOracle,
+    CompletionRegistry,
+    Referrals,
+    CoursePrice(Symbol),
+    AcceptedTokens(Symbol),
+    CourseSplit(Symbol),
+    Payout(Address, Address), // (recipient, token) -> claimable balance
+    EscrowCount,
+    Escrow(u64),
+    PayerEscrows(Address),
+    ReceiptCount,
+    Receipt(u64),
+    UserReceipts(Address),
+    InstallmentTerms(Symbol),
+    PlanCount,
+    Plan(u64),
+    EscrowPlan(u64),            // escrow id -> plan id
+    PayerPlan(Address, Symbol), // latest plan for (payer, course)
+}
@@ -0,0 +1,36 @@
+use soroban_sdk::contracterror;
+
+/// Peer-review contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PeerReviewError {
+    // Initialization errors
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+
+    // Authorization errors
+    Unauthorized = 3,
+
+    // Task errors
+    TaskNotFound = 4,
+    InvalidTask = 5,
+
+    // Submission errors
+    SubmissionClosed = 6,
+    AlreadySubmitted = 7,
+    SubmissionNotFound = 8,
+    InvalidSubmissionState = 9,
+
+    // Assignment errors
+    SubmissionsStillOpen = 10,
+    AlreadyAssigned = 11,
+    NotEnoughSubmissions = 12,
+
+    // Review errors
+    NotAssigned = 13,
+    AlreadyReviewed = 14,
+    ReviewClosed = 15,
+    ReviewStillOpen = 16,
+    InvalidScore = 17,
+}
@@ -0,0 +1,49 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+/// Peer-review contract events
+pub struct PeerReviewEvents;
+
+impl PeerReviewEvents {
+    fn topic(env: &Env, name: &str) -> (Symbol, Symbol) {
+        (Symbol::new(env, "peer_review"), Symbol::new(env, name))
+    }
+
+    /// Emit event when a task is created
+    pub fn emit_task_created(env: &Env, task_id: u64, course_id: &Symbol, review_deadline: u64) {
+        env.events().publish(
+            Self::topic(env, "task_created"),
+            (task_id, course_id.clone(), review_deadline),
+        );
+    }
+
+    /// Emit event when work is submitted
+    pub fn emit_submitted(env: &Env, task_id: u64, submission_id: u64) {
+        env.events().publish(Self::topic(env, "submitted"), (task_id, submission_id));
+    }
+
+    /// Emit event when reviewers have been assigned for a task
+    pub fn emit_assigned(env: &Env, task_id: u64, submissions: u32) {
+        env.events().publish(Self::topic(env, "assigned"), (task_id, submissions));
+    }
+
+    /// Emit event when a review is recorded
+    pub fn emit_reviewed(env: &Env, submission_id: u64, reviewer: &Address) {
+        env.events().publish(
+            Self::topic(env, "reviewed"),
+            (submission_id, reviewer.clone()),
+        );
+    }
+
+    /// Emit event when a submission needs instructor arbitration
+    pub fn emit_flagged(env: &Env, submission_id: u64, median: u32) {
+        env.events().publish(Self::topic(env, "flagged"), (submission_id, median));
+    }
+
+    /// Emit event when a submission's aggregate score is recorded
+    pub fn emit_finalized(env: &Env, submission_id: u64, score: u32, passed: bool) {
+        env.events().publish(
+            Self::topic(env, "finalized"),
+            (submission_id, score, passed),
+        );
+    }
+}
@@ -0,0 +1,371 @@
+#![no_std]
+
+mod types;
+mod errors;
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod tests;
+
+use soroban_sdk::{
+    contract, contractimpl, vec, Address, BytesN, Env, IntoVal, Symbol, Vec,
+};
+
+use shared::{
+    access_control::AccessControl,
+    cross_contract::CrossContract,
+    roles::{Permission, RoleLevel},
+    time::Clock,
+};
+
+pub use types::{ReviewRules, ReviewTask, SubmissionStatus, WorkSubmission};
+pub use errors::PeerReviewError;
+use events::PeerReviewEvents;
+use storage::PeerReviewStorage;
+
+/// Upper bound on reviewers per submission, keeping finalization cheap
+pub const MAX_REVIEWERS: u32 = 10;
+
+#[contract]
+pub struct PeerReview;
+
+#[contractimpl]
+impl PeerReview {
+    /// Initialize the contract with the Progress contract passes are reported to.
+    /// This contract must hold `UpdateProgress` there for passes to be recorded.
+    pub fn initialize(env: Env, admin: Address, progress: Address) -> Result<(), PeerReviewError> {
+        if PeerReviewStorage::get_admin(&env).is_some() {
+            return Err(PeerReviewError::AlreadyInitialized);
+        }
+
+        AccessControl::initialize(&env, &admin).map_err(|_| PeerReviewError::AlreadyInitialized)?;
+        PeerReviewStorage::set_admin(&env, &admin);
+        PeerReviewStorage::set_progress(&env, &progress);
+
+        Ok(())
+    }
+
+    /// Grant a role in this contract (requires GrantRole)
+    pub fn grant_role(env: Env, admin: Address, user: Address, level: RoleLevel) -> Result<(), PeerReviewError> {
+        admin.require_auth();
+        AccessControl::grant_role(&env, &admin, &user, level).map_err(|_| PeerReviewError::Unauthorized)
+    }
+
+    /// Revoke a user's role in this contract (requires RevokeRole)
+    pub fn revoke_role(env: Env, admin: Address, user: Address) -> Result<(), PeerReviewError> {
+        admin.require_auth();
+        AccessControl::revoke_role(&env, &admin, &user).map_err(|_| PeerReviewError::Unauthorized)
+    }
+
+    /// Create a peer-reviewed task for a course module
+    pub fn create_task(
+        env: Env,
+        instructor: Address,
+        course_id: Symbol,
+        module: u32,
+        rules: ReviewRules,
+    ) -> Result<u64, PeerReviewError> {
+        instructor.require_auth();
+        AccessControl::require_permission(&env, &instructor, &Permission::UpdateCourse)
+            .map_err(|_| PeerReviewError::Unauthorized)?;
+
+        if module == 0
+            || rules.reviewers_per_submission == 0
+            || rules.reviewers_per_submission > MAX_REVIEWERS
+            || rules.max_score == 0
+            || rules.pass_score > 100
+            || Clock::has_passed(&env, rules.submission_deadline)
+            || rules.review_deadline <= rules.submission_deadline
+        {
+            return Err(PeerReviewError::InvalidTask);
+        }
+
+        let task = ReviewTask {
+            id: PeerReviewStorage::next_task_id(&env),
+            course_id: course_id.clone(),
+            module,
+            instructor,
+            rules,
+            assigned: false,
+        };
+        PeerReviewStorage::set_task(&env, &task);
+        PeerReviewEvents::emit_task_created(&env, task.id, &course_id, task.rules.review_deadline);
+
+        Ok(task.id)
+    }
+
+    /// Submit work, identified by the hash of its off-chain content
+    pub fn submit_work(
+        env: Env,
+        author: Address,
+        task_id: u64,
+        content_hash: BytesN<32>,
+    ) -> Result<u64, PeerReviewError> {
+        author.require_auth();
+
+        let task = Self::load_task(&env, task_id)?;
+        if task.assigned || Clock::has_passed(&env, task.rules.submission_deadline) {
+            return Err(PeerReviewError::SubmissionClosed);
+        }
+        if PeerReviewStorage::get_author_submission(&env, task_id, &author).is_some() {
+            return Err(PeerReviewError::AlreadySubmitted);
+        }
+
+        let submission = WorkSubmission {
+            id: PeerReviewStorage::next_submission_id(&env),
+            task_id,
+            author: author.clone(),
+            content_hash,
+            reviewers: Vec::new(&env),
+            status: SubmissionStatus::Submitted,
+            score: 0,
+        };
+        PeerReviewStorage::set_submission(&env, &submission);
+        PeerReviewStorage::add_task_submission(&env, task_id, &author, submission.id);
+        PeerReviewEvents::emit_submitted(&env, task_id, submission.id);
+
+        Ok(submission.id)
+    }
+
+    /// Once submissions close, assign every submission to
+    /// `reviewers_per_submission` other authors. Authors are rotated by a
+    /// pseudorandom offset so nobody reviews their own work and
+    /// assignments cannot be predicted at submission time. Callable by anyone.
+    pub fn assign_reviewers(env: Env, task_id: u64) -> Result<(), PeerReviewError> {
+        let mut task = Self::load_task(&env, task_id)?;
+        if task.assigned {
+            return Err(PeerReviewError::AlreadyAssigned);
+        }
+        if !Clock::has_passed(&env, task.rules.submission_deadline) {
+            return Err(PeerReviewError::SubmissionsStillOpen);
+        }
+        if Clock::has_passed(&env, task.rules.review_deadline) {
+            return Err(PeerReviewError::ReviewClosed);
+        }
+
+        let submissions = PeerReviewStorage::get_task_submissions(&env, task_id);
+        let count = submissions.len();
+        let per_submission = task.rules.reviewers_per_submission;
+        if count <= per_submission {
+            return Err(PeerReviewError::NotEnoughSubmissions);
+        }
+
+        // Offsets 1..=count-per_submission never wrap back onto the author
+        let offset = env.prng().gen_range::<u64>(1..=(count - per_submission) as u64) as u32;
+        let authors: Vec<Address> = Self::authors_of(&env, &submissions);
+        for (index, submission_id) in submissions.iter().enumerate() {
+            let mut submission = Self::load_submission(&env, submission_id)?;
+            for k in 0..per_submission {
+                let reviewer = authors.get((index as u32 + offset + k) % count).unwrap();
+                PeerReviewStorage::add_assignment(&env, task_id, &reviewer, submission_id);
+                submission.reviewers.push_back(reviewer);
+            }
+            submission.status = SubmissionStatus::InReview;
+            PeerReviewStorage::set_submission(&env, &submission);
+        }
+
+        task.assigned = true;
+        PeerReviewStorage::set_task(&env, &task);
+        PeerReviewEvents::emit_assigned(&env, task_id, count);
+
+        Ok(())
+    }
+
+    /// Score an assigned submission before the review deadline
+    pub fn submit_review(
+        env: Env,
+        reviewer: Address,
+        submission_id: u64,
+        score: u32,
+    ) -> Result<(), PeerReviewError> {
+        reviewer.require_auth();
+
+        let submission = Self::load_submission(&env, submission_id)?;
+        let task = Self::load_task(&env, submission.task_id)?;
+        if submission.status != SubmissionStatus::InReview {
+            return Err(PeerReviewError::InvalidSubmissionState);
+        }
+        if !submission.reviewers.contains(&reviewer) {
+            return Err(PeerReviewError::NotAssigned);
+        }
+        if Clock::has_passed(&env, task.rules.review_deadline) {
+            return Err(PeerReviewError::ReviewClosed);
+        }
+        if score > task.rules.max_score {
+            return Err(PeerReviewError::InvalidScore);
+        }
+        if PeerReviewStorage::get_review(&env, submission_id, &reviewer).is_some() {
+            return Err(PeerReviewError::AlreadyReviewed);
+        }
+
+        PeerReviewStorage::set_review(&env, submission_id, &reviewer, score);
+        PeerReviewEvents::emit_reviewed(&env, submission_id, &reviewer);
+
+        Ok(())
+    }
+
+    /// After the review deadline, aggregate a submission's reviews into
+    /// their median. A submission with no reviews, or with any review
+    /// further than `outlier_threshold` from the median, is flagged for
+    /// instructor arbitration instead. Callable by anyone.
+    pub fn finalize(env: Env, submission_id: u64) -> Result<SubmissionStatus, PeerReviewError> {
+        let mut submission = Self::load_submission(&env, submission_id)?;
+        let task = Self::load_task(&env, submission.task_id)?;
+        if submission.status != SubmissionStatus::InReview {
+            return Err(PeerReviewError::InvalidSubmissionState);
+        }
+        if !Clock::has_passed(&env, task.rules.review_deadline) {
+            return Err(PeerReviewError::ReviewStillOpen);
+        }
+
+        let scores = Self::sorted_scores(&env, &submission);
+        let median = Self::median(&scores);
+        let has_outlier = scores
+            .iter()
+            .any(|score| score.abs_diff(median) > task.rules.outlier_threshold);
+
+        if scores.is_empty() || has_outlier {
+            submission.status = SubmissionStatus::Flagged;
+            PeerReviewStorage::set_submission(&env, &submission);
+            PeerReviewEvents::emit_flagged(&env, submission_id, median);
+            return Ok(submission.status);
+        }
+
+        Self::record_score(&env, &task, &mut submission, median)?;
+        Ok(submission.status)
+    }
+
+    /// Settle a flagged submission's score (the task's instructor or an admin)
+    pub fn arbitrate(
+        env: Env,
+        arbiter: Address,
+        submission_id: u64,
+        score: u32,
+    ) -> Result<(), PeerReviewError> {
+        arbiter.require_auth();
+
+        let mut submission = Self::load_submission(&env, submission_id)?;
+        let task = Self::load_task(&env, submission.task_id)?;
+        if arbiter != task.instructor && !AccessControl::is_admin(&env, &arbiter) {
+            return Err(PeerReviewError::Unauthorized);
+        }
+        if submission.status != SubmissionStatus::Flagged {
+            return Err(PeerReviewError::InvalidSubmissionState);
+        }
+        if score > task.rules.max_score {
+            return Err(PeerReviewError::InvalidScore);
+        }
+
+        Self::record_score(&env, &task, &mut submission, score)
+    }
+
+    /// Get a task by id
+    pub fn get_task(env: Env, task_id: u64) -> Option<ReviewTask> {
+        PeerReviewStorage::get_task(&env, task_id)
+    }
+
+    /// Get a submission by id
+    pub fn get_submission(env: Env, submission_id: u64) -> Option<WorkSubmission> {
+        PeerReviewStorage::get_submission(&env, submission_id)
+    }
+
+    /// Get the submissions a reviewer must review for a task
+    pub fn get_assignments(env: Env, task_id: u64, reviewer: Address) -> Vec<u64> {
+        PeerReviewStorage::get_assignments(&env, task_id, &reviewer)
+    }
+
+    /// Get a reviewer's score for a submission
+    pub fn get_review(env: Env, submission_id: u64, reviewer: Address) -> Option<u32> {
+        PeerReviewStorage::get_review(&env, submission_id, &reviewer)
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        PeerReviewStorage::get_admin(&env)
+    }
+}
+
+impl PeerReview {
+    fn load_task(env: &Env, task_id: u64) -> Result<ReviewTask, PeerReviewError> {
+        PeerReviewStorage::get_task(env, task_id).ok_or(PeerReviewError::TaskNotFound)
+    }
+
+    fn load_submission(env: &Env, submission_id: u64) -> Result<WorkSubmission, PeerReviewError> {
+        PeerReviewStorage::get_submission(env, submission_id).ok_or(PeerReviewError::SubmissionNotFound)
+    }
+
+    fn authors_of(env: &Env, submissions: &Vec<u64>) -> Vec<Address> {
+        let mut authors = Vec::new(env);
+        for submission_id in submissions.iter() {
+            if let Some(submission) = PeerReviewStorage::get_submission(env, submission_id) {
+                authors.push_back(submission.author);
+            }
+        }
+        authors
+    }
+
+    /// Scores received for a submission, in ascending order
+    fn sorted_scores(env: &Env, submission: &WorkSubmission) -> Vec<u32> {
+        let mut scores: Vec<u32> = Vec::new(env);
+        for reviewer in submission.reviewers.iter() {
+            let Some(score) = PeerReviewStorage::get_review(env, submission.id, &reviewer) else {
+                continue;
+            };
+            // Insertion sort; at most MAX_REVIEWERS entries
+            let mut index = scores.len();
+            while index > 0 && scores.get(index - 1).unwrap() > score {
+                index -= 1;
+            }
+            scores.insert(index, score);
+        }
+        scores
+    }
+
+    /// Median of sorted scores; the mean of the middle pair for even counts
+    fn median(scores: &Vec<u32>) -> u32 {
+        let len = scores.len();
+        if len == 0 {
+            return 0;
+        }
+        let upper = scores.get(len / 2).unwrap();
+        if len % 2 == 1 {
+            upper
+        } else {
+            (scores.get(len / 2 - 1).unwrap() + upper) / 2
+        }
+    }
+
+    /// Finalize a submission's score and report a pass to Progress
+    fn record_score(
+        env: &Env,
+        task: &ReviewTask,
+        submission: &mut WorkSubmission,
+        score: u32,
+    ) -> Result<(), PeerReviewError> {
+        submission.score = score;
+        submission.status = SubmissionStatus::Finalized;
+        PeerReviewStorage::set_submission(env, submission);
+
+        let passed = score * 100 >= task.rules.pass_score * task.rules.max_score;
+        PeerReviewEvents::emit_finalized(env, submission.id, score, passed);
+
+        if passed {
+            let progress = PeerReviewStorage::get_progress(env).ok_or(PeerReviewError::NotInitialized)?;
+            // Best effort: a module already marked complete must not block grading
+            CrossContract::try_invoke::<u32>(
+                env,
+                &progress,
+                &Symbol::new(env, "attest_module"),
+                vec![
+                    env,
+                    env.current_contract_address().into_val(env),
+                    submission.author.into_val(env),
+                    task.course_id.into_val(env),
+                    task.module.into_val(env),
+                ],
+            );
+        }
+        Ok(())
+    }
+}
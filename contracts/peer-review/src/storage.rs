@@ -0,0 +1,121 @@
+use soroban_sdk::{Address, Env, Vec};
+use crate::types::{DataKey, ReviewTask, WorkSubmission};
+
+/// Storage utilities for the peer-review contract
+pub struct PeerReviewStorage;
+
+impl PeerReviewStorage {
+    /// Get the contract admin
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Set the contract admin
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Get the Progress contract passes are reported to
+    pub fn get_progress(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Progress)
+    }
+
+    /// Set the Progress contract passes are reported to
+    pub fn set_progress(env: &Env, progress: &Address) {
+        env.storage().instance().set(&DataKey::Progress, progress);
+    }
+
+    /// Allocate the next task id
+    pub fn next_task_id(env: &Env) -> u64 {
+        let id: u64 = env.storage().instance().get(&DataKey::TaskCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::TaskCount, &id);
+        id
+    }
+
+    /// Get a task by id
+    pub fn get_task(env: &Env, task_id: u64) -> Option<ReviewTask> {
+        env.storage().persistent().get(&DataKey::Task(task_id))
+    }
+
+    /// Store a task
+    pub fn set_task(env: &Env, task: &ReviewTask) {
+        env.storage().persistent().set(&DataKey::Task(task.id), task);
+    }
+
+    /// Allocate the next submission id
+    pub fn next_submission_id(env: &Env) -> u64 {
+        let id: u64 = env.storage().instance().get(&DataKey::SubmissionCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::SubmissionCount, &id);
+        id
+    }
+
+    /// Get a submission by id
+    pub fn get_submission(env: &Env, submission_id: u64) -> Option<WorkSubmission> {
+        env.storage().persistent().get(&DataKey::Submission(submission_id))
+    }
+
+    /// Store a submission
+    pub fn set_submission(env: &Env, submission: &WorkSubmission) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Submission(submission.id), submission);
+    }
+
+    /// Get the ids of every submission to a task, in submission order
+    pub fn get_task_submissions(env: &Env, task_id: u64) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TaskSubmissions(task_id))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Record a new submission to a task
+    pub fn add_task_submission(env: &Env, task_id: u64, author: &Address, submission_id: u64) {
+        let mut submissions = Self::get_task_submissions(env, task_id);
+        submissions.push_back(submission_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::TaskSubmissions(task_id), &submissions);
+        env.storage()
+            .persistent()
+            .set(&DataKey::AuthorSubmission(task_id, author.clone()), &submission_id);
+    }
+
+    /// Get an author's submission to a task
+    pub fn get_author_submission(env: &Env, task_id: u64, author: &Address) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AuthorSubmission(task_id, author.clone()))
+    }
+
+    /// Get the submissions a reviewer has been assigned for a task
+    pub fn get_assignments(env: &Env, task_id: u64, reviewer: &Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Assignments(task_id, reviewer.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Assign a submission to a reviewer
+    pub fn add_assignment(env: &Env, task_id: u64, reviewer: &Address, submission_id: u64) {
+        let mut assignments = Self::get_assignments(env, task_id, reviewer);
+        assignments.push_back(submission_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Assignments(task_id, reviewer.clone()), &assignments);
+    }
+
+    /// Get a reviewer's score for a submission
+    pub fn get_review(env: &Env, submission_id: u64, reviewer: &Address) -> Option<u32> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Review(submission_id, reviewer.clone()))
+    }
+
+    /// Store a reviewer's score for a submission
+    pub fn set_review(env: &Env, submission_id: u64, reviewer: &Address, score: u32) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Review(submission_id, reviewer.clone()), &score);
+    }
+}
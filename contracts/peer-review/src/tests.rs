@@ -0,0 +1,206 @@
+use soroban_sdk::{
+    symbol_short, testutils::{Address as _, Ledger}, Address, BytesN, Env, Symbol, Vec,
+};
+use shared::{roles::RoleLevel, time::DAY};
+
+use progress::{Progress, ProgressClient};
+
+use crate::{PeerReview, PeerReviewClient, PeerReviewError, ReviewRules, SubmissionStatus};
+
+fn setup_test_env() -> (Env, PeerReviewClient<'static>, ProgressClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let instructor = Address::generate(&env);
+
+    let contract_id = env.register(PeerReview, ());
+    let client = PeerReviewClient::new(&env, &contract_id);
+
+    let progress_id = env.register(Progress, ());
+    let progress = ProgressClient::new(&env, &progress_id);
+    progress.initialize(&admin);
+    progress.add_course(&course(), &2);
+    progress.grant_role(&admin, &contract_id, &RoleLevel::Moderator);
+
+    client.initialize(&admin, &progress_id);
+    client.grant_role(&admin, &instructor, &RoleLevel::Instructor);
+
+    (env, client, progress, instructor)
+}
+
+fn course() -> Symbol {
+    symbol_short!("RUST101")
+}
+
+fn rules() -> ReviewRules {
+    ReviewRules {
+        reviewers_per_submission: 2,
+        max_score: 10,
+        pass_score: 60,
+        outlier_threshold: 3,
+        submission_deadline: DAY,
+        review_deadline: 3 * DAY,
+    }
+}
+
+/// Create a task, collect one submission from each of `count` authors and assign reviewers
+fn assigned_task(
+    env: &Env,
+    client: &PeerReviewClient,
+    instructor: &Address,
+    count: u32,
+) -> (u64, Vec<Address>, Vec<u64>) {
+    let task_id = client.create_task(instructor, &course(), &1, &rules());
+    let mut authors = Vec::new(env);
+    let mut submissions = Vec::new(env);
+    for i in 0..count {
+        let author = Address::generate(env);
+        let hash = BytesN::from_array(env, &[i as u8; 32]);
+        submissions.push_back(client.submit_work(&author, &task_id, &hash));
+        authors.push_back(author);
+    }
+    env.ledger().set_timestamp(DAY + 1);
+    client.assign_reviewers(&task_id);
+    (task_id, authors, submissions)
+}
+
+#[test]
+fn test_reviews_aggregate_into_median_and_feed_progress() {
+    let (env, client, progress, instructor) = setup_test_env();
+    let (task_id, authors, submissions) = assigned_task(&env, &client, &instructor, 4);
+
+    for author in authors.iter() {
+        let assignments = client.get_assignments(&task_id, &author);
+        assert_eq!(assignments.len(), 2);
+        let assigned = client.get_submission(&assignments.get(0).unwrap()).unwrap();
+        assert_ne!(assigned.author, author);
+        for submission_id in assignments.iter() {
+            client.submit_review(&author, &submission_id, &(6 + submission_id as u32 % 2));
+        }
+    }
+
+    env.ledger().set_timestamp(3 * DAY + 1);
+    for submission_id in submissions.iter() {
+        let submission = client.get_submission(&submission_id).unwrap();
+        assert_eq!(submission.reviewers.len(), 2);
+        assert!(!submission.reviewers.contains(&submission.author));
+        assert_eq!(client.finalize(&submission_id), SubmissionStatus::Finalized);
+    }
+
+    // Scores of 6 or 7 out of 10 pass at 60%
+    let first = client.get_submission(&submissions.get(0).unwrap()).unwrap();
+    assert_eq!(first.score, 7);
+    assert_eq!(progress.get_completion_percentage(&first.author, &course()), 50);
+}
+
+#[test]
+fn test_outlier_is_flagged_for_arbitration() {
+    let (env, client, progress, instructor) = setup_test_env();
+    let (_, _, submissions) = assigned_task(&env, &client, &instructor, 3);
+    let submission_id = submissions.get(0).unwrap();
+    let submission = client.get_submission(&submission_id).unwrap();
+
+    let generous = submission.reviewers.get(0).unwrap();
+    let harsh = submission.reviewers.get(1).unwrap();
+    assert_eq!(
+        client.try_submit_review(&submission.author, &submission_id, &5),
+        Err(Ok(PeerReviewError::NotAssigned))
+    );
+    assert_eq!(
+        client.try_submit_review(&generous, &submission_id, &11),
+        Err(Ok(PeerReviewError::InvalidScore))
+    );
+    client.submit_review(&generous, &submission_id, &10);
+    client.submit_review(&harsh, &submission_id, &1);
+    assert_eq!(
+        client.try_submit_review(&harsh, &submission_id, &5),
+        Err(Ok(PeerReviewError::AlreadyReviewed))
+    );
+    assert_eq!(
+        client.try_finalize(&submission_id),
+        Err(Ok(PeerReviewError::ReviewStillOpen))
+    );
+
+    env.ledger().set_timestamp(3 * DAY + 1);
+    assert_eq!(client.finalize(&submission_id), SubmissionStatus::Flagged);
+    assert_eq!(
+        client.try_arbitrate(&generous, &submission_id, &8),
+        Err(Ok(PeerReviewError::Unauthorized))
+    );
+
+    client.arbitrate(&instructor, &submission_id, &5);
+    let submission = client.get_submission(&submission_id).unwrap();
+    assert_eq!(submission.status, SubmissionStatus::Finalized);
+    assert_eq!(submission.score, 5);
+    assert!(progress.try_get_progress(&submission.author, &course()).is_err());
+
+    // Unreviewed submissions are flagged too
+    assert_eq!(client.finalize(&submissions.get(1).unwrap()), SubmissionStatus::Flagged);
+}
+
+#[test]
+fn test_assignment_and_review_rules() {
+    let (env, client, _progress, instructor) = setup_test_env();
+    let task_id = client.create_task(&instructor, &course(), &1, &rules());
+    let author = Address::generate(&env);
+    let hash = BytesN::from_array(&env, &[0; 32]);
+    let submission_id = client.submit_work(&author, &task_id, &hash);
+
+    assert_eq!(
+        client.try_submit_work(&author, &task_id, &hash),
+        Err(Ok(PeerReviewError::AlreadySubmitted))
+    );
+    assert_eq!(
+        client.try_assign_reviewers(&task_id),
+        Err(Ok(PeerReviewError::SubmissionsStillOpen))
+    );
+
+    // Two reviewers per submission need at least three authors
+    env.ledger().set_timestamp(DAY + 1);
+    assert_eq!(
+        client.try_assign_reviewers(&task_id),
+        Err(Ok(PeerReviewError::NotEnoughSubmissions))
+    );
+    assert_eq!(
+        client.try_submit_review(&author, &submission_id, &5),
+        Err(Ok(PeerReviewError::InvalidSubmissionState))
+    );
+}
+
+#[test]
+fn test_task_deadline_and_double_settlement_errors() {
+    let (env, client, _progress, instructor) = setup_test_env();
+    let author = Address::generate(&env);
+    let hash = BytesN::from_array(&env, &[0; 32]);
+
+    assert_eq!(
+        client.try_create_task(&author, &course(), &1, &rules()),
+        Err(Ok(PeerReviewError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_create_task(&instructor, &course(), &1, &ReviewRules { review_deadline: DAY, ..rules() }),
+        Err(Ok(PeerReviewError::InvalidTask))
+    );
+    assert_eq!(client.try_submit_work(&author, &99, &hash), Err(Ok(PeerReviewError::TaskNotFound)));
+
+    let (task_id, _, submissions) = assigned_task(&env, &client, &instructor, 3);
+    assert_eq!(client.try_submit_work(&author, &task_id, &hash), Err(Ok(PeerReviewError::SubmissionClosed)));
+    assert_eq!(client.try_assign_reviewers(&task_id), Err(Ok(PeerReviewError::AlreadyAssigned)));
+
+    // Reviews close at the deadline and a submission settles once
+    let submission_id = submissions.get(0).unwrap();
+    let reviewer = client.get_submission(&submission_id).unwrap().reviewers.get(0).unwrap();
+    env.ledger().set_timestamp(3 * DAY + 1);
+    assert_eq!(
+        client.try_submit_review(&reviewer, &submission_id, &5),
+        Err(Ok(PeerReviewError::ReviewClosed))
+    );
+    assert_eq!(client.finalize(&submission_id), SubmissionStatus::Flagged);
+    client.arbitrate(&instructor, &submission_id, &7);
+    assert_eq!(
+        client.try_arbitrate(&instructor, &submission_id, &9),
+        Err(Ok(PeerReviewError::InvalidSubmissionState))
+    );
+    assert_eq!(client.try_finalize(&submission_id), Err(Ok(PeerReviewError::InvalidSubmissionState)));
+}
@@ -0,0 +1,64 @@
+use soroban_sdk::{contracttype, Address, BytesN, Symbol, Vec};
+
+/// How a peer-reviewed task is run and graded
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ReviewRules {
+    pub reviewers_per_submission: u32,
+    pub max_score: u32,
+    pub pass_score: u32, // percent of max_score
+    pub outlier_threshold: u32, // max distance from the median before a review is flagged
+    pub submission_deadline: u64,
+    pub review_deadline: u64,
+}
+
+/// A piece of coursework graded by peers
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ReviewTask {
+    pub id: u64,
+    pub course_id: Symbol,
+    pub module: u32, // Progress module credited on a pass
+    pub instructor: Address,
+    pub rules: ReviewRules,
+    pub assigned: bool,
+}
+
+/// Where a submission is in the review workflow
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum SubmissionStatus {
+    Submitted,
+    InReview,
+    Flagged, // waiting for instructor arbitration
+    Finalized,
+}
+
+/// A student's work, identified to reviewers only by id and content hash
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct WorkSubmission {
+    pub id: u64,
+    pub task_id: u64,
+    pub author: Address,
+    pub content_hash: BytesN<32>,
+    pub reviewers: Vec<Address>,
+    pub status: SubmissionStatus,
+    pub score: u32, // aggregate, set when finalized
+}
+
+/// Storage keys for the peer-review contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Progress,
+    TaskCount,
+    Task(u64),
+    SubmissionCount,
+    Submission(u64),
+    TaskSubmissions(u64),
+    AuthorSubmission(u64, Address), // (task, author) -> submission id
+    Assignments(u64, Address),      // (task, reviewer) -> submission ids
+    Review(u64, Address),           // (submission, reviewer) -> score
+}
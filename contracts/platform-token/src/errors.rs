@@ -0,0 +1,27 @@
+use soroban_sdk::contracterror;
+
+/// Platform token contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PlatformTokenError {
+    // Initialization errors
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+
+    // Authorization errors
+    Unauthorized = 3,
+
+    // Balance errors
+    InvalidAmount = 4,
+    InsufficientBalance = 5,
+    InsufficientAllowance = 6,
+    InvalidExpiration = 7,
+
+    // Cap errors
+    InvalidTransferCap = 8,
+    TransferCapExceeded = 9,
+
+    // Metadata errors
+    InvalidMetadata = 10,
+}
@@ -0,0 +1,52 @@
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+/// Platform token events. Transfer, approve, mint and burn follow the
+/// SEP-41 topic layout so wallets and indexers pick them up unchanged.
+pub struct TokenEvents;
+
+impl TokenEvents {
+    /// Emit SEP-41 `approve` event
+    pub fn emit_approve(env: &Env, from: &Address, spender: &Address, amount: i128, expiration_ledger: u32) {
+        env.events().publish(
+            (symbol_short!("approve"), from.clone(), spender.clone()),
+            (amount, expiration_ledger),
+        );
+    }
+
+    /// Emit SEP-41 `transfer` event
+    pub fn emit_transfer(env: &Env, from: &Address, to: &Address, amount: i128) {
+        env.events().publish(
+            (symbol_short!("transfer"), from.clone(), to.clone()),
+            amount,
+        );
+    }
+
+    /// Emit SEP-41 `mint` event
+    pub fn emit_mint(env: &Env, minter: &Address, to: &Address, amount: i128) {
+        env.events().publish(
+            (symbol_short!("mint"), minter.clone(), to.clone()),
+            amount,
+        );
+    }
+
+    /// Emit SEP-41 `burn` event
+    pub fn emit_burn(env: &Env, from: &Address, amount: i128) {
+        env.events().publish((symbol_short!("burn"), from.clone()), amount);
+    }
+
+    /// Emit event when governance changes an address's transfer cap
+    pub fn emit_cap_updated(env: &Env, holder: &Address, limit: i128, period: u64) {
+        env.events().publish(
+            (Symbol::new(env, "platform_token"), Symbol::new(env, "cap_updated")),
+            (holder.clone(), limit, period),
+        );
+    }
+
+    /// Emit event when the governance address changes
+    pub fn emit_governance_changed(env: &Env, governance: &Address) {
+        env.events().publish(
+            (Symbol::new(env, "platform_token"), Symbol::new(env, "governance_changed")),
+            governance.clone(),
+        );
+    }
+}
@@ -0,0 +1,301 @@
+#![no_std]
+
+mod types;
+mod errors;
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod tests;
+
+use soroban_sdk::{
+    contract, contractimpl, panic_with_error,
+    token::{self, TokenInterface as _},
+    Address, Env, String,
+};
+
+use shared::{access_control::AccessControl, roles::{Permission, RoleLevel}, time::Clock};
+
+pub use types::{AllowanceValue, CapUsage, TokenMetadata, TransferCap};
+pub use errors::PlatformTokenError;
+use events::TokenEvents;
+use storage::TokenStorage;
+
+#[contract]
+pub struct PlatformToken;
+
+#[contractimpl]
+impl PlatformToken {
+    /// Initialize the token. The admin also starts out as the governance
+    /// address that configures transfer caps.
+    pub fn initialize(env: Env, admin: Address, metadata: TokenMetadata) -> Result<(), PlatformTokenError> {
+        if TokenStorage::get_admin(&env).is_some() {
+            return Err(PlatformTokenError::AlreadyInitialized);
+        }
+        if metadata.decimals > 18 || metadata.name.is_empty() || metadata.symbol.is_empty() {
+            return Err(PlatformTokenError::InvalidMetadata);
+        }
+
+        AccessControl::initialize(&env, &admin).map_err(|_| PlatformTokenError::AlreadyInitialized)?;
+        TokenStorage::set_admin(&env, &admin);
+        TokenStorage::set_governance(&env, &admin);
+        TokenStorage::set_metadata(&env, &metadata);
+
+        Ok(())
+    }
+
+    /// Grant a role in this contract (requires GrantRole)
+    pub fn grant_role(env: Env, admin: Address, user: Address, level: RoleLevel) -> Result<(), PlatformTokenError> {
+        admin.require_auth();
+        AccessControl::grant_role(&env, &admin, &user, level).map_err(|_| PlatformTokenError::Unauthorized)
+    }
+
+    /// Revoke a user's role in this contract (requires RevokeRole)
+    pub fn revoke_role(env: Env, admin: Address, user: Address) -> Result<(), PlatformTokenError> {
+        admin.require_auth();
+        AccessControl::revoke_role(&env, &admin, &user).map_err(|_| PlatformTokenError::Unauthorized)
+    }
+
+    /// Grant one permission on top of a user's role, such as MintTokens for
+    /// a rewards contract (requires GrantRole)
+    pub fn grant_permission(
+        env: Env,
+        admin: Address,
+        user: Address,
+        permission: Permission,
+    ) -> Result<(), PlatformTokenError> {
+        admin.require_auth();
+        AccessControl::grant_permission(&env, &admin, &user, permission).map_err(|_| PlatformTokenError::Unauthorized)
+    }
+
+    /// Withdraw a permission granted with `grant_permission` (requires RevokeRole)
+    pub fn revoke_permission(
+        env: Env,
+        admin: Address,
+        user: Address,
+        permission: Permission,
+    ) -> Result<(), PlatformTokenError> {
+        admin.require_auth();
+        AccessControl::revoke_permission(&env, &admin, &user, &permission).map_err(|_| PlatformTokenError::Unauthorized)
+    }
+
+    /// Mint new tokens. Requires the `MintTokens` permission, which reward
+    /// and incentive contracts are granted.
+    pub fn mint(env: Env, minter: Address, to: Address, amount: i128) -> Result<(), PlatformTokenError> {
+        minter.require_auth();
+        AccessControl::require_permission(&env, &minter, &Permission::MintTokens)
+            .map_err(|_| PlatformTokenError::Unauthorized)?;
+        if amount <= 0 {
+            return Err(PlatformTokenError::InvalidAmount);
+        }
+
+        TokenStorage::set_balance(&env, &to, TokenStorage::get_balance(&env, &to) + amount);
+        TokenStorage::set_total_supply(&env, TokenStorage::get_total_supply(&env) + amount);
+        TokenEvents::emit_mint(&env, &minter, &to, amount);
+
+        Ok(())
+    }
+
+    /// Hand cap configuration to a new governance address, such as a
+    /// governance contract
+    pub fn set_governance(env: Env, governance: Address, new_governance: Address) -> Result<(), PlatformTokenError> {
+        Self::require_governance(&env, &governance)?;
+        TokenStorage::set_governance(&env, &new_governance);
+        TokenEvents::emit_governance_changed(&env, &new_governance);
+        Ok(())
+    }
+
+    /// Limit how much `holder` may transfer out per rolling period
+    pub fn set_transfer_cap(
+        env: Env,
+        governance: Address,
+        holder: Address,
+        cap: TransferCap,
+    ) -> Result<(), PlatformTokenError> {
+        Self::require_governance(&env, &governance)?;
+        if cap.limit <= 0 || cap.period == 0 {
+            return Err(PlatformTokenError::InvalidTransferCap);
+        }
+
+        TokenStorage::set_transfer_cap(&env, &holder, &cap);
+        TokenEvents::emit_cap_updated(&env, &holder, cap.limit, cap.period);
+        Ok(())
+    }
+
+    /// Lift an address's transfer cap
+    pub fn remove_transfer_cap(env: Env, governance: Address, holder: Address) -> Result<(), PlatformTokenError> {
+        Self::require_governance(&env, &governance)?;
+        TokenStorage::remove_transfer_cap(&env, &holder);
+        TokenEvents::emit_cap_updated(&env, &holder, 0, 0);
+        Ok(())
+    }
+
+    /// Get an address's transfer cap
+    pub fn get_transfer_cap(env: Env, holder: Address) -> Option<TransferCap> {
+        TokenStorage::get_transfer_cap(&env, &holder)
+    }
+
+    /// Get how much more `holder` may transfer out in the current period
+    pub fn get_transfer_headroom(env: Env, holder: Address) -> Option<i128> {
+        let cap = TokenStorage::get_transfer_cap(&env, &holder)?;
+        Some(cap.limit - Self::current_usage(&env, &holder, &cap).spent)
+    }
+
+    /// Get the total supply
+    pub fn total_supply(env: Env) -> i128 {
+        TokenStorage::get_total_supply(&env)
+    }
+
+    /// Get the governance address
+    pub fn get_governance(env: Env) -> Option<Address> {
+        TokenStorage::get_governance(&env)
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        TokenStorage::get_admin(&env)
+    }
+}
+
+/// SEP-41 token interface
+#[contractimpl]
+impl token::TokenInterface for PlatformToken {
+    fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        TokenStorage::get_allowance(&env, &from, &spender).amount
+    }
+
+    fn approve(env: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        from.require_auth();
+        if amount < 0 {
+            panic_with_error!(&env, PlatformTokenError::InvalidAmount);
+        }
+        if amount > 0 && expiration_ledger < env.ledger().sequence() {
+            panic_with_error!(&env, PlatformTokenError::InvalidExpiration);
+        }
+
+        TokenStorage::set_allowance(&env, &from, &spender, &AllowanceValue { amount, expiration_ledger });
+        TokenEvents::emit_approve(&env, &from, &spender, amount, expiration_ledger);
+    }
+
+    fn balance(env: Env, id: Address) -> i128 {
+        TokenStorage::get_balance(&env, &id)
+    }
+
+    fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        Self::move_balance(&env, &from, &to, amount);
+    }
+
+    fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        spender.require_auth();
+        Self::spend_allowance(&env, &from, &spender, amount);
+        Self::move_balance(&env, &from, &to, amount);
+    }
+
+    fn burn(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        Self::require_burner(&env, &from);
+        Self::burn_balance(&env, &from, amount);
+    }
+
+    fn burn_from(env: Env, spender: Address, from: Address, amount: i128) {
+        spender.require_auth();
+        Self::require_burner(&env, &spender);
+        Self::spend_allowance(&env, &from, &spender, amount);
+        Self::burn_balance(&env, &from, amount);
+    }
+
+    fn decimals(env: Env) -> u32 {
+        Self::metadata(&env).decimals
+    }
+
+    fn name(env: Env) -> String {
+        Self::metadata(&env).name
+    }
+
+    fn symbol(env: Env) -> String {
+        Self::metadata(&env).symbol
+    }
+}
+
+impl PlatformToken {
+    fn require_governance(env: &Env, caller: &Address) -> Result<(), PlatformTokenError> {
+        caller.require_auth();
+        match TokenStorage::get_governance(env) {
+            Some(governance) if governance == *caller => Ok(()),
+            Some(_) => Err(PlatformTokenError::Unauthorized),
+            None => Err(PlatformTokenError::NotInitialized),
+        }
+    }
+
+    /// Burning shrinks the supply, so like minting it needs a permission:
+    /// `BurnTokens`, held by admins and granted to redemption contracts
+    fn require_burner(env: &Env, burner: &Address) {
+        if AccessControl::require_permission(env, burner, &Permission::BurnTokens).is_err() {
+            panic_with_error!(env, PlatformTokenError::Unauthorized);
+        }
+    }
+
+    fn metadata(env: &Env) -> TokenMetadata {
+        TokenStorage::get_metadata(env)
+            .unwrap_or_else(|| panic_with_error!(env, PlatformTokenError::NotInitialized))
+    }
+
+    /// Usage within the current period, starting a fresh window once the
+    /// previous one has run its course
+    fn current_usage(env: &Env, holder: &Address, cap: &TransferCap) -> CapUsage {
+        let now = Clock::now(env);
+        match TokenStorage::get_cap_usage(env, holder) {
+            Some(usage) if now < usage.window_start.saturating_add(cap.period) => usage,
+            _ => CapUsage { window_start: now, spent: 0 },
+        }
+    }
+
+    fn move_balance(env: &Env, from: &Address, to: &Address, amount: i128) {
+        if amount < 0 {
+            panic_with_error!(env, PlatformTokenError::InvalidAmount);
+        }
+
+        if let Some(cap) = TokenStorage::get_transfer_cap(env, from) {
+            let mut usage = Self::current_usage(env, from, &cap);
+            if usage.spent + amount > cap.limit {
+                panic_with_error!(env, PlatformTokenError::TransferCapExceeded);
+            }
+            usage.spent += amount;
+            TokenStorage::set_cap_usage(env, from, &usage);
+        }
+
+        Self::debit(env, from, amount);
+        TokenStorage::set_balance(env, to, TokenStorage::get_balance(env, to) + amount);
+        TokenEvents::emit_transfer(env, from, to, amount);
+    }
+
+    fn burn_balance(env: &Env, from: &Address, amount: i128) {
+        if amount < 0 {
+            panic_with_error!(env, PlatformTokenError::InvalidAmount);
+        }
+
+        Self::debit(env, from, amount);
+        TokenStorage::set_total_supply(env, TokenStorage::get_total_supply(env) - amount);
+        TokenEvents::emit_burn(env, from, amount);
+    }
+
+    fn debit(env: &Env, from: &Address, amount: i128) {
+        let balance = TokenStorage::get_balance(env, from);
+        if balance < amount {
+            panic_with_error!(env, PlatformTokenError::InsufficientBalance);
+        }
+        TokenStorage::set_balance(env, from, balance - amount);
+    }
+
+    fn spend_allowance(env: &Env, from: &Address, spender: &Address, amount: i128) {
+        let mut allowance = TokenStorage::get_allowance(env, from, spender);
+        if allowance.amount < amount {
+            panic_with_error!(env, PlatformTokenError::InsufficientAllowance);
+        }
+        if amount > 0 {
+            allowance.amount -= amount;
+            TokenStorage::set_allowance(env, from, spender, &allowance);
+        }
+    }
+}
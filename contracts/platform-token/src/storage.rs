@@ -0,0 +1,122 @@
+use soroban_sdk::{Address, Env};
+use crate::types::{AllowanceValue, CapUsage, DataKey, TokenMetadata, TransferCap};
+
+/// Storage utilities for the platform token contract
+pub struct TokenStorage;
+
+impl TokenStorage {
+    /// Get the contract admin
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Set the contract admin
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Get the address allowed to configure transfer caps
+    pub fn get_governance(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Governance)
+    }
+
+    /// Set the address allowed to configure transfer caps
+    pub fn set_governance(env: &Env, governance: &Address) {
+        env.storage().instance().set(&DataKey::Governance, governance);
+    }
+
+    /// Get the token metadata
+    pub fn get_metadata(env: &Env) -> Option<TokenMetadata> {
+        env.storage().instance().get(&DataKey::Metadata)
+    }
+
+    /// Set the token metadata
+    pub fn set_metadata(env: &Env, metadata: &TokenMetadata) {
+        env.storage().instance().set(&DataKey::Metadata, metadata);
+    }
+
+    /// Get the total supply
+    pub fn get_total_supply(env: &Env) -> i128 {
+        env.storage().instance().get(&DataKey::TotalSupply).unwrap_or(0)
+    }
+
+    /// Set the total supply
+    pub fn set_total_supply(env: &Env, supply: i128) {
+        env.storage().instance().set(&DataKey::TotalSupply, &supply);
+    }
+
+    /// Get an address's balance
+    pub fn get_balance(env: &Env, id: &Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Balance(id.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Set an address's balance
+    pub fn set_balance(env: &Env, id: &Address, amount: i128) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Balance(id.clone()), &amount);
+    }
+
+    /// Get an allowance, treating lapsed ones as zero
+    pub fn get_allowance(env: &Env, from: &Address, spender: &Address) -> AllowanceValue {
+        let allowance: Option<AllowanceValue> = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Allowance(from.clone(), spender.clone()));
+        match allowance {
+            Some(allowance) if allowance.expiration_ledger >= env.ledger().sequence() => allowance,
+            _ => AllowanceValue { amount: 0, expiration_ledger: 0 },
+        }
+    }
+
+    /// Set an allowance, keeping the entry alive until it lapses
+    pub fn set_allowance(env: &Env, from: &Address, spender: &Address, allowance: &AllowanceValue) {
+        let key = DataKey::Allowance(from.clone(), spender.clone());
+        env.storage().temporary().set(&key, allowance);
+        if allowance.amount > 0 {
+            let live_for = allowance
+                .expiration_ledger
+                .saturating_sub(env.ledger().sequence());
+            env.storage().temporary().extend_ttl(&key, live_for, live_for);
+        }
+    }
+
+    /// Get an address's transfer cap
+    pub fn get_transfer_cap(env: &Env, id: &Address) -> Option<TransferCap> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TransferCap(id.clone()))
+    }
+
+    /// Set an address's transfer cap
+    pub fn set_transfer_cap(env: &Env, id: &Address, cap: &TransferCap) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::TransferCap(id.clone()), cap);
+    }
+
+    /// Remove an address's transfer cap and its usage
+    pub fn remove_transfer_cap(env: &Env, id: &Address) {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::TransferCap(id.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::CapUsage(id.clone()));
+    }
+
+    /// Get how much of its cap an address has used
+    pub fn get_cap_usage(env: &Env, id: &Address) -> Option<CapUsage> {
+        env.storage().persistent().get(&DataKey::CapUsage(id.clone()))
+    }
+
+    /// Set how much of its cap an address has used
+    pub fn set_cap_usage(env: &Env, id: &Address, usage: &CapUsage) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::CapUsage(id.clone()), usage);
+    }
+}
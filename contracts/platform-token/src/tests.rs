@@ -0,0 +1,190 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::TokenClient,
+    Address, Env, String,
+};
+use shared::{roles::{Permission, RoleLevel}, time::DAY};
+
+use crate::{PlatformToken, PlatformTokenClient, PlatformTokenError, TokenMetadata, TransferCap};
+
+fn setup_test_env() -> (Env, PlatformTokenClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(PlatformToken, ());
+    let client = PlatformTokenClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let metadata = TokenMetadata {
+        decimals: 7,
+        name: String::from_str(&env, "StrellerMinds Learning Token"),
+        symbol: String::from_str(&env, "SML"),
+    };
+    client.initialize(&admin, &metadata);
+
+    (env, client, admin)
+}
+
+fn grant_burner(client: &PlatformTokenClient, admin: &Address, burner: &Address) {
+    client.grant_role(admin, burner, &RoleLevel::Student);
+    client.grant_permission(admin, burner, &Permission::BurnTokens);
+}
+
+#[test]
+fn test_sep41_interface() {
+    let (env, client, admin) = setup_test_env();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let spender = Address::generate(&env);
+
+    client.mint(&admin, &alice, &1_000);
+
+    // Generic SEP-41 clients work against the contract
+    let token = TokenClient::new(&env, &client.address);
+    assert_eq!(token.decimals(), 7);
+    assert_eq!(token.symbol(), String::from_str(&env, "SML"));
+    token.transfer(&alice, &bob, &300);
+    assert_eq!(token.balance(&alice), 700);
+    assert_eq!(token.balance(&bob), 300);
+
+    token.approve(&alice, &spender, &200, &100);
+    token.transfer_from(&spender, &alice, &bob, &150);
+    assert_eq!(token.allowance(&alice, &spender), 50);
+    assert!(token.try_transfer_from(&spender, &alice, &bob, &51).is_err());
+
+    grant_burner(&client, &admin, &spender);
+    grant_burner(&client, &admin, &bob);
+    token.burn_from(&spender, &alice, &50);
+    token.burn(&bob, &50);
+    assert_eq!(token.balance(&alice), 500);
+    assert_eq!(client.total_supply(), 900);
+
+    // Allowances lapse after their expiration ledger
+    token.approve(&alice, &spender, &100, &100);
+    env.ledger().set_sequence_number(101);
+    assert_eq!(token.allowance(&alice, &spender), 0);
+}
+
+#[test]
+fn test_minting_is_role_gated() {
+    let (env, client, admin) = setup_test_env();
+    let rewards = Address::generate(&env);
+    let student = Address::generate(&env);
+
+    assert_eq!(
+        client.try_mint(&rewards, &student, &10),
+        Err(Ok(PlatformTokenError::Unauthorized))
+    );
+
+    // Only role managers can hand out minting rights
+    assert_eq!(
+        client.try_grant_role(&student, &rewards, &RoleLevel::Student),
+        Err(Ok(PlatformTokenError::Unauthorized))
+    );
+    client.grant_role(&admin, &rewards, &RoleLevel::Student);
+    client.grant_permission(&admin, &rewards, &Permission::MintTokens);
+    client.mint(&rewards, &student, &10);
+    assert_eq!(client.balance(&student), 10);
+    assert_eq!(
+        client.try_mint(&rewards, &student, &0),
+        Err(Ok(PlatformTokenError::InvalidAmount))
+    );
+
+    client.revoke_permission(&admin, &rewards, &Permission::MintTokens);
+    assert_eq!(
+        client.try_mint(&rewards, &student, &10),
+        Err(Ok(PlatformTokenError::Unauthorized))
+    );
+    client.revoke_role(&admin, &rewards);
+    assert_eq!(client.try_revoke_role(&admin, &rewards), Err(Ok(PlatformTokenError::Unauthorized)));
+}
+
+#[test]
+fn test_burning_is_role_gated() {
+    let (env, client, admin) = setup_test_env();
+    let holder = Address::generate(&env);
+    let redeemer = Address::generate(&env);
+    client.mint(&admin, &holder, &100);
+    client.approve(&holder, &redeemer, &100, &100);
+
+    // Neither holders nor approved spenders may shrink the supply unaided
+    let unauthorized = Err(Ok(PlatformTokenError::Unauthorized.into()));
+    assert_eq!(client.try_burn(&holder, &10), unauthorized);
+    assert_eq!(client.try_burn_from(&redeemer, &holder, &10), unauthorized);
+
+    grant_burner(&client, &admin, &redeemer);
+    client.burn_from(&redeemer, &holder, &10);
+    client.burn(&admin, &0);
+    assert_eq!(client.total_supply(), 90);
+
+    client.revoke_permission(&admin, &redeemer, &Permission::BurnTokens);
+    assert_eq!(client.try_burn_from(&redeemer, &holder, &10), unauthorized);
+    assert_eq!(client.balance(&holder), 90);
+}
+
+#[test]
+fn test_transfer_caps_set_by_governance() {
+    let (env, client, admin) = setup_test_env();
+    let holder = Address::generate(&env);
+    let other = Address::generate(&env);
+    let governance = Address::generate(&env);
+    client.mint(&admin, &holder, &1_000);
+
+    client.set_governance(&admin, &governance);
+    let cap = TransferCap { limit: 100, period: DAY };
+    assert_eq!(
+        client.try_set_transfer_cap(&admin, &holder, &cap),
+        Err(Ok(PlatformTokenError::Unauthorized))
+    );
+    client.set_transfer_cap(&governance, &holder, &cap);
+
+    client.transfer(&holder, &other, &60);
+    assert_eq!(client.get_transfer_headroom(&holder), Some(40));
+    assert!(client.try_transfer(&holder, &other, &41).is_err());
+
+    // Burning is not a transfer and is not capped
+    grant_burner(&client, &admin, &holder);
+    client.burn(&holder, &200);
+
+    // A new period restores the full limit
+    env.ledger().set_timestamp(DAY);
+    client.transfer(&holder, &other, &100);
+
+    client.remove_transfer_cap(&governance, &holder);
+    client.transfer(&holder, &other, &500);
+    assert_eq!(client.balance(&other), 660);
+    assert_eq!(client.get_transfer_headroom(&holder), None);
+}
+
+#[test]
+fn test_transfer_and_allowance_error_paths() {
+    let (env, client, admin) = setup_test_env();
+    let holder = Address::generate(&env);
+    let spender = Address::generate(&env);
+    client.mint(&admin, &holder, &100);
+    grant_burner(&client, &admin, &spender);
+
+    let error = |e: PlatformTokenError| Err(Ok(e.into()));
+    assert_eq!(client.try_transfer(&holder, &spender, &101), error(PlatformTokenError::InsufficientBalance));
+    assert_eq!(client.try_transfer(&holder, &spender, &-1), error(PlatformTokenError::InvalidAmount));
+    assert_eq!(client.try_burn(&spender, &1), error(PlatformTokenError::InsufficientBalance));
+
+    // Allowances cannot be spent twice or set to expire in the past
+    env.ledger().set_sequence_number(10);
+    assert_eq!(client.try_approve(&holder, &spender, &50, &9), error(PlatformTokenError::InvalidExpiration));
+    client.approve(&holder, &spender, &50, &100);
+    client.transfer_from(&spender, &holder, &spender, &50);
+    assert_eq!(
+        client.try_transfer_from(&spender, &holder, &spender, &1),
+        error(PlatformTokenError::InsufficientAllowance)
+    );
+    assert_eq!(client.try_burn_from(&spender, &holder, &1), error(PlatformTokenError::InsufficientAllowance));
+
+    // Caps are governance-only and must be positive
+    assert_eq!(client.try_set_governance(&holder, &holder), Err(Ok(PlatformTokenError::Unauthorized)));
+    assert_eq!(
+        client.try_set_transfer_cap(&admin, &holder, &TransferCap { limit: 0, period: DAY }),
+        Err(Ok(PlatformTokenError::InvalidTransferCap))
+    );
+    assert_eq!(client.try_remove_transfer_cap(&holder, &holder), Err(Ok(PlatformTokenError::Unauthorized)));
+    assert_eq!(client.balance(&holder), 50);
+}
@@ -0,0 +1,48 @@
+use soroban_sdk::{contracttype, Address, String};
+
+/// SEP-41 token metadata
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TokenMetadata {
+    pub decimals: u32,
+    pub name: String,
+    pub symbol: String,
+}
+
+/// An allowance and the ledger it lapses after
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AllowanceValue {
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+/// Most an address may transfer out within each rolling period
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TransferCap {
+    pub limit: i128,
+    pub period: u64, // seconds
+}
+
+/// How much of a capped address's allowance the current period has used
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CapUsage {
+    pub window_start: u64,
+    pub spent: i128,
+}
+
+/// Storage keys for the platform token contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Governance,
+    Metadata,
+    TotalSupply,
+    Balance(Address),
+    Allowance(Address, Address), // (from, spender)
+    TransferCap(Address),
+    CapUsage(Address),
+}
@@ -7,7 +7,7 @@ use soroban_sdk::{
 // Import String for event logging
 use soroban_sdk::String;
 use shared::access_control::AccessControl;
-use shared::roles::Permission;
+use shared::roles::{Permission, RoleLevel};
 
 // Storage keys
 const ADMIN_KEY: Symbol = symbol_short!("ADMIN");
@@ -40,11 +40,9 @@ impl Progress {
             return Err(Error::AlreadyInitialized);
         }
 
-        // Require authorization from the admin
-        admin.require_auth();
-
         // Initialize shared RBAC, grant SuperAdmin to admin for centralized control
-        let _ = AccessControl::initialize(&env, &admin);
+        // (this also requires the admin's authorization)
+        AccessControl::initialize(&env, &admin).map_err(|_| Error::AlreadyInitialized)?;
 
         // Store admin address
         env.storage().instance().set(&ADMIN_KEY, &admin);
@@ -52,6 +50,18 @@ impl Progress {
         Ok(())
     }
 
+    // Grant a role in this contract (requires GrantRole)
+    pub fn grant_role(env: Env, admin: Address, user: Address, level: RoleLevel) -> Result<(), Error> {
+        admin.require_auth();
+        AccessControl::grant_role(&env, &admin, &user, level).map_err(|_| Error::Unauthorized)
+    }
+
+    // Revoke a user's role in this contract (requires RevokeRole)
+    pub fn revoke_role(env: Env, admin: Address, user: Address) -> Result<(), Error> {
+        admin.require_auth();
+        AccessControl::revoke_role(&env, &admin, &user).map_err(|_| Error::Unauthorized)
+    }
+
     // Add a new course
     pub fn add_course(env: Env, course_id: Symbol, total_modules: u32) -> Result<(), Error> {
         // Get admin and check authorization
@@ -107,70 +117,38 @@ impl Progress {
             return Err(Error::Unauthorized);
         }
 
-        // Check if course exists and get total modules
-        let total_modules = Self::get_course_modules(env.clone(), course_id.clone())?;
-
-        // Create a storage key for user progress
-        let key = (USER_PROGRESS, user.clone());
-
-        // Get or create user progress map
-        let mut user_progress: Map<Symbol, Vec<bool>> = if env.storage().instance().has(&key) {
-            env.storage().instance().get(&key).unwrap()
-        } else {
-            Map::new(&env)
-        };
-
-        // Get or create course progress vector
-        let mut course_progress = if user_progress.contains_key(course_id.clone()) {
-            user_progress.get(course_id.clone()).unwrap()
-        } else {
-            // Initialize with false values for each module (1-indexed, so we need total_modules + 1)
-            let mut progress = Vec::new(&env);
-            for _ in 0..=total_modules {
-                progress.push_back(false);
-            }
-            progress
-        };
+        Self::record_progress(&env, &user, course_id, module, completed)
+    }
 
-        // Validate module number
-        if module == 0 || module > total_modules {
-            // Log the invalid progress attempt
-            env.events().publish((symbol_short!("error"), "invalid_module"), 
-                String::from_str(&env, "invalid_module"));
-            return Err(Error::InvalidProgress);
-        }
+    // Record a module result for a student on their behalf. Attesters are
+    // instructors or automated graders holding UpdateProgress; students
+    // cannot attest their own work this way. Returns the new completion
+    // percentage.
+    pub fn attest_module(
+        env: Env,
+        attester: Address,
+        student: Address,
+        course_id: Symbol,
+        module: u32,
+    ) -> Result<u32, Error> {
+        // Require authorization from the attester
+        attester.require_auth();
 
-        // VALIDATION 1: Check if the module is already completed
-        let current_status = course_progress.get(module as u32).unwrap_or(false);
-        if current_status && completed {
-            // Log the attempt to modify a completed module
-            env.events().publish((symbol_short!("error"), "already_completed"), 
-                String::from_str(&env, "already_completed"));
-            return Err(Error::ModuleAlreadyCompleted);
+        // RBAC: attester must hold UpdateProgress
+        if AccessControl::require_permission(&env, &attester, &Permission::UpdateProgress).is_err() {
+            return Err(Error::Unauthorized);
         }
-
-        // VALIDATION 2: Ensure progress only increases (can't mark a completed module as incomplete)
-        if current_status && !completed {
-            // Log the non-increasing progress attempt
-            env.events().publish((symbol_short!("error"), "non_increasing"), 
-                String::from_str(&env, "non_increasing"));
-            return Err(Error::NonIncreasingProgress);
+        if attester == student {
+            return Err(Error::Unauthorized);
         }
-        
-        // Update the module progress (modules are 1-indexed in the API but 0-indexed in storage)
-        course_progress.set(module as u32, completed);
 
-        // Update the user progress map
-        user_progress.set(course_id, course_progress);
+        Self::record_progress(&env, &student, course_id.clone(), module, true)?;
 
-        // Store updated progress
-        env.storage().instance().set(&key, &user_progress);
+        // Log the attestation
+        env.events().publish((symbol_short!("info"), "module_attested"),
+            (attester, student.clone(), course_id.clone(), module));
 
-        // Log successful progress update
-        env.events().publish((symbol_short!("info"), "progress_update"), 
-            String::from_str(&env, "progress_update"));
-
-        Ok(())
+        Self::get_completion_percentage(env, student, course_id)
     }
 
     // Get user progress for a course
@@ -228,6 +206,92 @@ impl Progress {
         Ok(percentage as u32)
     }
 
+    // Whether a student has completed every module of a course. Certificate
+    // issuance should be gated on this.
+    pub fn is_course_complete(env: Env, user: Address, course_id: Symbol) -> bool {
+        Self::get_completion_percentage(env, user, course_id) == Ok(100)
+    }
+
+    // Record a module result, enforcing that progress only moves forward
+    fn record_progress(
+        env: &Env,
+        user: &Address,
+        course_id: Symbol,
+        module: u32,
+        completed: bool,
+    ) -> Result<(), Error> {
+        // Check if course exists and get total modules
+        let total_modules = Self::get_course_modules(env.clone(), course_id.clone())?;
+
+        // Create a storage key for user progress
+        let key = (USER_PROGRESS, user.clone());
+
+        // Get or create user progress map
+        let mut user_progress: Map<Symbol, Vec<bool>> = if env.storage().instance().has(&key) {
+            env.storage().instance().get(&key).unwrap()
+        } else {
+            Map::new(env)
+        };
+
+        // Get or create course progress vector
+        let mut course_progress = if user_progress.contains_key(course_id.clone()) {
+            user_progress.get(course_id.clone()).unwrap()
+        } else {
+            // Initialize with false values for each module (1-indexed, so we need total_modules + 1)
+            let mut progress = Vec::new(env);
+            for _ in 0..=total_modules {
+                progress.push_back(false);
+            }
+            progress
+        };
+
+        // Validate module number
+        if module == 0 || module > total_modules {
+            // Log the invalid progress attempt
+            env.events().publish((symbol_short!("error"), "invalid_module"), 
+                String::from_str(env, "invalid_module"));
+            return Err(Error::InvalidProgress);
+        }
+
+        // VALIDATION 1: Check if the module is already completed
+        let current_status = course_progress.get(module as u32).unwrap_or(false);
+        if current_status && completed {
+            // Log the attempt to modify a completed module
+            env.events().publish((symbol_short!("error"), "already_completed"), 
+                String::from_str(env, "already_completed"));
+            return Err(Error::ModuleAlreadyCompleted);
+        }
+
+        // VALIDATION 2: Ensure progress only increases (can't mark a completed module as incomplete)
+        if current_status && !completed {
+            // Log the non-increasing progress attempt
+            env.events().publish((symbol_short!("error"), "non_increasing"), 
+                String::from_str(env, "non_increasing"));
+            return Err(Error::NonIncreasingProgress);
+        }
+        
+        // Update the module progress (modules are 1-indexed in the API but 0-indexed in storage)
+        course_progress.set(module as u32, completed);
+
+        // Update the user progress map
+        user_progress.set(course_id.clone(), course_progress);
+
+        // Store updated progress
+        env.storage().instance().set(&key, &user_progress);
+
+        // Log successful progress update
+        env.events().publish((symbol_short!("info"), "progress_update"), 
+            String::from_str(env, "progress_update"));
+
+        // Log course completion once the last module is done
+        if completed && Self::is_course_complete(env.clone(), user.clone(), course_id.clone()) {
+            env.events().publish((symbol_short!("info"), "course_completed"),
+                (user.clone(), course_id));
+        }
+
+        Ok(())
+    }
+
     // Helper function to get admin
     fn get_admin(env: &Env) -> Result<Address, Error> {
         if !env.storage().instance().has(&ADMIN_KEY) {
@@ -717,4 +781,29 @@ mod test {
         let result = client.try_update_progress(&user, &course_id, &1, &false);
         assert_eq!(result, Err(Ok(Error::NonIncreasingProgress)));
     }
+
+    #[test]
+    fn test_attested_modules_complete_course() {
+        let (env, client, admin, student, course_id) = setup_test_env();
+        let grader = Address::generate(&env);
+
+        // Automated graders hold a role with UpdateProgress
+        client.grant_role(&admin, &grader, &shared::roles::RoleLevel::Moderator);
+
+        // Students cannot attest, for themselves or anyone else
+        let result = client.try_attest_module(&student, &student, &course_id, &1);
+        assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+        for module in 1..=4 {
+            assert_eq!(client.attest_module(&grader, &student, &course_id, &module), module * 20);
+        }
+        assert!(!client.is_course_complete(&student, &course_id));
+
+        let result = client.try_attest_module(&grader, &student, &course_id, &4);
+        assert_eq!(result, Err(Ok(Error::ModuleAlreadyCompleted)));
+
+        assert_eq!(client.attest_module(&grader, &student, &course_id, &5), 100);
+        assert!(client.is_course_complete(&student, &course_id));
+        assert!(!client.is_course_complete(&grader, &course_id));
+    }
 }
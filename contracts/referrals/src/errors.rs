@@ -0,0 +1,35 @@
+use soroban_sdk::contracterror;
+
+/// Referrals contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ReferralsError {
+    // Initialization errors
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+
+    // Authorization errors
+    Unauthorized = 3,
+
+    // Code errors
+    CodeTaken = 4,
+    CodeAlreadyRegistered = 5,
+    CodeNotFound = 6,
+
+    // Commission errors
+    CourseNotFound = 7,
+    NoCourseFee = 8,
+    InvalidCommission = 9,
+    InvalidAmount = 10,
+    InsufficientBudget = 11,
+
+    // Referral errors
+    SelfReferral = 12,
+    NotEnrolled = 13,
+    AlreadyReferred = 14,
+    ReferralNotFound = 15,
+    AlreadySettled = 16,
+    NotSettleable = 17,
+    NothingToClaim = 18,
+}
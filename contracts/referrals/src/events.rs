@@ -0,0 +1,58 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+/// Referrals contract events
+pub struct ReferralsEvents;
+
+impl ReferralsEvents {
+    fn topic(env: &Env, name: &str) -> (Symbol, Symbol) {
+        (Symbol::new(env, "referrals"), Symbol::new(env, name))
+    }
+
+    /// Emit event when a user registers a referral code
+    pub fn emit_code_registered(env: &Env, user: &Address, code: &Symbol) {
+        env.events().publish(
+            Self::topic(env, "code_registered"),
+            (user.clone(), code.clone()),
+        );
+    }
+
+    /// Emit event when a course's commission rate changes
+    pub fn emit_commission_updated(env: &Env, course_id: &Symbol, commission_bps: u32) {
+        env.events().publish(
+            Self::topic(env, "commission_updated"),
+            (course_id.clone(), commission_bps),
+        );
+    }
+
+    /// Emit event when a course's commission budget is topped up or reclaimed
+    pub fn emit_budget_updated(env: &Env, course_id: &Symbol, budget: i128) {
+        env.events().publish(
+            Self::topic(env, "budget_updated"),
+            (course_id.clone(), budget),
+        );
+    }
+
+    /// Emit event when an enrollment is attributed to a referrer
+    pub fn emit_referred(env: &Env, student: &Address, course_id: &Symbol, referrer: &Address) {
+        env.events().publish(
+            Self::topic(env, "referred"),
+            (student.clone(), course_id.clone(), referrer.clone()),
+        );
+    }
+
+    /// Emit event when a referral's commission is committed to the referrer
+    pub fn emit_settled(env: &Env, student: &Address, course_id: &Symbol, referrer: &Address, commission: i128) {
+        env.events().publish(
+            Self::topic(env, "settled"),
+            (student.clone(), course_id.clone(), referrer.clone(), commission),
+        );
+    }
+
+    /// Emit event when a referrer claims their commission
+    pub fn emit_claimed(env: &Env, referrer: &Address, token: &Address, amount: i128) {
+        env.events().publish(
+            Self::topic(env, "claimed"),
+            (referrer.clone(), token.clone(), amount),
+        );
+    }
+}
@@ -0,0 +1,299 @@
+#![no_std]
+
+mod types;
+mod errors;
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod tests;
+
+use soroban_sdk::{contract, contractimpl, token, vec, Address, Env, IntoVal, Symbol};
+
+use shared::{
+    access_control::AccessControl,
+    cross_contract::CrossContract,
+    fees::{Fees, BASIS_POINTS},
+    reentrancy_guard::ReentrancyLock,
+    time::Clock,
+};
+
+pub use types::{
+    CourseCommission, EnrollmentCourse, EnrollmentFee, EnrollmentRecord, EnrollmentStatus, Referral,
+};
+pub use errors::ReferralsError;
+use events::ReferralsEvents;
+use storage::ReferralsStorage;
+
+#[contract]
+pub struct Referrals;
+
+#[contractimpl]
+impl Referrals {
+    /// Initialize the contract with the Enrollment contract referrals are
+    /// attributed against
+    pub fn initialize(env: Env, admin: Address, enrollment: Address) -> Result<(), ReferralsError> {
+        if ReferralsStorage::get_admin(&env).is_some() {
+            return Err(ReferralsError::AlreadyInitialized);
+        }
+
+        AccessControl::initialize(&env, &admin).map_err(|_| ReferralsError::AlreadyInitialized)?;
+        ReferralsStorage::set_admin(&env, &admin);
+        ReferralsStorage::set_enrollment(&env, &enrollment);
+
+        Ok(())
+    }
+
+    /// Claim a referral code. Each user has one code and codes are unique.
+    pub fn register_code(env: Env, user: Address, code: Symbol) -> Result<(), ReferralsError> {
+        user.require_auth();
+        if ReferralsStorage::get_user_code(&env, &user).is_some() {
+            return Err(ReferralsError::CodeAlreadyRegistered);
+        }
+        if ReferralsStorage::get_code_owner(&env, &code).is_some() {
+            return Err(ReferralsError::CodeTaken);
+        }
+
+        ReferralsStorage::set_code(&env, &user, &code);
+        ReferralsEvents::emit_code_registered(&env, &user, &code);
+
+        Ok(())
+    }
+
+    /// Set the share of a course's enrollment fee paid to referrers (course
+    /// instructor or admin). Referrals already recorded keep their rate.
+    pub fn set_commission(env: Env, caller: Address, course_id: Symbol, commission_bps: u32) -> Result<(), ReferralsError> {
+        let course = Self::require_course_owner(&env, &caller, &course_id)?;
+        if commission_bps > BASIS_POINTS {
+            return Err(ReferralsError::InvalidCommission);
+        }
+
+        let commission = match ReferralsStorage::get_commission(&env, &course_id) {
+            Some(mut commission) => {
+                commission.commission_bps = commission_bps;
+                commission
+            }
+            None => {
+                let enrollment = ReferralsStorage::get_enrollment(&env).ok_or(ReferralsError::NotInitialized)?;
+                let fee: Option<EnrollmentFee> = CrossContract::invoke(
+                    &env,
+                    &enrollment,
+                    &Symbol::new(&env, "get_course_fee"),
+                    vec![&env, course.course_id.into_val(&env)],
+                )
+                .map_err(|_| ReferralsError::NoCourseFee)?;
+                CourseCommission {
+                    course_id: course_id.clone(),
+                    token: fee.ok_or(ReferralsError::NoCourseFee)?.token,
+                    commission_bps,
+                    budget: 0,
+                    paid: 0,
+                }
+            }
+        };
+        ReferralsStorage::set_commission(&env, &commission);
+        ReferralsEvents::emit_commission_updated(&env, &course_id, commission_bps);
+
+        Ok(())
+    }
+
+    /// Add to the budget commissions for a course are paid from. Anyone
+    /// may fund it, usually the instructor.
+    pub fn fund_commission(env: Env, funder: Address, course_id: Symbol, amount: i128) -> Result<(), ReferralsError> {
+        funder.require_auth();
+        if amount <= 0 {
+            return Err(ReferralsError::InvalidAmount);
+        }
+        let mut commission = ReferralsStorage::get_commission(&env, &course_id).ok_or(ReferralsError::CourseNotFound)?;
+
+        token::Client::new(&env, &commission.token).transfer(&funder, &env.current_contract_address(), &amount);
+        commission.budget += amount;
+        ReferralsStorage::set_commission(&env, &commission);
+        ReferralsEvents::emit_budget_updated(&env, &course_id, commission.budget);
+
+        Ok(())
+    }
+
+    /// Withdraw unspent commission budget to the course instructor (course
+    /// instructor or admin). Commission already settled stays claimable.
+    pub fn reclaim_budget(env: Env, caller: Address, course_id: Symbol, amount: i128) -> Result<(), ReferralsError> {
+        let _guard = ReentrancyLock::new(&env);
+        let course = Self::require_course_owner(&env, &caller, &course_id)?;
+        let mut commission = ReferralsStorage::get_commission(&env, &course_id).ok_or(ReferralsError::CourseNotFound)?;
+        if amount <= 0 {
+            return Err(ReferralsError::InvalidAmount);
+        }
+        if amount > commission.budget {
+            return Err(ReferralsError::InsufficientBudget);
+        }
+
+        commission.budget -= amount;
+        ReferralsStorage::set_commission(&env, &commission);
+        token::Client::new(&env, &commission.token).transfer(&env.current_contract_address(), &course.instructor, &amount);
+        ReferralsEvents::emit_budget_updated(&env, &course_id, commission.budget);
+
+        Ok(())
+    }
+
+    /// Attribute the student's enrollment in a course to the owner of a
+    /// referral code. Each enrollment has at most one referrer, and users
+    /// cannot refer themselves.
+    pub fn record_referral(env: Env, student: Address, course_id: Symbol, code: Symbol) -> Result<(), ReferralsError> {
+        student.require_auth();
+
+        let referrer = ReferralsStorage::get_code_owner(&env, &code).ok_or(ReferralsError::CodeNotFound)?;
+        if referrer == student {
+            return Err(ReferralsError::SelfReferral);
+        }
+        if ReferralsStorage::get_referral(&env, &student, &course_id).is_some() {
+            return Err(ReferralsError::AlreadyReferred);
+        }
+        let enrollment = Self::load_enrollment(&env, &student, &course_id)?;
+        if enrollment.status == EnrollmentStatus::Withdrawn {
+            return Err(ReferralsError::NotEnrolled);
+        }
+
+        let commission_bps = ReferralsStorage::get_commission(&env, &course_id)
+            .map(|commission| commission.commission_bps)
+            .unwrap_or(0);
+        ReferralsStorage::set_referral(
+            &env,
+            &Referral {
+                student: student.clone(),
+                course_id: course_id.clone(),
+                referrer: referrer.clone(),
+                code,
+                commission_bps,
+                commission: 0,
+                recorded_at: Clock::now(&env),
+                settled: false,
+            },
+        );
+        let count = ReferralsStorage::get_referral_count(&env, &referrer);
+        ReferralsStorage::set_referral_count(&env, &referrer, count + 1);
+        ReferralsEvents::emit_referred(&env, &student, &course_id, &referrer);
+
+        Ok(())
+    }
+
+    /// Commit a referral's commission to the referrer once the student's fee
+    /// has left Enrollment escrow, so refunded enrollments never pay out.
+    /// Commission is capped by the course's remaining budget. Callable by
+    /// anyone; returns the commission accrued.
+    pub fn settle(env: Env, student: Address, course_id: Symbol) -> Result<i128, ReferralsError> {
+        let mut referral = ReferralsStorage::get_referral(&env, &student, &course_id)
+            .ok_or(ReferralsError::ReferralNotFound)?;
+        if referral.settled {
+            return Err(ReferralsError::AlreadySettled);
+        }
+        let enrollment = Self::load_enrollment(&env, &student, &course_id)?;
+        let seated = matches!(enrollment.status, EnrollmentStatus::Enrolled | EnrollmentStatus::Completed);
+        if !seated || enrollment.escrowed > 0 {
+            return Err(ReferralsError::NotSettleable);
+        }
+
+        let mut amount = 0;
+        if let Some(mut commission) = ReferralsStorage::get_commission(&env, &course_id) {
+            amount = Fees::portion(enrollment.amount_paid, referral.commission_bps).min(commission.budget);
+            if amount > 0 {
+                commission.budget -= amount;
+                commission.paid += amount;
+                ReferralsStorage::set_commission(&env, &commission);
+                let accrued = ReferralsStorage::get_accrued(&env, &referral.referrer, &commission.token);
+                ReferralsStorage::set_accrued(&env, &referral.referrer, &commission.token, accrued + amount);
+            }
+        }
+
+        referral.commission = amount;
+        referral.settled = true;
+        ReferralsStorage::set_referral(&env, &referral);
+        ReferralsEvents::emit_settled(&env, &student, &course_id, &referral.referrer, amount);
+
+        Ok(amount)
+    }
+
+    /// Pay out a referrer's settled commission in one token
+    pub fn claim(env: Env, referrer: Address, token: Address) -> Result<i128, ReferralsError> {
+        let _guard = ReentrancyLock::new(&env);
+        referrer.require_auth();
+
+        let amount = ReferralsStorage::get_accrued(&env, &referrer, &token);
+        if amount <= 0 {
+            return Err(ReferralsError::NothingToClaim);
+        }
+
+        ReferralsStorage::set_accrued(&env, &referrer, &token, 0);
+        token::Client::new(&env, &token).transfer(&env.current_contract_address(), &referrer, &amount);
+        ReferralsEvents::emit_claimed(&env, &referrer, &token, amount);
+
+        Ok(amount)
+    }
+
+    /// Get a user's referral code
+    pub fn get_code(env: Env, user: Address) -> Option<Symbol> {
+        ReferralsStorage::get_user_code(&env, &user)
+    }
+
+    /// Get the owner of a referral code
+    pub fn get_code_owner(env: Env, code: Symbol) -> Option<Address> {
+        ReferralsStorage::get_code_owner(&env, &code)
+    }
+
+    /// Get a course's commission terms and budget
+    pub fn get_commission(env: Env, course_id: Symbol) -> Option<CourseCommission> {
+        ReferralsStorage::get_commission(&env, &course_id)
+    }
+
+    /// Get the referral behind a student's enrollment
+    pub fn get_referral(env: Env, student: Address, course_id: Symbol) -> Option<Referral> {
+        ReferralsStorage::get_referral(&env, &student, &course_id)
+    }
+
+    /// Get the number of referrals recorded for a referrer
+    pub fn get_referral_count(env: Env, referrer: Address) -> u32 {
+        ReferralsStorage::get_referral_count(&env, &referrer)
+    }
+
+    /// Get a referrer's settled, unclaimed commission in a token
+    pub fn get_claimable(env: Env, referrer: Address, token: Address) -> i128 {
+        ReferralsStorage::get_accrued(&env, &referrer, &token)
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        ReferralsStorage::get_admin(&env)
+    }
+}
+
+impl Referrals {
+    /// Require the caller to be the course's instructor or an admin
+    fn require_course_owner(env: &Env, caller: &Address, course_id: &Symbol) -> Result<EnrollmentCourse, ReferralsError> {
+        caller.require_auth();
+        let enrollment = ReferralsStorage::get_enrollment(env).ok_or(ReferralsError::NotInitialized)?;
+        let course: Option<EnrollmentCourse> = CrossContract::invoke(
+            env,
+            &enrollment,
+            &Symbol::new(env, "get_course"),
+            vec![env, course_id.into_val(env)],
+        )
+        .map_err(|_| ReferralsError::CourseNotFound)?;
+        let course = course.ok_or(ReferralsError::CourseNotFound)?;
+
+        if course.instructor != *caller && !AccessControl::is_admin(env, caller) {
+            return Err(ReferralsError::Unauthorized);
+        }
+        Ok(course)
+    }
+
+    fn load_enrollment(env: &Env, student: &Address, course_id: &Symbol) -> Result<EnrollmentRecord, ReferralsError> {
+        let enrollment = ReferralsStorage::get_enrollment(env).ok_or(ReferralsError::NotInitialized)?;
+        let record: Option<EnrollmentRecord> = CrossContract::invoke(
+            env,
+            &enrollment,
+            &Symbol::new(env, "get_enrollment"),
+            vec![env, student.into_val(env), course_id.into_val(env)],
+        )
+        .map_err(|_| ReferralsError::NotEnrolled)?;
+        record.ok_or(ReferralsError::NotEnrolled)
+    }
+}
@@ -0,0 +1,100 @@
+use soroban_sdk::{Address, Env, Symbol};
+use crate::types::{CourseCommission, DataKey, Referral};
+
+/// Storage utilities for the referrals contract
+pub struct ReferralsStorage;
+
+impl ReferralsStorage {
+    /// Get the contract admin
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Set the contract admin
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Get the Enrollment contract referrals are checked against
+    pub fn get_enrollment(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Enrollment)
+    }
+
+    /// Set the Enrollment contract referrals are checked against
+    pub fn set_enrollment(env: &Env, enrollment: &Address) {
+        env.storage().instance().set(&DataKey::Enrollment, enrollment);
+    }
+
+    /// Get the owner of a referral code
+    pub fn get_code_owner(env: &Env, code: &Symbol) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::CodeOwner(code.clone()))
+    }
+
+    /// Get a user's referral code
+    pub fn get_user_code(env: &Env, user: &Address) -> Option<Symbol> {
+        env.storage().persistent().get(&DataKey::UserCode(user.clone()))
+    }
+
+    /// Register a referral code to a user
+    pub fn set_code(env: &Env, user: &Address, code: &Symbol) {
+        env.storage().persistent().set(&DataKey::CodeOwner(code.clone()), user);
+        env.storage().persistent().set(&DataKey::UserCode(user.clone()), code);
+    }
+
+    /// Get a course's commission terms
+    pub fn get_commission(env: &Env, course_id: &Symbol) -> Option<CourseCommission> {
+        env.storage().persistent().get(&DataKey::Commission(course_id.clone()))
+    }
+
+    /// Store a course's commission terms
+    pub fn set_commission(env: &Env, commission: &CourseCommission) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Commission(commission.course_id.clone()), commission);
+    }
+
+    /// Get the referral behind a student's enrollment
+    pub fn get_referral(env: &Env, student: &Address, course_id: &Symbol) -> Option<Referral> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Referral(student.clone(), course_id.clone()))
+    }
+
+    /// Store a referral
+    pub fn set_referral(env: &Env, referral: &Referral) {
+        env.storage().persistent().set(
+            &DataKey::Referral(referral.student.clone(), referral.course_id.clone()),
+            referral,
+        );
+    }
+
+    /// Get the number of referrals recorded for a referrer
+    pub fn get_referral_count(env: &Env, referrer: &Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReferralCount(referrer.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Set the number of referrals recorded for a referrer
+    pub fn set_referral_count(env: &Env, referrer: &Address, count: u32) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReferralCount(referrer.clone()), &count);
+    }
+
+    /// Get a referrer's unclaimed commission in a token
+    pub fn get_accrued(env: &Env, referrer: &Address, token: &Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Accrued(referrer.clone(), token.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Set a referrer's unclaimed commission in a token
+    pub fn set_accrued(env: &Env, referrer: &Address, token: &Address, amount: i128) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Accrued(referrer.clone(), token.clone()), &amount);
+    }
+}
@@ -0,0 +1,214 @@
+use soroban_sdk::{
+    symbol_short, testutils::{Address as _, Ledger}, token, vec, Address, Env, Symbol,
+};
+use shared::{roles::RoleLevel, time::DAY};
+
+use enrollment::{CourseFee, EnrollmentContract, EnrollmentContractClient, RefundTier};
+
+use crate::{Referrals, ReferralsClient, ReferralsError};
+
+const FEE: i128 = 1_000;
+
+fn setup_test_env() -> (
+    Env,
+    ReferralsClient<'static>,
+    EnrollmentContractClient<'static>,
+    token::StellarAssetClient<'static>,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let instructor = Address::generate(&env);
+    let enrollment_id = env.register(EnrollmentContract, ());
+    let enrollment = EnrollmentContractClient::new(&env, &enrollment_id);
+    enrollment.initialize(&admin);
+    enrollment.grant_role(&admin, &instructor, &RoleLevel::Instructor);
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    let fee = CourseFee { token: asset.address(), amount: FEE };
+    enrollment.register_course(&instructor, &course(), &100, &Some(fee));
+
+    let contract_id = env.register(Referrals, ());
+    let client = ReferralsClient::new(&env, &contract_id);
+    client.initialize(&admin, &enrollment_id);
+
+    let referrer = Address::generate(&env);
+    client.register_code(&referrer, &code());
+
+    let token = token::StellarAssetClient::new(&env, &asset.address());
+    (env, client, enrollment, token, instructor, referrer)
+}
+
+fn course() -> Symbol {
+    symbol_short!("RUST101")
+}
+
+fn code() -> Symbol {
+    symbol_short!("ALICE")
+}
+
+fn enroll(
+    env: &Env,
+    enrollment: &EnrollmentContractClient,
+    token: &token::StellarAssetClient,
+) -> Address {
+    let student = Address::generate(env);
+    token.mint(&student, &FEE);
+    enrollment.enroll(&student, &course(), &FEE);
+    student
+}
+
+fn fund(
+    client: &ReferralsClient,
+    token: &token::StellarAssetClient,
+    instructor: &Address,
+    commission_bps: u32,
+    budget: i128,
+) {
+    client.set_commission(instructor, &course(), &commission_bps);
+    token.mint(instructor, &budget);
+    client.fund_commission(instructor, &course(), &budget);
+}
+
+#[test]
+fn test_codes_attribute_enrollments_once() {
+    let (env, client, enrollment, token, _instructor, referrer) = setup_test_env();
+    let other = Address::generate(&env);
+    assert_eq!(client.try_register_code(&other, &code()), Err(Ok(ReferralsError::CodeTaken)));
+    assert_eq!(
+        client.try_register_code(&referrer, &symbol_short!("ALICE2")),
+        Err(Ok(ReferralsError::CodeAlreadyRegistered))
+    );
+
+    assert_eq!(
+        client.try_record_referral(&other, &course(), &code()),
+        Err(Ok(ReferralsError::NotEnrolled))
+    );
+    token.mint(&referrer, &FEE);
+    enrollment.enroll(&referrer, &course(), &FEE);
+    assert_eq!(
+        client.try_record_referral(&referrer, &course(), &code()),
+        Err(Ok(ReferralsError::SelfReferral))
+    );
+
+    let student = enroll(&env, &enrollment, &token);
+    client.record_referral(&student, &course(), &code());
+    assert_eq!(client.get_referral(&student, &course()).unwrap().referrer, referrer);
+    assert_eq!(client.get_referral_count(&referrer), 1);
+    assert_eq!(
+        client.try_record_referral(&student, &course(), &code()),
+        Err(Ok(ReferralsError::AlreadyReferred))
+    );
+}
+
+#[test]
+fn test_commission_accrues_once_fee_leaves_escrow() {
+    let (env, client, enrollment, token, instructor, referrer) = setup_test_env();
+    let policy = vec![&env, RefundTier { within: 7 * DAY, refund_bps: 10_000 }];
+    enrollment.set_refund_policy(&instructor, &course(), &policy);
+    fund(&client, &token, &instructor, 1_000, 10_000);
+
+    let student = enroll(&env, &enrollment, &token);
+    client.record_referral(&student, &course(), &code());
+    assert_eq!(client.try_settle(&student, &course()), Err(Ok(ReferralsError::NotSettleable)));
+
+    // A refunded enrollment never pays commission
+    let refunded = enroll(&env, &enrollment, &token);
+    client.record_referral(&refunded, &course(), &code());
+    enrollment.withdraw(&refunded, &course());
+    assert_eq!(client.try_settle(&refunded, &course()), Err(Ok(ReferralsError::NotSettleable)));
+
+    env.ledger().set_timestamp(7 * DAY + 1);
+    enrollment.release_fees(&student, &course());
+    assert_eq!(client.settle(&student, &course()), 100);
+    assert_eq!(client.try_settle(&student, &course()), Err(Ok(ReferralsError::AlreadySettled)));
+
+    let token = token.address.clone();
+    assert_eq!(client.claim(&referrer, &token), 100);
+    assert_eq!(token::Client::new(&env, &token).balance(&referrer), 100);
+    assert_eq!(client.try_claim(&referrer, &token), Err(Ok(ReferralsError::NothingToClaim)));
+}
+
+#[test]
+fn test_commission_terms_and_budget() {
+    let (env, client, enrollment, token, instructor, referrer) = setup_test_env();
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_set_commission(&outsider, &course(), &500),
+        Err(Ok(ReferralsError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_commission(&instructor, &course(), &10_001),
+        Err(Ok(ReferralsError::InvalidCommission))
+    );
+    fund(&client, &token, &instructor, 2_000, 300);
+
+    // Referrals keep the rate in force when they were recorded
+    let early = enroll(&env, &enrollment, &token);
+    client.record_referral(&early, &course(), &code());
+    client.set_commission(&instructor, &course(), &500);
+    let late = enroll(&env, &enrollment, &token);
+    client.record_referral(&late, &course(), &code());
+
+    assert_eq!(client.settle(&early, &course()), 200);
+    assert_eq!(client.settle(&late, &course()), 50);
+
+    // Commission is capped by what is left in the budget
+    client.set_commission(&instructor, &course(), &10_000);
+    let capped = enroll(&env, &enrollment, &token);
+    client.record_referral(&capped, &course(), &code());
+    assert_eq!(client.settle(&capped, &course()), 50);
+    assert_eq!(client.get_commission(&course()).unwrap().budget, 0);
+    assert_eq!(client.get_claimable(&referrer, &token.address), 300);
+
+    assert_eq!(
+        client.try_reclaim_budget(&instructor, &course(), &1),
+        Err(Ok(ReferralsError::InsufficientBudget))
+    );
+}
+
+#[test]
+fn test_budget_and_settlement_error_paths() {
+    let (env, client, enrollment, token, instructor, referrer) = setup_test_env();
+    let student = enroll(&env, &enrollment, &token);
+
+    assert_eq!(
+        client.try_fund_commission(&instructor, &course(), &500),
+        Err(Ok(ReferralsError::CourseNotFound))
+    );
+    assert_eq!(
+        client.try_set_commission(&instructor, &symbol_short!("ART100"), &500),
+        Err(Ok(ReferralsError::CourseNotFound))
+    );
+    fund(&client, &token, &instructor, 1_000, 500);
+    assert_eq!(
+        client.try_fund_commission(&instructor, &course(), &0),
+        Err(Ok(ReferralsError::InvalidAmount))
+    );
+
+    // Only the course owner may take the budget back
+    assert_eq!(
+        client.try_reclaim_budget(&referrer, &course(), &100),
+        Err(Ok(ReferralsError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_reclaim_budget(&instructor, &course(), &0),
+        Err(Ok(ReferralsError::InvalidAmount))
+    );
+    client.reclaim_budget(&instructor, &course(), &200);
+    assert_eq!(client.get_commission(&course()).unwrap().budget, 300);
+    // The student's 1,000 fee plus the 200 reclaimed
+    assert_eq!(token::Client::new(&env, &token.address).balance(&instructor), 1_200);
+
+    assert_eq!(
+        client.try_record_referral(&student, &course(), &symbol_short!("NOBODY")),
+        Err(Ok(ReferralsError::CodeNotFound))
+    );
+    assert_eq!(client.try_settle(&student, &course()), Err(Ok(ReferralsError::ReferralNotFound)));
+    assert_eq!(
+        client.try_claim(&referrer, &token.address),
+        Err(Ok(ReferralsError::NothingToClaim))
+    );
+}
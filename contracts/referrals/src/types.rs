@@ -0,0 +1,86 @@
+use soroban_sdk::{contracttype, Address, Symbol};
+
+/// What a course pays referrers, funded from a budget the instructor tops up
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CourseCommission {
+    pub course_id: Symbol,
+    pub token: Address,      // the course's fee token
+    pub commission_bps: u32, // share of the enrollment fee
+    pub budget: i128,        // funded and not yet committed to referrers
+    pub paid: i128,          // lifetime commission committed to referrers
+}
+
+/// Who referred a student to a course, and what it earned
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Referral {
+    pub student: Address,
+    pub course_id: Symbol,
+    pub referrer: Address,
+    pub code: Symbol,
+    pub commission_bps: u32, // rate when the referral was recorded
+    pub commission: i128,    // set on settlement
+    pub recorded_at: u64,
+    pub settled: bool,
+}
+
+/// Mirror of the Enrollment contract's `Course`, decoded from
+/// cross-contract calls
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct EnrollmentCourse {
+    pub course_id: Symbol,
+    pub instructor: Address,
+    pub capacity: u32,
+    pub enrolled_count: u32,
+    pub refund_policy_version: u32,
+    pub created_at: u64,
+}
+
+/// Mirror of the Enrollment contract's `CourseFee`
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct EnrollmentFee {
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Mirror of the Enrollment contract's `EnrollmentStatus`
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum EnrollmentStatus {
+    Waitlisted,
+    Enrolled,
+    Completed,
+    Withdrawn,
+}
+
+/// Mirror of the Enrollment contract's `Enrollment`
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct EnrollmentRecord {
+    pub student: Address,
+    pub course_id: Symbol,
+    pub status: EnrollmentStatus,
+    pub amount_paid: i128,
+    pub escrowed: i128,
+    pub refund_policy_version: u32,
+    pub requested_at: u64,
+    pub enrolled_at: Option<u64>,
+    pub completed_at: Option<u64>,
+}
+
+/// Storage keys for the referrals contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Enrollment,
+    CodeOwner(Symbol),
+    UserCode(Address),
+    Commission(Symbol),
+    Referral(Address, Symbol), // (student, course)
+    ReferralCount(Address),    // referrals recorded per referrer
+    Accrued(Address, Address), // (referrer, token) unclaimed commission
+}
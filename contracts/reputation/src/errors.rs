@@ -0,0 +1,31 @@
+use soroban_sdk::contracterror;
+
+/// Reputation contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ReputationError {
+    // Initialization errors
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+
+    // Authorization errors
+    Unauthorized = 3,
+
+    // Configuration errors
+    InvalidConfig = 4,
+
+    // Rating errors
+    InvalidScore = 5,
+    NotEligible = 6,
+    CourseNotFound = 7,
+    AlreadyRated = 8,
+    SelfRating = 9,
+
+    // Review errors
+    NotRated = 10,
+    InvalidReview = 11,
+    ReviewNotFound = 12,
+    ReviewHidden = 13,
+    InvalidModerationState = 14,
+}
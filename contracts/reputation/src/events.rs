@@ -0,0 +1,42 @@
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+use crate::types::ModerationAction;
+
+/// Reputation contract events
+pub struct ReputationEvents;
+
+impl ReputationEvents {
+    fn topic(env: &Env, name: &str) -> (Symbol, Symbol) {
+        (Symbol::new(env, "reputation"), Symbol::new(env, name))
+    }
+
+    /// Emit event when a course is rated
+    pub fn emit_rated(env: &Env, course_id: &Symbol, rater: &Address, instructor: &Address, score: u32) {
+        env.events().publish(
+            Self::topic(env, "rated"),
+            (course_id.clone(), rater.clone(), instructor.clone(), score),
+        );
+    }
+
+    /// Emit event when a written review is attached or replaced
+    pub fn emit_review_attached(env: &Env, course_id: &Symbol, rater: &Address, content_hash: &BytesN<32>) {
+        env.events().publish(
+            Self::topic(env, "review_attached"),
+            (course_id.clone(), rater.clone(), content_hash.clone()),
+        );
+    }
+
+    /// Emit event when a moderator hides or restores a review
+    pub fn emit_moderated(
+        env: &Env,
+        entry_id: u64,
+        course_id: &Symbol,
+        rater: &Address,
+        action: ModerationAction,
+        reason: &Symbol,
+    ) {
+        env.events().publish(
+            Self::topic(env, "moderated"),
+            (entry_id, course_id.clone(), rater.clone(), action, reason.clone()),
+        );
+    }
+}
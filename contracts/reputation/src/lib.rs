@@ -0,0 +1,380 @@
+#![no_std]
+
+mod types;
+mod errors;
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod tests;
+
+use soroban_sdk::{contract, contractimpl, vec, Address, BytesN, Env, IntoVal, String, Symbol, Vec};
+
+use shared::{
+    access_control::AccessControl,
+    cross_contract::CrossContract,
+    roles::RoleLevel,
+    time::Clock,
+};
+
+pub use types::{
+    EnrollmentCourse, ModerationAction, ModerationEntry, Rating, RatingStats, ReputationConfig, Review,
+};
+pub use errors::ReputationError;
+use events::ReputationEvents;
+use storage::ReputationStorage;
+
+/// Scores are averages scaled by 100, so 4.25 stars reads as 425
+pub const SCORE_SCALE: u64 = 100;
+
+/// Upper bound on moderation log entries returned by one query
+const MAX_PAGE: u32 = 50;
+
+#[contract]
+pub struct Reputation;
+
+#[contractimpl]
+impl Reputation {
+    /// Initialize the contract with the Enrollment contract that vouches
+    /// for course completion
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        enrollment: Address,
+        config: ReputationConfig,
+    ) -> Result<(), ReputationError> {
+        if ReputationStorage::get_admin(&env).is_some() {
+            return Err(ReputationError::AlreadyInitialized);
+        }
+        Self::validate_config(&config)?;
+
+        AccessControl::initialize(&env, &admin).map_err(|_| ReputationError::AlreadyInitialized)?;
+        ReputationStorage::set_admin(&env, &admin);
+        ReputationStorage::set_enrollment(&env, &enrollment);
+        ReputationStorage::set_config(&env, &config);
+
+        Ok(())
+    }
+
+    /// Grant a role in this contract (requires GrantRole)
+    pub fn grant_role(env: Env, admin: Address, user: Address, level: RoleLevel) -> Result<(), ReputationError> {
+        admin.require_auth();
+        AccessControl::grant_role(&env, &admin, &user, level).map_err(|_| ReputationError::Unauthorized)
+    }
+
+    /// Revoke a user's role in this contract (requires RevokeRole)
+    pub fn revoke_role(env: Env, admin: Address, user: Address) -> Result<(), ReputationError> {
+        admin.require_auth();
+        AccessControl::revoke_role(&env, &admin, &user).map_err(|_| ReputationError::Unauthorized)
+    }
+
+    /// Change the ranking prior (admin only). The rating scale cannot
+    /// change once ratings exist.
+    pub fn set_config(env: Env, admin: Address, config: ReputationConfig) -> Result<(), ReputationError> {
+        admin.require_auth();
+        if !AccessControl::is_admin(&env, &admin) {
+            return Err(ReputationError::Unauthorized);
+        }
+        let current = ReputationStorage::get_config(&env).ok_or(ReputationError::NotInitialized)?;
+        if config.max_score != current.max_score && ReputationStorage::get_platform_stats(&env).count > 0 {
+            return Err(ReputationError::InvalidConfig);
+        }
+        Self::validate_config(&config)?;
+
+        ReputationStorage::set_config(&env, &config);
+
+        Ok(())
+    }
+
+    /// Rate a completed course, and through it its instructor. Only
+    /// students whose enrollment is marked completed may rate, once per
+    /// course.
+    pub fn rate(env: Env, rater: Address, course_id: Symbol, score: u32) -> Result<(), ReputationError> {
+        rater.require_auth();
+
+        let config = ReputationStorage::get_config(&env).ok_or(ReputationError::NotInitialized)?;
+        if score == 0 || score > config.max_score {
+            return Err(ReputationError::InvalidScore);
+        }
+        if ReputationStorage::get_rating(&env, &course_id, &rater).is_some() {
+            return Err(ReputationError::AlreadyRated);
+        }
+
+        let course = Self::verify_completion(&env, &rater, &course_id)?;
+        if course.instructor == rater {
+            return Err(ReputationError::SelfRating);
+        }
+
+        let rating = Rating {
+            rater: rater.clone(),
+            course_id: course_id.clone(),
+            instructor: course.instructor.clone(),
+            score,
+            rated_at: Clock::now(&env),
+        };
+        ReputationStorage::set_rating(&env, &rating);
+
+        let mut stats = ReputationStorage::get_course_stats(&env, &course_id);
+        Self::add_score(&mut stats, score);
+        ReputationStorage::set_course_stats(&env, &course_id, &stats);
+
+        let mut stats = ReputationStorage::get_instructor_stats(&env, &course.instructor);
+        Self::add_score(&mut stats, score);
+        ReputationStorage::set_instructor_stats(&env, &course.instructor, &stats);
+
+        let mut stats = ReputationStorage::get_platform_stats(&env);
+        Self::add_score(&mut stats, score);
+        ReputationStorage::set_platform_stats(&env, &stats);
+
+        ReputationEvents::emit_rated(&env, &course_id, &rater, &course.instructor, score);
+
+        Ok(())
+    }
+
+    /// Attach a written review to the rater's rating, or replace it. The
+    /// text is stored off-chain at `uri`; `content_hash` anchors it. A
+    /// hidden review cannot be replaced.
+    pub fn attach_review(
+        env: Env,
+        rater: Address,
+        course_id: Symbol,
+        content_hash: BytesN<32>,
+        uri: String,
+    ) -> Result<(), ReputationError> {
+        rater.require_auth();
+
+        if ReputationStorage::get_rating(&env, &course_id, &rater).is_none() {
+            return Err(ReputationError::NotRated);
+        }
+        if uri.is_empty() {
+            return Err(ReputationError::InvalidReview);
+        }
+        if let Some(existing) = ReputationStorage::get_review(&env, &course_id, &rater) {
+            if existing.hidden {
+                return Err(ReputationError::ReviewHidden);
+            }
+        }
+
+        let review = Review {
+            course_id: course_id.clone(),
+            rater: rater.clone(),
+            content_hash: content_hash.clone(),
+            uri,
+            submitted_at: Clock::now(&env),
+            hidden: false,
+        };
+        ReputationStorage::set_review(&env, &review);
+        ReputationEvents::emit_review_attached(&env, &course_id, &rater, &content_hash);
+
+        Ok(())
+    }
+
+    /// Hide a review that breaks content policy (moderators and admins).
+    /// The rating still counts. Returns the moderation log entry id.
+    pub fn hide_review(
+        env: Env,
+        moderator: Address,
+        course_id: Symbol,
+        rater: Address,
+        reason: Symbol,
+    ) -> Result<u64, ReputationError> {
+        Self::moderate(&env, &moderator, &course_id, &rater, ModerationAction::Hidden, &reason)
+    }
+
+    /// Make a hidden review visible again (moderators and admins)
+    pub fn restore_review(
+        env: Env,
+        moderator: Address,
+        course_id: Symbol,
+        rater: Address,
+        reason: Symbol,
+    ) -> Result<u64, ReputationError> {
+        Self::moderate(&env, &moderator, &course_id, &rater, ModerationAction::Restored, &reason)
+    }
+
+    /// Ranking score for a course: its average blended with
+    /// `prior_weight` phantom ratings at the platform mean, so a handful of
+    /// ratings cannot outrank a long track record. Scaled by 100.
+    pub fn get_course_score(env: Env, course_id: Symbol) -> u64 {
+        Self::weighted_score(&env, &ReputationStorage::get_course_stats(&env, &course_id))
+    }
+
+    /// Ranking scores for a batch of courses, in the order given
+    pub fn get_course_scores(env: Env, course_ids: Vec<Symbol>) -> Vec<u64> {
+        let mut scores = Vec::new(&env);
+        for course_id in course_ids.iter() {
+            scores.push_back(Self::weighted_score(&env, &ReputationStorage::get_course_stats(&env, &course_id)));
+        }
+        scores
+    }
+
+    /// Ranking score for an instructor across all their rated courses
+    pub fn get_instructor_score(env: Env, instructor: Address) -> u64 {
+        Self::weighted_score(&env, &ReputationStorage::get_instructor_stats(&env, &instructor))
+    }
+
+    /// Plain average of a course's ratings, scaled by 100; 0 when unrated
+    pub fn get_course_average(env: Env, course_id: Symbol) -> u64 {
+        Self::average(&ReputationStorage::get_course_stats(&env, &course_id))
+    }
+
+    /// Get a course's rating totals
+    pub fn get_course_stats(env: Env, course_id: Symbol) -> RatingStats {
+        ReputationStorage::get_course_stats(&env, &course_id)
+    }
+
+    /// Get an instructor's rating totals
+    pub fn get_instructor_stats(env: Env, instructor: Address) -> RatingStats {
+        ReputationStorage::get_instructor_stats(&env, &instructor)
+    }
+
+    /// Get a rater's rating of a course
+    pub fn get_rating(env: Env, course_id: Symbol, rater: Address) -> Option<Rating> {
+        ReputationStorage::get_rating(&env, &course_id, &rater)
+    }
+
+    /// Get a rater's written review of a course, hidden or not
+    pub fn get_review(env: Env, course_id: Symbol, rater: Address) -> Option<Review> {
+        ReputationStorage::get_review(&env, &course_id, &rater)
+    }
+
+    /// Get up to `limit` moderation log entries starting at id `start`,
+    /// oldest first
+    pub fn get_moderation_log(env: Env, start: u64, limit: u32) -> Vec<ModerationEntry> {
+        let mut log = Vec::new(&env);
+        let end = ReputationStorage::get_moderation_count(&env);
+        let mut id = start.max(1);
+        while id <= end && log.len() < limit.min(MAX_PAGE) {
+            if let Some(entry) = ReputationStorage::get_moderation(&env, id) {
+                log.push_back(entry);
+            }
+            id += 1;
+        }
+        log
+    }
+
+    /// Get the number of moderation log entries
+    pub fn get_moderation_count(env: Env) -> u64 {
+        ReputationStorage::get_moderation_count(&env)
+    }
+
+    /// Get the rating configuration
+    pub fn get_config(env: Env) -> Option<ReputationConfig> {
+        ReputationStorage::get_config(&env)
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        ReputationStorage::get_admin(&env)
+    }
+}
+
+impl Reputation {
+    fn validate_config(config: &ReputationConfig) -> Result<(), ReputationError> {
+        if config.max_score < 2 {
+            return Err(ReputationError::InvalidConfig);
+        }
+        Ok(())
+    }
+
+    /// Confirm with Enrollment that the rater completed the course, and
+    /// look up who taught it
+    fn verify_completion(env: &Env, rater: &Address, course_id: &Symbol) -> Result<EnrollmentCourse, ReputationError> {
+        let enrollment = ReputationStorage::get_enrollment(env).ok_or(ReputationError::NotInitialized)?;
+
+        let completed: bool = CrossContract::invoke(
+            env,
+            &enrollment,
+            &Symbol::new(env, "has_completed"),
+            vec![env, rater.into_val(env), course_id.into_val(env)],
+        )
+        .map_err(|_| ReputationError::NotEligible)?;
+        if !completed {
+            return Err(ReputationError::NotEligible);
+        }
+
+        let course: Option<EnrollmentCourse> = CrossContract::invoke(
+            env,
+            &enrollment,
+            &Symbol::new(env, "get_course"),
+            vec![env, course_id.into_val(env)],
+        )
+        .map_err(|_| ReputationError::CourseNotFound)?;
+        course.ok_or(ReputationError::CourseNotFound)
+    }
+
+    /// Hide or restore a review and log it. Moderation is for the
+    /// Moderator role specifically, so instructors cannot hide reviews of
+    /// their own courses.
+    fn moderate(
+        env: &Env,
+        moderator: &Address,
+        course_id: &Symbol,
+        rater: &Address,
+        action: ModerationAction,
+        reason: &Symbol,
+    ) -> Result<u64, ReputationError> {
+        moderator.require_auth();
+        let is_moderator = matches!(
+            AccessControl::get_role(env, moderator),
+            Some(role) if role.level == RoleLevel::Moderator && !role.is_expired(Clock::now(env))
+        );
+        if !is_moderator && !AccessControl::is_admin(env, moderator) {
+            return Err(ReputationError::Unauthorized);
+        }
+
+        let mut review = ReputationStorage::get_review(env, course_id, rater).ok_or(ReputationError::ReviewNotFound)?;
+        let hide = action == ModerationAction::Hidden;
+        if review.hidden == hide {
+            return Err(ReputationError::InvalidModerationState);
+        }
+        review.hidden = hide;
+        ReputationStorage::set_review(env, &review);
+
+        let entry = ModerationEntry {
+            id: ReputationStorage::get_moderation_count(env) + 1,
+            course_id: course_id.clone(),
+            rater: rater.clone(),
+            moderator: moderator.clone(),
+            action,
+            reason: reason.clone(),
+            at: Clock::now(env),
+        };
+        ReputationStorage::add_moderation(env, &entry);
+        ReputationEvents::emit_moderated(env, entry.id, course_id, rater, action, reason);
+
+        Ok(entry.id)
+    }
+
+    fn add_score(stats: &mut RatingStats, score: u32) {
+        stats.count += 1;
+        stats.total += score as u64;
+    }
+
+    fn average(stats: &RatingStats) -> u64 {
+        if stats.count == 0 {
+            return 0;
+        }
+        stats.total * SCORE_SCALE / stats.count as u64
+    }
+
+    /// Average blended with the platform mean; the scale's midpoint stands
+    /// in for the mean until anything has been rated
+    fn weighted_score(env: &Env, stats: &RatingStats) -> u64 {
+        let Some(config) = ReputationStorage::get_config(env) else {
+            return 0;
+        };
+        let platform = ReputationStorage::get_platform_stats(env);
+        let mean = if platform.count == 0 {
+            (config.max_score as u64 + 1) * SCORE_SCALE / 2
+        } else {
+            Self::average(&platform)
+        };
+
+        let prior = config.prior_weight as u64;
+        if prior + stats.count as u64 == 0 {
+            return 0;
+        }
+        (prior * mean + stats.total * SCORE_SCALE) / (prior + stats.count as u64)
+    }
+}
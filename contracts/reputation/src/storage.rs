@@ -0,0 +1,124 @@
+use soroban_sdk::{Address, Env, Symbol};
+use crate::types::{DataKey, ModerationEntry, Rating, RatingStats, ReputationConfig, Review};
+
+/// Storage utilities for the reputation contract
+pub struct ReputationStorage;
+
+impl ReputationStorage {
+    /// Get the contract admin
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Set the contract admin
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Get the Enrollment contract completions are checked against
+    pub fn get_enrollment(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Enrollment)
+    }
+
+    /// Set the Enrollment contract completions are checked against
+    pub fn set_enrollment(env: &Env, enrollment: &Address) {
+        env.storage().instance().set(&DataKey::Enrollment, enrollment);
+    }
+
+    /// Get the rating configuration
+    pub fn get_config(env: &Env) -> Option<ReputationConfig> {
+        env.storage().instance().get(&DataKey::Config)
+    }
+
+    /// Set the rating configuration
+    pub fn set_config(env: &Env, config: &ReputationConfig) {
+        env.storage().instance().set(&DataKey::Config, config);
+    }
+
+    /// Get the totals across every rating on the platform
+    pub fn get_platform_stats(env: &Env) -> RatingStats {
+        env.storage()
+            .instance()
+            .get(&DataKey::PlatformStats)
+            .unwrap_or_default()
+    }
+
+    /// Set the totals across every rating on the platform
+    pub fn set_platform_stats(env: &Env, stats: &RatingStats) {
+        env.storage().instance().set(&DataKey::PlatformStats, stats);
+    }
+
+    /// Get a course's rating totals
+    pub fn get_course_stats(env: &Env, course_id: &Symbol) -> RatingStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::CourseStats(course_id.clone()))
+            .unwrap_or_default()
+    }
+
+    /// Set a course's rating totals
+    pub fn set_course_stats(env: &Env, course_id: &Symbol, stats: &RatingStats) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::CourseStats(course_id.clone()), stats);
+    }
+
+    /// Get an instructor's rating totals across their courses
+    pub fn get_instructor_stats(env: &Env, instructor: &Address) -> RatingStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::InstructorStats(instructor.clone()))
+            .unwrap_or_default()
+    }
+
+    /// Set an instructor's rating totals across their courses
+    pub fn set_instructor_stats(env: &Env, instructor: &Address, stats: &RatingStats) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::InstructorStats(instructor.clone()), stats);
+    }
+
+    /// Get a rater's rating of a course
+    pub fn get_rating(env: &Env, course_id: &Symbol, rater: &Address) -> Option<Rating> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Rating(course_id.clone(), rater.clone()))
+    }
+
+    /// Store a rating
+    pub fn set_rating(env: &Env, rating: &Rating) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Rating(rating.course_id.clone(), rating.rater.clone()), rating);
+    }
+
+    /// Get a rater's written review of a course
+    pub fn get_review(env: &Env, course_id: &Symbol, rater: &Address) -> Option<Review> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Review(course_id.clone(), rater.clone()))
+    }
+
+    /// Store a written review
+    pub fn set_review(env: &Env, review: &Review) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Review(review.course_id.clone(), review.rater.clone()), review);
+    }
+
+    /// Number of moderation log entries
+    pub fn get_moderation_count(env: &Env) -> u64 {
+        env.storage().instance().get(&DataKey::ModerationCount).unwrap_or(0)
+    }
+
+    /// Append an entry to the moderation log
+    pub fn add_moderation(env: &Env, entry: &ModerationEntry) {
+        env.storage().instance().set(&DataKey::ModerationCount, &entry.id);
+        env.storage().persistent().set(&DataKey::Moderation(entry.id), entry);
+    }
+
+    /// Get a moderation log entry
+    pub fn get_moderation(env: &Env, entry_id: u64) -> Option<ModerationEntry> {
+        env.storage().persistent().get(&DataKey::Moderation(entry_id))
+    }
+}
@@ -0,0 +1,151 @@
+use soroban_sdk::{
+    symbol_short, testutils::Address as _, vec, Address, BytesN, Env, String, Symbol,
+};
+use shared::roles::RoleLevel;
+
+use enrollment::{EnrollmentContract, EnrollmentContractClient};
+
+use crate::{ModerationAction, Reputation, ReputationClient, ReputationConfig, ReputationError};
+
+fn setup_test_env(prior_weight: u32) -> (
+    Env,
+    ReputationClient<'static>,
+    EnrollmentContractClient<'static>,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let instructor = Address::generate(&env);
+    let enrollment_id = env.register(EnrollmentContract, ());
+    let enrollment = EnrollmentContractClient::new(&env, &enrollment_id);
+    enrollment.initialize(&admin);
+    enrollment.grant_role(&admin, &instructor, &RoleLevel::Instructor);
+    for course_id in [rust(), ml()] {
+        enrollment.register_course(&instructor, &course_id, &100, &None);
+    }
+
+    let contract_id = env.register(Reputation, ());
+    let client = ReputationClient::new(&env, &contract_id);
+    client.initialize(&admin, &enrollment_id, &ReputationConfig { max_score: 5, prior_weight });
+
+    (env, client, enrollment, admin, instructor)
+}
+
+fn rust() -> Symbol {
+    symbol_short!("RUST101")
+}
+
+fn ml() -> Symbol {
+    symbol_short!("ML301")
+}
+
+fn completer(
+    env: &Env,
+    enrollment: &EnrollmentContractClient,
+    instructor: &Address,
+    course_id: &Symbol,
+) -> Address {
+    let student = Address::generate(env);
+    enrollment.enroll(&student, course_id, &0);
+    enrollment.mark_completed(instructor, &student, course_id);
+    student
+}
+
+#[test]
+fn test_only_completers_rate_once() {
+    let (env, client, enrollment, _admin, instructor) = setup_test_env(0);
+    let enrolled = Address::generate(&env);
+    enrollment.enroll(&enrolled, &rust(), &0);
+    assert_eq!(
+        client.try_rate(&enrolled, &rust(), &5),
+        Err(Ok(ReputationError::NotEligible))
+    );
+
+    let student = completer(&env, &enrollment, &instructor, &rust());
+    assert_eq!(client.try_rate(&student, &rust(), &6), Err(Ok(ReputationError::InvalidScore)));
+    client.rate(&student, &rust(), &4);
+    assert_eq!(client.try_rate(&student, &rust(), &5), Err(Ok(ReputationError::AlreadyRated)));
+
+    let rating = client.get_rating(&rust(), &student).unwrap();
+    assert_eq!((rating.score, rating.instructor), (4, instructor.clone()));
+    assert_eq!(client.get_course_average(&rust()), 400);
+}
+
+#[test]
+fn test_course_and_instructor_aggregates() {
+    let (env, client, enrollment, _admin, instructor) = setup_test_env(0);
+    for score in [5, 4, 3] {
+        client.rate(&completer(&env, &enrollment, &instructor, &rust()), &rust(), &score);
+    }
+    client.rate(&completer(&env, &enrollment, &instructor, &ml()), &ml(), &2);
+
+    assert_eq!(client.get_course_stats(&rust()).count, 3);
+    assert_eq!(client.get_course_average(&rust()), 400);
+    assert_eq!(client.get_course_score(&ml()), 200);
+
+    // The instructor's average weighs each course by how often it was rated
+    let stats = client.get_instructor_stats(&instructor);
+    assert_eq!((stats.count, stats.total), (4, 14));
+    assert_eq!(client.get_instructor_score(&instructor), 350);
+}
+
+#[test]
+fn test_prior_pulls_sparse_ratings_toward_platform_mean() {
+    let (env, client, enrollment, _admin, instructor) = setup_test_env(4);
+    client.rate(&completer(&env, &enrollment, &instructor, &ml()), &ml(), &5);
+    for _ in 0..8 {
+        client.rate(&completer(&env, &enrollment, &instructor, &rust()), &rust(), &4);
+    }
+
+    // Blended with four ratings at the platform mean (37/9 = 4.11), a
+    // single 5 drops most of the way to it while eight 4s barely move
+    assert_eq!(client.get_course_average(&ml()), 500);
+    let scores = client.get_course_scores(&vec![&env, ml(), rust()]);
+    assert_eq!(scores, vec![&env, 428, 403]);
+    assert!(client.get_course_score(&symbol_short!("NEW")) > 0);
+}
+
+#[test]
+fn test_reviews_are_moderated_without_touching_ratings() {
+    let (env, client, enrollment, admin, instructor) = setup_test_env(0);
+    let student = completer(&env, &enrollment, &instructor, &rust());
+    let hash = BytesN::from_array(&env, &[7; 32]);
+    let uri = String::from_str(&env, "ipfs://review");
+
+    assert_eq!(
+        client.try_attach_review(&student, &rust(), &hash, &uri),
+        Err(Ok(ReputationError::NotRated))
+    );
+    client.rate(&student, &rust(), &1);
+    client.attach_review(&student, &rust(), &hash, &uri);
+
+    let moderator = Address::generate(&env);
+    client.grant_role(&admin, &moderator, &RoleLevel::Moderator);
+    client.grant_role(&admin, &instructor, &RoleLevel::Instructor);
+
+    // The instructor cannot bury a bad review of their own course
+    assert_eq!(
+        client.try_hide_review(&instructor, &rust(), &student, &symbol_short!("spam")),
+        Err(Ok(ReputationError::Unauthorized))
+    );
+    assert_eq!(client.hide_review(&moderator, &rust(), &student, &symbol_short!("abuse")), 1);
+    assert!(client.get_review(&rust(), &student).unwrap().hidden);
+    assert_eq!(client.get_course_average(&rust()), 100);
+    assert_eq!(
+        client.try_attach_review(&student, &rust(), &hash, &uri),
+        Err(Ok(ReputationError::ReviewHidden))
+    );
+    assert_eq!(
+        client.try_hide_review(&moderator, &rust(), &student, &symbol_short!("abuse")),
+        Err(Ok(ReputationError::InvalidModerationState))
+    );
+
+    client.restore_review(&admin, &rust(), &student, &symbol_short!("appeal"));
+    let log = client.get_moderation_log(&1, &10);
+    assert_eq!(log.len(), 2);
+    assert_eq!(log.get(0).unwrap().action, ModerationAction::Hidden);
+    assert_eq!(log.get(1).unwrap().moderator, admin);
+}
@@ -0,0 +1,90 @@
+use soroban_sdk::{contracttype, Address, BytesN, String, Symbol};
+
+/// Rating scale and ranking parameters
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ReputationConfig {
+    pub max_score: u32,    // ratings run from 1 to max_score
+    pub prior_weight: u32, // phantom ratings at the platform mean blended into scores
+}
+
+/// A completer's rating of a course
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Rating {
+    pub rater: Address,
+    pub course_id: Symbol,
+    pub instructor: Address,
+    pub score: u32,
+    pub rated_at: u64,
+}
+
+/// Written review anchored on-chain; the text itself lives at `uri`
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Review {
+    pub course_id: Symbol,
+    pub rater: Address,
+    pub content_hash: BytesN<32>, // sha256 of the review text
+    pub uri: String,
+    pub submitted_at: u64,
+    pub hidden: bool,
+}
+
+/// What a moderator did to a review
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum ModerationAction {
+    Hidden,
+    Restored,
+}
+
+/// Entry in the append-only moderation log
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ModerationEntry {
+    pub id: u64,
+    pub course_id: Symbol,
+    pub rater: Address,
+    pub moderator: Address,
+    pub action: ModerationAction,
+    pub reason: Symbol,
+    pub at: u64,
+}
+
+/// Running totals behind an average
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[contracttype]
+pub struct RatingStats {
+    pub count: u32,
+    pub total: u64,
+}
+
+/// Mirror of the Enrollment contract's `Course`, decoded from
+/// cross-contract calls
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct EnrollmentCourse {
+    pub course_id: Symbol,
+    pub instructor: Address,
+    pub capacity: u32,
+    pub enrolled_count: u32,
+    pub refund_policy_version: u32,
+    pub created_at: u64,
+}
+
+/// Storage keys for the reputation contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Enrollment,
+    Config,
+    PlatformStats,
+    CourseStats(Symbol),
+    InstructorStats(Address),
+    Rating(Symbol, Address),
+    Review(Symbol, Address),
+    ModerationCount,
+    Moderation(u64),
+}
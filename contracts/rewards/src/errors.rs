@@ -0,0 +1,28 @@
+use soroban_sdk::contracterror;
+
+/// Rewards contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RewardsError {
+    // Initialization errors
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+
+    // Authorization errors
+    Unauthorized = 3,
+
+    // Configuration errors
+    InvalidRule = 4,
+    InvalidCaps = 5,
+    RuleInactive = 6,
+
+    // Eligibility errors
+    AlreadyRewarded = 7,
+    NotEligible = 8,
+    CapExceeded = 9,
+
+    // Claim errors
+    NothingToClaim = 10,
+    PayoutFailed = 11,
+}
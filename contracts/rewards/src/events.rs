@@ -0,0 +1,38 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::types::RewardKind;
+
+/// Rewards contract events
+pub struct RewardsEvents;
+
+impl RewardsEvents {
+    fn topic(env: &Env, name: &str) -> (Symbol, Symbol) {
+        (Symbol::new(env, "rewards"), Symbol::new(env, name))
+    }
+
+    /// Emit event when an attester is allowed or removed
+    pub fn emit_attester_updated(env: &Env, attester: &Address, allowed: bool) {
+        env.events().publish(
+            Self::topic(env, "attester_updated"),
+            (attester.clone(), allowed),
+        );
+    }
+
+    /// Emit event when a reward rule changes
+    pub fn emit_rule_updated(env: &Env, kind: RewardKind, amount: i128, active: bool) {
+        env.events().publish(Self::topic(env, "rule_updated"), (kind, amount, active));
+    }
+
+    /// Emit event when a user is credited for an achievement
+    pub fn emit_credited(env: &Env, user: &Address, kind: RewardKind, reference: &Symbol, amount: i128) {
+        env.events().publish(
+            Self::topic(env, "credited"),
+            (user.clone(), kind, reference.clone(), amount),
+        );
+    }
+
+    /// Emit event when a user claims their pending rewards
+    pub fn emit_claimed(env: &Env, user: &Address, amount: i128) {
+        env.events().publish(Self::topic(env, "claimed"), (user.clone(), amount));
+    }
+}
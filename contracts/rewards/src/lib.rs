@@ -0,0 +1,252 @@
+#![no_std]
+
+mod types;
+mod errors;
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod tests;
+
+use soroban_sdk::{contract, contractimpl, vec, Address, Env, IntoVal, Symbol};
+
+use shared::{
+    access_control::AccessControl,
+    cross_contract::CrossContract,
+    reentrancy_guard::ReentrancyLock,
+    time::Clock,
+};
+
+pub use types::{RewardCaps, RewardKind, RewardRule, UserRewards};
+pub use errors::RewardsError;
+use events::RewardsEvents;
+use storage::RewardsStorage;
+
+#[contract]
+pub struct Rewards;
+
+#[contractimpl]
+impl Rewards {
+    /// Initialize the contract with the platform token rewards are minted in
+    /// and the Progress contract completions are checked against. This
+    /// contract must hold `MintTokens` on the token.
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        token: Address,
+        progress: Address,
+        caps: RewardCaps,
+    ) -> Result<(), RewardsError> {
+        if RewardsStorage::get_admin(&env).is_some() {
+            return Err(RewardsError::AlreadyInitialized);
+        }
+        Self::validate_caps(&caps)?;
+
+        AccessControl::initialize(&env, &admin).map_err(|_| RewardsError::AlreadyInitialized)?;
+        RewardsStorage::set_admin(&env, &admin);
+        RewardsStorage::set_token(&env, &token);
+        RewardsStorage::set_progress(&env, &progress);
+        RewardsStorage::set_caps(&env, &caps);
+
+        Ok(())
+    }
+
+    /// Allow a contract to attest achievements, or stop it (admin only)
+    pub fn set_attester(env: Env, admin: Address, attester: Address, allowed: bool) -> Result<(), RewardsError> {
+        Self::require_admin(&env, &admin)?;
+
+        RewardsStorage::set_attester(&env, &attester, allowed);
+        RewardsEvents::emit_attester_updated(&env, &attester, allowed);
+
+        Ok(())
+    }
+
+    /// Create or replace the rule for a kind of achievement
+    pub fn set_rule(env: Env, admin: Address, rule: RewardRule) -> Result<(), RewardsError> {
+        Self::require_admin(&env, &admin)?;
+        if rule.amount <= 0 {
+            return Err(RewardsError::InvalidRule);
+        }
+
+        RewardsStorage::set_rule(&env, &rule);
+        RewardsEvents::emit_rule_updated(&env, rule.kind, rule.amount, rule.active);
+        Ok(())
+    }
+
+    /// Replace the anti-sybil caps
+    pub fn set_caps(env: Env, admin: Address, caps: RewardCaps) -> Result<(), RewardsError> {
+        Self::require_admin(&env, &admin)?;
+        Self::validate_caps(&caps)?;
+        RewardsStorage::set_caps(&env, &caps);
+        Ok(())
+    }
+
+    /// Credit the course-completion reward once Progress reports the
+    /// course at 100%. Returns the amount credited.
+    pub fn claim_course_reward(env: Env, user: Address, course_id: Symbol) -> Result<i128, RewardsError> {
+        user.require_auth();
+
+        let progress = RewardsStorage::get_progress(&env).ok_or(RewardsError::NotInitialized)?;
+        let complete: bool = CrossContract::invoke(
+            &env,
+            &progress,
+            &Symbol::new(&env, "is_course_complete"),
+            vec![&env, user.into_val(&env), course_id.into_val(&env)],
+        )
+        .map_err(|_| RewardsError::NotEligible)?;
+        if !complete {
+            return Err(RewardsError::NotEligible);
+        }
+
+        Self::credit(&env, &user, RewardKind::CourseCompleted, &course_id)
+    }
+
+    /// Credit a reward attested by an allowlisted issuer, such as the
+    /// certificate contract on mint or the platform on a path or streak
+    /// milestone.
+    /// `reference` identifies the achievement (certificate, path or
+    /// milestone id) so each is rewarded once. Returns the amount credited.
+    pub fn record_achievement(
+        env: Env,
+        attester: Address,
+        user: Address,
+        kind: RewardKind,
+        reference: Symbol,
+    ) -> Result<i128, RewardsError> {
+        attester.require_auth();
+        if !RewardsStorage::is_attester(&env, &attester) {
+            return Err(RewardsError::Unauthorized);
+        }
+        if kind == RewardKind::CourseCompleted {
+            // Course completions are verified against Progress instead
+            return Err(RewardsError::Unauthorized);
+        }
+
+        Self::credit(&env, &user, kind, &reference)
+    }
+
+    /// Mint everything a user has earned but not yet claimed
+    pub fn claim(env: Env, user: Address) -> Result<i128, RewardsError> {
+        let _guard = ReentrancyLock::new(&env);
+        user.require_auth();
+
+        let mut rewards = RewardsStorage::get_user_rewards(&env, &user).ok_or(RewardsError::NothingToClaim)?;
+        let amount = rewards.earned - rewards.claimed;
+        if amount <= 0 {
+            return Err(RewardsError::NothingToClaim);
+        }
+
+        rewards.claimed = rewards.earned;
+        RewardsStorage::set_user_rewards(&env, &user, &rewards);
+
+        let token = RewardsStorage::get_token(&env).ok_or(RewardsError::NotInitialized)?;
+        CrossContract::invoke::<()>(
+            &env,
+            &token,
+            &Symbol::new(&env, "mint"),
+            vec![
+                &env,
+                env.current_contract_address().into_val(&env),
+                user.into_val(&env),
+                amount.into_val(&env),
+            ],
+        )
+        .map_err(|_| RewardsError::PayoutFailed)?;
+        RewardsEvents::emit_claimed(&env, &user, amount);
+
+        Ok(amount)
+    }
+
+    /// Get a user's reward accounting
+    pub fn get_user_rewards(env: Env, user: Address) -> Option<UserRewards> {
+        RewardsStorage::get_user_rewards(&env, &user)
+    }
+
+    /// Get what a user can claim now
+    pub fn get_claimable(env: Env, user: Address) -> i128 {
+        RewardsStorage::get_user_rewards(&env, &user)
+            .map(|rewards| rewards.earned - rewards.claimed)
+            .unwrap_or(0)
+    }
+
+    /// Whether a user has been rewarded for an achievement
+    pub fn is_rewarded(env: Env, user: Address, kind: RewardKind, reference: Symbol) -> bool {
+        RewardsStorage::is_rewarded(&env, &user, kind, &reference)
+    }
+
+    /// Get the rule for a kind of achievement
+    pub fn get_rule(env: Env, kind: RewardKind) -> Option<RewardRule> {
+        RewardsStorage::get_rule(&env, kind)
+    }
+
+    /// Get the anti-sybil caps
+    pub fn get_caps(env: Env) -> Option<RewardCaps> {
+        RewardsStorage::get_caps(&env)
+    }
+
+    /// Check whether an address may attest achievements
+    pub fn is_attester(env: Env, attester: Address) -> bool {
+        RewardsStorage::is_attester(&env, &attester)
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        RewardsStorage::get_admin(&env)
+    }
+}
+
+impl Rewards {
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), RewardsError> {
+        caller.require_auth();
+        if !AccessControl::is_admin(env, caller) {
+            return Err(RewardsError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    fn validate_caps(caps: &RewardCaps) -> Result<(), RewardsError> {
+        if caps.period == 0 || caps.period_limit <= 0 || caps.lifetime_limit < caps.period_limit {
+            return Err(RewardsError::InvalidCaps);
+        }
+        Ok(())
+    }
+
+    /// Credit a user for an achievement once, within their caps. An
+    /// achievement that would exceed a cap is refused rather than partly
+    /// paid, so it can be credited in full once the period rolls over.
+    fn credit(env: &Env, user: &Address, kind: RewardKind, reference: &Symbol) -> Result<i128, RewardsError> {
+        let rule = RewardsStorage::get_rule(env, kind).ok_or(RewardsError::RuleInactive)?;
+        if !rule.active {
+            return Err(RewardsError::RuleInactive);
+        }
+        if RewardsStorage::is_rewarded(env, user, kind, reference) {
+            return Err(RewardsError::AlreadyRewarded);
+        }
+
+        let caps = RewardsStorage::get_caps(env).ok_or(RewardsError::NotInitialized)?;
+        let now = Clock::now(env);
+        let mut rewards = RewardsStorage::get_user_rewards(env, user).unwrap_or(UserRewards {
+            earned: 0,
+            claimed: 0,
+            window_start: now,
+            window_earned: 0,
+        });
+        if now >= rewards.window_start.saturating_add(caps.period) {
+            rewards.window_start = now;
+            rewards.window_earned = 0;
+        }
+        if rewards.window_earned + rule.amount > caps.period_limit
+            || rewards.earned + rule.amount > caps.lifetime_limit
+        {
+            return Err(RewardsError::CapExceeded);
+        }
+
+        rewards.earned += rule.amount;
+        rewards.window_earned += rule.amount;
+        RewardsStorage::set_user_rewards(env, user, &rewards);
+        RewardsStorage::set_rewarded(env, user, kind, reference);
+        RewardsEvents::emit_credited(env, user, kind, reference, rule.amount);
+
+        Ok(rule.amount)
+    }
+}
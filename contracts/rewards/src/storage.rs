@@ -0,0 +1,101 @@
+use soroban_sdk::{Address, Env, Symbol};
+use crate::types::{DataKey, RewardCaps, RewardKind, RewardRule, UserRewards};
+
+/// Storage utilities for the rewards contract
+pub struct RewardsStorage;
+
+impl RewardsStorage {
+    /// Get the contract admin
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Set the contract admin
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Get the platform token rewards are minted in
+    pub fn get_token(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Token)
+    }
+
+    /// Set the platform token rewards are minted in
+    pub fn set_token(env: &Env, token: &Address) {
+        env.storage().instance().set(&DataKey::Token, token);
+    }
+
+    /// Get the Progress contract completions are checked against
+    pub fn get_progress(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Progress)
+    }
+
+    /// Set the Progress contract completions are checked against
+    pub fn set_progress(env: &Env, progress: &Address) {
+        env.storage().instance().set(&DataKey::Progress, progress);
+    }
+
+    /// Check whether an address may attest achievements
+    pub fn is_attester(env: &Env, attester: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Attester(attester.clone()))
+            .unwrap_or(false)
+    }
+
+    /// Allow an address to attest achievements, or stop it
+    pub fn set_attester(env: &Env, attester: &Address, allowed: bool) {
+        let key = DataKey::Attester(attester.clone());
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    /// Get the anti-sybil caps
+    pub fn get_caps(env: &Env) -> Option<RewardCaps> {
+        env.storage().instance().get(&DataKey::Caps)
+    }
+
+    /// Set the anti-sybil caps
+    pub fn set_caps(env: &Env, caps: &RewardCaps) {
+        env.storage().instance().set(&DataKey::Caps, caps);
+    }
+
+    /// Get the rule for a kind of achievement
+    pub fn get_rule(env: &Env, kind: RewardKind) -> Option<RewardRule> {
+        env.storage().persistent().get(&DataKey::Rule(kind))
+    }
+
+    /// Store a rule
+    pub fn set_rule(env: &Env, rule: &RewardRule) {
+        env.storage().persistent().set(&DataKey::Rule(rule.kind), rule);
+    }
+
+    /// Whether a user has already been rewarded for an achievement
+    pub fn is_rewarded(env: &Env, user: &Address, kind: RewardKind, reference: &Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Rewarded(user.clone(), kind, reference.clone()))
+    }
+
+    /// Record that a user has been rewarded for an achievement
+    pub fn set_rewarded(env: &Env, user: &Address, kind: RewardKind, reference: &Symbol) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Rewarded(user.clone(), kind, reference.clone()), &true);
+    }
+
+    /// Get a user's reward accounting
+    pub fn get_user_rewards(env: &Env, user: &Address) -> Option<UserRewards> {
+        env.storage().persistent().get(&DataKey::UserRewards(user.clone()))
+    }
+
+    /// Store a user's reward accounting
+    pub fn set_user_rewards(env: &Env, user: &Address, rewards: &UserRewards) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::UserRewards(user.clone()), rewards);
+    }
+}
@@ -0,0 +1,186 @@
+use soroban_sdk::{
+    symbol_short, testutils::{Address as _, Ledger}, Address, Env, String, Symbol,
+};
+use shared::{roles::{Permission, RoleLevel}, time::DAY};
+
+use platform_token::{PlatformToken, PlatformTokenClient, TokenMetadata};
+use progress::{Progress, ProgressClient};
+
+use crate::{RewardCaps, RewardKind, RewardRule, Rewards, RewardsClient, RewardsError};
+
+fn setup_test_env() -> (
+    Env,
+    RewardsClient<'static>,
+    PlatformTokenClient<'static>,
+    ProgressClient<'static>,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let grader = Address::generate(&env);
+    let contract_id = env.register(Rewards, ());
+    let client = RewardsClient::new(&env, &contract_id);
+
+    let token_id = env.register(PlatformToken, ());
+    let token = PlatformTokenClient::new(&env, &token_id);
+    let metadata = TokenMetadata {
+        decimals: 7,
+        name: String::from_str(&env, "StrellerMinds Learning Token"),
+        symbol: String::from_str(&env, "SML"),
+    };
+    token.initialize(&admin, &metadata);
+    token.grant_role(&admin, &contract_id, &RoleLevel::Student);
+    token.grant_permission(&admin, &contract_id, &Permission::MintTokens);
+
+    let progress_id = env.register(Progress, ());
+    let progress = ProgressClient::new(&env, &progress_id);
+    progress.initialize(&admin);
+    progress.add_course(&course(), &2);
+    progress.grant_role(&admin, &grader, &RoleLevel::Moderator);
+
+    let caps = RewardCaps { period: DAY, period_limit: 150, lifetime_limit: 250 };
+    client.initialize(&admin, &token_id, &progress_id, &caps);
+    client.set_attester(&admin, &grader, &true);
+    for (kind, amount) in [
+        (RewardKind::CourseCompleted, 100),
+        (RewardKind::PathCompleted, 100),
+        (RewardKind::StreakMaintained, 10),
+    ] {
+        client.set_rule(&admin, &RewardRule { kind, amount, active: true });
+    }
+
+    (env, client, token, progress, admin, grader)
+}
+
+fn course() -> Symbol {
+    symbol_short!("RUST101")
+}
+
+#[test]
+fn test_course_reward_requires_completion_in_progress() {
+    let (env, client, token, progress, _admin, grader) = setup_test_env();
+    let student = Address::generate(&env);
+
+    progress.attest_module(&grader, &student, &course(), &1);
+    assert_eq!(
+        client.try_claim_course_reward(&student, &course()),
+        Err(Ok(RewardsError::NotEligible))
+    );
+
+    progress.attest_module(&grader, &student, &course(), &2);
+    assert_eq!(client.claim_course_reward(&student, &course()), 100);
+    assert_eq!(
+        client.try_claim_course_reward(&student, &course()),
+        Err(Ok(RewardsError::AlreadyRewarded))
+    );
+
+    assert_eq!(client.get_claimable(&student), 100);
+    assert_eq!(client.claim(&student), 100);
+    assert_eq!(token.balance(&student), 100);
+    assert_eq!(client.try_claim(&student), Err(Ok(RewardsError::NothingToClaim)));
+}
+
+#[test]
+fn test_attested_achievements() {
+    let (env, client, _token, _progress, admin, grader) = setup_test_env();
+    let student = Address::generate(&env);
+    let path = symbol_short!("WEB3PATH");
+
+    assert_eq!(
+        client.try_record_achievement(&student, &student, &RewardKind::PathCompleted, &path),
+        Err(Ok(RewardsError::Unauthorized))
+    );
+    // Course completions cannot be attested around the Progress check
+    assert_eq!(
+        client.try_record_achievement(&grader, &student, &RewardKind::CourseCompleted, &course()),
+        Err(Ok(RewardsError::Unauthorized))
+    );
+    // No rule configured for certificates yet
+    assert_eq!(
+        client.try_record_achievement(&grader, &student, &RewardKind::CertificateEarned, &course()),
+        Err(Ok(RewardsError::RuleInactive))
+    );
+
+    client.record_achievement(&grader, &student, &RewardKind::PathCompleted, &path);
+    client.record_achievement(&grader, &student, &RewardKind::StreakMaintained, &symbol_short!("WEEK1"));
+    assert!(client.is_rewarded(&student, &RewardKind::PathCompleted, &path));
+    assert_eq!(client.claim(&student), 110);
+
+    // Attesting rests on the allowlist alone, not on roles in this contract
+    client.set_attester(&admin, &grader, &false);
+    assert_eq!(
+        client.try_record_achievement(&grader, &student, &RewardKind::PathCompleted, &symbol_short!("PATH2")),
+        Err(Ok(RewardsError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_attester(&grader, &grader, &true),
+        Err(Ok(RewardsError::Unauthorized))
+    );
+    client.set_attester(&admin, &grader, &true);
+
+    client.set_rule(&admin, &RewardRule { kind: RewardKind::StreakMaintained, amount: 10, active: false });
+    assert_eq!(
+        client.try_record_achievement(&grader, &student, &RewardKind::StreakMaintained, &symbol_short!("WEEK2")),
+        Err(Ok(RewardsError::RuleInactive))
+    );
+}
+
+#[test]
+fn test_anti_sybil_caps() {
+    let (env, client, _token, _progress, admin, grader) = setup_test_env();
+    let student = Address::generate(&env);
+    let kind = RewardKind::PathCompleted;
+
+    client.record_achievement(&grader, &student, &kind, &symbol_short!("PATH1"));
+    // 200 in one day is over the 150 period limit
+    assert_eq!(
+        client.try_record_achievement(&grader, &student, &kind, &symbol_short!("PATH2")),
+        Err(Ok(RewardsError::CapExceeded))
+    );
+
+    env.ledger().set_timestamp(DAY);
+    client.record_achievement(&grader, &student, &kind, &symbol_short!("PATH2"));
+
+    // 300 lifetime is over the 250 lifetime limit
+    env.ledger().set_timestamp(2 * DAY);
+    assert_eq!(
+        client.try_record_achievement(&grader, &student, &kind, &symbol_short!("PATH3")),
+        Err(Ok(RewardsError::CapExceeded))
+    );
+    assert_eq!(client.get_user_rewards(&student).unwrap().earned, 200);
+
+    let bad_caps = RewardCaps { period: DAY, period_limit: 100, lifetime_limit: 50 };
+    assert_eq!(
+        client.try_set_caps(&admin, &bad_caps),
+        Err(Ok(RewardsError::InvalidCaps))
+    );
+}
+
+#[test]
+fn test_payout_and_configuration_error_paths() {
+    let (env, client, token, _progress, admin, grader) = setup_test_env();
+    let student = Address::generate(&env);
+    let rule = RewardRule { kind: RewardKind::PathCompleted, amount: 0, active: true };
+
+    assert_eq!(client.try_set_rule(&admin, &rule), Err(Ok(RewardsError::InvalidRule)));
+    assert_eq!(
+        client.try_set_rule(&grader, &RewardRule { amount: 50, ..rule }),
+        Err(Ok(RewardsError::Unauthorized))
+    );
+    let caps = RewardCaps { period: DAY, period_limit: 1_000, lifetime_limit: 1_000 };
+    assert_eq!(client.try_set_caps(&grader, &caps), Err(Ok(RewardsError::Unauthorized)));
+    assert_eq!(client.try_claim(&student), Err(Ok(RewardsError::NothingToClaim)));
+
+    // A failed mint leaves the reward claimable
+    client.record_achievement(&grader, &student, &RewardKind::PathCompleted, &symbol_short!("PATH1"));
+    token.revoke_permission(&admin, &client.address, &Permission::MintTokens);
+    assert_eq!(client.try_claim(&student), Err(Ok(RewardsError::PayoutFailed)));
+    assert_eq!(client.get_claimable(&student), 100);
+
+    token.grant_permission(&admin, &client.address, &Permission::MintTokens);
+    assert_eq!(client.claim(&student), 100);
+    assert_eq!(token.balance(&student), 100);
+}
@@ -0,0 +1,53 @@
+use soroban_sdk::{contracttype, Address, Symbol};
+
+/// What a reward is paid for
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum RewardKind {
+    CourseCompleted,   // verified against the Progress contract
+    CertificateEarned, // attested by the certificate issuer
+    PathCompleted,     // attested when every course in a learning path is done
+    StreakMaintained,  // attested per streak milestone
+}
+
+/// How much a kind of achievement pays
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RewardRule {
+    pub kind: RewardKind,
+    pub amount: i128,
+    pub active: bool,
+}
+
+/// Anti-sybil limits on what a single address can earn
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RewardCaps {
+    pub period: u64, // seconds
+    pub period_limit: i128,
+    pub lifetime_limit: i128,
+}
+
+/// Per-user reward accounting
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct UserRewards {
+    pub earned: i128,  // lifetime total credited
+    pub claimed: i128, // lifetime total paid out
+    pub window_start: u64,
+    pub window_earned: i128,
+}
+
+/// Storage keys for the rewards contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Token,
+    Progress,
+    Caps,
+    Attester(Address),
+    Rule(RewardKind),
+    Rewarded(Address, RewardKind, Symbol), // one reward per (user, kind, reference)
+    UserRewards(Address),
+}
@@ -0,0 +1,39 @@
+use soroban_sdk::contracterror;
+
+/// Scholarship contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ScholarshipError {
+    // Initialization errors
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+
+    // Authorization errors
+    Unauthorized = 3,
+
+    // Pool errors
+    InvalidPool = 4,
+    PoolNotFound = 5,
+    PoolClosed = 6,
+    InvalidAmount = 7,
+    DeadlinePassed = 8,
+    DeadlineNotReached = 9,
+
+    // Application errors
+    CourseNotEligible = 10,
+    AlreadyApplied = 11,
+    ApplicationNotFound = 12,
+    InvalidApplicationState = 13,
+    AwardExceedsLimit = 14,
+    InsufficientFunds = 15,
+
+    // Integration errors
+    PaymentFailed = 16,
+
+    // Recovery errors
+    NothingToRecover = 17,
+
+    // Sponsor errors
+    TooManySponsors = 18,
+}
@@ -0,0 +1,66 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+/// Scholarship contract events
+pub struct ScholarshipEvents;
+
+impl ScholarshipEvents {
+    fn topic(env: &Env, name: &str) -> (Symbol, Symbol) {
+        (Symbol::new(env, "scholarship"), Symbol::new(env, name))
+    }
+
+    /// Emit event when a pool is created
+    pub fn emit_pool_created(env: &Env, pool_id: u64, max_award: i128, deadline: u64) {
+        env.events().publish(
+            Self::topic(env, "pool_created"),
+            (pool_id, max_award, deadline),
+        );
+    }
+
+    /// Emit event when a sponsor adds funds to a pool
+    pub fn emit_pool_funded(env: &Env, pool_id: u64, sponsor: &Address, amount: i128) {
+        env.events().publish(
+            Self::topic(env, "pool_funded"),
+            (pool_id, sponsor.clone(), amount),
+        );
+    }
+
+    /// Emit event when a student applies to a pool
+    pub fn emit_applied(env: &Env, application_id: u64, pool_id: u64, student: &Address, course_id: &Symbol) {
+        env.events().publish(
+            Self::topic(env, "applied"),
+            (application_id, pool_id, student.clone(), course_id.clone()),
+        );
+    }
+
+    /// Emit event when an application is approved and its award escrowed
+    pub fn emit_awarded(env: &Env, application_id: u64, student: &Address, award: i128, escrow_id: u64) {
+        env.events().publish(
+            Self::topic(env, "awarded"),
+            (application_id, student.clone(), award, escrow_id),
+        );
+    }
+
+    /// Emit event when an application is rejected
+    pub fn emit_rejected(env: &Env, application_id: u64, student: &Address) {
+        env.events().publish(
+            Self::topic(env, "rejected"),
+            (application_id, student.clone()),
+        );
+    }
+
+    /// Emit event when a refunded award is credited back to its pool
+    pub fn emit_award_recovered(env: &Env, application_id: u64, pool_id: u64, amount: i128) {
+        env.events().publish(
+            Self::topic(env, "award_recovered"),
+            (application_id, pool_id, amount),
+        );
+    }
+
+    /// Emit event when unspent funds are returned to a sponsor
+    pub fn emit_funds_returned(env: &Env, pool_id: u64, sponsor: &Address, amount: i128) {
+        env.events().publish(
+            Self::topic(env, "funds_returned"),
+            (pool_id, sponsor.clone(), amount),
+        );
+    }
+}
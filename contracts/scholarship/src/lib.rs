@@ -0,0 +1,416 @@
+#![no_std]
+
+mod types;
+mod errors;
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod tests;
+
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    contract, contractimpl, token, vec, Address, Env, IntoVal, Symbol, Vec,
+};
+
+use shared::{
+    access_control::AccessControl,
+    cross_contract::CrossContract,
+    reentrancy_guard::ReentrancyLock,
+    time::Clock,
+};
+
+pub use types::{Application, ApplicationStatus, CoursePrice, Escrow, EscrowStatus, Pool};
+pub use errors::ScholarshipError;
+use events::ScholarshipEvents;
+use storage::ScholarshipStorage;
+
+/// Upper bound on sponsors per pool, so closing it stays within budget
+const MAX_SPONSORS: u32 = 50;
+
+#[contract]
+pub struct Scholarship;
+
+#[contractimpl]
+impl Scholarship {
+    /// Initialize the contract with the Payments contract awards are paid into
+    pub fn initialize(env: Env, admin: Address, payments: Address) -> Result<(), ScholarshipError> {
+        if ScholarshipStorage::get_admin(&env).is_some() {
+            return Err(ScholarshipError::AlreadyInitialized);
+        }
+
+        AccessControl::initialize(&env, &admin).map_err(|_| ScholarshipError::AlreadyInitialized)?;
+        ScholarshipStorage::set_admin(&env, &admin);
+        ScholarshipStorage::set_payments(&env, &payments);
+
+        Ok(())
+    }
+
+    /// Define a pool's eligibility rules. Sponsors fund it separately.
+    pub fn create_pool(
+        env: Env,
+        admin: Address,
+        token: Address,
+        eligible_courses: Vec<Symbol>,
+        max_award: i128,
+        deadline: u64,
+    ) -> Result<u64, ScholarshipError> {
+        Self::require_admin(&env, &admin)?;
+
+        if eligible_courses.is_empty() || max_award <= 0 || Clock::has_passed(&env, deadline) {
+            return Err(ScholarshipError::InvalidPool);
+        }
+
+        let pool = Pool {
+            id: ScholarshipStorage::next_pool_id(&env),
+            token,
+            eligible_courses,
+            max_award,
+            deadline,
+            total_funded: 0,
+            available: 0,
+            sponsors: Vec::new(&env),
+            closed: false,
+        };
+        ScholarshipStorage::set_pool(&env, &pool);
+        ScholarshipEvents::emit_pool_created(&env, pool.id, max_award, deadline);
+
+        Ok(pool.id)
+    }
+
+    /// Add sponsor funds to a pool before its deadline
+    pub fn fund_pool(env: Env, sponsor: Address, pool_id: u64, amount: i128) -> Result<(), ScholarshipError> {
+        let _guard = ReentrancyLock::new(&env);
+        sponsor.require_auth();
+
+        let mut pool = Self::load_open_pool(&env, pool_id)?;
+        if amount <= 0 {
+            return Err(ScholarshipError::InvalidAmount);
+        }
+
+        token::Client::new(&env, &pool.token).transfer(&sponsor, &env.current_contract_address(), &amount);
+
+        let contribution = ScholarshipStorage::get_contribution(&env, pool_id, &sponsor);
+        if contribution == 0 {
+            if pool.sponsors.len() >= MAX_SPONSORS {
+                return Err(ScholarshipError::TooManySponsors);
+            }
+            pool.sponsors.push_back(sponsor.clone());
+        }
+        ScholarshipStorage::set_contribution(&env, pool_id, &sponsor, contribution + amount);
+        pool.total_funded += amount;
+        pool.available += amount;
+        ScholarshipStorage::set_pool(&env, &pool);
+        ScholarshipEvents::emit_pool_funded(&env, pool_id, &sponsor, amount);
+
+        Ok(())
+    }
+
+    /// Apply for an award towards one of the pool's eligible courses
+    pub fn apply(env: Env, student: Address, pool_id: u64, course_id: Symbol) -> Result<u64, ScholarshipError> {
+        student.require_auth();
+
+        let pool = Self::load_open_pool(&env, pool_id)?;
+        if !pool.eligible_courses.contains(&course_id) {
+            return Err(ScholarshipError::CourseNotEligible);
+        }
+        if ScholarshipStorage::get_student_application(&env, pool_id, &student).is_some() {
+            return Err(ScholarshipError::AlreadyApplied);
+        }
+
+        let application = Application {
+            id: ScholarshipStorage::next_application_id(&env),
+            pool_id,
+            student: student.clone(),
+            course_id: course_id.clone(),
+            status: ApplicationStatus::Pending,
+            award: 0,
+            escrow_id: 0,
+            applied_at: Clock::now(&env),
+        };
+        ScholarshipStorage::set_application(&env, &application);
+        ScholarshipEvents::emit_applied(&env, application.id, pool_id, &student, &course_id);
+
+        Ok(application.id)
+    }
+
+    /// Approve an application, paying the course fee from the pool straight
+    /// into a Payments escrow held for the student. Returns the escrow id.
+    pub fn approve(env: Env, admin: Address, application_id: u64) -> Result<u64, ScholarshipError> {
+        let _guard = ReentrancyLock::new(&env);
+        Self::require_admin(&env, &admin)?;
+
+        let mut application = Self::load_pending_application(&env, application_id)?;
+        let mut pool = Self::load_open_pool(&env, application.pool_id)?;
+        let payments = ScholarshipStorage::get_payments(&env).ok_or(ScholarshipError::NotInitialized)?;
+
+        let price: Option<CoursePrice> = CrossContract::invoke(
+            &env,
+            &payments,
+            &Symbol::new(&env, "get_course_price"),
+            vec![&env, application.course_id.into_val(&env)],
+        )
+        .map_err(|_| ScholarshipError::PaymentFailed)?;
+        let price = price.ok_or(ScholarshipError::PaymentFailed)?;
+
+        if price.token != pool.token {
+            return Err(ScholarshipError::PaymentFailed);
+        }
+        if price.amount > pool.max_award {
+            return Err(ScholarshipError::AwardExceedsLimit);
+        }
+        if price.amount > pool.available {
+            return Err(ScholarshipError::InsufficientFunds);
+        }
+
+        // Payments pulls the fee from this contract inside `pay_for`
+        env.authorize_as_current_contract(vec![
+            &env,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: pool.token.clone(),
+                    fn_name: Symbol::new(&env, "transfer"),
+                    args: (env.current_contract_address(), payments.clone(), price.amount).into_val(&env),
+                },
+                sub_invocations: Vec::new(&env),
+            }),
+        ]);
+        let escrow_id: u64 = CrossContract::invoke(
+            &env,
+            &payments,
+            &Symbol::new(&env, "pay_for"),
+            vec![
+                &env,
+                env.current_contract_address().into_val(&env),
+                application.student.into_val(&env),
+                application.course_id.into_val(&env),
+                Option::<Address>::None.into_val(&env),
+            ],
+        )
+        .map_err(|_| ScholarshipError::PaymentFailed)?;
+
+        pool.available -= price.amount;
+        ScholarshipStorage::set_pool(&env, &pool);
+
+        application.status = ApplicationStatus::Approved;
+        application.award = price.amount;
+        application.escrow_id = escrow_id;
+        ScholarshipStorage::set_application(&env, &application);
+        ScholarshipEvents::emit_awarded(&env, application_id, &application.student, price.amount, escrow_id);
+
+        Ok(escrow_id)
+    }
+
+    /// Reject a pending application
+    pub fn reject(env: Env, admin: Address, application_id: u64) -> Result<(), ScholarshipError> {
+        Self::require_admin(&env, &admin)?;
+
+        let mut application = Self::load_pending_application(&env, application_id)?;
+        application.status = ApplicationStatus::Rejected;
+        ScholarshipStorage::set_application(&env, &application);
+        ScholarshipEvents::emit_rejected(&env, application_id, &application.student);
+
+        Ok(())
+    }
+
+    /// Dispute an award's escrow on the student's behalf, as the escrow's
+    /// funder (the awarded student)
+    pub fn dispute_award(
+        env: Env,
+        student: Address,
+        application_id: u64,
+        reason: Symbol,
+    ) -> Result<(), ScholarshipError> {
+        student.require_auth();
+
+        let application = Self::load_approved_application(&env, application_id)?;
+        if application.student != student {
+            return Err(ScholarshipError::Unauthorized);
+        }
+        let payments = ScholarshipStorage::get_payments(&env).ok_or(ScholarshipError::NotInitialized)?;
+        CrossContract::invoke::<()>(
+            &env,
+            &payments,
+            &Symbol::new(&env, "open_dispute"),
+            vec![
+                &env,
+                env.current_contract_address().into_val(&env),
+                application.escrow_id.into_val(&env),
+                reason.into_val(&env),
+            ],
+        )
+        .map_err(|_| ScholarshipError::PaymentFailed)
+    }
+
+    /// Credit a refunded award back to its pool: the whole fee once the
+    /// escrow times out unreleased, or the refunded part of a settled
+    /// dispute. A closed pool reopens so `close_pool` can return the funds
+    /// to sponsors. Callable by anyone; returns the amount recovered.
+    pub fn recover_award(env: Env, application_id: u64) -> Result<i128, ScholarshipError> {
+        let _guard = ReentrancyLock::new(&env);
+
+        let mut application = Self::load_approved_application(&env, application_id)?;
+        let payments = ScholarshipStorage::get_payments(&env).ok_or(ScholarshipError::NotInitialized)?;
+        let this = env.current_contract_address();
+
+        let escrow: Option<Escrow> = CrossContract::invoke(
+            &env,
+            &payments,
+            &Symbol::new(&env, "get_escrow"),
+            vec![&env, application.escrow_id.into_val(&env)],
+        )
+        .map_err(|_| ScholarshipError::PaymentFailed)?;
+        let escrow = escrow.ok_or(ScholarshipError::PaymentFailed)?;
+
+        let recovered = match escrow.status {
+            EscrowStatus::Held if Clock::has_passed(&env, escrow.refundable_after) => {
+                CrossContract::invoke::<()>(
+                    &env,
+                    &payments,
+                    &Symbol::new(&env, "claim_refund"),
+                    vec![&env, this.into_val(&env), escrow.id.into_val(&env)],
+                )
+                .map_err(|_| ScholarshipError::PaymentFailed)?;
+                escrow.amount
+            }
+            EscrowStatus::Released | EscrowStatus::Refunded if escrow.refunded > 0 => {
+                // Dispute refunds are credited to this contract's payout
+                // balance, which may also hold other awards' refunds
+                let balance: i128 = CrossContract::invoke(
+                    &env,
+                    &payments,
+                    &Symbol::new(&env, "get_payout_balance"),
+                    vec![&env, this.into_val(&env), escrow.token.into_val(&env)],
+                )
+                .map_err(|_| ScholarshipError::PaymentFailed)?;
+                if balance > 0 {
+                    CrossContract::invoke::<i128>(
+                        &env,
+                        &payments,
+                        &Symbol::new(&env, "claim_payout"),
+                        vec![&env, this.into_val(&env), escrow.token.into_val(&env)],
+                    )
+                    .map_err(|_| ScholarshipError::PaymentFailed)?;
+                }
+                escrow.refunded
+            }
+            _ => return Err(ScholarshipError::NothingToRecover),
+        };
+
+        let mut pool = ScholarshipStorage::get_pool(&env, application.pool_id).ok_or(ScholarshipError::PoolNotFound)?;
+        pool.available += recovered;
+        pool.closed = false;
+        ScholarshipStorage::set_pool(&env, &pool);
+
+        application.status = ApplicationStatus::Recovered;
+        ScholarshipStorage::set_application(&env, &application);
+        ScholarshipEvents::emit_award_recovered(&env, application_id, pool.id, recovered);
+
+        Ok(recovered)
+    }
+
+    /// After the deadline, return the unspent balance to sponsors in
+    /// proportion to what each contributed. Callable by anyone.
+    pub fn close_pool(env: Env, pool_id: u64) -> Result<i128, ScholarshipError> {
+        let _guard = ReentrancyLock::new(&env);
+
+        let mut pool = ScholarshipStorage::get_pool(&env, pool_id).ok_or(ScholarshipError::PoolNotFound)?;
+        if pool.closed {
+            return Err(ScholarshipError::PoolClosed);
+        }
+        if !Clock::has_passed(&env, pool.deadline) {
+            return Err(ScholarshipError::DeadlineNotReached);
+        }
+
+        let unspent = pool.available;
+        if unspent > 0 {
+            let client = token::Client::new(&env, &pool.token);
+            let mut returned = 0;
+            for (index, sponsor) in pool.sponsors.iter().enumerate() {
+                let contribution = ScholarshipStorage::get_contribution(&env, pool_id, &sponsor);
+                let mut share = unspent * contribution / pool.total_funded;
+                // Rounding dust goes to the last sponsor
+                if index as u32 == pool.sponsors.len() - 1 {
+                    share = unspent - returned;
+                }
+                returned += share;
+                if share > 0 {
+                    client.transfer(&env.current_contract_address(), &sponsor, &share);
+                    ScholarshipEvents::emit_funds_returned(&env, pool_id, &sponsor, share);
+                }
+            }
+        }
+
+        pool.available = 0;
+        pool.closed = true;
+        ScholarshipStorage::set_pool(&env, &pool);
+
+        Ok(unspent)
+    }
+
+    /// Get a pool by id
+    pub fn get_pool(env: Env, pool_id: u64) -> Option<Pool> {
+        ScholarshipStorage::get_pool(&env, pool_id)
+    }
+
+    /// Get how much a sponsor has put into a pool
+    pub fn get_contribution(env: Env, pool_id: u64, sponsor: Address) -> i128 {
+        ScholarshipStorage::get_contribution(&env, pool_id, &sponsor)
+    }
+
+    /// Get an application by id
+    pub fn get_application(env: Env, application_id: u64) -> Option<Application> {
+        ScholarshipStorage::get_application(&env, application_id)
+    }
+
+    /// Get a student's application to a pool
+    pub fn get_student_application(env: Env, pool_id: u64, student: Address) -> Option<Application> {
+        ScholarshipStorage::get_student_application(&env, pool_id, &student)
+            .and_then(|id| ScholarshipStorage::get_application(&env, id))
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        ScholarshipStorage::get_admin(&env)
+    }
+}
+
+impl Scholarship {
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), ScholarshipError> {
+        caller.require_auth();
+        if !AccessControl::is_admin(env, caller) {
+            return Err(ScholarshipError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Load a pool that is still taking funds, applications and awards
+    fn load_open_pool(env: &Env, pool_id: u64) -> Result<Pool, ScholarshipError> {
+        let pool = ScholarshipStorage::get_pool(env, pool_id).ok_or(ScholarshipError::PoolNotFound)?;
+        if pool.closed {
+            return Err(ScholarshipError::PoolClosed);
+        }
+        if Clock::has_passed(env, pool.deadline) {
+            return Err(ScholarshipError::DeadlinePassed);
+        }
+        Ok(pool)
+    }
+
+    fn load_approved_application(env: &Env, application_id: u64) -> Result<Application, ScholarshipError> {
+        let application = ScholarshipStorage::get_application(env, application_id)
+            .ok_or(ScholarshipError::ApplicationNotFound)?;
+        if application.status != ApplicationStatus::Approved {
+            return Err(ScholarshipError::InvalidApplicationState);
+        }
+        Ok(application)
+    }
+
+    fn load_pending_application(env: &Env, application_id: u64) -> Result<Application, ScholarshipError> {
+        let application = ScholarshipStorage::get_application(env, application_id)
+            .ok_or(ScholarshipError::ApplicationNotFound)?;
+        if application.status != ApplicationStatus::Pending {
+            return Err(ScholarshipError::InvalidApplicationState);
+        }
+        Ok(application)
+    }
+}
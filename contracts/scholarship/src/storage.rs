@@ -0,0 +1,89 @@
+use soroban_sdk::{Address, Env};
+use crate::types::{Application, DataKey, Pool};
+
+/// Storage utilities for the scholarship contract
+pub struct ScholarshipStorage;
+
+impl ScholarshipStorage {
+    /// Get the contract admin
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Set the contract admin
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Get the Payments contract awards are escrowed in
+    pub fn get_payments(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Payments)
+    }
+
+    /// Set the Payments contract awards are escrowed in
+    pub fn set_payments(env: &Env, payments: &Address) {
+        env.storage().instance().set(&DataKey::Payments, payments);
+    }
+
+    /// Allocate the next pool id
+    pub fn next_pool_id(env: &Env) -> u64 {
+        let id: u64 = env.storage().instance().get(&DataKey::PoolCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::PoolCount, &id);
+        id
+    }
+
+    /// Get a pool by id
+    pub fn get_pool(env: &Env, pool_id: u64) -> Option<Pool> {
+        env.storage().persistent().get(&DataKey::Pool(pool_id))
+    }
+
+    /// Store a pool
+    pub fn set_pool(env: &Env, pool: &Pool) {
+        env.storage().persistent().set(&DataKey::Pool(pool.id), pool);
+    }
+
+    /// Get how much a sponsor has put into a pool
+    pub fn get_contribution(env: &Env, pool_id: u64, sponsor: &Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Contribution(pool_id, sponsor.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Set how much a sponsor has put into a pool
+    pub fn set_contribution(env: &Env, pool_id: u64, sponsor: &Address, amount: i128) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Contribution(pool_id, sponsor.clone()), &amount);
+    }
+
+    /// Allocate the next application id
+    pub fn next_application_id(env: &Env) -> u64 {
+        let id: u64 = env.storage().instance().get(&DataKey::ApplicationCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::ApplicationCount, &id);
+        id
+    }
+
+    /// Get an application by id
+    pub fn get_application(env: &Env, application_id: u64) -> Option<Application> {
+        env.storage().persistent().get(&DataKey::Application(application_id))
+    }
+
+    /// Store an application and index it by pool and student
+    pub fn set_application(env: &Env, application: &Application) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Application(application.id), application);
+        env.storage().persistent().set(
+            &DataKey::StudentApplication(application.pool_id, application.student.clone()),
+            &application.id,
+        );
+    }
+
+    /// Get a student's application to a pool
+    pub fn get_student_application(env: &Env, pool_id: u64, student: &Address) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::StudentApplication(pool_id, student.clone()))
+    }
+}
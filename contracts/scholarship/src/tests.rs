@@ -0,0 +1,234 @@
+use soroban_sdk::{
+    symbol_short, testutils::{Address as _, Ledger}, token, vec, Address, Env, Symbol,
+};
+use shared::{roles::RoleLevel, time::DAY};
+
+use payments::{EscrowStatus, Payments, PaymentsClient, PaymentsConfig};
+
+use crate::{ApplicationStatus, Scholarship, ScholarshipClient, ScholarshipError};
+
+fn setup_test_env() -> (
+    Env,
+    ScholarshipClient<'static>,
+    PaymentsClient<'static>,
+    Address,
+    token::Client<'static>,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let instructor = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &asset.address());
+
+    let payments_id = env.register(Payments, ());
+    let payments = PaymentsClient::new(&env, &payments_id);
+    let config = PaymentsConfig {
+        platform: Address::generate(&env),
+        platform_bps: 1_000,
+        release_timeout: 30 * DAY,
+    };
+    payments.initialize(&admin, &config);
+    payments.grant_role(&admin, &instructor, &RoleLevel::Instructor);
+    payments.set_course_price(&instructor, &course(), &instructor, &asset.address(), &400);
+    payments.set_course_price(&instructor, &symbol_short!("ML301"), &instructor, &asset.address(), &900);
+
+    let contract_id = env.register(Scholarship, ());
+    let client = ScholarshipClient::new(&env, &contract_id);
+    client.initialize(&admin, &payments_id);
+
+    (env, client, payments, admin, token)
+}
+
+fn course() -> Symbol {
+    symbol_short!("RUST101")
+}
+
+fn sponsor(env: &Env, token: &token::Client, amount: i128) -> Address {
+    let sponsor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token.address).mint(&sponsor, &amount);
+    sponsor
+}
+
+fn create_pool(
+    env: &Env,
+    client: &ScholarshipClient,
+    admin: &Address,
+    token: &token::Client,
+) -> u64 {
+    let courses = vec![env, course(), symbol_short!("ML301")];
+    client.create_pool(admin, &token.address, &courses, &500, &(10 * DAY))
+}
+
+#[test]
+fn test_approved_award_is_paid_into_course_escrow() {
+    let (env, client, payments, admin, token) = setup_test_env();
+    let pool_id = create_pool(&env, &client, &admin, &token);
+    let sponsor = sponsor(&env, &token, 1_000);
+    client.fund_pool(&sponsor, &pool_id, &1_000);
+
+    let student = Address::generate(&env);
+    let application_id = client.apply(&student, &pool_id, &course());
+    let escrow_id = client.approve(&admin, &application_id);
+
+    let escrow = payments.get_escrow(&escrow_id).unwrap();
+    assert_eq!(escrow.payer, student);
+    assert_eq!(escrow.funder, client.address);
+    assert_eq!(escrow.amount, 400);
+    assert_eq!(escrow.status, EscrowStatus::Held);
+    assert_eq!(token.balance(&payments.address), 400);
+    assert_eq!(payments.get_payer_escrows(&student), vec![&env, escrow_id]);
+    assert_eq!(payments.get_receipts(&student, &0, &10).get_unchecked(0).amount, 400);
+    assert_eq!(payments.get_receipt_count(&client.address), 0);
+
+    let application = client.get_application(&application_id).unwrap();
+    assert_eq!(application.status, ApplicationStatus::Approved);
+    assert_eq!(application.escrow_id, escrow_id);
+    assert_eq!(client.get_pool(&pool_id).unwrap().available, 600);
+    assert_eq!(
+        client.try_approve(&admin, &application_id),
+        Err(Ok(ScholarshipError::InvalidApplicationState))
+    );
+}
+
+#[test]
+fn test_eligibility_and_award_limits() {
+    let (env, client, _payments, admin, token) = setup_test_env();
+    let pool_id = create_pool(&env, &client, &admin, &token);
+    let student = Address::generate(&env);
+
+    assert_eq!(
+        client.try_apply(&student, &pool_id, &symbol_short!("ART100")),
+        Err(Ok(ScholarshipError::CourseNotEligible))
+    );
+
+    // Course costs more than the pool's max award
+    let application_id = client.apply(&student, &pool_id, &symbol_short!("ML301"));
+    assert_eq!(
+        client.try_apply(&student, &pool_id, &course()),
+        Err(Ok(ScholarshipError::AlreadyApplied))
+    );
+    assert_eq!(
+        client.try_approve(&admin, &application_id),
+        Err(Ok(ScholarshipError::AwardExceedsLimit))
+    );
+
+    // Within the limit but the pool is not funded enough
+    let other = Address::generate(&env);
+    let application_id = client.apply(&other, &pool_id, &course());
+    assert_eq!(
+        client.try_approve(&admin, &application_id),
+        Err(Ok(ScholarshipError::InsufficientFunds))
+    );
+    assert_eq!(
+        client.try_approve(&student, &application_id),
+        Err(Ok(ScholarshipError::Unauthorized))
+    );
+
+    client.reject(&admin, &application_id);
+    assert_eq!(
+        client.get_student_application(&pool_id, &other).unwrap().status,
+        ApplicationStatus::Rejected
+    );
+}
+
+#[test]
+fn test_deadline_closes_applications() {
+    let (env, client, _payments, admin, token) = setup_test_env();
+    let pool_id = create_pool(&env, &client, &admin, &token);
+    let student = Address::generate(&env);
+
+    assert_eq!(
+        client.try_close_pool(&pool_id),
+        Err(Ok(ScholarshipError::DeadlineNotReached))
+    );
+    // Closing pays every sponsor, so their number is capped
+    for _ in 0..50 {
+        client.fund_pool(&sponsor(&env, &token, 1), &pool_id, &1);
+    }
+    assert_eq!(
+        client.try_fund_pool(&sponsor(&env, &token, 1), &pool_id, &1),
+        Err(Ok(ScholarshipError::TooManySponsors))
+    );
+
+    env.ledger().set_timestamp(10 * DAY + 1);
+    assert_eq!(
+        client.try_apply(&student, &pool_id, &course()),
+        Err(Ok(ScholarshipError::DeadlinePassed))
+    );
+    assert_eq!(
+        client.try_create_pool(&admin, &token.address, &vec![&env, course()], &500, &DAY),
+        Err(Ok(ScholarshipError::InvalidPool))
+    );
+}
+
+#[test]
+fn test_unspent_funds_return_to_sponsors_pro_rata() {
+    let (env, client, _payments, admin, token) = setup_test_env();
+    let pool_id = create_pool(&env, &client, &admin, &token);
+    let first = sponsor(&env, &token, 1_000);
+    let second = sponsor(&env, &token, 1_000);
+    client.fund_pool(&first, &pool_id, &300);
+    client.fund_pool(&second, &pool_id, &600);
+    client.fund_pool(&first, &pool_id, &100);
+    assert_eq!(client.get_contribution(&pool_id, &first), 400);
+    assert_eq!(client.get_pool(&pool_id).unwrap().sponsors.len(), 2);
+
+    let student = Address::generate(&env);
+    let application_id = client.apply(&student, &pool_id, &course());
+    client.approve(&admin, &application_id);
+
+    // 1,000 funded, 400 awarded: 600 returned 40/60
+    env.ledger().set_timestamp(10 * DAY + 1);
+    assert_eq!(client.close_pool(&pool_id), 600);
+    assert_eq!(token.balance(&first), 600 + 240);
+    assert_eq!(token.balance(&second), 400 + 360);
+    assert_eq!(token.balance(&client.address), 0);
+
+    assert!(client.get_pool(&pool_id).unwrap().closed);
+    assert_eq!(
+        client.try_close_pool(&pool_id),
+        Err(Ok(ScholarshipError::PoolClosed))
+    );
+}
+
+#[test]
+fn test_refunded_awards_return_to_the_pool() {
+    let (env, client, payments, admin, token) = setup_test_env();
+    let pool_id = create_pool(&env, &client, &admin, &token);
+    let sponsor = sponsor(&env, &token, 1_000);
+    client.fund_pool(&sponsor, &pool_id, &1_000);
+
+    let timed_out = client.apply(&Address::generate(&env), &pool_id, &course());
+    client.approve(&admin, &timed_out);
+    let student = Address::generate(&env);
+    let disputed = client.apply(&student, &pool_id, &course());
+    let escrow_id = client.approve(&admin, &disputed);
+    assert_eq!(client.try_recover_award(&timed_out), Err(Ok(ScholarshipError::NothingToRecover)));
+
+    // Only the awarded student may dispute; a quarter of the fee comes back
+    assert_eq!(
+        client.try_dispute_award(&sponsor, &disputed, &symbol_short!("no_show")),
+        Err(Ok(ScholarshipError::Unauthorized))
+    );
+    client.dispute_award(&student, &disputed, &symbol_short!("no_show"));
+    payments.resolve_dispute(&admin, &escrow_id, &2_500);
+    assert_eq!(client.recover_award(&disputed), 100);
+    assert_eq!(client.get_pool(&pool_id).unwrap().available, 300);
+    assert_eq!(client.get_application(&disputed).unwrap().status, ApplicationStatus::Recovered);
+    assert_eq!(
+        client.try_recover_award(&disputed),
+        Err(Ok(ScholarshipError::InvalidApplicationState))
+    );
+
+    // A recovery after closing reopens the pool for another distribution
+    env.ledger().set_timestamp(10 * DAY + 1);
+    assert_eq!(client.close_pool(&pool_id), 300);
+    env.ledger().set_timestamp(31 * DAY);
+    assert_eq!(client.recover_award(&timed_out), 400);
+    assert!(!client.get_pool(&pool_id).unwrap().closed);
+    assert_eq!(client.close_pool(&pool_id), 400);
+    assert_eq!(token.balance(&sponsor), 700);
+    assert_eq!(token.balance(&client.address), 0);
+}
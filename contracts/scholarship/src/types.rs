@@ -0,0 +1,95 @@
+use soroban_sdk::{contracttype, Address, Symbol, Vec};
+
+/// A sponsor-funded pool and the rules for awarding from it
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Pool {
+    pub id: u64,
+    pub token: Address,
+    pub eligible_courses: Vec<Symbol>,
+    pub max_award: i128,
+    pub deadline: u64, // applications and awards close after this
+    pub total_funded: i128,
+    pub available: i128, // funded minus awarded
+    pub sponsors: Vec<Address>,
+    pub closed: bool,
+}
+
+/// Where an application stands
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum ApplicationStatus {
+    Pending,
+    Approved,
+    Rejected,
+    Recovered, // refunded award credited back to the pool
+}
+
+/// A student's request for an award towards one course
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Application {
+    pub id: u64,
+    pub pool_id: u64,
+    pub student: Address,
+    pub course_id: Symbol,
+    pub status: ApplicationStatus,
+    pub award: i128,
+    pub escrow_id: u64, // Payments escrow holding the award; 0 until approved
+    pub applied_at: u64,
+}
+
+/// Mirror of the Payments contract's `CoursePrice`, decoded from
+/// cross-contract calls
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CoursePrice {
+    pub course_id: Symbol,
+    pub owner: Address,
+    pub instructor: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Mirror of the Payments contract's `EscrowStatus`
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum EscrowStatus {
+    Held,
+    Disputed,
+    Released,
+    Refunded,
+}
+
+/// Mirror of the Payments contract's `Escrow`, decoded from cross-contract
+/// calls
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Escrow {
+    pub id: u64,
+    pub payer: Address,
+    pub funder: Address,
+    pub course_id: Symbol,
+    pub instructor: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub referrer: Option<Address>,
+    pub status: EscrowStatus,
+    pub created_at: u64,
+    pub refundable_after: u64,
+    pub refunded: i128,
+}
+
+/// Storage keys for the scholarship contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Payments,
+    PoolCount,
+    Pool(u64),
+    Contribution(u64, Address),
+    ApplicationCount,
+    Application(u64),
+    StudentApplication(u64, Address), // (pool, student) -> application id
+}
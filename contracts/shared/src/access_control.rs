@@ -1,15 +1,17 @@
-use soroban_sdk::{Address, Env, Vec};
+use soroban_sdk::{token, Address, Env, Vec};
 use crate::errors::AccessControlError;
 use crate::events::AccessControlEvents;
 use crate::storage::AccessControlStorage;
-use crate::roles::{Role, RoleLevel, Permission};
+use crate::roles::{AdminAction, AdminProposal, Role, RoleLevel, Permission, RoleSweepBounty};
 use crate::permissions::RolePermissions;
+use crate::time::Clock;
 
 /// OpenZeppelin-style AccessControl implementation
 pub struct AccessControl;
 
 impl AccessControl {
-    /// Initialize the access control system
+    /// Initialize the access control system, granting `admin` the
+    /// SuperAdmin role. Requires the admin's authorization.
     pub fn initialize(env: &Env, admin: &Address) -> Result<(), AccessControlError> {
         if AccessControlStorage::is_initialized(env) {
             return Err(AccessControlError::AlreadyInitialized);
@@ -20,6 +22,10 @@ impl AccessControl {
 
         // Store admin address and mark as initialized
         AccessControlStorage::set_admin(env, admin);
+        let mut admins = Vec::new(env);
+        admins.push_back(admin.clone());
+        AccessControlStorage::set_admins(env, &admins);
+        AccessControlStorage::set_admin_quorum(env, 1);
         AccessControlStorage::set_initialized(env);
 
         // Grant SuperAdmin role to the initial admin
@@ -27,7 +33,7 @@ impl AccessControl {
             &env, 
             RoleLevel::SuperAdmin,
             admin.clone(),
-            env.ledger().timestamp(),
+            Clock::now(env),
         );
         AccessControlStorage::set_role(env, admin, &super_admin_role);
 
@@ -66,12 +72,15 @@ impl AccessControl {
             return Err(AccessControlError::CannotGrantHigherRole);
         }
 
+        Self::require_unfrozen(env)?;
+        Self::require_single_admin_scope(env, &role_level)?;
+
         // Create role with default permissions
         let role = RolePermissions::create_role_with_default_permissions(
             &env,
             role_level,
             granter.clone(),
-            env.ledger().timestamp(),
+            Clock::now(env),
         );
 
         // Store role
@@ -106,12 +115,15 @@ impl AccessControl {
             return Err(AccessControlError::CannotGrantHigherRole);
         }
 
+        Self::require_unfrozen(env)?;
+        Self::require_single_admin_scope(env, &role_level)?;
+
         // Create custom role
         let role = Role::new(
             role_level,
             permissions,
             granter.clone(),
-            env.ledger().timestamp(),
+            Clock::now(env),
         );
 
         // Store role
@@ -148,6 +160,9 @@ impl AccessControl {
             return Err(AccessControlError::CannotGrantHigherRole);
         }
 
+        Self::require_unfrozen(env)?;
+        Self::require_single_admin_scope(env, &user_role.level)?;
+
         // Prevent self-revocation
         if revoker == user {
             return Err(AccessControlError::CannotRevokeOwnRole);
@@ -191,12 +206,15 @@ impl AccessControl {
             return Err(AccessControlError::CannotGrantHigherRole);
         }
 
+        Self::require_unfrozen(env)?;
+        Self::require_single_admin_scope(env, &source_role.level)?;
+
         // Create new role for target user
         let new_role = Role::new(
             source_role.level.clone(),
             source_role.permissions.clone(),
             transferrer.clone(),
-            env.ledger().timestamp(),
+            Clock::now(env),
         );
 
         // Store role in history for source user
@@ -242,12 +260,16 @@ impl AccessControl {
             return Err(AccessControlError::CannotGrantHigherRole);
         }
 
+        Self::require_unfrozen(env)?;
+        Self::require_single_admin_scope(env, &current_role.level)?;
+        Self::require_single_admin_scope(env, &new_role_level)?;
+
         // Create new role
         let new_role = Role::new(
             new_role_level,
             new_permissions,
             updater.clone(),
-            env.ledger().timestamp(),
+            Clock::now(env),
         );
 
         // Store old role in history
@@ -277,6 +299,8 @@ impl AccessControl {
             return Err(AccessControlError::PermissionDenied);
         }
 
+        Self::require_unfrozen(env)?;
+
         // Get user's current role
         let mut user_role = AccessControlStorage::get_role(env, user)
             .ok_or(AccessControlError::RoleNotFound)?;
@@ -308,6 +332,8 @@ impl AccessControl {
             return Err(AccessControlError::PermissionDenied);
         }
 
+        Self::require_unfrozen(env)?;
+
         // Get user's current role
         let mut user_role = AccessControlStorage::get_role(env, user)
             .ok_or(AccessControlError::RoleNotFound)?;
@@ -373,17 +399,259 @@ impl AccessControl {
             return Err(AccessControlError::PermissionDenied);
         }
 
+        // With more than one approval required, admin changes go through proposals
+        if AccessControlStorage::get_admin_quorum(env) > 1 {
+            return Err(AccessControlError::QuorumRequired);
+        }
+
         let old_admin = AccessControlStorage::get_admin(env);
         
         // Set new admin
         AccessControlStorage::set_admin(env, new_admin);
 
+        // Keep the admin set in sync with the primary admin
+        let mut admins = AccessControlStorage::get_admins(env);
+        if let Some(index) = admins.first_index_of(&old_admin) {
+            admins.remove(index);
+        }
+        if !admins.contains(new_admin) {
+            admins.push_back(new_admin.clone());
+        }
+        AccessControlStorage::set_admins(env, &admins);
+
         // Emit event
         AccessControlEvents::emit_admin_changed(env, &old_admin, new_admin);
 
         Ok(())
     }
 
+    /// Get the set of root admins
+    pub fn get_admins(env: &Env) -> Vec<Address> {
+        AccessControlStorage::get_admins(env)
+    }
+
+    /// Check whether an address belongs to the root admin set
+    pub fn is_admin(env: &Env, address: &Address) -> bool {
+        AccessControlStorage::get_admins(env).contains(address)
+    }
+
+    /// Get the number of admin approvals required for sensitive operations
+    pub fn get_admin_quorum(env: &Env) -> u32 {
+        AccessControlStorage::get_admin_quorum(env)
+    }
+
+    /// Check whether role changes are currently frozen
+    pub fn is_frozen(env: &Env) -> bool {
+        AccessControlStorage::is_frozen(env)
+    }
+
+    /// Get an admin proposal by id
+    pub fn get_admin_proposal(env: &Env, proposal_id: u64) -> Option<AdminProposal> {
+        AccessControlStorage::get_admin_proposal(env, proposal_id)
+    }
+
+    /// Propose a sensitive admin action.
+    ///
+    /// The proposer's approval is counted immediately, so with a quorum of
+    /// one the action executes in the same call.
+    pub fn propose_admin_action(
+        env: &Env,
+        proposer: &Address,
+        action: AdminAction,
+    ) -> Result<u64, AccessControlError> {
+        if !AccessControlStorage::is_initialized(env) {
+            return Err(AccessControlError::NotInitialized);
+        }
+
+        proposer.require_auth();
+
+        if !Self::is_admin(env, proposer) {
+            return Err(AccessControlError::NotAdmin);
+        }
+
+        Self::validate_admin_action(env, &action)?;
+
+        let mut approvals = Vec::new(env);
+        approvals.push_back(proposer.clone());
+        let proposal = AdminProposal {
+            id: AccessControlStorage::next_admin_proposal_id(env),
+            action,
+            proposer: proposer.clone(),
+            approvals,
+            created_at: Clock::now(env),
+            executed: false,
+        };
+        AccessControlStorage::set_admin_proposal(env, &proposal);
+        AccessControlEvents::emit_admin_action_proposed(env, proposal.id, proposer);
+
+        Self::execute_if_quorum(env, proposer, proposal.clone())?;
+
+        Ok(proposal.id)
+    }
+
+    /// Approve a pending admin action, executing it once quorum is reached.
+    ///
+    /// Returns whether the action was executed by this approval.
+    pub fn approve_admin_action(
+        env: &Env,
+        approver: &Address,
+        proposal_id: u64,
+    ) -> Result<bool, AccessControlError> {
+        approver.require_auth();
+
+        if !Self::is_admin(env, approver) {
+            return Err(AccessControlError::NotAdmin);
+        }
+
+        let mut proposal = AccessControlStorage::get_admin_proposal(env, proposal_id)
+            .ok_or(AccessControlError::ProposalNotFound)?;
+
+        if proposal.executed {
+            return Err(AccessControlError::ProposalAlreadyExecuted);
+        }
+
+        if proposal.approvals.contains(approver) {
+            return Err(AccessControlError::AlreadyApproved);
+        }
+
+        proposal.approvals.push_back(approver.clone());
+        AccessControlStorage::set_admin_proposal(env, &proposal);
+
+        Self::execute_if_quorum(env, approver, proposal)
+    }
+
+    /// Configure (or clear) the bounty paid to callers of `cleanup_expired_roles`
+    pub fn set_cleanup_bounty(
+        env: &Env,
+        admin: &Address,
+        bounty: Option<RoleSweepBounty>,
+    ) -> Result<(), AccessControlError> {
+        if let Some(ref bounty) = bounty {
+            if bounty.amount_per_role <= 0 {
+                return Err(AccessControlError::InvalidBountyAmount);
+            }
+        }
+
+        Self::require_super_admin(env, admin)?;
+
+        AccessControlStorage::set_sweep_bounty(env, &bounty);
+        Ok(())
+    }
+
+    /// Add role holders missing from the holder index, so roles granted
+    /// before the index existed are reached by `cleanup_expired_roles`.
+    ///
+    /// Root admins are always considered; other holders must be listed in
+    /// `users`. Addresses without a role are ignored. Returns the number of
+    /// holders added.
+    pub fn backfill_role_holders(
+        env: &Env,
+        admin: &Address,
+        users: Vec<Address>,
+    ) -> Result<u32, AccessControlError> {
+        Self::require_super_admin(env, admin)?;
+
+        let mut added = 0;
+        for user in AccessControlStorage::get_admins(env).iter().chain(users.iter()) {
+            if AccessControlStorage::has_role(env, &user) && AccessControlStorage::index_role_holder(env, &user) {
+                added += 1;
+            }
+        }
+        Ok(added)
+    }
+
+    /// Get the configured cleanup bounty, if any
+    pub fn get_cleanup_bounty(env: &Env) -> Option<RoleSweepBounty> {
+        AccessControlStorage::get_sweep_bounty(env)
+    }
+
+    /// Remove expired role grants. Callable by anyone.
+    ///
+    /// Walks the role holder index page by page, examining at most `limit`
+    /// role holders and resuming where the previous sweep stopped, and
+    /// returns the number of roles removed. When a bounty is configured the
+    /// caller is paid per removed role on a best-effort basis, so an empty
+    /// bounty pool never blocks the cleanup itself.
+    pub fn cleanup_expired_roles(
+        env: &Env,
+        caller: &Address,
+        limit: u32,
+    ) -> Result<u32, AccessControlError> {
+        if !AccessControlStorage::is_initialized(env) {
+            return Err(AccessControlError::NotInitialized);
+        }
+
+        caller.require_auth();
+
+        let total = AccessControlStorage::get_role_holder_count(env);
+        let page_count = AccessControlStorage::get_role_holder_page_count(env);
+        if total == 0 || page_count == 0 || limit == 0 {
+            return Ok(0);
+        }
+
+        let now = Clock::now(env);
+        let (mut page, mut offset) = AccessControlStorage::get_sweep_cursor(env);
+        if page >= page_count {
+            (page, offset) = (0, 0);
+        }
+        let mut budget = limit.min(total);
+        let mut removed = 0;
+
+        // Each holder is examined at most once, so a window never spans more
+        // than one pass over the pages
+        for _ in 0..=page_count {
+            if budget == 0 {
+                break;
+            }
+            let holders = AccessControlStorage::get_role_holder_page(env, page);
+            let start = offset.min(holders.len());
+            let end = start + budget.min(holders.len() - start);
+            budget -= end - start;
+
+            // Collect first, then remove, so the page isn't mutated mid-scan
+            let mut expired: Vec<(Address, Role)> = Vec::new(env);
+            for index in start..end {
+                let user = holders.get_unchecked(index);
+                if let Some(role) = AccessControlStorage::get_role(env, &user) {
+                    if role.is_expired(now) {
+                        expired.push_back((user, role));
+                    }
+                }
+            }
+            for (user, role) in expired.iter() {
+                AccessControlStorage::add_role_history(env, &user, &role);
+                AccessControlStorage::add_role_revocation(env, &user, &role);
+                AccessControlStorage::remove_role(env, &user);
+                AccessControlEvents::emit_role_expired(env, &user, &role);
+            }
+            removed += expired.len();
+
+            // Removals compact the page, so the next unexamined holder moves down
+            offset = end - expired.len();
+            if offset >= AccessControlStorage::get_role_holder_page(env, page).len() {
+                page += 1;
+                offset = 0;
+            }
+            if page >= AccessControlStorage::get_role_holder_page_count(env) {
+                page = 0;
+            }
+        }
+        AccessControlStorage::set_sweep_cursor(env, (page, offset));
+
+        if removed > 0 {
+            if let Some(bounty) = AccessControlStorage::get_sweep_bounty(env) {
+                let amount = bounty.amount_per_role.saturating_mul(removed as i128);
+                let _ = token::Client::new(env, &bounty.token).try_transfer(
+                    &env.current_contract_address(),
+                    caller,
+                    &amount,
+                );
+            }
+        }
+
+        Ok(removed)
+    }
+
     /// Require a specific permission (for use in function modifiers)
     pub fn require_permission(
         env: &Env,
@@ -431,4 +699,173 @@ impl AccessControl {
             Err(AccessControlError::PermissionDenied)
         }
     }
-} 
\ No newline at end of file
+
+    /// Require the caller to hold the SuperAdmin role
+    fn require_super_admin(env: &Env, admin: &Address) -> Result<(), AccessControlError> {
+        let admin_role = AccessControlStorage::validate_user_role(env, admin)?;
+
+        if admin_role.level != RoleLevel::SuperAdmin {
+            AccessControlEvents::emit_access_denied(env, admin, &Permission::InitializeContract);
+            return Err(AccessControlError::PermissionDenied);
+        }
+        Ok(())
+    }
+
+    /// Reject role changes while the emergency freeze is active
+    fn require_unfrozen(env: &Env) -> Result<(), AccessControlError> {
+        if AccessControlStorage::is_frozen(env) {
+            return Err(AccessControlError::Frozen);
+        }
+        Ok(())
+    }
+
+    /// Admin-level role changes need the proposal flow once quorum exceeds one
+    fn require_single_admin_scope(env: &Env, level: &RoleLevel) -> Result<(), AccessControlError> {
+        if *level >= RoleLevel::Admin && AccessControlStorage::get_admin_quorum(env) > 1 {
+            return Err(AccessControlError::QuorumRequired);
+        }
+        Ok(())
+    }
+
+    /// Check that an admin action can be applied to the current state
+    fn validate_admin_action(env: &Env, action: &AdminAction) -> Result<(), AccessControlError> {
+        let admins = AccessControlStorage::get_admins(env);
+        let quorum = AccessControlStorage::get_admin_quorum(env);
+
+        match action {
+            AdminAction::AddAdmin(admin) => {
+                if admins.contains(admin) {
+                    return Err(AccessControlError::AdminAlreadyExists);
+                }
+            }
+            AdminAction::RemoveAdmin(admin) => {
+                if !admins.contains(admin) {
+                    return Err(AccessControlError::NotAdmin);
+                }
+                if admins.len() - 1 < quorum {
+                    return Err(AccessControlError::InvalidQuorum);
+                }
+            }
+            AdminAction::SetQuorum(new_quorum) => {
+                if *new_quorum == 0 || *new_quorum > admins.len() {
+                    return Err(AccessControlError::InvalidQuorum);
+                }
+            }
+            AdminAction::GrantRole(user, level) => {
+                if *level == RoleLevel::SuperAdmin || admins.contains(user) {
+                    return Err(AccessControlError::InvalidRole);
+                }
+            }
+            AdminAction::RevokeRole(user) => {
+                if admins.contains(user) {
+                    return Err(AccessControlError::InvalidRole);
+                }
+                if !AccessControlStorage::has_role(env, user) {
+                    return Err(AccessControlError::RoleNotFound);
+                }
+            }
+            AdminAction::Freeze | AdminAction::Unfreeze => {}
+        }
+
+        Ok(())
+    }
+
+    /// Execute a proposal if enough current admins have approved it
+    fn execute_if_quorum(
+        env: &Env,
+        executor: &Address,
+        mut proposal: AdminProposal,
+    ) -> Result<bool, AccessControlError> {
+        let admins = AccessControlStorage::get_admins(env);
+        let quorum = AccessControlStorage::get_admin_quorum(env);
+
+        // Approvals from admins removed since approving no longer count
+        let approvals = proposal.approvals.iter().filter(|a| admins.contains(a)).count() as u32;
+        AccessControlEvents::emit_admin_action_approved(env, proposal.id, executor, approvals, quorum);
+
+        if approvals < quorum {
+            return Ok(false);
+        }
+
+        Self::validate_admin_action(env, &proposal.action)?;
+        Self::apply_admin_action(env, executor, &proposal.action);
+
+        proposal.executed = true;
+        AccessControlStorage::set_admin_proposal(env, &proposal);
+        AccessControlEvents::emit_admin_action_executed(env, proposal.id, executor);
+
+        Ok(true)
+    }
+
+    /// Apply a validated admin action
+    fn apply_admin_action(env: &Env, executor: &Address, action: &AdminAction) {
+        match action {
+            AdminAction::AddAdmin(admin) => {
+                let mut admins = AccessControlStorage::get_admins(env);
+                admins.push_back(admin.clone());
+                AccessControlStorage::set_admins(env, &admins);
+
+                let role = RolePermissions::create_role_with_default_permissions(
+                    env,
+                    RoleLevel::SuperAdmin,
+                    executor.clone(),
+                    Clock::now(env),
+                );
+                AccessControlStorage::set_role(env, admin, &role);
+                AccessControlStorage::add_role_grant(env, admin, &role);
+                AccessControlEvents::emit_admin_added(env, executor, admin);
+            }
+            AdminAction::RemoveAdmin(admin) => {
+                let mut admins = AccessControlStorage::get_admins(env);
+                if let Some(index) = admins.first_index_of(admin) {
+                    admins.remove(index);
+                }
+                AccessControlStorage::set_admins(env, &admins);
+
+                // Keep the primary admin pointing at a current member
+                if AccessControlStorage::get_admin(env) == *admin {
+                    AccessControlStorage::set_admin(env, &admins.get_unchecked(0));
+                }
+
+                if let Some(role) = AccessControlStorage::get_role(env, admin) {
+                    AccessControlStorage::add_role_history(env, admin, &role);
+                    AccessControlStorage::add_role_revocation(env, admin, &role);
+                    AccessControlStorage::remove_role(env, admin);
+                }
+                AccessControlEvents::emit_admin_removed(env, executor, admin);
+            }
+            AdminAction::SetQuorum(new_quorum) => {
+                let old_quorum = AccessControlStorage::get_admin_quorum(env);
+                AccessControlStorage::set_admin_quorum(env, *new_quorum);
+                AccessControlEvents::emit_quorum_changed(env, executor, old_quorum, *new_quorum);
+            }
+            AdminAction::GrantRole(user, level) => {
+                let role = RolePermissions::create_role_with_default_permissions(
+                    env,
+                    level.clone(),
+                    executor.clone(),
+                    Clock::now(env),
+                );
+                AccessControlStorage::set_role(env, user, &role);
+                AccessControlStorage::add_role_grant(env, user, &role);
+                AccessControlEvents::emit_role_granted(env, executor, user, &role);
+            }
+            AdminAction::RevokeRole(user) => {
+                if let Some(role) = AccessControlStorage::get_role(env, user) {
+                    AccessControlStorage::add_role_history(env, user, &role);
+                    AccessControlStorage::add_role_revocation(env, user, &role);
+                    AccessControlStorage::remove_role(env, user);
+                    AccessControlEvents::emit_role_revoked(env, executor, user, &role);
+                }
+            }
+            AdminAction::Freeze => {
+                AccessControlStorage::set_frozen(env, true);
+                AccessControlEvents::emit_freeze_toggled(env, executor, true);
+            }
+            AdminAction::Unfreeze => {
+                AccessControlStorage::set_frozen(env, false);
+                AccessControlEvents::emit_freeze_toggled(env, executor, false);
+            }
+        }
+    }
+}
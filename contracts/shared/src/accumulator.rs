@@ -0,0 +1,139 @@
+//! Append-only commitment accumulator
+//!
+//! An incremental Merkle tree of fixed depth that stores only its frontier
+//! (the rightmost filled node on each level), so inserting a commitment
+//! costs one hash per level however many leaves the tree holds. Unlike the
+//! sorted-pair trees in [`crate::merkle`], nodes are hashed in position
+//! order, `sha256(left || right)`, which is what membership circuits
+//! expect. Empty leaves are 32 zero bytes.
+//!
+//! Off-chain provers rebuild the tree from the leaf insertions and prove
+//! membership against a root the contract has published. Recent roots are
+//! kept so a proof built just before another insertion still verifies.
+
+use soroban_sdk::{contracttype, Bytes, BytesN, Env, Symbol, Vec};
+use crate::errors::AccumulatorError;
+
+/// Levels in every accumulator tree, allowing about a million leaves
+pub const TREE_DEPTH: u32 = 20;
+
+/// How many of the latest roots `is_known_root` accepts
+pub const ROOT_HISTORY: u32 = 30;
+
+/// Storage keys for accumulators
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AccumulatorKey {
+    /// Tree state of a named accumulator
+    State(Symbol),
+}
+
+/// Stored state of one accumulator
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccumulatorState {
+    pub size: u32,                 // leaves inserted so far
+    pub frontier: Vec<BytesN<32>>, // rightmost filled node per level
+    pub roots: Vec<BytesN<32>>,    // latest roots, newest last
+}
+
+/// Named append-only commitment trees
+pub struct CommitmentAccumulator;
+
+impl CommitmentAccumulator {
+    /// Hash two sibling nodes into their parent
+    pub fn hash_pair(env: &Env, left: &BytesN<32>, right: &BytesN<32>) -> BytesN<32> {
+        let mut data = Bytes::from_array(env, &left.to_array());
+        data.extend_from_array(&right.to_array());
+        env.crypto().sha256(&data).to_bytes()
+    }
+
+    /// Roots of empty subtrees, from a single empty leaf up to the empty tree
+    pub fn zero_hashes(env: &Env) -> Vec<BytesN<32>> {
+        let mut zeros = Vec::new(env);
+        let mut node = BytesN::from_array(env, &[0; 32]);
+        zeros.push_back(node.clone());
+        for _ in 0..TREE_DEPTH {
+            node = Self::hash_pair(env, &node, &node);
+            zeros.push_back(node.clone());
+        }
+        zeros
+    }
+
+    /// Append a commitment and return its leaf index
+    pub fn insert(env: &Env, name: &Symbol, commitment: &BytesN<32>) -> Result<u32, AccumulatorError> {
+        let zeros = Self::zero_hashes(env);
+        let mut state = Self::get_state(env, name).unwrap_or(AccumulatorState {
+            size: 0,
+            frontier: Vec::new(env),
+            roots: Vec::new(env),
+        });
+        if state.size >= 1 << TREE_DEPTH {
+            return Err(AccumulatorError::TreeFull);
+        }
+
+        let index = state.size;
+        let mut position = index;
+        let mut node = commitment.clone();
+        for level in 0..TREE_DEPTH {
+            if position & 1 == 0 {
+                // Left child: remember it and pair it with an empty right subtree
+                if level < state.frontier.len() {
+                    state.frontier.set(level, node.clone());
+                } else {
+                    state.frontier.push_back(node.clone());
+                }
+                node = Self::hash_pair(env, &node, &zeros.get_unchecked(level));
+            } else {
+                node = Self::hash_pair(env, &state.frontier.get_unchecked(level), &node);
+            }
+            position /= 2;
+        }
+
+        state.size += 1;
+        state.roots.push_back(node);
+        if state.roots.len() > ROOT_HISTORY {
+            state.roots.pop_front();
+        }
+        env.storage().persistent().set(&AccumulatorKey::State(name.clone()), &state);
+
+        Ok(index)
+    }
+
+    /// Get an accumulator's state
+    pub fn get_state(env: &Env, name: &Symbol) -> Option<AccumulatorState> {
+        env.storage().persistent().get(&AccumulatorKey::State(name.clone()))
+    }
+
+    /// Current root; the empty-tree root before any insertion
+    pub fn root(env: &Env, name: &Symbol) -> BytesN<32> {
+        Self::get_state(env, name)
+            .and_then(|state| state.roots.last())
+            .unwrap_or_else(|| Self::zero_hashes(env).get_unchecked(TREE_DEPTH))
+    }
+
+    /// How many commitments have been inserted
+    pub fn size(env: &Env, name: &Symbol) -> u32 {
+        Self::get_state(env, name).map_or(0, |state| state.size)
+    }
+
+    /// Whether `root` is one of the latest roots of the accumulator
+    pub fn is_known_root(env: &Env, name: &Symbol, root: &BytesN<32>) -> bool {
+        Self::get_state(env, name).is_some_and(|state| state.roots.contains(root))
+    }
+
+    /// Fold a leaf and its sibling path (leaf level first) into a root
+    pub fn compute_root(env: &Env, leaf: &BytesN<32>, index: u32, path: &Vec<BytesN<32>>) -> BytesN<32> {
+        let mut node = leaf.clone();
+        let mut position = index;
+        for sibling in path.iter() {
+            node = if position & 1 == 0 {
+                Self::hash_pair(env, &node, &sibling)
+            } else {
+                Self::hash_pair(env, &sibling, &node)
+            };
+            position /= 2;
+        }
+        node
+    }
+}
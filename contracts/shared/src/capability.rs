@@ -0,0 +1,132 @@
+use soroban_sdk::{Address, Env, Symbol};
+use crate::access_control::AccessControl;
+use crate::errors::AccessControlError;
+use crate::events::AccessControlEvents;
+use crate::roles::{Capability, Permission};
+use crate::storage::AccessControlStorage;
+
+/// Single-use capability grants.
+///
+/// A capability lets an address perform exactly one operation gated by a
+/// permission it does not otherwise hold, optionally restricted to a scope
+/// and always bounded by a ledger deadline. This allows tightly scoped
+/// automation keys without handing out broad roles.
+pub struct Capabilities;
+
+impl Capabilities {
+    /// Grant a single-use capability. The granter must hold `permission`.
+    pub fn grant(
+        env: &Env,
+        granter: &Address,
+        holder: &Address,
+        permission: Permission,
+        scope: Option<Symbol>,
+        expires_at_ledger: u32,
+    ) -> Result<u64, AccessControlError> {
+        granter.require_auth();
+
+        if AccessControlStorage::is_frozen(env) {
+            return Err(AccessControlError::Frozen);
+        }
+
+        // Nobody can delegate a permission they do not hold themselves
+        AccessControl::require_permission(env, granter, &permission)?;
+
+        if expires_at_ledger <= env.ledger().sequence() {
+            return Err(AccessControlError::CapabilityExpired);
+        }
+
+        let capability = Capability {
+            id: AccessControlStorage::next_capability_id(env),
+            holder: holder.clone(),
+            permission,
+            scope,
+            granted_by: granter.clone(),
+            expires_at_ledger,
+        };
+        AccessControlStorage::set_capability(env, &capability);
+        AccessControlEvents::emit_capability_granted(env, granter, &capability);
+
+        Ok(capability.id)
+    }
+
+    /// Consume a capability for one operation.
+    ///
+    /// The capability is deleted before returning, so it cannot be replayed
+    /// even if the surrounding operation is retried in the same transaction.
+    /// Callers are expected to have already authenticated `holder`. Like
+    /// grants, capabilities cannot be used while role changes are frozen.
+    pub fn consume(
+        env: &Env,
+        holder: &Address,
+        capability_id: u64,
+        permission: &Permission,
+        scope: &Option<Symbol>,
+    ) -> Result<(), AccessControlError> {
+        if AccessControlStorage::is_frozen(env) {
+            return Err(AccessControlError::Frozen);
+        }
+
+        let capability = AccessControlStorage::get_capability(env, capability_id)
+            .ok_or(AccessControlError::CapabilityNotFound)?;
+
+        if capability.holder != *holder || capability.permission != *permission {
+            return Err(AccessControlError::CapabilityMismatch);
+        }
+
+        // An unscoped capability covers any scope; a scoped one only its own
+        if capability.scope.is_some() && capability.scope != *scope {
+            return Err(AccessControlError::CapabilityMismatch);
+        }
+
+        if env.ledger().sequence() > capability.expires_at_ledger {
+            return Err(AccessControlError::CapabilityExpired);
+        }
+
+        AccessControlStorage::remove_capability(env, capability_id);
+        AccessControlEvents::emit_capability_consumed(env, &capability);
+
+        Ok(())
+    }
+
+    /// Revoke an unused capability. Allowed for the granter or any root admin.
+    pub fn revoke(env: &Env, revoker: &Address, capability_id: u64) -> Result<(), AccessControlError> {
+        revoker.require_auth();
+
+        let capability = AccessControlStorage::get_capability(env, capability_id)
+            .ok_or(AccessControlError::CapabilityNotFound)?;
+
+        if capability.granted_by != *revoker && !AccessControl::is_admin(env, revoker) {
+            return Err(AccessControlError::Unauthorized);
+        }
+
+        AccessControlStorage::remove_capability(env, capability_id);
+        AccessControlEvents::emit_capability_revoked(env, revoker, capability_id);
+
+        Ok(())
+    }
+
+    /// Get an unconsumed capability by id
+    pub fn get_capability(env: &Env, capability_id: u64) -> Option<Capability> {
+        AccessControlStorage::get_capability(env, capability_id)
+    }
+
+    /// Require `permission`, falling back to consuming a capability when the
+    /// user holds no role granting it.
+    pub fn require_permission_or_capability(
+        env: &Env,
+        user: &Address,
+        permission: &Permission,
+        scope: &Option<Symbol>,
+        capability_id: Option<u64>,
+    ) -> Result<(), AccessControlError> {
+        if AccessControl::has_permission(env, user, permission) {
+            return Ok(());
+        }
+
+        match capability_id {
+            Some(capability_id) => Self::consume(env, user, capability_id, permission, scope),
+            None => AccessControl::require_permission(env, user, permission),
+        }
+    }
+}
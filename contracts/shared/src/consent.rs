@@ -0,0 +1,88 @@
+use soroban_sdk::{contracttype, Address, Env};
+use crate::errors::ConsentError;
+use crate::time::Clock;
+
+/// Storage keys for consent grants
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConsentKey {
+    /// Access a holder granted a viewer, keyed by (holder, viewer)
+    Grant(Address, Address),
+}
+
+/// Access a holder has granted a viewer
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConsentGrant {
+    pub holder: Address,
+    pub viewer: Address,
+    pub granted_at: u64,
+    pub expires_at: Option<u64>,
+}
+
+/// Holder-controlled access to detailed credential data.
+///
+/// A holder grants a viewer access, optionally until an expiry, and can
+/// revoke it at any time. Contracts keep existence and validity checks
+/// public and gate detailed data on `require`. Holders always see their
+/// own data.
+pub struct Consent;
+
+impl Consent {
+    /// Grant `viewer` access to `holder`'s data, replacing any earlier grant
+    pub fn grant(
+        env: &Env,
+        holder: &Address,
+        viewer: &Address,
+        expires_at: Option<u64>,
+    ) -> Result<ConsentGrant, ConsentError> {
+        holder.require_auth();
+        if holder == viewer || expires_at.is_some_and(|expires_at| expires_at <= Clock::now(env)) {
+            return Err(ConsentError::InvalidGrant);
+        }
+
+        let grant = ConsentGrant {
+            holder: holder.clone(),
+            viewer: viewer.clone(),
+            granted_at: Clock::now(env),
+            expires_at,
+        };
+        env.storage()
+            .persistent()
+            .set(&ConsentKey::Grant(holder.clone(), viewer.clone()), &grant);
+        Ok(grant)
+    }
+
+    /// Revoke `viewer`'s access to `holder`'s data
+    pub fn revoke(env: &Env, holder: &Address, viewer: &Address) -> Result<(), ConsentError> {
+        holder.require_auth();
+        let key = ConsentKey::Grant(holder.clone(), viewer.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(ConsentError::GrantNotFound);
+        }
+        env.storage().persistent().remove(&key);
+        Ok(())
+    }
+
+    /// Get the grant `holder` gave `viewer`, expired or not
+    pub fn get_grant(env: &Env, holder: &Address, viewer: &Address) -> Option<ConsentGrant> {
+        env.storage()
+            .persistent()
+            .get(&ConsentKey::Grant(holder.clone(), viewer.clone()))
+    }
+
+    /// Whether `viewer` may currently see `holder`'s data
+    pub fn is_granted(env: &Env, holder: &Address, viewer: &Address) -> bool {
+        holder == viewer
+            || Self::get_grant(env, holder, viewer).is_some_and(|grant| !Clock::is_expired(env, grant.expires_at))
+    }
+
+    /// Authenticate `viewer` and check it may see `holder`'s data
+    pub fn require(env: &Env, holder: &Address, viewer: &Address) -> Result<(), ConsentError> {
+        viewer.require_auth();
+        if !Self::is_granted(env, holder, viewer) {
+            return Err(ConsentError::AccessDenied);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use soroban_sdk::{Address, Env, InvokeError, Symbol, TryFromVal, Val, Vec};
+use crate::errors::CrossContractError;
+use crate::event_schema::{StandardEvent, EventData, ErrorEventData};
+
+/// Uniform wrapper around `env.try_invoke_contract`.
+///
+/// Every failed sub-invocation is recorded as a `cross_contract_call_failed`
+/// event and mapped onto [`CrossContractError`], so composing contracts can
+/// propagate failures with `?` instead of matching nested host results.
+pub struct CrossContract;
+
+impl CrossContract {
+    /// Invoke `func` on `contract`, returning a typed error on failure.
+    pub fn invoke<T>(
+        env: &Env,
+        contract: &Address,
+        func: &Symbol,
+        args: Vec<Val>,
+    ) -> Result<T, CrossContractError>
+    where
+        T: TryFromVal<Env, Val>,
+    {
+        match env.try_invoke_contract::<T, InvokeError>(contract, func, args) {
+            Ok(Ok(value)) => Ok(value),
+            Ok(Err(_)) => {
+                Self::record_failure(env, contract, func, None);
+                Err(CrossContractError::InvalidReturnValue)
+            }
+            Err(Ok(InvokeError::Contract(code))) | Err(Err(InvokeError::Contract(code))) => {
+                Self::record_failure(env, contract, func, Some(code));
+                Err(CrossContractError::CalleeFailed)
+            }
+            Err(Ok(InvokeError::Abort)) | Err(Err(InvokeError::Abort)) => {
+                Self::record_failure(env, contract, func, None);
+                Err(CrossContractError::CalleeAborted)
+            }
+        }
+    }
+
+    /// Invoke `func` on `contract` with try-call semantics.
+    ///
+    /// Failures are still recorded, but the caller continues with `None`
+    /// instead of aborting, which suits best-effort notifications and hooks.
+    pub fn try_invoke<T>(
+        env: &Env,
+        contract: &Address,
+        func: &Symbol,
+        args: Vec<Val>,
+    ) -> Option<T>
+    where
+        T: TryFromVal<Env, Val>,
+    {
+        Self::invoke(env, contract, func, args).ok()
+    }
+
+    /// Emit a standard error event describing the failed sub-invocation
+    fn record_failure(env: &Env, contract: &Address, func: &Symbol, error_code: Option<u32>) {
+        let event_data = ErrorEventData::CrossContractCallFailed {
+            callee: contract.clone(),
+            function: func.clone(),
+            error_code,
+        };
+        StandardEvent::new(
+            env,
+            Symbol::new(env, "cross_contract"),
+            env.current_contract_address(),
+            EventData::Error(event_data),
+        ).emit(env);
+    }
+}
@@ -29,4 +29,82 @@ pub enum AccessControlError {
     // Input validation errors
     InvalidAddress = 13,
     InvalidRole = 14,
-} 
\ No newline at end of file
+
+    // Multi-admin quorum errors
+    NotAdmin = 15,
+    AdminAlreadyExists = 16,
+    InvalidQuorum = 17,
+    ProposalNotFound = 18,
+    ProposalAlreadyExecuted = 19,
+    AlreadyApproved = 20,
+    QuorumRequired = 21,
+    Frozen = 22,
+
+    // Capability errors
+    CapabilityNotFound = 23,
+    CapabilityExpired = 24,
+    CapabilityMismatch = 25,
+
+    // Sweep bounty errors
+    InvalidBountyAmount = 26,
+}
+/// Errors surfaced by the shared cross-contract invocation helper
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CrossContractError {
+    // The callee panicked or the host trapped during the call
+    CalleeAborted = 1,
+    // The callee returned one of its own contract errors
+    CalleeFailed = 2,
+    // The callee returned a value of an unexpected type
+    InvalidReturnValue = 3,
+}
+
+/// Errors surfaced by the shared signature and nonce helpers
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SignatureError {
+    // No signing key has been registered for the signer
+    SigningKeyNotFound = 1,
+    // The nonce has already been consumed
+    NonceAlreadyUsed = 2,
+    // The signed payload is past its deadline
+    SignatureExpired = 3,
+}
+
+/// Errors surfaced by the shared fee-splitting helpers
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum FeeError {
+    // Amounts must be positive
+    InvalidAmount = 1,
+    // A split needs at least one share
+    EmptySplit = 2,
+    // Shares must add up to exactly 100%
+    InvalidShares = 3,
+}
+
+/// Errors surfaced by the shared consent helpers
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ConsentError {
+    // A grant must expire in the future and name someone other than the holder
+    InvalidGrant = 1,
+    // The holder has not granted the viewer access
+    GrantNotFound = 2,
+    // The viewer has no current grant from the holder
+    AccessDenied = 3,
+}
+
+/// Errors surfaced by the shared commitment accumulator
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AccumulatorError {
+    // Every leaf of the tree is taken
+    TreeFull = 1,
+}
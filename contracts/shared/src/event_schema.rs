@@ -72,6 +72,16 @@ pub enum AccessControlEventData {
     RoleExpired { user: Address, role_level: u32 },
     AccessDenied { user: Address, permission: String },
     HierarchyViolation { granter: Address, target: Address, target_level: u32 },
+    AdminActionProposed { proposal_id: u64, proposer: Address },
+    AdminActionApproved { proposal_id: u64, approver: Address, approvals: u32, quorum: u32 },
+    AdminActionExecuted { proposal_id: u64, executor: Address },
+    AdminAdded { admin: Address },
+    AdminRemoved { admin: Address },
+    QuorumChanged { old_quorum: u32, new_quorum: u32 },
+    FreezeToggled { frozen: bool },
+    CapabilityGranted { capability_id: u64, granter: Address, holder: Address, permission: String, expires_at_ledger: u32 },
+    CapabilityConsumed { capability_id: u64, holder: Address },
+    CapabilityRevoked { capability_id: u64, revoker: Address },
 }
 
 /// Certificate event data
@@ -153,6 +163,7 @@ pub enum ErrorEventData {
     ResourceNotFound { resource_type: String, resource_id: String },
     InvalidInput { function: String, parameter: String, provided_value: String, expected_format: String },
     SystemError { function: String, error_code: u32, error_message: String },
+    CrossContractCallFailed { callee: Address, function: Symbol, error_code: Option<u32> },
 }
 
 /// Multisig event data
@@ -266,6 +277,16 @@ impl StandardEvent {
                 AccessControlEventData::RoleExpired { .. } => "role_expired",
                 AccessControlEventData::AccessDenied { .. } => "access_denied",
                 AccessControlEventData::HierarchyViolation { .. } => "hierarchy_violation",
+                AccessControlEventData::AdminActionProposed { .. } => "admin_action_proposed",
+                AccessControlEventData::AdminActionApproved { .. } => "admin_action_approved",
+                AccessControlEventData::AdminActionExecuted { .. } => "admin_action_executed",
+                AccessControlEventData::AdminAdded { .. } => "admin_added",
+                AccessControlEventData::AdminRemoved { .. } => "admin_removed",
+                AccessControlEventData::QuorumChanged { .. } => "quorum_changed",
+                AccessControlEventData::FreezeToggled { .. } => "freeze_toggled",
+                AccessControlEventData::CapabilityGranted { .. } => "capability_granted",
+                AccessControlEventData::CapabilityConsumed { .. } => "capability_consumed",
+                AccessControlEventData::CapabilityRevoked { .. } => "capability_revoked",
             },
             EventData::Certificate(data) => match data {
                 CertificateEventData::CertificateMinted { .. } => "certificate_minted",
@@ -329,6 +350,7 @@ impl StandardEvent {
                 ErrorEventData::ResourceNotFound { .. } => "resource_not_found",
                 ErrorEventData::InvalidInput { .. } => "invalid_input",
                 ErrorEventData::SystemError { .. } => "system_error",
+                ErrorEventData::CrossContractCallFailed { .. } => "cross_contract_call_failed",
             },
         }
     }
@@ -1,5 +1,5 @@
 use soroban_sdk::{Address, Env, Symbol, String};
-use crate::roles::{Role, RoleLevel, Permission};
+use crate::roles::{Capability, Role, RoleLevel, Permission};
 use crate::event_schema::{StandardEvent, EventData, AccessControlEventData};
 
 /// RBAC event emissions
@@ -167,4 +167,137 @@ impl AccessControlEvents {
             EventData::AccessControl(event_data),
         ).emit(env);
     }
+
+    /// Emits event when an admin action is proposed
+    pub fn emit_admin_action_proposed(env: &Env, proposal_id: u64, proposer: &Address) {
+        let event_data = AccessControlEventData::AdminActionProposed {
+            proposal_id,
+            proposer: proposer.clone(),
+        };
+        StandardEvent::new(
+            env,
+            Symbol::new(env, "access_control"),
+            proposer.clone(),
+            EventData::AccessControl(event_data),
+        ).emit(env);
+    }
+
+    /// Emits event when an admin approves a pending action
+    pub fn emit_admin_action_approved(env: &Env, proposal_id: u64, approver: &Address, approvals: u32, quorum: u32) {
+        let event_data = AccessControlEventData::AdminActionApproved {
+            proposal_id,
+            approver: approver.clone(),
+            approvals,
+            quorum,
+        };
+        StandardEvent::new(
+            env,
+            Symbol::new(env, "access_control"),
+            approver.clone(),
+            EventData::AccessControl(event_data),
+        ).emit(env);
+    }
+
+    /// Emits event when an admin action reaches quorum and is executed
+    pub fn emit_admin_action_executed(env: &Env, proposal_id: u64, executor: &Address) {
+        let event_data = AccessControlEventData::AdminActionExecuted {
+            proposal_id,
+            executor: executor.clone(),
+        };
+        StandardEvent::new(
+            env,
+            Symbol::new(env, "access_control"),
+            executor.clone(),
+            EventData::AccessControl(event_data),
+        ).emit(env);
+    }
+
+    /// Emits event when an admin joins the root admin set
+    pub fn emit_admin_added(env: &Env, executor: &Address, admin: &Address) {
+        let event_data = AccessControlEventData::AdminAdded { admin: admin.clone() };
+        StandardEvent::new(
+            env,
+            Symbol::new(env, "access_control"),
+            executor.clone(),
+            EventData::AccessControl(event_data),
+        ).emit(env);
+    }
+
+    /// Emits event when an admin leaves the root admin set
+    pub fn emit_admin_removed(env: &Env, executor: &Address, admin: &Address) {
+        let event_data = AccessControlEventData::AdminRemoved { admin: admin.clone() };
+        StandardEvent::new(
+            env,
+            Symbol::new(env, "access_control"),
+            executor.clone(),
+            EventData::AccessControl(event_data),
+        ).emit(env);
+    }
+
+    /// Emits event when the admin quorum changes
+    pub fn emit_quorum_changed(env: &Env, executor: &Address, old_quorum: u32, new_quorum: u32) {
+        let event_data = AccessControlEventData::QuorumChanged { old_quorum, new_quorum };
+        StandardEvent::new(
+            env,
+            Symbol::new(env, "access_control"),
+            executor.clone(),
+            EventData::AccessControl(event_data),
+        ).emit(env);
+    }
+
+    /// Emits event when role changes are frozen or unfrozen
+    pub fn emit_freeze_toggled(env: &Env, executor: &Address, frozen: bool) {
+        let event_data = AccessControlEventData::FreezeToggled { frozen };
+        StandardEvent::new(
+            env,
+            Symbol::new(env, "access_control"),
+            executor.clone(),
+            EventData::AccessControl(event_data),
+        ).emit(env);
+    }
+
+    /// Emits event when a single-use capability is granted
+    pub fn emit_capability_granted(env: &Env, granter: &Address, capability: &Capability) {
+        let event_data = AccessControlEventData::CapabilityGranted {
+            capability_id: capability.id,
+            granter: granter.clone(),
+            holder: capability.holder.clone(),
+            permission: String::from_str(env, capability.permission.to_string()),
+            expires_at_ledger: capability.expires_at_ledger,
+        };
+        StandardEvent::new(
+            env,
+            Symbol::new(env, "access_control"),
+            granter.clone(),
+            EventData::AccessControl(event_data),
+        ).emit(env);
+    }
+
+    /// Emits event when a capability is used up
+    pub fn emit_capability_consumed(env: &Env, capability: &Capability) {
+        let event_data = AccessControlEventData::CapabilityConsumed {
+            capability_id: capability.id,
+            holder: capability.holder.clone(),
+        };
+        StandardEvent::new(
+            env,
+            Symbol::new(env, "access_control"),
+            capability.holder.clone(),
+            EventData::AccessControl(event_data),
+        ).emit(env);
+    }
+
+    /// Emits event when a capability is withdrawn before use
+    pub fn emit_capability_revoked(env: &Env, revoker: &Address, capability_id: u64) {
+        let event_data = AccessControlEventData::CapabilityRevoked {
+            capability_id,
+            revoker: revoker.clone(),
+        };
+        StandardEvent::new(
+            env,
+            Symbol::new(env, "access_control"),
+            revoker.clone(),
+            EventData::AccessControl(event_data),
+        ).emit(env);
+    }
 }
@@ -0,0 +1,89 @@
+//! Fee splitting
+//!
+//! Fees are split between recipients by basis points. Integer division
+//! leaves rounding dust, which always goes to the first share so that the
+//! parts add up to the original amount exactly.
+
+use soroban_sdk::{contracttype, token, Address, Env, Vec};
+use crate::errors::FeeError;
+
+/// Basis points making up 100%
+pub const BASIS_POINTS: u32 = 10_000;
+
+/// One recipient's part of a fee
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeShare {
+    pub recipient: Address,
+    pub bps: u32,
+}
+
+/// Fee calculation and collection helpers
+pub struct Fees;
+
+impl Fees {
+    /// `bps` basis points of `amount`, rounded down
+    pub fn portion(amount: i128, bps: u32) -> i128 {
+        amount * bps as i128 / BASIS_POINTS as i128
+    }
+
+    /// Check that a split is non-empty and adds up to exactly 100%
+    pub fn validate_shares(shares: &Vec<FeeShare>) -> Result<(), FeeError> {
+        if shares.is_empty() {
+            return Err(FeeError::EmptySplit);
+        }
+
+        let mut total: u32 = 0;
+        for share in shares.iter() {
+            total = total.checked_add(share.bps).ok_or(FeeError::InvalidShares)?;
+        }
+        if total != BASIS_POINTS {
+            return Err(FeeError::InvalidShares);
+        }
+
+        Ok(())
+    }
+
+    /// Split `amount` into one part per share, in the same order
+    pub fn split(env: &Env, amount: i128, shares: &Vec<FeeShare>) -> Result<Vec<i128>, FeeError> {
+        if amount <= 0 {
+            return Err(FeeError::InvalidAmount);
+        }
+        Self::validate_shares(shares)?;
+
+        let mut parts = Vec::new(env);
+        let mut distributed = 0;
+        for share in shares.iter() {
+            let part = Self::portion(amount, share.bps);
+            distributed += part;
+            parts.push_back(part);
+        }
+
+        // Rounding dust goes to the first share
+        let first = parts.get_unchecked(0);
+        parts.set(0, first + amount - distributed);
+
+        Ok(parts)
+    }
+
+    /// Transfer `amount` of `token` from `payer` directly to each share's
+    /// recipient. Requires `payer`'s authorization through the token.
+    pub fn collect(
+        env: &Env,
+        token: &Address,
+        payer: &Address,
+        amount: i128,
+        shares: &Vec<FeeShare>,
+    ) -> Result<(), FeeError> {
+        let parts = Self::split(env, amount, shares)?;
+        let client = token::Client::new(env, token);
+
+        for (share, part) in shares.iter().zip(parts.iter()) {
+            if part > 0 {
+                client.transfer(payer, &share.recipient, &part);
+            }
+        }
+
+        Ok(())
+    }
+}
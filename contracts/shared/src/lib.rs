@@ -8,6 +8,15 @@ pub mod errors;
 pub mod reentrancy_guard;
 pub mod validation;
 pub mod gas_testing;
+pub mod cross_contract;
+pub mod time;
+pub mod capability;
+pub mod nonce;
+pub mod signature;
+pub mod merkle;
+pub mod fees;
+pub mod consent;
+pub mod accumulator;
 
 #[cfg(test)]
 mod simple_tests;
@@ -0,0 +1,81 @@
+//! Merkle tree verification
+//!
+//! Trees hash with SHA-256 and combine each pair of nodes in sorted order,
+//! so a proof is just the list of sibling hashes from leaf to root with no
+//! left/right flags.
+//!
+//! Leaves and interior nodes are domain-separated as in RFC 6962: a leaf is
+//! `sha256(0x00 || data)` and a parent is `sha256(0x01 || low || high)`, so
+//! the 64 bytes of an interior node can never be passed off as leaf data.
+//! Off-chain tooling must build trees the same way; this is not compatible
+//! with OpenZeppelin's keccak256-based `StandardMerkleTree`.
+
+use soroban_sdk::{Bytes, BytesN, Env, Vec};
+
+/// Prefix hashed in front of leaf data
+const LEAF_PREFIX: u8 = 0x00;
+
+/// Prefix hashed in front of a pair of child nodes
+const NODE_PREFIX: u8 = 0x01;
+
+/// Merkle proof helpers over SHA-256
+pub struct Merkle;
+
+impl Merkle {
+    /// Hash raw leaf data into a leaf node
+    pub fn hash_leaf(env: &Env, data: &Bytes) -> BytesN<32> {
+        let mut prefixed = Bytes::from_array(env, &[LEAF_PREFIX]);
+        prefixed.append(data);
+        env.crypto().sha256(&prefixed).to_bytes()
+    }
+
+    /// Hash two nodes into their parent, ordering the pair first
+    pub fn hash_pair(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+        let (first, second) = if a <= b { (a, b) } else { (b, a) };
+        let mut data = Bytes::from_array(env, &[NODE_PREFIX]);
+        data.extend_from_array(&first.to_array());
+        data.extend_from_array(&second.to_array());
+        env.crypto().sha256(&data).to_bytes()
+    }
+
+    /// Fold a proof onto `leaf` and return the resulting root
+    pub fn process_proof(env: &Env, leaf: &BytesN<32>, proof: &Vec<BytesN<32>>) -> BytesN<32> {
+        let mut node = leaf.clone();
+        for sibling in proof.iter() {
+            node = Self::hash_pair(env, &node, &sibling);
+        }
+        node
+    }
+
+    /// Check that `leaf` is included in the tree committed to by `root`
+    pub fn verify(env: &Env, root: &BytesN<32>, leaf: &BytesN<32>, proof: &Vec<BytesN<32>>) -> bool {
+        Self::process_proof(env, leaf, proof) == *root
+    }
+
+    /// Compute the root over a list of leaf nodes.
+    ///
+    /// An odd node at the end of a level is promoted unchanged. Returns
+    /// `None` for an empty list.
+    pub fn compute_root(env: &Env, leaves: &Vec<BytesN<32>>) -> Option<BytesN<32>> {
+        if leaves.is_empty() {
+            return None;
+        }
+
+        let mut level = leaves.clone();
+        while level.len() > 1 {
+            let mut next = Vec::new(env);
+            let mut i = 0;
+            while i < level.len() {
+                let left = level.get_unchecked(i);
+                match level.get(i + 1) {
+                    Some(right) => next.push_back(Self::hash_pair(env, &left, &right)),
+                    None => next.push_back(left),
+                }
+                i += 2;
+            }
+            level = next;
+        }
+
+        level.get(0)
+    }
+}
@@ -0,0 +1,33 @@
+use soroban_sdk::{contracttype, Address, Env};
+use crate::errors::SignatureError;
+
+/// Storage keys for replay protection
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum NonceKey {
+    /// Marks a nonce as consumed for an account
+    Used(Address, u64),
+}
+
+/// Unordered nonces for replay protection.
+///
+/// Each `(account, nonce)` pair can be consumed once. Nonces need not be
+/// sequential, so an issuer can hand out many signed payloads that are
+/// redeemed in any order.
+pub struct Nonces;
+
+impl Nonces {
+    /// Check whether a nonce has already been consumed
+    pub fn is_used(env: &Env, account: &Address, nonce: u64) -> bool {
+        env.storage().persistent().has(&NonceKey::Used(account.clone(), nonce))
+    }
+
+    /// Consume a nonce, failing if it was used before
+    pub fn consume(env: &Env, account: &Address, nonce: u64) -> Result<(), SignatureError> {
+        if Self::is_used(env, account, nonce) {
+            return Err(SignatureError::NonceAlreadyUsed);
+        }
+        env.storage().persistent().set(&NonceKey::Used(account.clone(), nonce), &true);
+        Ok(())
+    }
+}
@@ -153,9 +153,10 @@ impl RolePermissions {
 
     /// Remove a permission from a role
     pub fn remove_permission(role: &mut Role, permission: &Permission) {
-        // Since Soroban SDK Vec doesn't have retain, we'll need to rebuild the vector
-        // For now, we'll leave this as a placeholder since it's not used in tests
-        // TODO: Implement proper permission removal when needed
+        // `add_permission` keeps entries unique, so one removal suffices
+        if let Some(index) = role.permissions.first_index_of(permission) {
+            role.permissions.remove(index);
+        }
     }
 
     /// Check if a role can grant another role (hierarchy check)
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, Vec};
+use soroban_sdk::{contracttype, Address, Symbol, Vec};
 
 /// Role hierarchy levels (higher number = more permissions)
 #[contracttype]
@@ -94,6 +94,52 @@ impl Role {
     }
 }
 
+/// Reward paid from the contract balance for each expired role swept
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleSweepBounty {
+    pub token: Address,
+    pub amount_per_role: i128,
+}
+
+/// Sensitive operations that require approval from a quorum of admins
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AdminAction {
+    AddAdmin(Address),
+    RemoveAdmin(Address),
+    SetQuorum(u32),
+    GrantRole(Address, RoleLevel),
+    RevokeRole(Address),
+    Freeze,
+    Unfreeze,
+}
+
+/// A pending or executed admin action and the admins who approved it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminProposal {
+    pub id: u64,
+    pub action: AdminAction,
+    pub proposer: Address,
+    pub approvals: Vec<Address>,
+    pub created_at: u64,
+    pub executed: bool,
+}
+
+/// Single-use grant allowing `holder` to perform one `permission`-gated
+/// operation, optionally limited to one scope (e.g. a course id)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Capability {
+    pub id: u64,
+    pub holder: Address,
+    pub permission: Permission,
+    pub scope: Option<Symbol>,
+    pub granted_by: Address,
+    pub expires_at_ledger: u32,
+}
+
 /// Permission types for the RBAC system
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -141,6 +187,10 @@ pub enum Permission {
     ViewAllCourses,
     ViewAllUsers,
     ViewSystemStats,
+
+    // Compliance permissions, held by a dedicated ComplianceOfficer role
+    // rather than by default
+    ProcessErasure,
 }
 
 impl Permission {
@@ -174,6 +224,7 @@ impl Permission {
             Permission::ViewAllCourses => "ViewAllCourses",
             Permission::ViewAllUsers => "ViewAllUsers",
             Permission::ViewSystemStats => "ViewSystemStats",
+            Permission::ProcessErasure => "ProcessErasure",
         }
     }
 } 
\ No newline at end of file
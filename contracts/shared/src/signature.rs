@@ -0,0 +1,89 @@
+use soroban_sdk::{contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env};
+use crate::errors::SignatureError;
+use crate::nonce::Nonces;
+use crate::time::Clock;
+
+/// Prefix of every message signed for `verify_once`, so the bytes can't be
+/// mistaken for another protocol's signed data
+pub const SIGNATURE_DOMAIN: &[u8] = b"StrellerMinds:verify_once:v1";
+
+/// Storage keys for registered signing keys
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SignatureKey {
+    /// ed25519 public key an address signs off-chain payloads with
+    SigningKey(Address),
+}
+
+/// Verification of off-chain ed25519 signatures.
+///
+/// Addresses register the public key they sign off-chain payloads with;
+/// contracts then verify a payload against the signer's registered key and
+/// consume its nonce in one step.
+pub struct SignatureVerifier;
+
+impl SignatureVerifier {
+    /// Register (or rotate) the signing key for `owner`
+    pub fn set_signing_key(env: &Env, owner: &Address, public_key: &BytesN<32>) {
+        owner.require_auth();
+        env.storage()
+            .persistent()
+            .set(&SignatureKey::SigningKey(owner.clone()), public_key);
+    }
+
+    /// Get the signing key registered for `owner`
+    pub fn get_signing_key(env: &Env, owner: &Address) -> Option<BytesN<32>> {
+        env.storage().persistent().get(&SignatureKey::SigningKey(owner.clone()))
+    }
+
+    /// Verify `signature` over `payload` against `signer`'s registered key.
+    ///
+    /// An invalid signature traps in the host, aborting the invocation.
+    pub fn verify(
+        env: &Env,
+        signer: &Address,
+        payload: &Bytes,
+        signature: &BytesN<64>,
+    ) -> Result<(), SignatureError> {
+        let public_key = Self::get_signing_key(env, signer)
+            .ok_or(SignatureError::SigningKeyNotFound)?;
+        env.crypto().ed25519_verify(&public_key, payload, signature);
+        Ok(())
+    }
+
+    /// The bytes signed for `verify_once`: `SIGNATURE_DOMAIN || contract ||
+    /// payload || nonce || deadline`, where `contract` is the verifying
+    /// contract's address as XDR and both numbers are big-endian. Nonces are
+    /// tracked per contract, so binding the contract stops a signature being
+    /// replayed on another contract that trusts the same key.
+    pub fn signed_message(env: &Env, payload: &Bytes, nonce: u64, deadline: u64) -> Bytes {
+        let mut message = Bytes::from_slice(env, SIGNATURE_DOMAIN);
+        message.append(&env.current_contract_address().to_xdr(env));
+        message.append(payload);
+        message.extend_from_array(&nonce.to_be_bytes());
+        message.extend_from_array(&deadline.to_be_bytes());
+        message
+    }
+
+    /// Verify a signature over `signed_message(payload, nonce, deadline)` and
+    /// consume the nonce so it cannot be replayed.
+    ///
+    /// The contract, nonce and deadline are always part of the signed bytes,
+    /// so a signature can't be reused on another contract, with a different
+    /// nonce or with a later deadline.
+    pub fn verify_once(
+        env: &Env,
+        signer: &Address,
+        payload: &Bytes,
+        signature: &BytesN<64>,
+        nonce: u64,
+        deadline: u64,
+    ) -> Result<(), SignatureError> {
+        if Clock::has_passed(env, deadline) {
+            return Err(SignatureError::SignatureExpired);
+        }
+        let message = Self::signed_message(env, payload, nonce, deadline);
+        Self::verify(env, signer, &message, signature)?;
+        Nonces::consume(env, signer, nonce)
+    }
+}
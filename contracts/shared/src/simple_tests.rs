@@ -51,4 +51,981 @@ fn test_role_level_conversions() {
     assert_eq!(RoleLevel::Instructor.to_u32(), 3);
     assert_eq!(RoleLevel::Admin.to_u32(), 4);
     assert_eq!(RoleLevel::SuperAdmin.to_u32(), 5);
-}
\ No newline at end of file
+}
+mod cross_contract_tests {
+    use crate::cross_contract::CrossContract;
+    use crate::errors::CrossContractError;
+    use soroban_sdk::{
+        contract, contracterror, contractimpl, testutils::Events, vec, Env, IntoVal, Symbol,
+    };
+
+    #[contracterror]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[repr(u32)]
+    pub enum CalleeError {
+        Rejected = 7,
+    }
+
+    #[contract]
+    pub struct Callee;
+
+    #[contractimpl]
+    impl Callee {
+        pub fn double(_env: Env, value: u32) -> u32 {
+            value * 2
+        }
+
+        pub fn reject(_env: Env) -> Result<u32, CalleeError> {
+            Err(CalleeError::Rejected)
+        }
+
+        pub fn abort(_env: Env) -> u32 {
+            panic!("callee aborted")
+        }
+    }
+
+    #[contract]
+    pub struct Caller;
+
+    #[test]
+    fn test_invoke_returns_value() {
+        let env = Env::default();
+        let callee = env.register(Callee, ());
+        let caller = env.register(Caller, ());
+
+        let result: Result<u32, CrossContractError> = env.as_contract(&caller, || {
+            CrossContract::invoke(&env, &callee, &Symbol::new(&env, "double"), vec![&env, 21u32.into_val(&env)])
+        });
+        assert_eq!(result, Ok(42));
+        assert_eq!(env.events().all().len(), 0);
+    }
+
+    #[test]
+    fn test_invoke_maps_callee_errors() {
+        let env = Env::default();
+        let callee = env.register(Callee, ());
+        let caller = env.register(Caller, ());
+
+        env.as_contract(&caller, || {
+            let failed: Result<u32, CrossContractError> =
+                CrossContract::invoke(&env, &callee, &Symbol::new(&env, "reject"), vec![&env]);
+            assert_eq!(failed, Err(CrossContractError::CalleeFailed));
+
+            let aborted: Result<u32, CrossContractError> =
+                CrossContract::invoke(&env, &callee, &Symbol::new(&env, "abort"), vec![&env]);
+            assert_eq!(aborted, Err(CrossContractError::CalleeAborted));
+
+            let mistyped: Result<bool, CrossContractError> =
+                CrossContract::invoke(&env, &callee, &Symbol::new(&env, "double"), vec![&env, 1u32.into_val(&env)]);
+            assert_eq!(mistyped, Err(CrossContractError::InvalidReturnValue));
+        });
+        assert_eq!(env.events().all().len(), 3);
+    }
+
+    #[test]
+    fn test_try_invoke_swallows_failures() {
+        let env = Env::default();
+        let callee = env.register(Callee, ());
+        let caller = env.register(Caller, ());
+
+        let result: Option<u32> = env.as_contract(&caller, || {
+            CrossContract::try_invoke(&env, &callee, &Symbol::new(&env, "reject"), vec![&env])
+        });
+        assert_eq!(result, None);
+        assert_eq!(env.events().all().len(), 1);
+    }
+}
+
+mod time_tests {
+    use crate::time::{Clock, ExpiryState, DAY};
+    use soroban_sdk::{testutils::Ledger, Env};
+
+    #[test]
+    fn test_clock_follows_ledger() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_000);
+
+        assert_eq!(Clock::now(&env), 1_000);
+        assert_eq!(Clock::after(&env, DAY), 1_000 + DAY);
+        assert_eq!(Clock::after(&env, u64::MAX), u64::MAX);
+        assert!(Clock::has_passed(&env, 999));
+        assert!(!Clock::has_passed(&env, 1_000));
+        assert_eq!(Clock::remaining(&env, 1_500), 500);
+        assert_eq!(Clock::remaining(&env, 500), 0);
+    }
+
+    #[test]
+    fn test_expiry_state_with_grace_period() {
+        let env = Env::default();
+        let expires_at = 10 * DAY;
+
+        env.ledger().set_timestamp(expires_at);
+        assert_eq!(Clock::expiry_state(&env, Some(expires_at), DAY), ExpiryState::Active);
+        assert_eq!(Clock::expiry_state(&env, None, 0), ExpiryState::Active);
+
+        env.ledger().set_timestamp(expires_at + DAY);
+        assert_eq!(Clock::expiry_state(&env, Some(expires_at), DAY), ExpiryState::InGracePeriod);
+        assert!(Clock::is_expired(&env, Some(expires_at)));
+
+        env.ledger().set_timestamp(expires_at + DAY + 1);
+        assert_eq!(Clock::expiry_state(&env, Some(expires_at), DAY), ExpiryState::Expired);
+        assert!(!Clock::in_grace_period(&env, expires_at, DAY));
+    }
+}
+
+mod role_sweep_tests {
+    use crate::access_control::AccessControl;
+    use crate::errors::AccessControlError;
+    use crate::roles::{Permission, Role, RoleLevel, RoleSweepBounty};
+    use crate::storage::{AccessControlStorage, DataKey, ROLE_HOLDER_PAGE_SIZE};
+    use soroban_sdk::{
+        contract, testutils::{Address as _, Events, Ledger}, token, Address, Env, Vec,
+    };
+
+    #[contract]
+    pub struct Host;
+
+    fn grant_expiring(env: &Env, admin: &Address, user: &Address, expires_at: u64) {
+        let mut permissions = Vec::new(env);
+        permissions.push_back(Permission::ViewProgress);
+        let role = Role::new(RoleLevel::Student, permissions, admin.clone(), 0).with_expiry(expires_at);
+        AccessControlStorage::set_role(env, user, &role);
+    }
+
+    #[test]
+    fn test_cleanup_removes_only_expired_roles() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let host = env.register(Host, ());
+        let admin = Address::generate(&env);
+        let expired_user = Address::generate(&env);
+        let active_user = Address::generate(&env);
+        let sweeper = Address::generate(&env);
+
+        env.as_contract(&host, || {
+            AccessControl::initialize(&env, &admin).unwrap();
+            grant_expiring(&env, &admin, &expired_user, 100);
+            grant_expiring(&env, &admin, &active_user, 1_000);
+            assert_eq!(AccessControlStorage::get_role_holder_count(&env), 3);
+        });
+
+        env.ledger().set_timestamp(500);
+        env.as_contract(&host, || {
+            assert_eq!(AccessControl::cleanup_expired_roles(&env, &sweeper, 10), Ok(1));
+            assert!(AccessControl::get_role(&env, &expired_user).is_none());
+            assert!(AccessControl::get_role(&env, &active_user).is_some());
+            assert!(AccessControl::get_role(&env, &admin).is_some());
+            assert_eq!(AccessControl::get_role_revocations(&env, &expired_user).len(), 1);
+            assert_eq!(AccessControlStorage::get_role_holder_count(&env), 2);
+        });
+
+        // Nothing left to sweep
+        env.as_contract(&host, || {
+            assert_eq!(AccessControl::cleanup_expired_roles(&env, &sweeper, 10), Ok(0));
+        });
+    }
+
+    #[test]
+    fn test_cleanup_respects_limit_and_resumes() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let host = env.register(Host, ());
+        let admin = Address::generate(&env);
+        let sweeper = Address::generate(&env);
+
+        env.as_contract(&host, || {
+            AccessControl::initialize(&env, &admin).unwrap();
+            for _ in 0..4 {
+                grant_expiring(&env, &admin, &Address::generate(&env), 100);
+            }
+        });
+
+        env.ledger().set_timestamp(500);
+        // First window holds the non-expiring admin plus one expired role
+        for expected in [1, 2, 1] {
+            env.as_contract(&host, || {
+                assert_eq!(AccessControl::cleanup_expired_roles(&env, &sweeper, 2), Ok(expected));
+            });
+        }
+        env.as_contract(&host, || {
+            assert_eq!(AccessControlStorage::get_role_holder_count(&env), 1);
+        });
+    }
+
+    #[test]
+    fn test_cleanup_cursor_survives_wrapped_window() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let host = env.register(Host, ());
+        let admin = Address::generate(&env);
+        let sweeper = Address::generate(&env);
+        let mut users = Vec::new(&env);
+
+        // Holders 0 and 1 expire; every other holder stays
+        env.as_contract(&host, || {
+            AccessControl::initialize(&env, &admin).unwrap();
+            AccessControlStorage::remove_role(&env, &admin);
+            for index in 0..10 {
+                let user = Address::generate(&env);
+                grant_expiring(&env, &admin, &user, if index < 2 { 100 } else { 1_000 });
+                users.push_back(user);
+            }
+            AccessControlStorage::set_sweep_cursor(&env, (0, 8));
+        });
+
+        env.ledger().set_timestamp(500);
+        env.as_contract(&host, || {
+            // Examines 8, 9, 0 and 1, then resumes at the original holder 2
+            assert_eq!(AccessControl::cleanup_expired_roles(&env, &sweeper, 4), Ok(2));
+            let (page, offset) = AccessControlStorage::get_sweep_cursor(&env);
+            let holders = AccessControlStorage::get_role_holder_page(&env, page);
+            assert_eq!(holders.get_unchecked(offset), users.get_unchecked(2));
+        });
+    }
+
+    #[test]
+    fn test_cleanup_walks_holder_pages() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let host = env.register(Host, ());
+        let admin = Address::generate(&env);
+        let sweeper = Address::generate(&env);
+
+        env.as_contract(&host, || {
+            AccessControl::initialize(&env, &admin).unwrap();
+            for _ in 0..2 * ROLE_HOLDER_PAGE_SIZE {
+                grant_expiring(&env, &admin, &Address::generate(&env), 100);
+            }
+            assert_eq!(AccessControlStorage::get_role_holder_page_count(&env), 3);
+        });
+
+        env.ledger().set_timestamp(500);
+        // The first window covers page 0 (the admin stays) and part of page 1;
+        // the second finishes pages 1 and 2 and wraps back to the admin
+        for expected in [59, 41] {
+            env.as_contract(&host, || {
+                assert_eq!(AccessControl::cleanup_expired_roles(&env, &sweeper, 60), Ok(expected));
+            });
+        }
+        env.as_contract(&host, || {
+            assert_eq!(AccessControlStorage::get_role_holder_count(&env), 1);
+            assert_eq!(AccessControlStorage::get_role_holder_page_count(&env), 1);
+            assert_eq!(AccessControlStorage::get_role_holder_page(&env, 0).get_unchecked(0), admin);
+        });
+    }
+
+    #[test]
+    fn test_backfill_indexes_roles_granted_before_the_index() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let host = env.register(Host, ());
+        let admin = Address::generate(&env);
+        let legacy = Address::generate(&env);
+        let stranger = Address::generate(&env);
+        let sweeper = Address::generate(&env);
+
+        env.as_contract(&host, || {
+            AccessControl::initialize(&env, &admin).unwrap();
+            // Written the way roles were stored before the holder index
+            let mut permissions = Vec::new(&env);
+            permissions.push_back(Permission::ViewProgress);
+            let role = Role::new(RoleLevel::Student, permissions, admin.clone(), 0).with_expiry(100);
+            env.storage().instance().set(&DataKey::Role(legacy.clone()), &role);
+            AccessControlStorage::unindex_role_holder(&env, &admin);
+
+            let mut users = Vec::new(&env);
+            users.push_back(legacy.clone());
+            users.push_back(stranger.clone());
+            assert_eq!(AccessControl::backfill_role_holders(&env, &admin, users.clone()), Ok(2));
+            assert_eq!(AccessControl::backfill_role_holders(&env, &admin, users), Ok(0));
+            assert_eq!(AccessControlStorage::get_role_holder_count(&env), 2);
+        });
+
+        env.ledger().set_timestamp(500);
+        env.as_contract(&host, || {
+            assert_eq!(AccessControl::cleanup_expired_roles(&env, &sweeper, 10), Ok(1));
+            assert!(AccessControl::get_role(&env, &legacy).is_none());
+        });
+    }
+
+    #[test]
+    fn test_cleanup_pays_bounty() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let host = env.register(Host, ());
+        let admin = Address::generate(&env);
+        let sweeper = Address::generate(&env);
+        let asset = env.register_stellar_asset_contract_v2(admin.clone());
+        token::StellarAssetClient::new(&env, &asset.address()).mint(&host, &1_000);
+
+        env.as_contract(&host, || {
+            AccessControl::initialize(&env, &admin).unwrap();
+            let bounty = RoleSweepBounty { token: asset.address(), amount_per_role: 10 };
+            AccessControl::set_cleanup_bounty(&env, &admin, Some(bounty)).unwrap();
+            grant_expiring(&env, &admin, &Address::generate(&env), 100);
+            grant_expiring(&env, &admin, &Address::generate(&env), 100);
+        });
+
+        env.ledger().set_timestamp(500);
+        env.as_contract(&host, || {
+            assert_eq!(AccessControl::cleanup_expired_roles(&env, &sweeper, 10), Ok(2));
+        });
+        assert_eq!(token::Client::new(&env, &asset.address()).balance(&sweeper), 20);
+    }
+
+    #[test]
+    fn test_set_cleanup_bounty_requires_super_admin() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let host = env.register(Host, ());
+        let admin = Address::generate(&env);
+        let instructor = Address::generate(&env);
+        let token = Address::generate(&env);
+
+        env.as_contract(&host, || {
+            AccessControl::initialize(&env, &admin).unwrap();
+            AccessControl::grant_role(&env, &admin, &instructor, RoleLevel::Instructor).unwrap();
+            let bounty = RoleSweepBounty { token: token.clone(), amount_per_role: 10 };
+            assert!(AccessControl::set_cleanup_bounty(&env, &instructor, Some(bounty)).is_err());
+        });
+
+        // An invalid amount is an argument error, not an access denial
+        env.as_contract(&host, || {
+            let bounty = RoleSweepBounty { token, amount_per_role: 0 };
+            assert_eq!(
+                AccessControl::set_cleanup_bounty(&env, &instructor, Some(bounty)),
+                Err(AccessControlError::InvalidBountyAmount)
+            );
+        });
+        assert!(env.events().all().is_empty());
+    }
+}
+
+mod admin_quorum_tests {
+    use crate::access_control::AccessControl;
+    use crate::errors::AccessControlError;
+    use crate::roles::{AdminAction, RoleLevel};
+    use soroban_sdk::{contract, testutils::Address as _, Address, Env};
+
+    #[contract]
+    pub struct Host;
+
+    fn setup() -> (Env, Address, Address, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let host = env.register(Host, ());
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+
+        env.as_contract(&host, || AccessControl::initialize(&env, &first).unwrap());
+        env.as_contract(&host, || {
+            AccessControl::propose_admin_action(&env, &first, AdminAction::AddAdmin(second.clone())).unwrap();
+        });
+        env.as_contract(&host, || {
+            AccessControl::propose_admin_action(&env, &first, AdminAction::SetQuorum(2)).unwrap();
+        });
+        (env, host, first, second)
+    }
+
+    #[test]
+    fn test_single_admin_actions_execute_immediately() {
+        let (env, host, first, second) = setup();
+
+        env.as_contract(&host, || {
+            assert_eq!(AccessControl::get_admins(&env).len(), 2);
+            assert!(AccessControl::is_admin(&env, &second));
+            assert_eq!(AccessControl::get_admin_quorum(&env), 2);
+            assert_eq!(AccessControl::get_role(&env, &second).unwrap().level, RoleLevel::SuperAdmin);
+            assert!(AccessControl::get_admin_proposal(&env, 2).unwrap().executed);
+            assert!(AccessControl::is_admin(&env, &first));
+        });
+    }
+
+    #[test]
+    fn test_quorum_gates_sensitive_operations() {
+        let (env, host, first, second) = setup();
+        let user = Address::generate(&env);
+
+        // Admin-level grants must go through a proposal once quorum > 1
+        env.as_contract(&host, || {
+            assert_eq!(
+                AccessControl::grant_role(&env, &first, &user, RoleLevel::Admin),
+                Err(AccessControlError::QuorumRequired)
+            );
+            assert_eq!(
+                AccessControl::change_admin(&env, &first, &user),
+                Err(AccessControlError::QuorumRequired)
+            );
+        });
+
+        let proposal_id = env.as_contract(&host, || {
+            AccessControl::propose_admin_action(&env, &first, AdminAction::GrantRole(user.clone(), RoleLevel::Admin)).unwrap()
+        });
+        env.as_contract(&host, || {
+            assert!(AccessControl::get_role(&env, &user).is_none());
+            assert_eq!(
+                AccessControl::approve_admin_action(&env, &first, proposal_id),
+                Err(AccessControlError::AlreadyApproved)
+            );
+        });
+        env.as_contract(&host, || {
+            assert_eq!(AccessControl::approve_admin_action(&env, &second, proposal_id), Ok(true));
+            assert_eq!(AccessControl::get_role(&env, &user).unwrap().level, RoleLevel::Admin);
+        });
+        env.as_contract(&host, || {
+            assert_eq!(
+                AccessControl::approve_admin_action(&env, &second, proposal_id),
+                Err(AccessControlError::ProposalAlreadyExecuted)
+            );
+        });
+    }
+
+    #[test]
+    fn test_freeze_blocks_role_changes() {
+        let (env, host, first, second) = setup();
+        let user = Address::generate(&env);
+
+        let freeze = env.as_contract(&host, || {
+            AccessControl::propose_admin_action(&env, &first, AdminAction::Freeze).unwrap()
+        });
+        env.as_contract(&host, || {
+            AccessControl::approve_admin_action(&env, &second, freeze).unwrap();
+            assert!(AccessControl::is_frozen(&env));
+            assert_eq!(
+                AccessControl::grant_role(&env, &first, &user, RoleLevel::Student),
+                Err(AccessControlError::Frozen)
+            );
+        });
+
+        let unfreeze = env.as_contract(&host, || {
+            AccessControl::propose_admin_action(&env, &second, AdminAction::Unfreeze).unwrap()
+        });
+        env.as_contract(&host, || {
+            AccessControl::approve_admin_action(&env, &first, unfreeze).unwrap();
+            assert!(!AccessControl::is_frozen(&env));
+            assert!(AccessControl::grant_role(&env, &first, &user, RoleLevel::Student).is_ok());
+        });
+    }
+
+    #[test]
+    fn test_admin_set_cannot_drop_below_quorum() {
+        let (env, host, first, second) = setup();
+        let outsider = Address::generate(&env);
+
+        env.as_contract(&host, || {
+            assert_eq!(
+                AccessControl::propose_admin_action(&env, &first, AdminAction::RemoveAdmin(second.clone())),
+                Err(AccessControlError::InvalidQuorum)
+            );
+        });
+        env.as_contract(&host, || {
+            assert_eq!(
+                AccessControl::propose_admin_action(&env, &first, AdminAction::SetQuorum(3)),
+                Err(AccessControlError::InvalidQuorum)
+            );
+        });
+        env.as_contract(&host, || {
+            assert_eq!(
+                AccessControl::propose_admin_action(&env, &outsider, AdminAction::Freeze),
+                Err(AccessControlError::NotAdmin)
+            );
+        });
+
+        // Lower the quorum, then removing an admin is allowed
+        let lower = env.as_contract(&host, || {
+            AccessControl::propose_admin_action(&env, &first, AdminAction::SetQuorum(1)).unwrap()
+        });
+        env.as_contract(&host, || AccessControl::approve_admin_action(&env, &second, lower).unwrap());
+        env.as_contract(&host, || {
+            AccessControl::propose_admin_action(&env, &second, AdminAction::RemoveAdmin(first.clone())).unwrap();
+            assert!(!AccessControl::is_admin(&env, &first));
+            assert!(AccessControl::get_role(&env, &first).is_none());
+            assert_eq!(AccessControl::get_admin(&env), Ok(second.clone()));
+        });
+    }
+}
+
+mod capability_tests {
+    use crate::access_control::AccessControl;
+    use crate::capability::Capabilities;
+    use crate::errors::AccessControlError;
+    use crate::roles::{Permission, RoleLevel};
+    use crate::storage::AccessControlStorage;
+    use soroban_sdk::{contract, symbol_short, testutils::{Address as _, Ledger}, Address, Env};
+
+    #[contract]
+    pub struct Host;
+
+    fn setup() -> (Env, Address, Address, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let host = env.register(Host, ());
+        let admin = Address::generate(&env);
+        let bot = Address::generate(&env);
+        env.as_contract(&host, || AccessControl::initialize(&env, &admin).unwrap());
+        (env, host, admin, bot)
+    }
+
+    #[test]
+    fn test_capability_is_single_use() {
+        let (env, host, admin, bot) = setup();
+        let scope = Some(symbol_short!("RUST101"));
+
+        let id = env.as_contract(&host, || {
+            Capabilities::grant(&env, &admin, &bot, Permission::IssueCertificate, scope.clone(), 100).unwrap()
+        });
+        env.as_contract(&host, || {
+            assert!(!AccessControl::has_permission(&env, &bot, &Permission::IssueCertificate));
+            assert_eq!(
+                Capabilities::require_permission_or_capability(&env, &bot, &Permission::IssueCertificate, &scope, Some(id)),
+                Ok(())
+            );
+            assert!(Capabilities::get_capability(&env, id).is_none());
+            assert_eq!(
+                Capabilities::consume(&env, &bot, id, &Permission::IssueCertificate, &scope),
+                Err(AccessControlError::CapabilityNotFound)
+            );
+        });
+    }
+
+    #[test]
+    fn test_capability_scope_and_expiry_are_enforced() {
+        let (env, host, admin, bot) = setup();
+        let scope = Some(symbol_short!("RUST101"));
+
+        let id = env.as_contract(&host, || {
+            Capabilities::grant(&env, &admin, &bot, Permission::IssueCertificate, scope.clone(), 100).unwrap()
+        });
+        env.as_contract(&host, || {
+            assert_eq!(
+                Capabilities::consume(&env, &bot, id, &Permission::IssueCertificate, &Some(symbol_short!("GO101"))),
+                Err(AccessControlError::CapabilityMismatch)
+            );
+            assert_eq!(
+                Capabilities::consume(&env, &bot, id, &Permission::RevokeCertificate, &scope),
+                Err(AccessControlError::CapabilityMismatch)
+            );
+            assert_eq!(
+                Capabilities::consume(&env, &admin, id, &Permission::IssueCertificate, &scope),
+                Err(AccessControlError::CapabilityMismatch)
+            );
+        });
+
+        env.ledger().set_sequence_number(101);
+        env.as_contract(&host, || {
+            assert_eq!(
+                Capabilities::consume(&env, &bot, id, &Permission::IssueCertificate, &scope),
+                Err(AccessControlError::CapabilityExpired)
+            );
+        });
+    }
+
+    #[test]
+    fn test_capability_cannot_be_consumed_while_frozen() {
+        let (env, host, admin, bot) = setup();
+
+        let id = env.as_contract(&host, || {
+            Capabilities::grant(&env, &admin, &bot, Permission::IssueCertificate, None, 100).unwrap()
+        });
+        env.as_contract(&host, || {
+            AccessControlStorage::set_frozen(&env, true);
+            assert_eq!(
+                Capabilities::consume(&env, &bot, id, &Permission::IssueCertificate, &None),
+                Err(AccessControlError::Frozen)
+            );
+            assert!(Capabilities::get_capability(&env, id).is_some());
+        });
+        env.as_contract(&host, || {
+            AccessControlStorage::set_frozen(&env, false);
+            assert_eq!(Capabilities::consume(&env, &bot, id, &Permission::IssueCertificate, &None), Ok(()));
+        });
+    }
+
+    #[test]
+    fn test_capability_grant_requires_permission_and_revoke() {
+        let (env, host, admin, bot) = setup();
+        let student = Address::generate(&env);
+
+        env.as_contract(&host, || {
+            AccessControl::grant_role(&env, &admin, &student, RoleLevel::Student).unwrap();
+        });
+        env.as_contract(&host, || {
+            assert_eq!(
+                Capabilities::grant(&env, &student, &bot, Permission::IssueCertificate, None, 100),
+                Err(AccessControlError::PermissionDenied)
+            );
+        });
+
+        let id = env.as_contract(&host, || {
+            Capabilities::grant(&env, &admin, &bot, Permission::IssueCertificate, None, 100).unwrap()
+        });
+        env.as_contract(&host, || {
+            assert_eq!(Capabilities::revoke(&env, &bot, id), Err(AccessControlError::Unauthorized));
+        });
+        env.as_contract(&host, || {
+            Capabilities::revoke(&env, &admin, id).unwrap();
+            assert!(Capabilities::get_capability(&env, id).is_none());
+        });
+    }
+}
+
+mod signature_tests {
+    use crate::errors::SignatureError;
+    use crate::nonce::Nonces;
+    use crate::signature::{SignatureVerifier, SIGNATURE_DOMAIN};
+    use ed25519_dalek::{Signer, SigningKey};
+    use soroban_sdk::{contract, testutils::{Address as _, Ledger}, xdr::ToXdr, Address, Bytes, BytesN, Env};
+
+    #[contract]
+    pub struct Host;
+
+    fn setup() -> (Env, Address, Address, SigningKey) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let host = env.register(Host, ());
+        let issuer = Address::generate(&env);
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+
+        let public_key = BytesN::from_array(&env, &key.verifying_key().to_bytes());
+        env.as_contract(&host, || {
+            SignatureVerifier::set_signing_key(&env, &issuer, &public_key);
+        });
+
+        (env, host, issuer, key)
+    }
+
+    fn sign(env: &Env, key: &SigningKey, payload: &[u8]) -> BytesN<64> {
+        BytesN::from_array(env, &key.sign(payload).to_bytes())
+    }
+
+    fn sign_once(
+        env: &Env,
+        key: &SigningKey,
+        host: &Address,
+        payload: &[u8],
+        nonce: u64,
+        deadline: u64,
+    ) -> BytesN<64> {
+        let mut message = std::vec::Vec::from(SIGNATURE_DOMAIN);
+        message.extend(host.clone().to_xdr(env).iter());
+        message.extend_from_slice(payload);
+        message.extend_from_slice(&nonce.to_be_bytes());
+        message.extend_from_slice(&deadline.to_be_bytes());
+        sign(env, key, &message)
+    }
+
+    #[test]
+    fn test_verify_once_rejects_replay() {
+        let (env, host, issuer, key) = setup();
+        let payload = Bytes::from_slice(&env, b"voucher-1");
+        let signature = sign_once(&env, &key, &host, b"voucher-1", 1, 1_000);
+
+        env.as_contract(&host, || {
+            SignatureVerifier::verify_once(&env, &issuer, &payload, &signature, 1, 1_000).unwrap();
+            assert!(Nonces::is_used(&env, &issuer, 1));
+            assert_eq!(
+                SignatureVerifier::verify_once(&env, &issuer, &payload, &signature, 1, 1_000),
+                Err(SignatureError::NonceAlreadyUsed)
+            );
+        });
+    }
+
+    #[test]
+    fn test_verify_requires_key_and_deadline() {
+        let (env, host, issuer, key) = setup();
+        let stranger = Address::generate(&env);
+        let payload = Bytes::from_slice(&env, b"voucher-2");
+        let signature = sign(&env, &key, b"voucher-2");
+
+        env.as_contract(&host, || {
+            assert_eq!(
+                SignatureVerifier::verify(&env, &stranger, &payload, &signature),
+                Err(SignatureError::SigningKeyNotFound)
+            );
+        });
+
+        env.ledger().set_timestamp(1_001);
+        env.as_contract(&host, || {
+            assert_eq!(
+                SignatureVerifier::verify_once(&env, &issuer, &payload, &signature, 2, 1_000),
+                Err(SignatureError::SignatureExpired)
+            );
+            assert!(!Nonces::is_used(&env, &issuer, 2));
+        });
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_verify_once_binds_nonce_into_signature() {
+        let (env, host, issuer, key) = setup();
+        let payload = Bytes::from_slice(&env, b"voucher-4");
+        let signature = sign_once(&env, &key, &host, b"voucher-4", 4, 1_000);
+
+        // Replaying the signature under a fresh nonce no longer verifies
+        env.as_contract(&host, || {
+            let _ = SignatureVerifier::verify_once(&env, &issuer, &payload, &signature, 5, 1_000);
+        });
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_verify_once_binds_contract_into_signature() {
+        let (env, host, issuer, key) = setup();
+        let other = env.register(Host, ());
+        let public_key = BytesN::from_array(&env, &key.verifying_key().to_bytes());
+        env.as_contract(&other, || {
+            SignatureVerifier::set_signing_key(&env, &issuer, &public_key);
+        });
+        let payload = Bytes::from_slice(&env, b"voucher-6");
+        let signature = sign_once(&env, &key, &host, b"voucher-6", 6, 1_000);
+
+        // Another contract trusting the same key rejects the host's voucher
+        env.as_contract(&other, || {
+            let _ = SignatureVerifier::verify_once(&env, &issuer, &payload, &signature, 6, 1_000);
+        });
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_verify_panics_on_bad_signature() {
+        let (env, host, issuer, key) = setup();
+        let payload = Bytes::from_slice(&env, b"voucher-3");
+        let signature = sign(&env, &key, b"tampered");
+
+        env.as_contract(&host, || {
+            let _ = SignatureVerifier::verify(&env, &issuer, &payload, &signature);
+        });
+    }
+}
+
+mod merkle_tests {
+    use crate::merkle::Merkle;
+    use soroban_sdk::{vec, Bytes, BytesN, Env, Vec};
+
+    fn leaves(env: &Env) -> Vec<BytesN<32>> {
+        let mut leaves = Vec::new(env);
+        for i in 0u8..5 {
+            leaves.push_back(Merkle::hash_leaf(env, &Bytes::from_array(env, &[i])));
+        }
+        leaves
+    }
+
+    #[test]
+    fn test_merkle_proofs_verify_against_root() {
+        let env = Env::default();
+        let leaves = leaves(&env);
+        let root = Merkle::compute_root(&env, &leaves).unwrap();
+
+        // Level 1: h01, h23, l4; level 2: h0123, l4
+        let h01 = Merkle::hash_pair(&env, &leaves.get(0).unwrap(), &leaves.get(1).unwrap());
+        let h23 = Merkle::hash_pair(&env, &leaves.get(2).unwrap(), &leaves.get(3).unwrap());
+        let h0123 = Merkle::hash_pair(&env, &h01, &h23);
+
+        let proof = vec![&env, leaves.get(3).unwrap(), h01.clone(), leaves.get(4).unwrap()];
+        assert!(Merkle::verify(&env, &root, &leaves.get(2).unwrap(), &proof));
+
+        let proof = vec![&env, h0123];
+        assert!(Merkle::verify(&env, &root, &leaves.get(4).unwrap(), &proof));
+    }
+
+    #[test]
+    fn test_merkle_rejects_wrong_leaf_and_empty_tree() {
+        let env = Env::default();
+        let leaves = leaves(&env);
+        let root = Merkle::compute_root(&env, &leaves).unwrap();
+
+        let outsider = Merkle::hash_leaf(&env, &Bytes::from_array(&env, &[9]));
+        let proof = vec![&env, leaves.get(1).unwrap()];
+        assert!(!Merkle::verify(&env, &root, &outsider, &proof));
+
+        assert_eq!(Merkle::compute_root(&env, &Vec::new(&env)), None);
+    }
+
+    #[test]
+    fn test_merkle_interior_node_is_not_a_leaf() {
+        let env = Env::default();
+        let leaves = leaves(&env);
+        let (a, b) = (leaves.get(0).unwrap(), leaves.get(1).unwrap());
+        let (low, high) = if a <= b { (a, b) } else { (b, a) };
+
+        // The bytes an interior node hashes don't hash to it as leaf data
+        let mut children = Bytes::from_array(&env, &low.to_array());
+        children.extend_from_array(&high.to_array());
+        assert_ne!(Merkle::hash_leaf(&env, &children), Merkle::hash_pair(&env, &low, &high));
+    }
+}
+
+mod fee_tests {
+    use crate::errors::FeeError;
+    use crate::fees::{FeeShare, Fees};
+    use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, Vec};
+
+    fn shares(env: &Env, a: &Address, b: &Address, c: &Address) -> Vec<FeeShare> {
+        vec![
+            env,
+            FeeShare { recipient: a.clone(), bps: 3_333 },
+            FeeShare { recipient: b.clone(), bps: 3_333 },
+            FeeShare { recipient: c.clone(), bps: 3_334 },
+        ]
+    }
+
+    #[test]
+    fn test_split_assigns_rounding_dust_to_first_share() {
+        let env = Env::default();
+        let (a, b, c) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+
+        let parts = Fees::split(&env, 100, &shares(&env, &a, &b, &c)).unwrap();
+        assert_eq!(parts, vec![&env, 34, 33, 33]);
+    }
+
+    #[test]
+    fn test_split_rejects_invalid_input() {
+        let env = Env::default();
+        let a = Address::generate(&env);
+
+        assert_eq!(Fees::split(&env, 100, &Vec::new(&env)), Err(FeeError::EmptySplit));
+        assert_eq!(
+            Fees::split(&env, 100, &vec![&env, FeeShare { recipient: a.clone(), bps: 9_000 }]),
+            Err(FeeError::InvalidShares)
+        );
+        assert_eq!(
+            Fees::split(&env, 0, &vec![&env, FeeShare { recipient: a, bps: 10_000 }]),
+            Err(FeeError::InvalidAmount)
+        );
+    }
+
+    #[test]
+    fn test_collect_transfers_each_part() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let admin = Address::generate(&env);
+        let payer = Address::generate(&env);
+        let (a, b, c) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+
+        let asset = env.register_stellar_asset_contract_v2(admin);
+        token::StellarAssetClient::new(&env, &asset.address()).mint(&payer, &1_000);
+
+        Fees::collect(&env, &asset.address(), &payer, 100, &shares(&env, &a, &b, &c)).unwrap();
+
+        let client = token::Client::new(&env, &asset.address());
+        assert_eq!(client.balance(&payer), 900);
+        assert_eq!(client.balance(&a), 34);
+        assert_eq!(client.balance(&b), 33);
+        assert_eq!(client.balance(&c), 33);
+    }
+}
+
+mod consent_tests {
+    use crate::consent::Consent;
+    use crate::errors::ConsentError;
+    use soroban_sdk::{contract, testutils::{Address as _, Ledger}, Address, Env};
+
+    #[contract]
+    pub struct Host;
+
+    fn setup() -> (Env, Address, Address, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(100);
+        let host = env.register(Host, ());
+        let holder = Address::generate(&env);
+        let viewer = Address::generate(&env);
+        (env, host, holder, viewer)
+    }
+
+    #[test]
+    fn test_grant_expires_and_revokes() {
+        let (env, host, holder, viewer) = setup();
+        // Each frame authorizes an address once, so every call gets its own
+        let call = |f: &dyn Fn()| env.as_contract(&host, f);
+
+        call(&|| assert_eq!(Consent::require(&env, &holder, &viewer), Err(ConsentError::AccessDenied)));
+        call(&|| {
+            Consent::grant(&env, &holder, &viewer, Some(200)).unwrap();
+        });
+        call(&|| assert!(Consent::require(&env, &holder, &viewer).is_ok()));
+
+        env.ledger().set_timestamp(201);
+        call(&|| assert!(!Consent::is_granted(&env, &holder, &viewer)));
+        call(&|| {
+            Consent::grant(&env, &holder, &viewer, None).unwrap();
+        });
+        call(&|| Consent::revoke(&env, &holder, &viewer).unwrap());
+        call(&|| {
+            assert!(!Consent::is_granted(&env, &holder, &viewer));
+            assert!(Consent::is_granted(&env, &holder, &holder));
+        });
+        call(&|| assert_eq!(Consent::revoke(&env, &holder, &viewer), Err(ConsentError::GrantNotFound)));
+    }
+
+    #[test]
+    fn test_grant_validation() {
+        let (env, host, holder, viewer) = setup();
+
+        env.as_contract(&host, || {
+            assert_eq!(Consent::grant(&env, &holder, &holder, None), Err(ConsentError::InvalidGrant));
+        });
+        env.as_contract(&host, || {
+            assert_eq!(Consent::grant(&env, &holder, &viewer, Some(100)), Err(ConsentError::InvalidGrant));
+        });
+    }
+}
+
+mod accumulator_tests {
+    use crate::accumulator::{CommitmentAccumulator, ROOT_HISTORY, TREE_DEPTH};
+    use soroban_sdk::{contract, symbol_short, BytesN, Env, Vec};
+
+    #[contract]
+    pub struct Host;
+
+    fn commitment(env: &Env, byte: u8) -> BytesN<32> {
+        BytesN::from_array(env, &[byte; 32])
+    }
+
+    #[test]
+    fn test_root_matches_sibling_path() {
+        let env = Env::default();
+        let host = env.register(Host, ());
+        let name = symbol_short!("certs");
+
+        env.as_contract(&host, || {
+            let zeros = CommitmentAccumulator::zero_hashes(&env);
+            assert_eq!(CommitmentAccumulator::root(&env, &name), zeros.get_unchecked(TREE_DEPTH));
+
+            for byte in 1..=3 {
+                CommitmentAccumulator::insert(&env, &name, &commitment(&env, byte)).unwrap();
+            }
+            assert_eq!(CommitmentAccumulator::size(&env, &name), 3);
+
+            // Leaf 2's path: an empty leaf, then the pair (leaf 0, leaf 1), then empty subtrees
+            let mut path = Vec::new(&env);
+            path.push_back(zeros.get_unchecked(0));
+            path.push_back(CommitmentAccumulator::hash_pair(&env, &commitment(&env, 1), &commitment(&env, 2)));
+            for level in 2..TREE_DEPTH {
+                path.push_back(zeros.get_unchecked(level));
+            }
+            let root = CommitmentAccumulator::compute_root(&env, &commitment(&env, 3), 2, &path);
+            assert_eq!(CommitmentAccumulator::root(&env, &name), root);
+        });
+    }
+
+    #[test]
+    fn test_only_recent_roots_are_known() {
+        let env = Env::default();
+        let host = env.register(Host, ());
+        let name = symbol_short!("certs");
+
+        env.as_contract(&host, || {
+            CommitmentAccumulator::insert(&env, &name, &commitment(&env, 0)).unwrap();
+            let first = CommitmentAccumulator::root(&env, &name);
+            for byte in 1..ROOT_HISTORY as u8 {
+                CommitmentAccumulator::insert(&env, &name, &commitment(&env, byte)).unwrap();
+            }
+            assert!(CommitmentAccumulator::is_known_root(&env, &name, &first));
+
+            CommitmentAccumulator::insert(&env, &name, &commitment(&env, 99)).unwrap();
+            assert!(!CommitmentAccumulator::is_known_root(&env, &name, &first));
+            assert!(!CommitmentAccumulator::is_known_root(&env, &symbol_short!("other"), &first));
+        });
+    }
+}
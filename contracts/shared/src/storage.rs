@@ -1,6 +1,10 @@
 use soroban_sdk::{Address, Env, Vec, contracttype};
-use crate::roles::Role;
+use crate::roles::{AdminProposal, Capability, Role, RoleSweepBounty};
 use crate::errors::AccessControlError;
+use crate::time::Clock;
+
+/// Most role holders kept in one page of the role holder index
+pub const ROLE_HOLDER_PAGE_SIZE: u32 = 50;
 
 /// Storage keys for the RBAC system
 #[contracttype]
@@ -20,6 +24,32 @@ pub enum DataKey {
     RoleRevocations(Address),
     /// Key for storing system configuration
     Config,
+    /// Number of addresses in the role holder index
+    RoleHolderCount,
+    /// Number of pages in the role holder index
+    RoleHolderPageCount,
+    /// Key for storing one page of the role holder index
+    RoleHolderPage(u32),
+    /// Key for storing the index page a role holder is kept in
+    RoleHolderPageOf(Address),
+    /// Page and offset in the role holder index where the next expiry sweep resumes
+    RoleSweepCursor,
+    /// Optional bounty paid to callers of the expiry sweep
+    RoleSweepBounty,
+    /// Set of root admins
+    Admins,
+    /// Number of admin approvals required for sensitive operations
+    AdminQuorum,
+    /// Counter used to assign admin proposal ids
+    AdminProposalCount,
+    /// Key for storing an admin proposal
+    AdminProposal(u64),
+    /// Flag set while role changes are frozen
+    Frozen,
+    /// Counter used to assign capability ids
+    CapabilityCount,
+    /// Key for storing an unconsumed capability
+    Capability(u64),
 }
 
 /// RBAC storage operations
@@ -36,6 +66,84 @@ impl AccessControlStorage {
         env.storage().instance().get(&DataKey::Admin).unwrap()
     }
 
+    /// Gets the set of root admins, falling back to the single admin
+    pub fn get_admins(env: &Env) -> Vec<Address> {
+        match env.storage().instance().get(&DataKey::Admins) {
+            Some(admins) => admins,
+            None => {
+                let mut admins = Vec::new(env);
+                if let Some(admin) = env.storage().instance().get::<_, Address>(&DataKey::Admin) {
+                    admins.push_back(admin);
+                }
+                admins
+            }
+        }
+    }
+
+    /// Sets the set of root admins
+    pub fn set_admins(env: &Env, admins: &Vec<Address>) {
+        env.storage().instance().set(&DataKey::Admins, admins);
+    }
+
+    /// Gets the admin quorum (defaults to 1)
+    pub fn get_admin_quorum(env: &Env) -> u32 {
+        env.storage().instance().get(&DataKey::AdminQuorum).unwrap_or(1)
+    }
+
+    /// Sets the admin quorum
+    pub fn set_admin_quorum(env: &Env, quorum: u32) {
+        env.storage().instance().set(&DataKey::AdminQuorum, &quorum);
+    }
+
+    /// Allocates the next admin proposal id
+    pub fn next_admin_proposal_id(env: &Env) -> u64 {
+        let id: u64 = env.storage().instance().get(&DataKey::AdminProposalCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::AdminProposalCount, &id);
+        id
+    }
+
+    /// Stores an admin proposal
+    pub fn set_admin_proposal(env: &Env, proposal: &AdminProposal) {
+        env.storage().persistent().set(&DataKey::AdminProposal(proposal.id), proposal);
+    }
+
+    /// Gets an admin proposal
+    pub fn get_admin_proposal(env: &Env, id: u64) -> Option<AdminProposal> {
+        env.storage().persistent().get(&DataKey::AdminProposal(id))
+    }
+
+    /// Checks whether role changes are frozen
+    pub fn is_frozen(env: &Env) -> bool {
+        env.storage().instance().get(&DataKey::Frozen).unwrap_or(false)
+    }
+
+    /// Sets the role change freeze flag
+    pub fn set_frozen(env: &Env, frozen: bool) {
+        env.storage().instance().set(&DataKey::Frozen, &frozen);
+    }
+
+    /// Allocates the next capability id
+    pub fn next_capability_id(env: &Env) -> u64 {
+        let id: u64 = env.storage().instance().get(&DataKey::CapabilityCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::CapabilityCount, &id);
+        id
+    }
+
+    /// Stores a capability
+    pub fn set_capability(env: &Env, capability: &Capability) {
+        env.storage().persistent().set(&DataKey::Capability(capability.id), capability);
+    }
+
+    /// Gets a capability
+    pub fn get_capability(env: &Env, id: u64) -> Option<Capability> {
+        env.storage().persistent().get(&DataKey::Capability(id))
+    }
+
+    /// Removes a capability
+    pub fn remove_capability(env: &Env, id: u64) {
+        env.storage().persistent().remove(&DataKey::Capability(id));
+    }
+
     /// Marks the contract as initialized
     pub fn set_initialized(env: &Env) {
         env.storage().instance().set(&DataKey::Initialized, &true);
@@ -50,6 +158,53 @@ impl AccessControlStorage {
     pub fn set_role(env: &Env, user: &Address, role: &Role) {
         let key = DataKey::Role(user.clone());
         env.storage().instance().set(&key, role);
+        Self::index_role_holder(env, user);
+    }
+
+    /// Adds a user to the role holder index, returning whether it was missing.
+    /// New holders go on the last page, or a new one once it is full.
+    pub fn index_role_holder(env: &Env, user: &Address) -> bool {
+        let page_of = DataKey::RoleHolderPageOf(user.clone());
+        if env.storage().persistent().has(&page_of) {
+            return false;
+        }
+
+        let page_count = Self::get_role_holder_page_count(env);
+        let mut page = page_count.saturating_sub(1);
+        let mut holders = Self::get_role_holder_page(env, page);
+        if page_count == 0 || holders.len() >= ROLE_HOLDER_PAGE_SIZE {
+            page = page_count;
+            holders = Vec::new(env);
+            env.storage().instance().set(&DataKey::RoleHolderPageCount, &(page_count + 1));
+        }
+        holders.push_back(user.clone());
+        Self::set_role_holder_page(env, page, &holders);
+        env.storage().persistent().set(&page_of, &page);
+        env.storage().instance().set(&DataKey::RoleHolderCount, &(Self::get_role_holder_count(env) + 1));
+        true
+    }
+
+    /// Removes a user from the role holder index, dropping trailing empty pages
+    pub fn unindex_role_holder(env: &Env, user: &Address) {
+        let page_of = DataKey::RoleHolderPageOf(user.clone());
+        let Some(page) = env.storage().persistent().get::<_, u32>(&page_of) else {
+            return;
+        };
+        env.storage().persistent().remove(&page_of);
+
+        let mut holders = Self::get_role_holder_page(env, page);
+        if let Some(index) = holders.first_index_of(user) {
+            holders.remove(index);
+        }
+        Self::set_role_holder_page(env, page, &holders);
+        env.storage().instance().set(&DataKey::RoleHolderCount, &Self::get_role_holder_count(env).saturating_sub(1));
+
+        let mut page_count = Self::get_role_holder_page_count(env);
+        while page_count > 0 && Self::get_role_holder_page(env, page_count - 1).is_empty() {
+            page_count -= 1;
+            env.storage().persistent().remove(&DataKey::RoleHolderPage(page_count));
+        }
+        env.storage().instance().set(&DataKey::RoleHolderPageCount, &page_count);
     }
 
     /// Gets a role for a user
@@ -66,6 +221,53 @@ impl AccessControlStorage {
     pub fn remove_role(env: &Env, user: &Address) {
         let key = DataKey::Role(user.clone());
         env.storage().instance().remove(&key);
+        Self::unindex_role_holder(env, user);
+    }
+
+    /// Gets how many addresses currently hold a role
+    pub fn get_role_holder_count(env: &Env) -> u32 {
+        env.storage().instance().get(&DataKey::RoleHolderCount).unwrap_or(0)
+    }
+
+    /// Gets how many pages the role holder index spans
+    pub fn get_role_holder_page_count(env: &Env) -> u32 {
+        env.storage().instance().get(&DataKey::RoleHolderPageCount).unwrap_or(0)
+    }
+
+    /// Gets one page of the role holder index
+    pub fn get_role_holder_page(env: &Env, page: u32) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RoleHolderPage(page))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Stores one page of the role holder index
+    pub fn set_role_holder_page(env: &Env, page: u32, holders: &Vec<Address>) {
+        env.storage().persistent().set(&DataKey::RoleHolderPage(page), holders);
+    }
+
+    /// Gets the page and offset where the next expiry sweep resumes
+    pub fn get_sweep_cursor(env: &Env) -> (u32, u32) {
+        env.storage().instance().get(&DataKey::RoleSweepCursor).unwrap_or((0, 0))
+    }
+
+    /// Sets the page and offset where the next expiry sweep resumes
+    pub fn set_sweep_cursor(env: &Env, cursor: (u32, u32)) {
+        env.storage().instance().set(&DataKey::RoleSweepCursor, &cursor);
+    }
+
+    /// Gets the expiry sweep bounty, if configured
+    pub fn get_sweep_bounty(env: &Env) -> Option<RoleSweepBounty> {
+        env.storage().instance().get(&DataKey::RoleSweepBounty)
+    }
+
+    /// Sets or clears the expiry sweep bounty
+    pub fn set_sweep_bounty(env: &Env, bounty: &Option<RoleSweepBounty>) {
+        match bounty {
+            Some(bounty) => env.storage().instance().set(&DataKey::RoleSweepBounty, bounty),
+            None => env.storage().instance().remove(&DataKey::RoleSweepBounty),
+        }
     }
 
     /// Checks if a user has a role
@@ -142,8 +344,7 @@ impl AccessControlStorage {
     /// Validates that a user has a valid role
     pub fn validate_user_role(env: &Env, user: &Address) -> Result<Role, AccessControlError> {
         if let Some(role) = Self::get_role(env, user) {
-            let current_time = env.ledger().timestamp();
-            if role.is_valid(current_time) {
+            if role.is_valid(Clock::now(env)) {
                 Ok(role)
             } else {
                 Err(AccessControlError::RoleNotFound) // Role expired
@@ -0,0 +1,84 @@
+//! Ledger clock abstraction
+//!
+//! All expiry and deadline logic should read time through [`Clock`] rather
+//! than calling `env.ledger().timestamp()` directly. Tests then only need to
+//! move the ledger timestamp to exercise every time-dependent path.
+
+use soroban_sdk::{contracttype, Env};
+
+/// Seconds in one minute
+pub const MINUTE: u64 = 60;
+/// Seconds in one hour
+pub const HOUR: u64 = 60 * MINUTE;
+/// Seconds in one day
+pub const DAY: u64 = 24 * HOUR;
+/// Seconds in one week
+pub const WEEK: u64 = 7 * DAY;
+
+/// Validity of a time-bounded item relative to the current ledger time
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ExpiryState {
+    /// Not yet expired (or never expires)
+    Active,
+    /// Past expiry but still within the grace period
+    InGracePeriod,
+    /// Past expiry and past any grace period
+    Expired,
+}
+
+/// Ledger-backed clock helpers
+pub struct Clock;
+
+impl Clock {
+    /// Current ledger timestamp in seconds
+    pub fn now(env: &Env) -> u64 {
+        env.ledger().timestamp()
+    }
+
+    /// Timestamp `duration` seconds from now, saturating on overflow
+    pub fn after(env: &Env, duration: u64) -> u64 {
+        Self::now(env).saturating_add(duration)
+    }
+
+    /// Whether `deadline` lies strictly in the past
+    pub fn has_passed(env: &Env, deadline: u64) -> bool {
+        Self::now(env) > deadline
+    }
+
+    /// Whether an optional expiry has passed; `None` never expires
+    pub fn is_expired(env: &Env, expires_at: Option<u64>) -> bool {
+        match expires_at {
+            Some(expires_at) => Self::has_passed(env, expires_at),
+            None => false,
+        }
+    }
+
+    /// End of the grace window that follows `expires_at`
+    pub fn grace_deadline(expires_at: u64, grace_period: u64) -> u64 {
+        expires_at.saturating_add(grace_period)
+    }
+
+    /// Whether `expires_at` has passed but the grace window has not
+    pub fn in_grace_period(env: &Env, expires_at: u64, grace_period: u64) -> bool {
+        Self::has_passed(env, expires_at)
+            && !Self::has_passed(env, Self::grace_deadline(expires_at, grace_period))
+    }
+
+    /// Classify an optional expiry against the current time and a grace period
+    pub fn expiry_state(env: &Env, expires_at: Option<u64>, grace_period: u64) -> ExpiryState {
+        match expires_at {
+            None => ExpiryState::Active,
+            Some(expires_at) if !Self::has_passed(env, expires_at) => ExpiryState::Active,
+            Some(expires_at) if Self::in_grace_period(env, expires_at, grace_period) => {
+                ExpiryState::InGracePeriod
+            }
+            Some(_) => ExpiryState::Expired,
+        }
+    }
+
+    /// Seconds remaining until `deadline`, or zero once it has passed
+    pub fn remaining(env: &Env, deadline: u64) -> u64 {
+        deadline.saturating_sub(Self::now(env))
+    }
+}
@@ -1,4 +1,5 @@
 use soroban_sdk::{Env, BytesN};
+use crate::time::Clock;
 
 /// Configuration constants for metadata validation that can be reused across contracts
 pub struct ValidationConfig;
@@ -296,7 +297,7 @@ impl CoreValidator {
     
     /// Validates expiry date
     pub fn validate_expiry_date(env: &Env, expiry_date: u64) -> Result<(), ValidationError> {
-        let current_time = env.ledger().timestamp();
+        let current_time = Clock::now(env);
         
         // Allow non-expiring certificates when expiry_date == 0
         if expiry_date == 0 {
@@ -0,0 +1,26 @@
+use soroban_sdk::contracterror;
+
+/// Staking contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum StakingError {
+    // Initialization errors
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+
+    // Authorization errors
+    Unauthorized = 3,
+
+    // Configuration errors
+    InvalidConfig = 4,
+    InvalidTiers = 5,
+    TierNotFound = 6,
+
+    // Stake errors
+    InvalidAmount = 7,
+    BelowTierMinimum = 8,
+    AlreadyStaked = 9,
+    NoStake = 10,
+    InsufficientRewardPool = 11,
+}
@@ -0,0 +1,39 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+/// Staking contract events
+pub struct StakingEvents;
+
+impl StakingEvents {
+    fn topic(env: &Env, name: &str) -> (Symbol, Symbol) {
+        (Symbol::new(env, "staking"), Symbol::new(env, name))
+    }
+
+    /// Emit event when the duration tiers are replaced
+    pub fn emit_tiers_updated(env: &Env, count: u32) {
+        env.events().publish(Self::topic(env, "tiers_updated"), count);
+    }
+
+    /// Emit event when the reward pool is topped up
+    pub fn emit_rewards_funded(env: &Env, funder: &Address, amount: i128) {
+        env.events().publish(
+            Self::topic(env, "rewards_funded"),
+            (funder.clone(), amount),
+        );
+    }
+
+    /// Emit event when a user locks tokens
+    pub fn emit_staked(env: &Env, user: &Address, tier: u32, amount: i128, unlocks_at: u64) {
+        env.events().publish(
+            Self::topic(env, "staked"),
+            (user.clone(), tier, amount, unlocks_at),
+        );
+    }
+
+    /// Emit event when a user withdraws their stake
+    pub fn emit_unstaked(env: &Env, user: &Address, paid_out: i128, penalty: i128) {
+        env.events().publish(
+            Self::topic(env, "unstaked"),
+            (user.clone(), paid_out, penalty),
+        );
+    }
+}
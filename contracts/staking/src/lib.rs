@@ -0,0 +1,250 @@
+#![no_std]
+
+mod types;
+mod errors;
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod tests;
+
+use soroban_sdk::{contract, contractimpl, token, Address, Env, Vec};
+
+use shared::{
+    access_control::AccessControl,
+    fees::{Fees, BASIS_POINTS},
+    reentrancy_guard::ReentrancyLock,
+    time::Clock,
+};
+
+pub use types::{Stake, StakeTier, StakingConfig};
+pub use errors::StakingError;
+use events::StakingEvents;
+use storage::StakingStorage;
+
+/// Upper bound on configured duration tiers
+const MAX_TIERS: u32 = 10;
+
+#[contract]
+pub struct Staking;
+
+#[contractimpl]
+impl Staking {
+    /// Initialize the contract with the staked token and penalty rate
+    pub fn initialize(env: Env, admin: Address, config: StakingConfig) -> Result<(), StakingError> {
+        if StakingStorage::get_admin(&env).is_some() {
+            return Err(StakingError::AlreadyInitialized);
+        }
+        Self::validate_config(&config)?;
+
+        AccessControl::initialize(&env, &admin).map_err(|_| StakingError::AlreadyInitialized)?;
+        StakingStorage::set_admin(&env, &admin);
+        StakingStorage::set_config(&env, &config);
+
+        Ok(())
+    }
+
+    /// Change the penalty rate. The staked token cannot change.
+    pub fn set_config(env: Env, admin: Address, config: StakingConfig) -> Result<(), StakingError> {
+        Self::require_admin(&env, &admin)?;
+        let current = Self::load_config(&env)?;
+        if config.token != current.token {
+            return Err(StakingError::InvalidConfig);
+        }
+        Self::validate_config(&config)?;
+
+        StakingStorage::set_config(&env, &config);
+
+        Ok(())
+    }
+
+    /// Replace the duration tiers. Longer tiers must lock for longer and
+    /// require at least as much. Existing stakes keep their terms.
+    pub fn set_tiers(env: Env, admin: Address, tiers: Vec<StakeTier>) -> Result<(), StakingError> {
+        Self::require_admin(&env, &admin)?;
+        Self::validate_tiers(&tiers)?;
+
+        StakingStorage::set_tiers(&env, &tiers);
+        StakingEvents::emit_tiers_updated(&env, tiers.len());
+
+        Ok(())
+    }
+
+    /// Add tokens to the pool that pays staking rewards
+    pub fn fund_rewards(env: Env, funder: Address, amount: i128) -> Result<(), StakingError> {
+        let _guard = ReentrancyLock::new(&env);
+        funder.require_auth();
+
+        let config = Self::load_config(&env)?;
+        if amount <= 0 {
+            return Err(StakingError::InvalidAmount);
+        }
+
+        token::Client::new(&env, &config.token).transfer(&funder, &env.current_contract_address(), &amount);
+        StakingStorage::set_reward_pool(&env, StakingStorage::get_reward_pool(&env) + amount);
+        StakingEvents::emit_rewards_funded(&env, &funder, amount);
+
+        Ok(())
+    }
+
+    /// Lock tokens in a duration tier. The tier's reward is reserved from
+    /// the pool up front, so a stake that runs its course is always paid.
+    pub fn stake(env: Env, user: Address, amount: i128, tier: u32) -> Result<Stake, StakingError> {
+        let _guard = ReentrancyLock::new(&env);
+        user.require_auth();
+
+        let config = Self::load_config(&env)?;
+        if StakingStorage::get_stake(&env, &user).is_some() {
+            return Err(StakingError::AlreadyStaked);
+        }
+        let terms = tier
+            .checked_sub(1)
+            .and_then(|index| StakingStorage::get_tiers(&env).get(index))
+            .ok_or(StakingError::TierNotFound)?;
+        if amount < terms.min_amount {
+            return Err(StakingError::BelowTierMinimum);
+        }
+
+        let reward = Fees::portion(amount, terms.reward_bps);
+        let pool = StakingStorage::get_reward_pool(&env);
+        if reward > pool {
+            return Err(StakingError::InsufficientRewardPool);
+        }
+
+        token::Client::new(&env, &config.token).transfer(&user, &env.current_contract_address(), &amount);
+        StakingStorage::set_reward_pool(&env, pool - reward);
+        StakingStorage::set_total_staked(&env, StakingStorage::get_total_staked(&env) + amount);
+
+        let stake = Stake {
+            user: user.clone(),
+            amount,
+            tier,
+            reward,
+            staked_at: Clock::now(&env),
+            unlocks_at: Clock::after(&env, terms.lock_period),
+        };
+        StakingStorage::set_stake(&env, &stake);
+        StakingEvents::emit_staked(&env, &user, tier, amount, stake.unlocks_at);
+
+        Ok(stake)
+    }
+
+    /// Withdraw a stake. Once unlocked the user gets the stake plus its
+    /// reward; before that the penalty is kept and, with the forfeited
+    /// reward, returned to the reward pool. Returns the amount paid out.
+    pub fn unstake(env: Env, user: Address) -> Result<i128, StakingError> {
+        let _guard = ReentrancyLock::new(&env);
+        user.require_auth();
+
+        let config = Self::load_config(&env)?;
+        let stake = StakingStorage::get_stake(&env, &user).ok_or(StakingError::NoStake)?;
+        let (paid_out, penalty) = Self::settlement(&env, &config, &stake);
+
+        if Clock::now(&env) < stake.unlocks_at {
+            StakingStorage::set_reward_pool(&env, StakingStorage::get_reward_pool(&env) + penalty + stake.reward);
+        }
+        StakingStorage::set_total_staked(&env, StakingStorage::get_total_staked(&env) - stake.amount);
+        StakingStorage::remove_stake(&env, &user);
+
+        token::Client::new(&env, &config.token).transfer(&env.current_contract_address(), &user, &paid_out);
+        StakingEvents::emit_unstaked(&env, &user, paid_out, penalty);
+
+        Ok(paid_out)
+    }
+
+    /// Tier level of the user's stake, or 0 without one. Enrollment gates
+    /// premium courses on this.
+    pub fn get_stake_tier(env: Env, user: Address) -> u32 {
+        StakingStorage::get_stake(&env, &user).map(|stake| stake.tier).unwrap_or(0)
+    }
+
+    /// Amount the user would receive by unstaking now
+    pub fn get_unstake_quote(env: Env, user: Address) -> i128 {
+        match (StakingStorage::get_stake(&env, &user), StakingStorage::get_config(&env)) {
+            (Some(stake), Some(config)) => Self::settlement(&env, &config, &stake).0,
+            _ => 0,
+        }
+    }
+
+    /// Get a user's stake
+    pub fn get_stake(env: Env, user: Address) -> Option<Stake> {
+        StakingStorage::get_stake(&env, &user)
+    }
+
+    /// Get the duration tiers, lowest first
+    pub fn get_tiers(env: Env) -> Vec<StakeTier> {
+        StakingStorage::get_tiers(&env)
+    }
+
+    /// Get the reward pool balance not reserved by any stake
+    pub fn get_reward_pool(env: Env) -> i128 {
+        StakingStorage::get_reward_pool(&env)
+    }
+
+    /// Get the total amount currently staked
+    pub fn get_total_staked(env: Env) -> i128 {
+        StakingStorage::get_total_staked(&env)
+    }
+
+    /// Get the staking configuration
+    pub fn get_config(env: Env) -> Option<StakingConfig> {
+        StakingStorage::get_config(&env)
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        StakingStorage::get_admin(&env)
+    }
+}
+
+impl Staking {
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), StakingError> {
+        caller.require_auth();
+        if !AccessControl::is_admin(env, caller) {
+            return Err(StakingError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    fn load_config(env: &Env) -> Result<StakingConfig, StakingError> {
+        StakingStorage::get_config(env).ok_or(StakingError::NotInitialized)
+    }
+
+    fn validate_config(config: &StakingConfig) -> Result<(), StakingError> {
+        if config.early_unstake_penalty_bps > BASIS_POINTS {
+            return Err(StakingError::InvalidConfig);
+        }
+        Ok(())
+    }
+
+    fn validate_tiers(tiers: &Vec<StakeTier>) -> Result<(), StakingError> {
+        if tiers.is_empty() || tiers.len() > MAX_TIERS {
+            return Err(StakingError::InvalidTiers);
+        }
+
+        let mut previous: Option<StakeTier> = None;
+        for tier in tiers.iter() {
+            if tier.min_amount <= 0 || tier.reward_bps > BASIS_POINTS {
+                return Err(StakingError::InvalidTiers);
+            }
+            if let Some(previous) = previous {
+                if tier.lock_period <= previous.lock_period || tier.min_amount < previous.min_amount {
+                    return Err(StakingError::InvalidTiers);
+                }
+            }
+            previous = Some(tier);
+        }
+
+        Ok(())
+    }
+
+    /// What unstaking now pays the user, and the penalty kept back
+    fn settlement(env: &Env, config: &StakingConfig, stake: &Stake) -> (i128, i128) {
+        if Clock::now(env) >= stake.unlocks_at {
+            (stake.amount + stake.reward, 0)
+        } else {
+            let penalty = Fees::portion(stake.amount, config.early_unstake_penalty_bps);
+            (stake.amount - penalty, penalty)
+        }
+    }
+}
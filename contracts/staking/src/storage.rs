@@ -0,0 +1,77 @@
+use soroban_sdk::{Address, Env, Vec};
+use crate::types::{DataKey, Stake, StakeTier, StakingConfig};
+
+/// Storage utilities for the staking contract
+pub struct StakingStorage;
+
+impl StakingStorage {
+    /// Get the contract admin
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Set the contract admin
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Get the staking configuration
+    pub fn get_config(env: &Env) -> Option<StakingConfig> {
+        env.storage().instance().get(&DataKey::Config)
+    }
+
+    /// Set the staking configuration
+    pub fn set_config(env: &Env, config: &StakingConfig) {
+        env.storage().instance().set(&DataKey::Config, config);
+    }
+
+    /// Get the duration tiers, lowest first
+    pub fn get_tiers(env: &Env) -> Vec<StakeTier> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Tiers)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Replace the duration tiers
+    pub fn set_tiers(env: &Env, tiers: &Vec<StakeTier>) {
+        env.storage().instance().set(&DataKey::Tiers, tiers);
+    }
+
+    /// Get the unreserved reward pool balance
+    pub fn get_reward_pool(env: &Env) -> i128 {
+        env.storage().instance().get(&DataKey::RewardPool).unwrap_or(0)
+    }
+
+    /// Set the unreserved reward pool balance
+    pub fn set_reward_pool(env: &Env, amount: i128) {
+        env.storage().instance().set(&DataKey::RewardPool, &amount);
+    }
+
+    /// Get the total amount currently staked
+    pub fn get_total_staked(env: &Env) -> i128 {
+        env.storage().instance().get(&DataKey::TotalStaked).unwrap_or(0)
+    }
+
+    /// Set the total amount currently staked
+    pub fn set_total_staked(env: &Env, amount: i128) {
+        env.storage().instance().set(&DataKey::TotalStaked, &amount);
+    }
+
+    /// Get a user's stake
+    pub fn get_stake(env: &Env, user: &Address) -> Option<Stake> {
+        env.storage().persistent().get(&DataKey::Stake(user.clone()))
+    }
+
+    /// Store a user's stake
+    pub fn set_stake(env: &Env, stake: &Stake) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Stake(stake.user.clone()), stake);
+    }
+
+    /// Remove a user's stake
+    pub fn remove_stake(env: &Env, user: &Address) {
+        env.storage().persistent().remove(&DataKey::Stake(user.clone()));
+    }
+}
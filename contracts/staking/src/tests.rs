@@ -0,0 +1,129 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger}, token, vec, Address, Env,
+};
+use shared::time::DAY;
+
+use crate::{StakeTier, Staking, StakingClient, StakingConfig, StakingError};
+
+fn setup_test_env() -> (Env, StakingClient<'static>, Address, token::Client<'static>) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Staking, ());
+    let client = StakingClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &asset.address());
+    token::StellarAssetClient::new(&env, &asset.address()).mint(&admin, &10_000);
+
+    // 10% penalty for leaving early
+    client.initialize(&admin, &StakingConfig { token: asset.address(), early_unstake_penalty_bps: 1_000 });
+    client.set_tiers(&admin, &vec![
+        &env,
+        StakeTier { lock_period: 30 * DAY, min_amount: 100, reward_bps: 200 },
+        StakeTier { lock_period: 180 * DAY, min_amount: 1_000, reward_bps: 1_500 },
+    ]);
+    client.fund_rewards(&admin, &1_000);
+
+    (env, client, admin, token)
+}
+
+fn staker(env: &Env, token: &token::Client, amount: i128) -> Address {
+    let user = Address::generate(env);
+    token::StellarAssetClient::new(env, &token.address).mint(&user, &amount);
+    user
+}
+
+#[test]
+fn test_matured_stake_pays_boosted_reward() {
+    let (env, client, _admin, token) = setup_test_env();
+    let user = staker(&env, &token, 2_000);
+
+    assert_eq!(client.get_stake_tier(&user), 0);
+    let stake = client.stake(&user, &2_000, &2);
+    assert_eq!(stake.reward, 300);
+    assert_eq!(stake.unlocks_at, 180 * DAY);
+    assert_eq!(client.get_stake_tier(&user), 2);
+    assert_eq!(client.get_reward_pool(), 700);
+    assert_eq!(client.get_total_staked(), 2_000);
+    assert_eq!(
+        client.try_stake(&user, &100, &1),
+        Err(Ok(StakingError::AlreadyStaked))
+    );
+
+    env.ledger().set_timestamp(180 * DAY);
+    assert_eq!(client.get_unstake_quote(&user), 2_300);
+    assert_eq!(client.unstake(&user), 2_300);
+    assert_eq!(token.balance(&user), 2_300);
+    assert_eq!(client.get_stake_tier(&user), 0);
+    assert_eq!(client.get_total_staked(), 0);
+}
+
+#[test]
+fn test_early_unstake_penalty_goes_to_reward_pool() {
+    let (env, client, _admin, token) = setup_test_env();
+    let user = staker(&env, &token, 1_000);
+    client.stake(&user, &1_000, &1);
+    assert_eq!(client.get_reward_pool(), 980);
+
+    env.ledger().set_timestamp(10 * DAY);
+    assert_eq!(client.unstake(&user), 900);
+
+    // Penalty plus the forfeited reward reservation
+    assert_eq!(client.get_reward_pool(), 1_100);
+    assert_eq!(token.balance(&client.address), 1_100);
+    assert_eq!(client.try_unstake(&user), Err(Ok(StakingError::NoStake)));
+}
+
+#[test]
+fn test_tier_and_pool_limits() {
+    let (env, client, admin, token) = setup_test_env();
+    let user = staker(&env, &token, 20_000);
+
+    assert_eq!(client.try_stake(&user, &500, &2), Err(Ok(StakingError::BelowTierMinimum)));
+    assert_eq!(client.try_stake(&user, &500, &0), Err(Ok(StakingError::TierNotFound)));
+    assert_eq!(client.try_stake(&user, &500, &3), Err(Ok(StakingError::TierNotFound)));
+
+    // A 15% reward on 10,000 would need more than the pool holds
+    assert_eq!(
+        client.try_stake(&user, &10_000, &2),
+        Err(Ok(StakingError::InsufficientRewardPool))
+    );
+
+    let shorter_second = vec![
+        &env,
+        StakeTier { lock_period: 30 * DAY, min_amount: 100, reward_bps: 0 },
+        StakeTier { lock_period: 30 * DAY, min_amount: 200, reward_bps: 0 },
+    ];
+    assert_eq!(
+        client.try_set_tiers(&admin, &shorter_second),
+        Err(Ok(StakingError::InvalidTiers))
+    );
+    assert_eq!(
+        client.try_set_tiers(&user, &shorter_second),
+        Err(Ok(StakingError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_funding_and_unstake_error_paths() {
+    let (env, client, admin, token) = setup_test_env();
+    let user = staker(&env, &token, 1_000);
+
+    assert_eq!(client.try_fund_rewards(&admin, &0), Err(Ok(StakingError::InvalidAmount)));
+    let config = client.get_config().unwrap();
+    assert_eq!(client.try_set_config(&user, &config), Err(Ok(StakingError::Unauthorized)));
+
+    // Only the staker's own stake can be withdrawn
+    client.stake(&user, &1_000, &1);
+    let stranger = Address::generate(&env);
+    assert_eq!(client.try_unstake(&stranger), Err(Ok(StakingError::NoStake)));
+
+    // The penalty applies up to the last second of the lock
+    env.ledger().set_timestamp(30 * DAY - 1);
+    assert_eq!(client.get_unstake_quote(&user), 900);
+    env.ledger().set_timestamp(30 * DAY);
+    assert_eq!(client.unstake(&user), 1_020);
+    assert_eq!(client.try_unstake(&user), Err(Ok(StakingError::NoStake)));
+}
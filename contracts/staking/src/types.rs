@@ -0,0 +1,42 @@
+use soroban_sdk::{contracttype, Address};
+
+/// Token and penalty settings
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct StakingConfig {
+    pub token: Address,
+    pub early_unstake_penalty_bps: u32, // share of the stake kept on early unstake
+}
+
+/// A duration tier. Tier levels start at 1, in the order tiers are configured.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct StakeTier {
+    pub lock_period: u64,
+    pub min_amount: i128,
+    pub reward_bps: u32, // reward on the staked amount for seeing the lock through
+}
+
+/// A user's locked position
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Stake {
+    pub user: Address,
+    pub amount: i128,
+    pub tier: u32,
+    pub reward: i128, // reserved from the reward pool when staking
+    pub staked_at: u64,
+    pub unlocks_at: u64,
+}
+
+/// Storage keys for the staking contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Config,
+    Tiers,
+    RewardPool,
+    TotalStaked,
+    Stake(Address),
+}
@@ -0,0 +1,27 @@
+use soroban_sdk::contracterror;
+
+/// Subscriptions contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SubscriptionError {
+    // Initialization errors
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+
+    // Authorization errors
+    Unauthorized = 3,
+
+    // Plan errors
+    PlanNotFound = 4,
+    PlanAlreadyExists = 5,
+    PlanInactive = 6,
+    InvalidPlan = 7,
+
+    // Subscription errors
+    SubscriptionNotFound = 8,
+    AlreadySubscribed = 9,
+    RenewalWindowNotOpen = 10,
+    SubscriptionExpired = 11,
+    SubscriptionCancelled = 12,
+}
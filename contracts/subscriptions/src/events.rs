@@ -0,0 +1,42 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+/// Subscriptions contract events
+pub struct SubscriptionEvents;
+
+impl SubscriptionEvents {
+    fn topic(env: &Env, name: &str) -> (Symbol, Symbol) {
+        (Symbol::new(env, "subscriptions"), Symbol::new(env, name))
+    }
+
+    /// Emit event when a plan is created or updated
+    pub fn emit_plan_updated(env: &Env, plan_id: &Symbol, tier: u32, active: bool) {
+        env.events().publish(
+            Self::topic(env, "plan_updated"),
+            (plan_id.clone(), tier, active),
+        );
+    }
+
+    /// Emit event when a user starts a subscription
+    pub fn emit_subscribed(env: &Env, user: &Address, plan_id: &Symbol, expires_at: u64) {
+        env.events().publish(
+            Self::topic(env, "subscribed"),
+            (user.clone(), plan_id.clone(), expires_at),
+        );
+    }
+
+    /// Emit event when a user renews a subscription
+    pub fn emit_renewed(env: &Env, user: &Address, plan_id: &Symbol, expires_at: u64) {
+        env.events().publish(
+            Self::topic(env, "renewed"),
+            (user.clone(), plan_id.clone(), expires_at),
+        );
+    }
+
+    /// Emit event when a user cancels a subscription
+    pub fn emit_cancelled(env: &Env, user: &Address, plan_id: &Symbol, expires_at: u64) {
+        env.events().publish(
+            Self::topic(env, "cancelled"),
+            (user.clone(), plan_id.clone(), expires_at),
+        );
+    }
+}
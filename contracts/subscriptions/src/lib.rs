@@ -0,0 +1,234 @@
+#![no_std]
+
+mod types;
+mod errors;
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod tests;
+
+use soroban_sdk::{contract, contractimpl, token, Address, Env, Symbol, Vec};
+
+use shared::{
+    access_control::AccessControl,
+    reentrancy_guard::ReentrancyLock,
+    time::{Clock, ExpiryState},
+};
+
+pub use types::{Plan, Subscription};
+pub use errors::SubscriptionError;
+use events::SubscriptionEvents;
+use storage::SubscriptionStorage;
+
+#[contract]
+pub struct Subscriptions;
+
+#[contractimpl]
+impl Subscriptions {
+    /// Initialize the contract and the shared RBAC system
+    pub fn initialize(env: Env, admin: Address, treasury: Address) -> Result<(), SubscriptionError> {
+        if SubscriptionStorage::get_admin(&env).is_some() {
+            return Err(SubscriptionError::AlreadyInitialized);
+        }
+
+        AccessControl::initialize(&env, &admin).map_err(|_| SubscriptionError::AlreadyInitialized)?;
+        SubscriptionStorage::set_admin(&env, &admin);
+        SubscriptionStorage::set_treasury(&env, &treasury);
+
+        Ok(())
+    }
+
+    /// Create a plan
+    pub fn create_plan(env: Env, admin: Address, plan: Plan) -> Result<(), SubscriptionError> {
+        Self::require_admin(&env, &admin)?;
+        if SubscriptionStorage::get_plan(&env, &plan.plan_id).is_some() {
+            return Err(SubscriptionError::PlanAlreadyExists);
+        }
+        Self::validate_plan(&plan)?;
+
+        SubscriptionStorage::set_plan(&env, &plan);
+        SubscriptionEvents::emit_plan_updated(&env, &plan.plan_id, plan.tier, plan.active);
+
+        Ok(())
+    }
+
+    /// Replace a plan's terms. Existing subscribers keep the access they
+    /// already paid for; new terms apply from their next renewal.
+    pub fn update_plan(env: Env, admin: Address, plan: Plan) -> Result<(), SubscriptionError> {
+        Self::require_admin(&env, &admin)?;
+        Self::load_plan(&env, &plan.plan_id)?;
+        Self::validate_plan(&plan)?;
+
+        SubscriptionStorage::set_plan(&env, &plan);
+        SubscriptionEvents::emit_plan_updated(&env, &plan.plan_id, plan.tier, plan.active);
+
+        Ok(())
+    }
+
+    /// Subscribe to a plan, paying for its first period. A user holds one
+    /// subscription at a time; a new one can start once the old one has
+    /// fully expired.
+    pub fn subscribe(env: Env, user: Address, plan_id: Symbol) -> Result<Subscription, SubscriptionError> {
+        let _guard = ReentrancyLock::new(&env);
+        user.require_auth();
+
+        let plan = Self::load_plan(&env, &plan_id)?;
+        if !plan.active {
+            return Err(SubscriptionError::PlanInactive);
+        }
+        if let Some(existing) = SubscriptionStorage::get_subscription(&env, &user) {
+            if Self::state_of(&env, &existing) != ExpiryState::Expired {
+                return Err(SubscriptionError::AlreadySubscribed);
+            }
+        }
+
+        Self::collect(&env, &user, &plan)?;
+
+        let subscription = Subscription {
+            user: user.clone(),
+            plan_id: plan_id.clone(),
+            tier: plan.tier,
+            started_at: Clock::now(&env),
+            expires_at: Clock::after(&env, plan.period),
+            renewals: 0,
+            cancelled: false,
+        };
+        SubscriptionStorage::set_subscription(&env, &subscription);
+        SubscriptionEvents::emit_subscribed(&env, &user, &plan_id, subscription.expires_at);
+
+        Ok(subscription)
+    }
+
+    /// Pay for another period. Renewal opens `renewal_window` seconds before
+    /// expiry and stays open through the grace period; the new period always
+    /// starts at the old expiry so access is continuous. Retired plans cannot
+    /// be renewed.
+    pub fn renew(env: Env, user: Address) -> Result<Subscription, SubscriptionError> {
+        let _guard = ReentrancyLock::new(&env);
+        user.require_auth();
+
+        let mut subscription = SubscriptionStorage::get_subscription(&env, &user)
+            .ok_or(SubscriptionError::SubscriptionNotFound)?;
+        if subscription.cancelled {
+            return Err(SubscriptionError::SubscriptionCancelled);
+        }
+
+        let plan = Self::load_plan(&env, &subscription.plan_id)?;
+        if !plan.active {
+            return Err(SubscriptionError::PlanInactive);
+        }
+        let opens_at = subscription.expires_at.saturating_sub(plan.renewal_window);
+        if Clock::now(&env) < opens_at {
+            return Err(SubscriptionError::RenewalWindowNotOpen);
+        }
+        if Clock::expiry_state(&env, Some(subscription.expires_at), plan.grace_period) == ExpiryState::Expired {
+            return Err(SubscriptionError::SubscriptionExpired);
+        }
+
+        Self::collect(&env, &user, &plan)?;
+
+        subscription.expires_at = subscription.expires_at.saturating_add(plan.period);
+        subscription.tier = plan.tier;
+        subscription.renewals += 1;
+        SubscriptionStorage::set_subscription(&env, &subscription);
+        SubscriptionEvents::emit_renewed(&env, &user, &subscription.plan_id, subscription.expires_at);
+
+        Ok(subscription)
+    }
+
+    /// Stop future renewals. Access continues until the paid period ends.
+    pub fn cancel(env: Env, user: Address) -> Result<(), SubscriptionError> {
+        user.require_auth();
+
+        let mut subscription = SubscriptionStorage::get_subscription(&env, &user)
+            .ok_or(SubscriptionError::SubscriptionNotFound)?;
+        if subscription.cancelled {
+            return Err(SubscriptionError::SubscriptionCancelled);
+        }
+
+        subscription.cancelled = true;
+        SubscriptionStorage::set_subscription(&env, &subscription);
+        SubscriptionEvents::emit_cancelled(&env, &user, &subscription.plan_id, subscription.expires_at);
+
+        Ok(())
+    }
+
+    /// Whether the user currently has access at `tier` or above. Access
+    /// continues through the grace period unless the subscription was
+    /// cancelled. Other contracts can gate features on this.
+    pub fn has_active_subscription(env: Env, user: Address, tier: u32) -> bool {
+        match SubscriptionStorage::get_subscription(&env, &user) {
+            Some(subscription) => {
+                subscription.tier >= tier && Self::state_of(&env, &subscription) != ExpiryState::Expired
+            }
+            None => false,
+        }
+    }
+
+    /// Get where a user's subscription stands relative to its expiry
+    pub fn get_subscription_state(env: Env, user: Address) -> Option<ExpiryState> {
+        SubscriptionStorage::get_subscription(&env, &user).map(|subscription| Self::state_of(&env, &subscription))
+    }
+
+    /// Get a user's subscription
+    pub fn get_subscription(env: Env, user: Address) -> Option<Subscription> {
+        SubscriptionStorage::get_subscription(&env, &user)
+    }
+
+    /// Get a plan by id
+    pub fn get_plan(env: Env, plan_id: Symbol) -> Option<Plan> {
+        SubscriptionStorage::get_plan(&env, &plan_id)
+    }
+
+    /// Get the ids of every plan
+    pub fn get_plans(env: Env) -> Vec<Symbol> {
+        SubscriptionStorage::get_plan_ids(&env)
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        SubscriptionStorage::get_admin(&env)
+    }
+}
+
+impl Subscriptions {
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), SubscriptionError> {
+        caller.require_auth();
+        if !AccessControl::is_admin(env, caller) {
+            return Err(SubscriptionError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    fn validate_plan(plan: &Plan) -> Result<(), SubscriptionError> {
+        if plan.tier == 0 || plan.price <= 0 || plan.period == 0 || plan.renewal_window > plan.period {
+            return Err(SubscriptionError::InvalidPlan);
+        }
+        Ok(())
+    }
+
+    fn load_plan(env: &Env, plan_id: &Symbol) -> Result<Plan, SubscriptionError> {
+        SubscriptionStorage::get_plan(env, plan_id).ok_or(SubscriptionError::PlanNotFound)
+    }
+
+    /// Expiry state of a subscription, honouring its plan's grace period.
+    /// Cancelled subscriptions get no grace.
+    fn state_of(env: &Env, subscription: &Subscription) -> ExpiryState {
+        let grace_period = if subscription.cancelled {
+            0
+        } else {
+            SubscriptionStorage::get_plan(env, &subscription.plan_id)
+                .map(|plan| plan.grace_period)
+                .unwrap_or(0)
+        };
+        Clock::expiry_state(env, Some(subscription.expires_at), grace_period)
+    }
+
+    /// Take one period's payment from the user to the treasury
+    fn collect(env: &Env, user: &Address, plan: &Plan) -> Result<(), SubscriptionError> {
+        let treasury = SubscriptionStorage::get_treasury(env).ok_or(SubscriptionError::NotInitialized)?;
+        token::Client::new(env, &plan.token).transfer(user, &treasury, &plan.price);
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+use crate::types::{DataKey, Plan, Subscription};
+
+/// Storage utilities for the subscriptions contract
+pub struct SubscriptionStorage;
+
+impl SubscriptionStorage {
+    /// Get the contract admin
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Set the contract admin
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Get the address subscription payments go to
+    pub fn get_treasury(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Treasury)
+    }
+
+    /// Set the address subscription payments go to
+    pub fn set_treasury(env: &Env, treasury: &Address) {
+        env.storage().instance().set(&DataKey::Treasury, treasury);
+    }
+
+    /// Get a plan by id
+    pub fn get_plan(env: &Env, plan_id: &Symbol) -> Option<Plan> {
+        env.storage().persistent().get(&DataKey::Plan(plan_id.clone()))
+    }
+
+    /// Store a plan, listing its id on first write
+    pub fn set_plan(env: &Env, plan: &Plan) {
+        let key = DataKey::Plan(plan.plan_id.clone());
+        if !env.storage().persistent().has(&key) {
+            let mut ids = Self::get_plan_ids(env);
+            ids.push_back(plan.plan_id.clone());
+            env.storage().instance().set(&DataKey::PlanIds, &ids);
+        }
+        env.storage().persistent().set(&key, plan);
+    }
+
+    /// Get the ids of every plan ever created
+    pub fn get_plan_ids(env: &Env) -> Vec<Symbol> {
+        env.storage()
+            .instance()
+            .get(&DataKey::PlanIds)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Get a user's subscription
+    pub fn get_subscription(env: &Env, user: &Address) -> Option<Subscription> {
+        env.storage().persistent().get(&DataKey::Subscription(user.clone()))
+    }
+
+    /// Store a user's subscription
+    pub fn set_subscription(env: &Env, subscription: &Subscription) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Subscription(subscription.user.clone()), subscription);
+    }
+}
@@ -0,0 +1,164 @@
+use soroban_sdk::{
+    symbol_short, testutils::{Address as _, Ledger}, token, Address, Env, Symbol,
+};
+use shared::time::{ExpiryState, DAY};
+
+use crate::{Plan, SubscriptionError, Subscriptions, SubscriptionsClient};
+
+const BASIC: u32 = 1;
+const PREMIUM: u32 = 2;
+
+fn setup_test_env() -> (
+    Env,
+    SubscriptionsClient<'static>,
+    Address,
+    Address,
+    token::Client<'static>,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Subscriptions, ());
+    let client = SubscriptionsClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    client.initialize(&admin, &treasury);
+
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &asset.address());
+
+    client.create_plan(&admin, &plan(&token, monthly(), BASIC, 100));
+    client.create_plan(&admin, &plan(&token, symbol_short!("pro"), PREMIUM, 300));
+
+    (env, client, admin, treasury, token)
+}
+
+fn monthly() -> Symbol {
+    symbol_short!("monthly")
+}
+
+fn plan(token: &token::Client, plan_id: Symbol, tier: u32, price: i128) -> Plan {
+    Plan {
+        plan_id,
+        tier,
+        token: token.address.clone(),
+        price,
+        period: 30 * DAY,
+        renewal_window: 5 * DAY,
+        grace_period: 3 * DAY,
+        active: true,
+    }
+}
+
+fn funded_user(env: &Env, token: &token::Client) -> Address {
+    let user = Address::generate(env);
+    token::StellarAssetClient::new(env, &token.address).mint(&user, &1_000);
+    user
+}
+
+#[test]
+fn test_subscribe_grants_tier_access() {
+    let (env, client, _admin, treasury, token) = setup_test_env();
+    let user = funded_user(&env, &token);
+
+    let subscription = client.subscribe(&user, &monthly());
+    assert_eq!(subscription.expires_at, 30 * DAY);
+    assert_eq!(token.balance(&treasury), 100);
+
+    assert!(client.has_active_subscription(&user, &BASIC));
+    assert!(!client.has_active_subscription(&user, &PREMIUM));
+    assert_eq!(
+        client.try_subscribe(&user, &symbol_short!("pro")),
+        Err(Ok(SubscriptionError::AlreadySubscribed))
+    );
+}
+
+#[test]
+fn test_grace_period_then_expiry() {
+    let (env, client, _admin, _treasury, token) = setup_test_env();
+    let user = funded_user(&env, &token);
+    client.subscribe(&user, &monthly());
+
+    env.ledger().set_timestamp(31 * DAY);
+    assert_eq!(client.get_subscription_state(&user), Some(ExpiryState::InGracePeriod));
+    assert!(client.has_active_subscription(&user, &BASIC));
+
+    env.ledger().set_timestamp(34 * DAY);
+    assert_eq!(client.get_subscription_state(&user), Some(ExpiryState::Expired));
+    assert!(!client.has_active_subscription(&user, &BASIC));
+    assert_eq!(client.try_renew(&user), Err(Ok(SubscriptionError::SubscriptionExpired)));
+
+    // A fully expired user can start over, on any plan
+    client.subscribe(&user, &symbol_short!("pro"));
+    assert!(client.has_active_subscription(&user, &PREMIUM));
+}
+
+#[test]
+fn test_renewal_window_and_continuity() {
+    let (env, client, _admin, treasury, token) = setup_test_env();
+    let user = funded_user(&env, &token);
+    client.subscribe(&user, &monthly());
+
+    env.ledger().set_timestamp(20 * DAY);
+    assert_eq!(client.try_renew(&user), Err(Ok(SubscriptionError::RenewalWindowNotOpen)));
+
+    env.ledger().set_timestamp(26 * DAY);
+    assert_eq!(client.renew(&user).expires_at, 60 * DAY);
+
+    // Renewing during grace still extends from the old expiry
+    env.ledger().set_timestamp(61 * DAY);
+    let renewed = client.renew(&user);
+    assert_eq!(renewed.expires_at, 90 * DAY);
+    assert_eq!(renewed.renewals, 2);
+    assert_eq!(token.balance(&treasury), 300);
+}
+
+#[test]
+fn test_cancel_stops_renewals_and_grace() {
+    let (env, client, _admin, _treasury, token) = setup_test_env();
+    let user = funded_user(&env, &token);
+    client.subscribe(&user, &monthly());
+    client.cancel(&user);
+
+    env.ledger().set_timestamp(29 * DAY);
+    assert!(client.has_active_subscription(&user, &BASIC));
+    assert_eq!(client.try_renew(&user), Err(Ok(SubscriptionError::SubscriptionCancelled)));
+
+    env.ledger().set_timestamp(31 * DAY);
+    assert!(!client.has_active_subscription(&user, &BASIC));
+}
+
+#[test]
+fn test_plan_management() {
+    let (env, client, admin, _treasury, token) = setup_test_env();
+    let user = funded_user(&env, &token);
+    let outsider = Address::generate(&env);
+
+    assert_eq!(
+        client.try_create_plan(&outsider, &plan(&token, symbol_short!("x"), BASIC, 1)),
+        Err(Ok(SubscriptionError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_create_plan(&admin, &plan(&token, monthly(), BASIC, 1)),
+        Err(Ok(SubscriptionError::PlanAlreadyExists))
+    );
+    assert_eq!(
+        client.try_create_plan(&admin, &plan(&token, symbol_short!("free"), BASIC, 0)),
+        Err(Ok(SubscriptionError::InvalidPlan))
+    );
+
+    let subscriber = funded_user(&env, &token);
+    client.subscribe(&subscriber, &monthly());
+    let mut retired = plan(&token, monthly(), BASIC, 100);
+    retired.active = false;
+    client.update_plan(&admin, &retired);
+    assert_eq!(
+        client.try_subscribe(&user, &monthly()),
+        Err(Ok(SubscriptionError::PlanInactive))
+    );
+    // Existing subscribers keep access but cannot renew a retired plan
+    env.ledger().set_timestamp(26 * DAY);
+    assert!(client.has_active_subscription(&subscriber, &BASIC));
+    assert_eq!(client.try_renew(&subscriber), Err(Ok(SubscriptionError::PlanInactive)));
+    assert_eq!(client.get_plans().len(), 2);
+}
@@ -0,0 +1,39 @@
+use soroban_sdk::{contracttype, Address, Symbol};
+
+/// A purchasable subscription plan. Higher tiers include lower ones.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Plan {
+    pub plan_id: Symbol,
+    pub tier: u32,
+    pub token: Address,
+    pub price: i128,
+    pub period: u64,         // seconds of access bought per payment
+    pub renewal_window: u64, // how long before expiry renewal opens
+    pub grace_period: u64,   // access kept (and renewal allowed) after expiry
+    pub active: bool,        // inactive plans accept no new subscribers
+}
+
+/// A user's subscription
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Subscription {
+    pub user: Address,
+    pub plan_id: Symbol,
+    pub tier: u32,
+    pub started_at: u64,
+    pub expires_at: u64,
+    pub renewals: u32,
+    pub cancelled: bool, // no further renewals; access runs to expiry
+}
+
+/// Storage keys for the subscriptions contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Treasury,
+    Plan(Symbol),
+    PlanIds,
+    Subscription(Address),
+}
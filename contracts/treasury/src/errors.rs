@@ -0,0 +1,29 @@
+use soroban_sdk::contracterror;
+
+/// Treasury contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TreasuryError {
+    // Initialization errors
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+
+    // Authorization errors
+    Unauthorized = 3,
+
+    // Configuration errors
+    InvalidConfig = 4,
+    InvalidBudget = 5,
+    BudgetNotFound = 6,
+
+    // Proposal errors
+    InvalidAmount = 7,
+    ProposalNotFound = 8,
+    InvalidProposalState = 9,
+    AlreadyApproved = 10,
+    ProposalExpired = 11,
+
+    // Spending errors
+    BudgetExceeded = 12,
+}
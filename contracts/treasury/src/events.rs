@@ -0,0 +1,63 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+/// Treasury contract events
+pub struct TreasuryEvents;
+
+impl TreasuryEvents {
+    fn topic(env: &Env, name: &str) -> (Symbol, Symbol) {
+        (Symbol::new(env, "treasury"), Symbol::new(env, name))
+    }
+
+    /// Emit event when the signer set or threshold changes
+    pub fn emit_config_updated(env: &Env, signers: u32, threshold: u32) {
+        env.events().publish(
+            Self::topic(env, "config_updated"),
+            (signers, threshold),
+        );
+    }
+
+    /// Emit event when a category's budget is set
+    pub fn emit_budget_updated(env: &Env, category: &Symbol, period_limit: i128, period: u64) {
+        env.events().publish(
+            Self::topic(env, "budget_updated"),
+            (category.clone(), period_limit, period),
+        );
+    }
+
+    /// Emit event when tokens are deposited
+    pub fn emit_deposited(env: &Env, from: &Address, token: &Address, amount: i128) {
+        env.events().publish(
+            Self::topic(env, "deposited"),
+            (from.clone(), token.clone(), amount),
+        );
+    }
+
+    /// Emit event when a spending proposal is created
+    pub fn emit_proposed(env: &Env, proposal_id: u64, category: &Symbol, recipient: &Address, amount: i128) {
+        env.events().publish(
+            Self::topic(env, "proposed"),
+            (proposal_id, category.clone(), recipient.clone(), amount),
+        );
+    }
+
+    /// Emit event when a signer or governance approves a proposal
+    pub fn emit_approved(env: &Env, proposal_id: u64, approver: &Address, approvals: u32) {
+        env.events().publish(
+            Self::topic(env, "approved"),
+            (proposal_id, approver.clone(), approvals),
+        );
+    }
+
+    /// Emit event when a proposal is cancelled
+    pub fn emit_cancelled(env: &Env, proposal_id: u64) {
+        env.events().publish(Self::topic(env, "cancelled"), proposal_id);
+    }
+
+    /// Emit event when a proposal's payment is made
+    pub fn emit_disbursed(env: &Env, disbursement_id: u64, proposal_id: u64, recipient: &Address, amount: i128) {
+        env.events().publish(
+            Self::topic(env, "disbursed"),
+            (disbursement_id, proposal_id, recipient.clone(), amount),
+        );
+    }
+}
@@ -0,0 +1,429 @@
+#![no_std]
+
+mod types;
+mod errors;
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod tests;
+
+use soroban_sdk::{contract, contractimpl, token, Address, Env, String, Symbol, Vec};
+
+use shared::{
+    access_control::AccessControl,
+    reentrancy_guard::ReentrancyLock,
+    time::Clock,
+};
+
+pub use types::{Budget, Disbursement, ProposalStatus, SpendingProposal, TreasuryConfig};
+pub use errors::TreasuryError;
+use events::TreasuryEvents;
+use storage::TreasuryStorage;
+
+/// Upper bound on multisig signers
+const MAX_SIGNERS: u32 = 20;
+
+/// Upper bound on disbursements returned by one history query
+const MAX_PAGE: u32 = 50;
+
+#[contract]
+pub struct Treasury;
+
+#[contractimpl]
+impl Treasury {
+    /// Initialize the contract with its multisig signers
+    pub fn initialize(env: Env, admin: Address, config: TreasuryConfig) -> Result<(), TreasuryError> {
+        if TreasuryStorage::get_admin(&env).is_some() {
+            return Err(TreasuryError::AlreadyInitialized);
+        }
+        Self::validate_config(&config)?;
+
+        AccessControl::initialize(&env, &admin).map_err(|_| TreasuryError::AlreadyInitialized)?;
+        TreasuryStorage::set_admin(&env, &admin);
+        TreasuryStorage::set_config(&env, &config);
+
+        Ok(())
+    }
+
+    /// Replace the signer set and threshold (governance, or `threshold` of
+    /// the current signers together). Open proposals only count approvals
+    /// from the new signers and are re-checked against the new threshold.
+    pub fn set_config(env: Env, approvers: Vec<Address>, config: TreasuryConfig) -> Result<(), TreasuryError> {
+        Self::require_quorum(&env, &approvers)?;
+        Self::validate_config(&config)?;
+
+        TreasuryStorage::set_config(&env, &config);
+        TreasuryEvents::emit_config_updated(&env, config.signers.len(), config.threshold);
+
+        Ok(())
+    }
+
+    /// Connect the governance contract, whose approval passes a proposal
+    /// on its own (governance, or `threshold` of the current signers
+    /// together)
+    pub fn set_governance(env: Env, approvers: Vec<Address>, governance: Address) -> Result<(), TreasuryError> {
+        Self::require_quorum(&env, &approvers)?;
+
+        TreasuryStorage::set_governance(&env, &governance);
+
+        Ok(())
+    }
+
+    /// Create or update a category's budget (governance, or `threshold` of
+    /// the current signers together). Spending already made in the current
+    /// window still counts against a new limit.
+    pub fn set_budget(
+        env: Env,
+        approvers: Vec<Address>,
+        category: Symbol,
+        token: Address,
+        period_limit: i128,
+        period: u64,
+    ) -> Result<(), TreasuryError> {
+        Self::require_quorum(&env, &approvers)?;
+        if period_limit <= 0 || period == 0 {
+            return Err(TreasuryError::InvalidBudget);
+        }
+
+        let budget = match TreasuryStorage::get_budget(&env, &category) {
+            Some(existing) if existing.token == token => Budget { period, period_limit, ..existing },
+            Some(_) => return Err(TreasuryError::InvalidBudget),
+            None => Budget {
+                category: category.clone(),
+                token,
+                period,
+                period_limit,
+                window_start: Clock::now(&env),
+                window_spent: 0,
+                total_spent: 0,
+            },
+        };
+        TreasuryStorage::set_budget(&env, &budget);
+        TreasuryEvents::emit_budget_updated(&env, &category, period_limit, period);
+
+        Ok(())
+    }
+
+    /// Deposit tokens with an on-chain record. Fees can also be sent to the
+    /// contract address directly.
+    pub fn deposit(env: Env, from: Address, token: Address, amount: i128) -> Result<(), TreasuryError> {
+        let _guard = ReentrancyLock::new(&env);
+        from.require_auth();
+        if amount <= 0 {
+            return Err(TreasuryError::InvalidAmount);
+        }
+
+        token::Client::new(&env, &token).transfer(&from, &env.current_contract_address(), &amount);
+        TreasuryEvents::emit_deposited(&env, &from, &token, amount);
+
+        Ok(())
+    }
+
+    /// Propose a payment from a budget category (signers only). The
+    /// proposer's approval is counted.
+    pub fn propose(
+        env: Env,
+        proposer: Address,
+        category: Symbol,
+        recipient: Address,
+        amount: i128,
+        memo: String,
+    ) -> Result<u64, TreasuryError> {
+        proposer.require_auth();
+        let config = Self::load_config(&env)?;
+        if !config.signers.contains(&proposer) {
+            return Err(TreasuryError::Unauthorized);
+        }
+        TreasuryStorage::get_budget(&env, &category).ok_or(TreasuryError::BudgetNotFound)?;
+        if amount <= 0 {
+            return Err(TreasuryError::InvalidAmount);
+        }
+
+        let mut proposal = SpendingProposal {
+            id: TreasuryStorage::next_proposal_id(&env),
+            proposer: proposer.clone(),
+            category: category.clone(),
+            recipient: recipient.clone(),
+            amount,
+            memo,
+            approvals: Vec::new(&env),
+            status: ProposalStatus::Pending,
+            created_at: Clock::now(&env),
+            expires_at: Clock::after(&env, config.proposal_ttl),
+        };
+        TreasuryEvents::emit_proposed(&env, proposal.id, &category, &recipient, amount);
+        Self::record_approval(&env, &config, &mut proposal, &proposer);
+        TreasuryStorage::set_proposal(&env, &proposal);
+
+        Ok(proposal.id)
+    }
+
+    /// Approve a pending proposal. It passes once `threshold` signers have
+    /// approved, or at once when the approver is the governance contract.
+    pub fn approve(env: Env, approver: Address, proposal_id: u64) -> Result<ProposalStatus, TreasuryError> {
+        approver.require_auth();
+        let config = Self::load_config(&env)?;
+        let is_governance = TreasuryStorage::get_governance(&env).as_ref() == Some(&approver);
+        if !is_governance && !config.signers.contains(&approver) {
+            return Err(TreasuryError::Unauthorized);
+        }
+
+        let mut proposal = Self::load_live_proposal(&env, proposal_id)?;
+        if proposal.status != ProposalStatus::Pending {
+            return Err(TreasuryError::InvalidProposalState);
+        }
+        if proposal.approvals.contains(&approver) {
+            return Err(TreasuryError::AlreadyApproved);
+        }
+
+        if is_governance {
+            proposal.approvals.push_back(approver.clone());
+            proposal.status = ProposalStatus::Approved;
+            TreasuryEvents::emit_approved(&env, proposal_id, &approver, proposal.approvals.len());
+        } else {
+            Self::record_approval(&env, &config, &mut proposal, &approver);
+        }
+        TreasuryStorage::set_proposal(&env, &proposal);
+
+        Ok(proposal.status)
+    }
+
+    /// Withdraw a proposal that has not been executed (proposer or admin)
+    pub fn cancel(env: Env, caller: Address, proposal_id: u64) -> Result<(), TreasuryError> {
+        caller.require_auth();
+        let mut proposal = TreasuryStorage::get_proposal(&env, proposal_id).ok_or(TreasuryError::ProposalNotFound)?;
+        if caller != proposal.proposer && !AccessControl::is_admin(&env, &caller) {
+            return Err(TreasuryError::Unauthorized);
+        }
+        if !matches!(proposal.status, ProposalStatus::Pending | ProposalStatus::Approved) {
+            return Err(TreasuryError::InvalidProposalState);
+        }
+
+        proposal.status = ProposalStatus::Cancelled;
+        TreasuryStorage::set_proposal(&env, &proposal);
+        TreasuryEvents::emit_cancelled(&env, proposal_id);
+
+        Ok(())
+    }
+
+    /// Pay out an approved proposal within its category's period limit and
+    /// record the disbursement. Callable by anyone. Returns the
+    /// disbursement id.
+    pub fn execute(env: Env, proposal_id: u64) -> Result<u64, TreasuryError> {
+        let _guard = ReentrancyLock::new(&env);
+
+        let mut proposal = Self::load_live_proposal(&env, proposal_id)?;
+        if proposal.status != ProposalStatus::Approved {
+            return Err(TreasuryError::InvalidProposalState);
+        }
+
+        let mut budget = TreasuryStorage::get_budget(&env, &proposal.category).ok_or(TreasuryError::BudgetNotFound)?;
+        Self::roll_window(&env, &mut budget);
+        if budget.window_spent + proposal.amount > budget.period_limit {
+            return Err(TreasuryError::BudgetExceeded);
+        }
+
+        budget.window_spent += proposal.amount;
+        budget.total_spent += proposal.amount;
+        TreasuryStorage::set_budget(&env, &budget);
+
+        proposal.status = ProposalStatus::Executed;
+        TreasuryStorage::set_proposal(&env, &proposal);
+
+        token::Client::new(&env, &budget.token).transfer(
+            &env.current_contract_address(),
+            &proposal.recipient,
+            &proposal.amount,
+        );
+
+        let disbursement = Disbursement {
+            id: TreasuryStorage::get_disbursement_count(&env) + 1,
+            proposal_id,
+            category: proposal.category,
+            token: budget.token,
+            recipient: proposal.recipient.clone(),
+            amount: proposal.amount,
+            executed_at: Clock::now(&env),
+        };
+        TreasuryStorage::add_disbursement(&env, &disbursement);
+        TreasuryEvents::emit_disbursed(&env, disbursement.id, proposal_id, &proposal.recipient, proposal.amount);
+
+        Ok(disbursement.id)
+    }
+
+    /// How much more a category may spend in its current period
+    pub fn get_budget_headroom(env: Env, category: Symbol) -> i128 {
+        match TreasuryStorage::get_budget(&env, &category) {
+            Some(mut budget) => {
+                Self::roll_window(&env, &mut budget);
+                budget.period_limit - budget.window_spent
+            }
+            None => 0,
+        }
+    }
+
+    /// Get a category's budget
+    pub fn get_budget(env: Env, category: Symbol) -> Option<Budget> {
+        TreasuryStorage::get_budget(&env, &category)
+    }
+
+    /// Get every budget category
+    pub fn get_categories(env: Env) -> Vec<Symbol> {
+        TreasuryStorage::get_categories(&env)
+    }
+
+    /// Get a proposal by id
+    pub fn get_proposal(env: Env, proposal_id: u64) -> Option<SpendingProposal> {
+        let mut proposal = TreasuryStorage::get_proposal(&env, proposal_id)?;
+        if let Some(config) = TreasuryStorage::get_config(&env) {
+            Self::recount_approvals(&env, &config, &mut proposal);
+        }
+        Some(proposal)
+    }
+
+    /// Get a disbursement by id
+    pub fn get_disbursement(env: Env, disbursement_id: u64) -> Option<Disbursement> {
+        TreasuryStorage::get_disbursement(&env, disbursement_id)
+    }
+
+    /// Get up to `limit` disbursements starting at id `start`, oldest first
+    pub fn get_disbursements(env: Env, start: u64, limit: u32) -> Vec<Disbursement> {
+        let mut history = Vec::new(&env);
+        let end = TreasuryStorage::get_disbursement_count(&env);
+        let mut id = start.max(1);
+        while id <= end && history.len() < limit.min(MAX_PAGE) {
+            if let Some(disbursement) = TreasuryStorage::get_disbursement(&env, id) {
+                history.push_back(disbursement);
+            }
+            id += 1;
+        }
+        history
+    }
+
+    /// Get the number of disbursements made
+    pub fn get_disbursement_count(env: Env) -> u64 {
+        TreasuryStorage::get_disbursement_count(&env)
+    }
+
+    /// Get the approval configuration
+    pub fn get_config(env: Env) -> Option<TreasuryConfig> {
+        TreasuryStorage::get_config(&env)
+    }
+
+    /// Get the governance contract, if connected
+    pub fn get_governance(env: Env) -> Option<Address> {
+        TreasuryStorage::get_governance(&env)
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        TreasuryStorage::get_admin(&env)
+    }
+}
+
+impl Treasury {
+    /// Require the call to be authorized by the governance contract or by
+    /// `threshold` distinct current signers. The admin key alone cannot
+    /// change who controls spending.
+    fn require_quorum(env: &Env, approvers: &Vec<Address>) -> Result<(), TreasuryError> {
+        let config = Self::load_config(env)?;
+        let governance = TreasuryStorage::get_governance(env);
+        let mut by_governance = false;
+        let mut signer_approvals = 0;
+        for (index, approver) in approvers.iter().enumerate() {
+            if approvers.first_index_of(&approver) != Some(index as u32) {
+                return Err(TreasuryError::Unauthorized);
+            }
+            if governance.as_ref() == Some(&approver) {
+                by_governance = true;
+            } else if config.signers.contains(&approver) {
+                signer_approvals += 1;
+            } else {
+                return Err(TreasuryError::Unauthorized);
+            }
+            approver.require_auth();
+        }
+        if !by_governance && signer_approvals < config.threshold {
+            return Err(TreasuryError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    fn load_config(env: &Env) -> Result<TreasuryConfig, TreasuryError> {
+        TreasuryStorage::get_config(env).ok_or(TreasuryError::NotInitialized)
+    }
+
+    fn validate_config(config: &TreasuryConfig) -> Result<(), TreasuryError> {
+        let signers = config.signers.len();
+        if signers == 0 || signers > MAX_SIGNERS || config.threshold == 0 || config.threshold > signers {
+            return Err(TreasuryError::InvalidConfig);
+        }
+        for (index, signer) in config.signers.iter().enumerate() {
+            if config.signers.first_index_of(&signer) != Some(index as u32) {
+                return Err(TreasuryError::InvalidConfig);
+            }
+        }
+        if config.proposal_ttl == 0 {
+            return Err(TreasuryError::InvalidConfig);
+        }
+        Ok(())
+    }
+
+    /// Load a proposal that has not yet expired, counting only the
+    /// approvals that are still valid under the current config
+    fn load_live_proposal(env: &Env, proposal_id: u64) -> Result<SpendingProposal, TreasuryError> {
+        let mut proposal = TreasuryStorage::get_proposal(env, proposal_id).ok_or(TreasuryError::ProposalNotFound)?;
+        if Clock::has_passed(env, proposal.expires_at) {
+            return Err(TreasuryError::ProposalExpired);
+        }
+        Self::recount_approvals(env, &Self::load_config(env)?, &mut proposal);
+        Ok(proposal)
+    }
+
+    /// Drop approvals from addresses that are no longer signers or the
+    /// governance contract, then re-check the proposal against the threshold
+    fn recount_approvals(env: &Env, config: &TreasuryConfig, proposal: &mut SpendingProposal) {
+        if !matches!(proposal.status, ProposalStatus::Pending | ProposalStatus::Approved) {
+            return;
+        }
+
+        let governance = TreasuryStorage::get_governance(env);
+        let mut approvals = Vec::new(env);
+        let mut by_governance = false;
+        for approver in proposal.approvals.iter() {
+            if governance.as_ref() == Some(&approver) {
+                by_governance = true;
+            } else if !config.signers.contains(&approver) {
+                continue;
+            }
+            approvals.push_back(approver);
+        }
+
+        let signer_approvals = approvals.len() - by_governance as u32;
+        proposal.status = if by_governance || signer_approvals >= config.threshold {
+            ProposalStatus::Approved
+        } else {
+            ProposalStatus::Pending
+        };
+        proposal.approvals = approvals;
+    }
+
+    /// Add a signer's approval, passing the proposal at the threshold
+    fn record_approval(env: &Env, config: &TreasuryConfig, proposal: &mut SpendingProposal, signer: &Address) {
+        proposal.approvals.push_back(signer.clone());
+        if proposal.approvals.len() >= config.threshold {
+            proposal.status = ProposalStatus::Approved;
+        }
+        TreasuryEvents::emit_approved(env, proposal.id, signer, proposal.approvals.len());
+    }
+
+    /// Start a fresh spending window once the current period has elapsed
+    fn roll_window(env: &Env, budget: &mut Budget) {
+        let now = Clock::now(env);
+        if now >= budget.window_start.saturating_add(budget.period) {
+            budget.window_start = now;
+            budget.window_spent = 0;
+        }
+    }
+}
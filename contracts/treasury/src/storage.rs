@@ -0,0 +1,96 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+use crate::types::{Budget, DataKey, Disbursement, SpendingProposal, TreasuryConfig};
+
+/// Storage utilities for the treasury contract
+pub struct TreasuryStorage;
+
+impl TreasuryStorage {
+    /// Get the contract admin
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Set the contract admin
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Get the approval configuration
+    pub fn get_config(env: &Env) -> Option<TreasuryConfig> {
+        env.storage().instance().get(&DataKey::Config)
+    }
+
+    /// Set the approval configuration
+    pub fn set_config(env: &Env, config: &TreasuryConfig) {
+        env.storage().instance().set(&DataKey::Config, config);
+    }
+
+    /// Get the governance contract, if one is connected
+    pub fn get_governance(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Governance)
+    }
+
+    /// Connect the governance contract
+    pub fn set_governance(env: &Env, governance: &Address) {
+        env.storage().instance().set(&DataKey::Governance, governance);
+    }
+
+    /// Get a category's budget
+    pub fn get_budget(env: &Env, category: &Symbol) -> Option<Budget> {
+        env.storage().persistent().get(&DataKey::Budget(category.clone()))
+    }
+
+    /// Store a budget, indexing its category on first write
+    pub fn set_budget(env: &Env, budget: &Budget) {
+        let key = DataKey::Budget(budget.category.clone());
+        if !env.storage().persistent().has(&key) {
+            let mut categories = Self::get_categories(env);
+            categories.push_back(budget.category.clone());
+            env.storage().instance().set(&DataKey::Categories, &categories);
+        }
+        env.storage().persistent().set(&key, budget);
+    }
+
+    /// Get every budget category
+    pub fn get_categories(env: &Env) -> Vec<Symbol> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Categories)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Allocate the next proposal id
+    pub fn next_proposal_id(env: &Env) -> u64 {
+        let id: u64 = env.storage().instance().get(&DataKey::ProposalCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::ProposalCount, &id);
+        id
+    }
+
+    /// Get a proposal by id
+    pub fn get_proposal(env: &Env, proposal_id: u64) -> Option<SpendingProposal> {
+        env.storage().persistent().get(&DataKey::Proposal(proposal_id))
+    }
+
+    /// Store a proposal
+    pub fn set_proposal(env: &Env, proposal: &SpendingProposal) {
+        env.storage().persistent().set(&DataKey::Proposal(proposal.id), proposal);
+    }
+
+    /// Number of disbursements recorded so far
+    pub fn get_disbursement_count(env: &Env) -> u64 {
+        env.storage().instance().get(&DataKey::DisbursementCount).unwrap_or(0)
+    }
+
+    /// Append a disbursement to the history
+    pub fn add_disbursement(env: &Env, disbursement: &Disbursement) {
+        env.storage().instance().set(&DataKey::DisbursementCount, &disbursement.id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Disbursement(disbursement.id), disbursement);
+    }
+
+    /// Get a disbursement by id
+    pub fn get_disbursement(env: &Env, disbursement_id: u64) -> Option<Disbursement> {
+        env.storage().persistent().get(&DataKey::Disbursement(disbursement_id))
+    }
+}
@@ -0,0 +1,244 @@
+use soroban_sdk::{
+    symbol_short, testutils::{Address as _, Ledger}, token, vec, Address, Env, String, Symbol, Vec,
+};
+use shared::time::DAY;
+
+use crate::{ProposalStatus, Treasury, TreasuryClient, TreasuryConfig, TreasuryError};
+
+fn setup_test_env() -> (
+    Env,
+    TreasuryClient<'static>,
+    Address,
+    [Address; 3],
+    token::Client<'static>,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Treasury, ());
+    let client = TreasuryClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let signers: [Address; 3] = core::array::from_fn(|_| Address::generate(&env));
+
+    // 2-of-3 multisig
+    let config = TreasuryConfig {
+        signers: vec![&env, signers[0].clone(), signers[1].clone(), signers[2].clone()],
+        threshold: 2,
+        proposal_ttl: 7 * DAY,
+    };
+    client.initialize(&admin, &config);
+
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &asset.address());
+    token::StellarAssetClient::new(&env, &asset.address()).mint(&admin, &10_000);
+    client.deposit(&admin, &asset.address(), &10_000);
+
+    // 1,000 per 30 days for grants
+    client.set_budget(&quorum(&env, &signers), &grants(), &asset.address(), &1_000, &(30 * DAY));
+
+    (env, client, admin, signers, token)
+}
+
+fn grants() -> Symbol {
+    symbol_short!("grants")
+}
+
+/// Two of the three signers, enough to change the configuration
+fn quorum(env: &Env, signers: &[Address; 3]) -> Vec<Address> {
+    vec![env, signers[0].clone(), signers[1].clone()]
+}
+
+fn propose(
+    env: &Env,
+    client: &TreasuryClient,
+    signers: &[Address; 3],
+    recipient: &Address,
+    amount: i128,
+) -> u64 {
+    client.propose(&signers[0], &grants(), recipient, &amount, &String::from_str(env, "course grant"))
+}
+
+#[test]
+fn test_multisig_approval_and_history() {
+    let (env, client, _admin, signers, token) = setup_test_env();
+    let recipient = Address::generate(&env);
+    let proposal_id = propose(&env, &client, &signers, &recipient, 400);
+
+    assert_eq!(
+        client.try_execute(&proposal_id),
+        Err(Ok(TreasuryError::InvalidProposalState))
+    );
+    assert_eq!(
+        client.try_approve(&signers[0], &proposal_id),
+        Err(Ok(TreasuryError::AlreadyApproved))
+    );
+    assert_eq!(
+        client.try_approve(&recipient, &proposal_id),
+        Err(Ok(TreasuryError::Unauthorized))
+    );
+    assert_eq!(client.approve(&signers[2], &proposal_id), ProposalStatus::Approved);
+
+    assert_eq!(client.execute(&proposal_id), 1);
+    assert_eq!(token.balance(&recipient), 400);
+    assert_eq!(client.get_proposal(&proposal_id).unwrap().status, ProposalStatus::Executed);
+
+    let history = client.get_disbursements(&1, &10);
+    assert_eq!(history.len(), 1);
+    let disbursement = history.get(0).unwrap();
+    assert_eq!(disbursement.proposal_id, proposal_id);
+    assert_eq!(disbursement.amount, 400);
+    assert_eq!(client.get_budget(&grants()).unwrap().total_spent, 400);
+}
+
+#[test]
+fn test_period_limit_resets_each_period() {
+    let (env, client, _admin, signers, token) = setup_test_env();
+    let recipient = Address::generate(&env);
+    let first = propose(&env, &client, &signers, &recipient, 700);
+    let second = propose(&env, &client, &signers, &recipient, 700);
+    client.approve(&signers[1], &first);
+    client.approve(&signers[1], &second);
+
+    client.execute(&first);
+    assert_eq!(client.get_budget_headroom(&grants()), 300);
+    assert_eq!(client.try_execute(&second), Err(Ok(TreasuryError::BudgetExceeded)));
+
+    // Approved proposals expire after a week, so re-propose next period
+    env.ledger().set_timestamp(30 * DAY);
+    assert_eq!(client.try_execute(&second), Err(Ok(TreasuryError::ProposalExpired)));
+    let third = propose(&env, &client, &signers, &recipient, 700);
+    client.approve(&signers[2], &third);
+    client.execute(&third);
+    assert_eq!(token.balance(&recipient), 1_400);
+    assert_eq!(client.get_disbursement_count(), 2);
+}
+
+#[test]
+fn test_governance_approval_and_config_changes() {
+    let (env, client, admin, signers, _token) = setup_test_env();
+    let governance = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    client.set_governance(&quorum(&env, &signers), &governance);
+
+    let proposal_id = propose(&env, &client, &signers, &recipient, 100);
+    assert_eq!(client.approve(&governance, &proposal_id), ProposalStatus::Approved);
+    client.execute(&proposal_id);
+
+    // Governance can retune the multisig; bad configs are refused
+    let config = TreasuryConfig {
+        signers: vec![&env, signers[0].clone(), signers[0].clone()],
+        threshold: 1,
+        proposal_ttl: DAY,
+    };
+    assert_eq!(
+        client.try_set_config(&vec![&env, governance.clone()], &config),
+        Err(Ok(TreasuryError::InvalidConfig))
+    );
+    let config = TreasuryConfig { signers: vec![&env, signers[0].clone()], ..config };
+    client.set_config(&vec![&env, governance.clone()], &config);
+    let single = propose(&env, &client, &signers, &recipient, 50);
+    assert_eq!(client.get_proposal(&single).unwrap().status, ProposalStatus::Approved);
+
+    let cancelled = client.propose(&signers[0], &grants(), &recipient, &10, &String::from_str(&env, ""));
+    client.cancel(&admin, &cancelled);
+    assert_eq!(client.try_execute(&cancelled), Err(Ok(TreasuryError::InvalidProposalState)));
+    assert_eq!(
+        client.try_set_config(&vec![&env, recipient.clone()], &config),
+        Err(Ok(TreasuryError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_admin_alone_cannot_take_over_spending() {
+    let (env, client, admin, signers, token) = setup_test_env();
+    let takeover = TreasuryConfig {
+        signers: vec![&env, admin.clone()],
+        threshold: 1,
+        proposal_ttl: DAY,
+    };
+    let admin_only = vec![&env, admin.clone()];
+    assert_eq!(client.try_set_config(&admin_only, &takeover), Err(Ok(TreasuryError::Unauthorized)));
+    assert_eq!(
+        client.try_set_budget(&admin_only, &grants(), &token.address, &10_000, &DAY),
+        Err(Ok(TreasuryError::Unauthorized))
+    );
+    assert_eq!(client.try_set_governance(&admin_only, &admin), Err(Ok(TreasuryError::Unauthorized)));
+
+    // One signer is below the threshold, and naming it twice does not help
+    let one_signer = vec![&env, signers[0].clone()];
+    assert_eq!(client.try_set_config(&one_signer, &takeover), Err(Ok(TreasuryError::Unauthorized)));
+    let repeated = vec![&env, signers[0].clone(), signers[0].clone()];
+    assert_eq!(client.try_set_config(&repeated, &takeover), Err(Ok(TreasuryError::Unauthorized)));
+    let with_admin = vec![&env, signers[0].clone(), admin.clone()];
+    assert_eq!(client.try_set_config(&with_admin, &takeover), Err(Ok(TreasuryError::Unauthorized)));
+    assert_eq!(client.get_config().unwrap().threshold, 2);
+
+    client.set_config(&quorum(&env, &signers), &takeover);
+    assert_eq!(client.get_config().unwrap().signers, vec![&env, admin.clone()]);
+}
+
+#[test]
+fn test_removed_signers_approvals_stop_counting() {
+    let (env, client, _admin, signers, token) = setup_test_env();
+    let governance = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    client.set_governance(&quorum(&env, &signers), &governance);
+
+    let proposal_id = propose(&env, &client, &signers, &recipient, 300);
+    assert_eq!(client.approve(&signers[1], &proposal_id), ProposalStatus::Approved);
+    let voted = propose(&env, &client, &signers, &recipient, 200);
+    client.approve(&governance, &voted);
+
+    // Dropping the second signer takes the first proposal back below threshold
+    let config = TreasuryConfig {
+        signers: vec![&env, signers[0].clone(), signers[2].clone()],
+        threshold: 2,
+        proposal_ttl: 7 * DAY,
+    };
+    client.set_config(&quorum(&env, &signers), &config);
+    let proposal = client.get_proposal(&proposal_id).unwrap();
+    assert_eq!(proposal.status, ProposalStatus::Pending);
+    assert_eq!(proposal.approvals, vec![&env, signers[0].clone()]);
+    assert_eq!(client.try_execute(&proposal_id), Err(Ok(TreasuryError::InvalidProposalState)));
+
+    assert_eq!(client.approve(&signers[2], &proposal_id), ProposalStatus::Approved);
+    client.execute(&proposal_id);
+    // A governance approval survives signer changes
+    client.execute(&voted);
+    assert_eq!(token.balance(&recipient), 500);
+}
+
+#[test]
+fn test_spending_error_paths() {
+    let (env, client, admin, signers, token) = setup_test_env();
+    let recipient = Address::generate(&env);
+    let memo = String::from_str(&env, "");
+
+    assert_eq!(client.try_deposit(&admin, &token.address, &0), Err(Ok(TreasuryError::InvalidAmount)));
+    assert_eq!(
+        client.try_propose(&recipient, &grants(), &recipient, &100, &memo),
+        Err(Ok(TreasuryError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_propose(&signers[0], &symbol_short!("travel"), &recipient, &100, &memo),
+        Err(Ok(TreasuryError::BudgetNotFound))
+    );
+    assert_eq!(
+        client.try_propose(&signers[0], &grants(), &recipient, &0, &memo),
+        Err(Ok(TreasuryError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_set_budget(&vec![&env, recipient.clone()], &grants(), &token.address, &1_000, &DAY),
+        Err(Ok(TreasuryError::Unauthorized))
+    );
+    assert_eq!(client.try_approve(&signers[1], &99), Err(Ok(TreasuryError::ProposalNotFound)));
+
+    // Only the proposer or an admin may cancel, and a proposal pays once
+    let proposal_id = propose(&env, &client, &signers, &recipient, 300);
+    assert_eq!(client.try_cancel(&signers[1], &proposal_id), Err(Ok(TreasuryError::Unauthorized)));
+    client.approve(&signers[1], &proposal_id);
+    client.execute(&proposal_id);
+    assert_eq!(client.try_execute(&proposal_id), Err(Ok(TreasuryError::InvalidProposalState)));
+    assert_eq!(client.try_cancel(&admin, &proposal_id), Err(Ok(TreasuryError::InvalidProposalState)));
+    assert_eq!(token.balance(&recipient), 300);
+}
@@ -0,0 +1,77 @@
+use soroban_sdk::{contracttype, Address, String, Symbol, Vec};
+
+/// Who can approve spending
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TreasuryConfig {
+    pub signers: Vec<Address>,
+    pub threshold: u32,    // signer approvals needed to pass a proposal
+    pub proposal_ttl: u64, // seconds a proposal stays executable
+}
+
+/// Spending allowance for one category, in one token
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Budget {
+    pub category: Symbol,
+    pub token: Address,
+    pub period: u64,
+    pub period_limit: i128,
+    pub window_start: u64,
+    pub window_spent: i128,
+    pub total_spent: i128,
+}
+
+/// Lifecycle of a spending proposal
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum ProposalStatus {
+    Pending,
+    Approved,
+    Executed,
+    Cancelled,
+}
+
+/// A request to pay `amount` from a budget category
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SpendingProposal {
+    pub id: u64,
+    pub proposer: Address,
+    pub category: Symbol,
+    pub recipient: Address,
+    pub amount: i128,
+    pub memo: String,
+    pub approvals: Vec<Address>,
+    pub status: ProposalStatus,
+    pub created_at: u64,
+    pub expires_at: u64,
+}
+
+/// A completed payment out of the treasury
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Disbursement {
+    pub id: u64,
+    pub proposal_id: u64,
+    pub category: Symbol,
+    pub token: Address,
+    pub recipient: Address,
+    pub amount: i128,
+    pub executed_at: u64,
+}
+
+/// Storage keys for the treasury contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Config,
+    Governance,
+    Budget(Symbol),
+    Categories,
+    ProposalCount,
+    Proposal(u64),
+    DisbursementCount,
+    Disbursement(u64),
+}
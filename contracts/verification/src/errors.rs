@@ -0,0 +1,23 @@
+use soroban_sdk::contracterror;
+
+/// Verification contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum VerificationError {
+    // Initialization errors
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+
+    // Authorization errors
+    Unauthorized = 3,
+    NoAccess = 4,
+
+    // Configuration errors
+    InvalidConfig = 5,
+
+    // Verification errors
+    EmptyBatch = 6,
+    BatchTooLarge = 7,
+    RegistryUnavailable = 8,
+}
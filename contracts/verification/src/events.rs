@@ -0,0 +1,42 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+/// Verification contract events
+pub struct VerificationEvents;
+
+impl VerificationEvents {
+    fn topic(env: &Env, name: &str) -> (Symbol, Symbol) {
+        (Symbol::new(env, "verification"), Symbol::new(env, name))
+    }
+
+    /// Emit event when the configuration changes
+    pub fn emit_config_updated(env: &Env, pass_price: i128, pass_duration: u64) {
+        env.events().publish(
+            Self::topic(env, "config_updated"),
+            (pass_price, pass_duration),
+        );
+    }
+
+    /// Emit event when an employer buys or extends a pass
+    pub fn emit_pass_purchased(env: &Env, employer: &Address, price: i128, expires_at: u64) {
+        env.events().publish(
+            Self::topic(env, "pass_purchased"),
+            (employer.clone(), price, expires_at),
+        );
+    }
+
+    /// Emit event when an employer is added to or removed from the allowlist
+    pub fn emit_allowlist_updated(env: &Env, employer: &Address, allowed: bool) {
+        env.events().publish(
+            Self::topic(env, "allowlist_updated"),
+            (employer.clone(), allowed),
+        );
+    }
+
+    /// Emit event when an employer verifies a credential
+    pub fn emit_verified(env: &Env, record_id: u64, employer: &Address, student: &Address, course_id: &Symbol, valid: bool) {
+        env.events().publish(
+            Self::topic(env, "verified"),
+            (record_id, employer.clone(), student.clone(), course_id.clone(), valid),
+        );
+    }
+}
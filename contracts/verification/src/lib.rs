@@ -0,0 +1,221 @@
+#![no_std]
+
+mod types;
+mod errors;
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod tests;
+
+use soroban_sdk::{contract, contractimpl, token, vec, Address, Env, IntoVal, Symbol, Vec};
+
+use shared::{access_control::AccessControl, cross_contract::CrossContract, time::Clock};
+
+pub use types::{VerificationConfig, VerificationQuery, VerificationRecord};
+pub use errors::VerificationError;
+use events::VerificationEvents;
+use storage::VerificationStorage;
+
+/// Upper bound on queries in one `verify_batch` call
+const MAX_BATCH: u32 = 25;
+
+/// Upper bound on records returned by one `get_verifications` call
+const MAX_RECORD_PAGE: u32 = 50;
+
+#[contract]
+pub struct VerificationContract;
+
+#[contractimpl]
+impl VerificationContract {
+    /// Initialize the contract with pass pricing and the registry to check
+    pub fn initialize(env: Env, admin: Address, config: VerificationConfig) -> Result<(), VerificationError> {
+        if VerificationStorage::get_admin(&env).is_some() {
+            return Err(VerificationError::AlreadyInitialized);
+        }
+        Self::validate_config(&config)?;
+
+        AccessControl::initialize(&env, &admin).map_err(|_| VerificationError::AlreadyInitialized)?;
+        VerificationStorage::set_admin(&env, &admin);
+        VerificationStorage::set_config(&env, &config);
+
+        Ok(())
+    }
+
+    /// Update pass pricing or the registry (admin only). Passes already
+    /// bought keep their expiry.
+    pub fn update_config(env: Env, admin: Address, config: VerificationConfig) -> Result<(), VerificationError> {
+        Self::require_admin(&env, &admin)?;
+        Self::validate_config(&config)?;
+
+        VerificationStorage::set_config(&env, &config);
+        VerificationEvents::emit_config_updated(&env, config.pass_price, config.pass_duration);
+
+        Ok(())
+    }
+
+    /// Let an employer verify without a pass, or withdraw that (admin only)
+    pub fn set_allowlisted(env: Env, admin: Address, employer: Address, allowed: bool) -> Result<(), VerificationError> {
+        Self::require_admin(&env, &admin)?;
+
+        VerificationStorage::set_allowlisted(&env, &employer, allowed);
+        VerificationEvents::emit_allowlist_updated(&env, &employer, allowed);
+
+        Ok(())
+    }
+
+    /// Buy a verification pass. Buying before the current pass runs out
+    /// extends it. Returns the new expiry.
+    pub fn buy_pass(env: Env, employer: Address) -> Result<u64, VerificationError> {
+        employer.require_auth();
+        let config = Self::load_config(&env)?;
+
+        token::Client::new(&env, &config.token).transfer(&employer, &config.treasury, &config.pass_price);
+
+        let now = Clock::now(&env);
+        let start = VerificationStorage::get_pass_expiry(&env, &employer).map_or(now, |expiry| expiry.max(now));
+        let expires_at = start.saturating_add(config.pass_duration);
+        VerificationStorage::set_pass_expiry(&env, &employer, expires_at);
+        VerificationEvents::emit_pass_purchased(&env, &employer, config.pass_price, expires_at);
+
+        Ok(expires_at)
+    }
+
+    /// Check whether a student completed a course. The check is logged so
+    /// the student can see who verified them.
+    pub fn verify(env: Env, employer: Address, student: Address, course_id: Symbol) -> Result<bool, VerificationError> {
+        let config = Self::authorize_employer(&env, &employer)?;
+        Self::check_and_log(&env, &config, &employer, student, course_id)
+    }
+
+    /// Check up to 25 credentials at once, for HR systems screening many
+    /// candidates. Results are in query order and each check is logged.
+    pub fn verify_batch(
+        env: Env,
+        employer: Address,
+        queries: Vec<VerificationQuery>,
+    ) -> Result<Vec<bool>, VerificationError> {
+        if queries.is_empty() {
+            return Err(VerificationError::EmptyBatch);
+        }
+        if queries.len() > MAX_BATCH {
+            return Err(VerificationError::BatchTooLarge);
+        }
+        let config = Self::authorize_employer(&env, &employer)?;
+
+        let mut results = Vec::new(&env);
+        for query in queries.iter() {
+            results.push_back(Self::check_and_log(&env, &config, &employer, query.student, query.course_id)?);
+        }
+        Ok(results)
+    }
+
+    /// Whether an employer may currently verify
+    pub fn has_access(env: Env, employer: Address) -> bool {
+        Self::can_verify(&env, &employer)
+    }
+
+    /// Get when an employer's pass expires, if it ever bought one
+    pub fn get_pass_expiry(env: Env, employer: Address) -> Option<u64> {
+        VerificationStorage::get_pass_expiry(&env, &employer)
+    }
+
+    /// Whether an employer is on the allowlist
+    pub fn is_allowlisted(env: Env, employer: Address) -> bool {
+        VerificationStorage::is_allowlisted(&env, &employer)
+    }
+
+    /// Get a verification record
+    pub fn get_record(env: Env, record_id: u64) -> Option<VerificationRecord> {
+        VerificationStorage::get_record(&env, record_id)
+    }
+
+    /// Get a page of the verifications of a student's credentials, oldest
+    /// first. At most 50 are returned per call.
+    pub fn get_verifications(env: Env, student: Address, offset: u32, limit: u32) -> Vec<VerificationRecord> {
+        let ids = VerificationStorage::get_student_records(&env, &student);
+        let end = offset.saturating_add(limit.min(MAX_RECORD_PAGE)).min(ids.len());
+        let mut records = Vec::new(&env);
+        for index in offset..end {
+            if let Some(record) = VerificationStorage::get_record(&env, ids.get_unchecked(index)) {
+                records.push_back(record);
+            }
+        }
+        records
+    }
+
+    /// Get how many times a student's credentials were verified
+    pub fn get_verification_count(env: Env, student: Address) -> u32 {
+        VerificationStorage::get_student_records(&env, &student).len()
+    }
+
+    /// Get the contract configuration
+    pub fn get_config(env: Env) -> Option<VerificationConfig> {
+        VerificationStorage::get_config(&env)
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        VerificationStorage::get_admin(&env)
+    }
+}
+
+impl VerificationContract {
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), VerificationError> {
+        caller.require_auth();
+        if !AccessControl::is_admin(env, caller) {
+            return Err(VerificationError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    fn validate_config(config: &VerificationConfig) -> Result<(), VerificationError> {
+        if config.pass_price <= 0 || config.pass_duration == 0 {
+            return Err(VerificationError::InvalidConfig);
+        }
+        Ok(())
+    }
+
+    fn load_config(env: &Env) -> Result<VerificationConfig, VerificationError> {
+        VerificationStorage::get_config(env).ok_or(VerificationError::NotInitialized)
+    }
+
+    fn can_verify(env: &Env, employer: &Address) -> bool {
+        VerificationStorage::is_allowlisted(env, employer)
+            || VerificationStorage::get_pass_expiry(env, employer).is_some_and(|expiry| Clock::now(env) < expiry)
+    }
+
+    fn authorize_employer(env: &Env, employer: &Address) -> Result<VerificationConfig, VerificationError> {
+        employer.require_auth();
+        let config = Self::load_config(env)?;
+        if !Self::can_verify(env, employer) {
+            return Err(VerificationError::NoAccess);
+        }
+        Ok(config)
+    }
+
+    fn check_and_log(
+        env: &Env,
+        config: &VerificationConfig,
+        employer: &Address,
+        student: Address,
+        course_id: Symbol,
+    ) -> Result<bool, VerificationError> {
+        let args = vec![env, student.into_val(env), course_id.into_val(env)];
+        let valid = CrossContract::invoke::<bool>(env, &config.registry, &Symbol::new(env, "has_completed"), args)
+            .map_err(|_| VerificationError::RegistryUnavailable)?;
+
+        let record = VerificationRecord {
+            id: VerificationStorage::next_record_id(env),
+            employer: employer.clone(),
+            student,
+            course_id,
+            valid,
+            verified_at: Clock::now(env),
+        };
+        VerificationStorage::add_record(env, &record);
+        VerificationEvents::emit_verified(env, record.id, employer, &record.student, &record.course_id, valid);
+
+        Ok(valid)
+    }
+}
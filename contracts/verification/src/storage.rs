@@ -0,0 +1,87 @@
+use soroban_sdk::{Address, Env, Vec};
+use crate::types::{DataKey, VerificationConfig, VerificationRecord};
+
+/// Storage utilities for the verification contract
+pub struct VerificationStorage;
+
+impl VerificationStorage {
+    /// Get the contract admin
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Set the contract admin
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Get the contract configuration
+    pub fn get_config(env: &Env) -> Option<VerificationConfig> {
+        env.storage().instance().get(&DataKey::Config)
+    }
+
+    /// Set the contract configuration
+    pub fn set_config(env: &Env, config: &VerificationConfig) {
+        env.storage().instance().set(&DataKey::Config, config);
+    }
+
+    /// Get when an employer's pass expires
+    pub fn get_pass_expiry(env: &Env, employer: &Address) -> Option<u64> {
+        env.storage().persistent().get(&DataKey::PassExpiry(employer.clone()))
+    }
+
+    /// Set when an employer's pass expires
+    pub fn set_pass_expiry(env: &Env, employer: &Address, expires_at: u64) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::PassExpiry(employer.clone()), &expires_at);
+    }
+
+    /// Whether an employer may verify without a pass
+    pub fn is_allowlisted(env: &Env, employer: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Allowlisted(employer.clone()))
+            .unwrap_or(false)
+    }
+
+    /// Add an employer to or remove it from the allowlist
+    pub fn set_allowlisted(env: &Env, employer: &Address, allowed: bool) {
+        let key = DataKey::Allowlisted(employer.clone());
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    /// Allocate the next verification record id
+    pub fn next_record_id(env: &Env) -> u64 {
+        let id: u64 = env.storage().instance().get(&DataKey::RecordCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::RecordCount, &id);
+        id
+    }
+
+    /// Get a verification record
+    pub fn get_record(env: &Env, record_id: u64) -> Option<VerificationRecord> {
+        env.storage().persistent().get(&DataKey::Record(record_id))
+    }
+
+    /// Store a verification record and index it under its student
+    pub fn add_record(env: &Env, record: &VerificationRecord) {
+        env.storage().persistent().set(&DataKey::Record(record.id), record);
+        let mut records = Self::get_student_records(env, &record.student);
+        records.push_back(record.id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::StudentRecords(record.student.clone()), &records);
+    }
+
+    /// Get the ids of every verification of a student's credentials
+    pub fn get_student_records(env: &Env, student: &Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::StudentRecords(student.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+}
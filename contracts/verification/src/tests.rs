@@ -0,0 +1,177 @@
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::{Address as _, Ledger}, token, vec, Address, Env, Symbol,
+};
+
+use shared::time::DAY;
+
+use crate::{VerificationConfig, VerificationContract, VerificationContractClient, VerificationError, VerificationQuery};
+
+/// Stand-in for the Enrollment contract's completion records
+#[contract]
+pub struct MockRegistry;
+
+#[contractimpl]
+impl MockRegistry {
+    pub fn complete(env: Env, student: Address, course_id: Symbol) {
+        env.storage().persistent().set(&(student, course_id), &true);
+    }
+
+    pub fn has_completed(env: Env, student: Address, course_id: Symbol) -> bool {
+        env.storage().persistent().has(&(student, course_id))
+    }
+}
+
+fn setup_test_env() -> (
+    Env,
+    VerificationContractClient<'static>,
+    MockRegistryClient<'static>,
+    Address,
+    Address,
+    token::Client<'static>,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let registry_id = env.register(MockRegistry, ());
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    let contract_id = env.register(VerificationContract, ());
+    let client = VerificationContractClient::new(&env, &contract_id);
+    client.initialize(
+        &admin,
+        &VerificationConfig {
+            registry: registry_id.clone(),
+            token: asset.address(),
+            pass_price: 500,
+            pass_duration: 30 * DAY,
+            treasury: treasury.clone(),
+        },
+    );
+
+    let registry = MockRegistryClient::new(&env, &registry_id);
+    let token = token::Client::new(&env, &asset.address());
+    (env, client, registry, admin, treasury, token)
+}
+
+fn funded_employer(env: &Env, token: &token::Client) -> Address {
+    let employer = Address::generate(env);
+    token::StellarAssetClient::new(env, &token.address).mint(&employer, &1_000);
+    employer
+}
+
+#[test]
+fn test_pass_holder_verifies_and_student_sees_log() {
+    let (env, client, registry, _admin, treasury, token) = setup_test_env();
+    let employer = funded_employer(&env, &token);
+    let student = Address::generate(&env);
+    registry.complete(&student, &symbol_short!("rust101"));
+    assert_eq!(
+        client.try_verify(&employer, &student, &symbol_short!("rust101")),
+        Err(Ok(VerificationError::NoAccess))
+    );
+
+    assert_eq!(client.buy_pass(&employer), 30 * DAY);
+    assert_eq!(token.balance(&treasury), 500);
+    assert!(client.verify(&employer, &student, &symbol_short!("rust101")));
+    assert!(!client.verify(&employer, &student, &symbol_short!("web3")));
+
+    let log = client.get_verifications(&student, &0, &10);
+    assert_eq!(log.len(), 2);
+    assert_eq!(log.get_unchecked(0).employer, employer);
+    assert!(log.get_unchecked(0).valid);
+    assert!(!log.get_unchecked(1).valid);
+}
+
+#[test]
+fn test_pass_expires_and_extends() {
+    let (env, client, _registry, admin, _treasury, token) = setup_test_env();
+    let employer = funded_employer(&env, &token);
+    client.buy_pass(&employer);
+    env.ledger().set_timestamp(10 * DAY);
+    // Buying again extends from the current expiry
+    assert_eq!(client.buy_pass(&employer), 60 * DAY);
+
+    env.ledger().set_timestamp(60 * DAY);
+    assert!(!client.has_access(&employer));
+
+    let partner = Address::generate(&env);
+    client.set_allowlisted(&admin, &partner, &true);
+    assert!(client.has_access(&partner));
+    client.set_allowlisted(&admin, &partner, &false);
+    assert!(!client.has_access(&partner));
+}
+
+#[test]
+fn test_batch_verification() {
+    let (env, client, registry, admin, _treasury, _token) = setup_test_env();
+    let employer = Address::generate(&env);
+    client.set_allowlisted(&admin, &employer, &true);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    registry.complete(&alice, &symbol_short!("rust101"));
+
+    let queries = vec![
+        &env,
+        VerificationQuery { student: alice.clone(), course_id: symbol_short!("rust101") },
+        VerificationQuery { student: bob.clone(), course_id: symbol_short!("rust101") },
+    ];
+    assert_eq!(client.verify_batch(&employer, &queries), vec![&env, true, false]);
+    assert_eq!(client.get_verification_count(&alice), 1);
+    assert_eq!(client.get_verification_count(&bob), 1);
+    assert_eq!(
+        client.try_verify_batch(&employer, &vec![&env]),
+        Err(Ok(VerificationError::EmptyBatch))
+    );
+}
+
+#[test]
+fn test_configuration_access_and_registry_errors() {
+    let (env, client, _registry, admin, treasury, token) = setup_test_env();
+    let mut config = client.get_config().unwrap();
+    let employer = funded_employer(&env, &token);
+    assert_eq!(client.try_initialize(&admin, &config), Err(Ok(VerificationError::AlreadyInitialized)));
+    assert_eq!(
+        client.try_update_config(&employer, &config),
+        Err(Ok(VerificationError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_allowlisted(&employer, &employer, &true),
+        Err(Ok(VerificationError::Unauthorized))
+    );
+    config.pass_duration = 0;
+    assert_eq!(client.try_update_config(&admin, &config), Err(Ok(VerificationError::InvalidConfig)));
+
+    let student = Address::generate(&env);
+    let query = VerificationQuery { student: student.clone(), course_id: symbol_short!("rust101") };
+    let mut queries = vec![&env];
+    for _ in 0..26 {
+        queries.push_back(query.clone());
+    }
+    assert_eq!(
+        client.try_verify_batch(&employer, &queries),
+        Err(Ok(VerificationError::BatchTooLarge))
+    );
+    assert_eq!(
+        client.try_verify_batch(&employer, &vec![&env, query]),
+        Err(Ok(VerificationError::NoAccess))
+    );
+
+    // A registry that cannot answer fails the check instead of logging it as invalid
+    client.buy_pass(&employer);
+    config.pass_duration = 30 * DAY;
+    config.registry = Address::generate(&env);
+    client.update_config(&admin, &config);
+    assert_eq!(
+        client.try_verify(&employer, &student, &symbol_short!("rust101")),
+        Err(Ok(VerificationError::RegistryUnavailable))
+    );
+    assert_eq!(client.get_verification_count(&student), 0);
+
+    env.ledger().set_timestamp(30 * DAY);
+    assert_eq!(
+        client.try_verify(&employer, &student, &symbol_short!("rust101")),
+        Err(Ok(VerificationError::NoAccess))
+    );
+    assert_eq!(token.balance(&treasury), 500);
+}
@@ -0,0 +1,45 @@
+use soroban_sdk::{contracttype, Address, Symbol};
+
+/// Pass pricing and where completions are looked up
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct VerificationConfig {
+    pub registry: Address, // Enrollment contract answering `has_completed`
+    pub token: Address,    // token passes are paid in
+    pub pass_price: i128,
+    pub pass_duration: u64, // seconds a pass lasts
+    pub treasury: Address,  // receives pass payments
+}
+
+/// One credential an employer asks about
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct VerificationQuery {
+    pub student: Address,
+    pub course_id: Symbol,
+}
+
+/// A logged verification
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct VerificationRecord {
+    pub id: u64,
+    pub employer: Address,
+    pub student: Address,
+    pub course_id: Symbol,
+    pub valid: bool,
+    pub verified_at: u64,
+}
+
+/// Storage keys for the verification contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Config,
+    PassExpiry(Address),
+    Allowlisted(Address),
+    RecordCount,
+    Record(u64),
+    StudentRecords(Address),
+}
@@ -0,0 +1,24 @@
+use soroban_sdk::contracterror;
+
+/// Vesting contract errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum VestingError {
+    // Initialization errors
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+
+    // Authorization errors
+    Unauthorized = 3,
+
+    // Grant errors
+    InvalidSchedule = 4,
+    InvalidAmount = 5,
+    GrantNotFound = 6,
+    NotRevocable = 7,
+    AlreadyRevoked = 8,
+
+    // Claim errors
+    NothingToClaim = 9,
+}
@@ -0,0 +1,34 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+/// Vesting contract events
+pub struct VestingEvents;
+
+impl VestingEvents {
+    fn topic(env: &Env, name: &str) -> (Symbol, Symbol) {
+        (Symbol::new(env, "vesting"), Symbol::new(env, name))
+    }
+
+    /// Emit event when a grant is created
+    pub fn emit_grant_created(env: &Env, grant_id: u64, beneficiary: &Address, total: i128) {
+        env.events().publish(
+            Self::topic(env, "grant_created"),
+            (grant_id, beneficiary.clone(), total),
+        );
+    }
+
+    /// Emit event when a beneficiary claims vested tokens
+    pub fn emit_claimed(env: &Env, grant_id: u64, beneficiary: &Address, amount: i128) {
+        env.events().publish(
+            Self::topic(env, "claimed"),
+            (grant_id, beneficiary.clone(), amount),
+        );
+    }
+
+    /// Emit event when a grant is revoked
+    pub fn emit_revoked(env: &Env, grant_id: u64, vested: i128, returned: i128) {
+        env.events().publish(
+            Self::topic(env, "revoked"),
+            (grant_id, vested, returned),
+        );
+    }
+}
@@ -0,0 +1,189 @@
+#![no_std]
+
+mod types;
+mod errors;
+mod events;
+mod storage;
+
+#[cfg(test)]
+mod tests;
+
+use soroban_sdk::{contract, contractimpl, token, Address, Env, Vec};
+
+use shared::{
+    access_control::AccessControl,
+    reentrancy_guard::ReentrancyLock,
+    time::Clock,
+};
+
+pub use types::{Grant, VestingSchedule};
+pub use errors::VestingError;
+use events::VestingEvents;
+use storage::VestingStorage;
+
+#[contract]
+pub struct Vesting;
+
+#[contractimpl]
+impl Vesting {
+    /// Initialize the contract and the shared RBAC system
+    pub fn initialize(env: Env, admin: Address) -> Result<(), VestingError> {
+        if VestingStorage::get_admin(&env).is_some() {
+            return Err(VestingError::AlreadyInitialized);
+        }
+
+        AccessControl::initialize(&env, &admin).map_err(|_| VestingError::AlreadyInitialized)?;
+        VestingStorage::set_admin(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Lock `total` tokens from the funder (an admin) for a beneficiary.
+    /// Nothing vests before `start + cliff`; after that tokens vest linearly
+    /// from `start` until `start + duration`.
+    pub fn create_grant(
+        env: Env,
+        funder: Address,
+        beneficiary: Address,
+        token: Address,
+        total: i128,
+        schedule: VestingSchedule,
+        revocable: bool,
+    ) -> Result<u64, VestingError> {
+        let _guard = ReentrancyLock::new(&env);
+        Self::require_admin(&env, &funder)?;
+
+        if total <= 0 {
+            return Err(VestingError::InvalidAmount);
+        }
+        if schedule.duration == 0 || schedule.cliff > schedule.duration {
+            return Err(VestingError::InvalidSchedule);
+        }
+
+        token::Client::new(&env, &token).transfer(&funder, &env.current_contract_address(), &total);
+
+        let grant = Grant {
+            id: VestingStorage::next_grant_id(&env),
+            beneficiary: beneficiary.clone(),
+            funder,
+            token,
+            total,
+            claimed: 0,
+            schedule,
+            revocable,
+            revoked_at: None,
+        };
+        VestingStorage::set_grant(&env, &grant);
+        VestingStorage::add_beneficiary_grant(&env, &beneficiary, grant.id);
+        VestingEvents::emit_grant_created(&env, grant.id, &beneficiary, total);
+
+        Ok(grant.id)
+    }
+
+    /// Transfer everything vested but not yet claimed to the beneficiary
+    pub fn claim(env: Env, beneficiary: Address, grant_id: u64) -> Result<i128, VestingError> {
+        let _guard = ReentrancyLock::new(&env);
+        beneficiary.require_auth();
+
+        let mut grant = Self::load_grant(&env, grant_id)?;
+        if grant.beneficiary != beneficiary {
+            return Err(VestingError::Unauthorized);
+        }
+
+        let amount = Self::vested(&env, &grant) - grant.claimed;
+        if amount <= 0 {
+            return Err(VestingError::NothingToClaim);
+        }
+
+        grant.claimed += amount;
+        VestingStorage::set_grant(&env, &grant);
+        token::Client::new(&env, &grant.token).transfer(&env.current_contract_address(), &beneficiary, &amount);
+        VestingEvents::emit_claimed(&env, grant_id, &beneficiary, amount);
+
+        Ok(amount)
+    }
+
+    /// Stop a revocable grant. What has vested stays claimable by the
+    /// beneficiary; the unvested rest goes back to the funder.
+    pub fn revoke(env: Env, admin: Address, grant_id: u64) -> Result<i128, VestingError> {
+        let _guard = ReentrancyLock::new(&env);
+        Self::require_admin(&env, &admin)?;
+
+        let mut grant = Self::load_grant(&env, grant_id)?;
+        if !grant.revocable {
+            return Err(VestingError::NotRevocable);
+        }
+        if grant.revoked_at.is_some() {
+            return Err(VestingError::AlreadyRevoked);
+        }
+
+        let vested = Self::vested(&env, &grant);
+        let unvested = grant.total - vested;
+        grant.revoked_at = Some(Clock::now(&env));
+        VestingStorage::set_grant(&env, &grant);
+
+        if unvested > 0 {
+            token::Client::new(&env, &grant.token).transfer(&env.current_contract_address(), &grant.funder, &unvested);
+        }
+        VestingEvents::emit_revoked(&env, grant_id, vested, unvested);
+
+        Ok(unvested)
+    }
+
+    /// Get how much a beneficiary could claim from a grant now
+    pub fn get_claimable(env: Env, grant_id: u64) -> i128 {
+        VestingStorage::get_grant(&env, grant_id)
+            .map(|grant| Self::vested(&env, &grant) - grant.claimed)
+            .unwrap_or(0)
+    }
+
+    /// Get how much of a grant has vested so far, claimed or not
+    pub fn get_vested(env: Env, grant_id: u64) -> i128 {
+        VestingStorage::get_grant(&env, grant_id)
+            .map(|grant| Self::vested(&env, &grant))
+            .unwrap_or(0)
+    }
+
+    /// Get a grant by id
+    pub fn get_grant(env: Env, grant_id: u64) -> Option<Grant> {
+        VestingStorage::get_grant(&env, grant_id)
+    }
+
+    /// Get the ids of a beneficiary's grants
+    pub fn get_beneficiary_grants(env: Env, beneficiary: Address) -> Vec<u64> {
+        VestingStorage::get_beneficiary_grants(&env, &beneficiary)
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        VestingStorage::get_admin(&env)
+    }
+}
+
+impl Vesting {
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), VestingError> {
+        caller.require_auth();
+        if !AccessControl::is_admin(env, caller) {
+            return Err(VestingError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    fn load_grant(env: &Env, grant_id: u64) -> Result<Grant, VestingError> {
+        VestingStorage::get_grant(env, grant_id).ok_or(VestingError::GrantNotFound)
+    }
+
+    /// Amount vested as of now, or as of revocation for revoked grants
+    fn vested(env: &Env, grant: &Grant) -> i128 {
+        let at = grant.revoked_at.unwrap_or_else(|| Clock::now(env));
+        let schedule = &grant.schedule;
+        let elapsed = at.saturating_sub(schedule.start);
+        if elapsed < schedule.cliff {
+            0
+        } else if elapsed >= schedule.duration {
+            grant.total
+        } else {
+            grant.total * elapsed as i128 / schedule.duration as i128
+        }
+    }
+}
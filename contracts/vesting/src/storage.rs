@@ -0,0 +1,51 @@
+use soroban_sdk::{Address, Env, Vec};
+use crate::types::{DataKey, Grant};
+
+/// Storage utilities for the vesting contract
+pub struct VestingStorage;
+
+impl VestingStorage {
+    /// Get the contract admin
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Set the contract admin
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    /// Allocate the next grant id
+    pub fn next_grant_id(env: &Env) -> u64 {
+        let id: u64 = env.storage().instance().get(&DataKey::GrantCount).unwrap_or(0) + 1;
+        env.storage().instance().set(&DataKey::GrantCount, &id);
+        id
+    }
+
+    /// Get a grant by id
+    pub fn get_grant(env: &Env, grant_id: u64) -> Option<Grant> {
+        env.storage().persistent().get(&DataKey::Grant(grant_id))
+    }
+
+    /// Store a grant
+    pub fn set_grant(env: &Env, grant: &Grant) {
+        env.storage().persistent().set(&DataKey::Grant(grant.id), grant);
+    }
+
+    /// Get the ids of a beneficiary's grants
+    pub fn get_beneficiary_grants(env: &Env, beneficiary: &Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BeneficiaryGrants(beneficiary.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Index a new grant under its beneficiary
+    pub fn add_beneficiary_grant(env: &Env, beneficiary: &Address, grant_id: u64) {
+        let mut grants = Self::get_beneficiary_grants(env, beneficiary);
+        grants.push_back(grant_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::BeneficiaryGrants(beneficiary.clone()), &grants);
+    }
+}
@@ -0,0 +1,144 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger}, token, Address, Env,
+};
+use shared::time::DAY;
+
+use crate::{Vesting, VestingClient, VestingError, VestingSchedule};
+
+fn setup_test_env() -> (Env, VestingClient<'static>, Address, token::Client<'static>) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Vesting, ());
+    let client = VestingClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    let token = token::Client::new(&env, &asset.address());
+    token::StellarAssetClient::new(&env, &asset.address()).mint(&admin, &10_000);
+
+    (env, client, admin, token)
+}
+
+fn schedule(cliff: u64, duration: u64) -> VestingSchedule {
+    VestingSchedule { start: 0, cliff, duration }
+}
+
+/// 1,000 tokens over 100 days with a 25 day cliff
+fn grant(
+    client: &VestingClient,
+    admin: &Address,
+    token: &token::Client,
+    beneficiary: &Address,
+    revocable: bool,
+) -> u64 {
+    client.create_grant(
+        admin, beneficiary, &token.address, &1_000, &schedule(25 * DAY, 100 * DAY), &revocable,
+    )
+}
+
+#[test]
+fn test_cliff_then_linear_vesting() {
+    let (env, client, admin, token) = setup_test_env();
+    let instructor = Address::generate(&env);
+    let grant_id = grant(&client, &admin, &token, &instructor, false);
+    assert_eq!(token.balance(&client.address), 1_000);
+
+    env.ledger().set_timestamp(24 * DAY);
+    assert_eq!(client.get_claimable(&grant_id), 0);
+    assert_eq!(
+        client.try_claim(&instructor, &grant_id),
+        Err(Ok(VestingError::NothingToClaim))
+    );
+
+    // At the cliff the first quarter vests at once
+    env.ledger().set_timestamp(25 * DAY);
+    assert_eq!(client.claim(&instructor, &grant_id), 250);
+
+    env.ledger().set_timestamp(60 * DAY);
+    assert_eq!(client.get_vested(&grant_id), 600);
+    assert_eq!(client.get_claimable(&grant_id), 350);
+
+    env.ledger().set_timestamp(200 * DAY);
+    assert_eq!(client.claim(&instructor, &grant_id), 750);
+    assert_eq!(token.balance(&instructor), 1_000);
+
+    assert_eq!(
+        client.try_revoke(&admin, &grant_id),
+        Err(Ok(VestingError::NotRevocable))
+    );
+}
+
+#[test]
+fn test_revocation_returns_unvested_tokens() {
+    let (env, client, admin, token) = setup_test_env();
+    let member = Address::generate(&env);
+    let grant_id = grant(&client, &admin, &token, &member, true);
+
+    env.ledger().set_timestamp(40 * DAY);
+    assert_eq!(client.revoke(&admin, &grant_id), 600);
+    assert_eq!(token.balance(&admin), 9_600);
+    assert_eq!(
+        client.try_revoke(&admin, &grant_id),
+        Err(Ok(VestingError::AlreadyRevoked))
+    );
+
+    // Vesting stops at revocation; the vested part stays claimable
+    env.ledger().set_timestamp(90 * DAY);
+    assert_eq!(client.claim(&member, &grant_id), 400);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_multiple_beneficiaries_and_validation() {
+    let (env, client, admin, token) = setup_test_env();
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let a = grant(&client, &admin, &token, &first, false);
+    let b = grant(&client, &admin, &token, &second, true);
+    let c = grant(&client, &admin, &token, &first, true);
+
+    assert_eq!(client.get_beneficiary_grants(&first).len(), 2);
+    assert_eq!(client.get_beneficiary_grants(&second).get(0), Some(b));
+    assert_ne!(a, c);
+
+    env.ledger().set_timestamp(50 * DAY);
+    assert_eq!(
+        client.try_claim(&second, &a),
+        Err(Ok(VestingError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_create_grant(&first, &first, &token.address, &1_000, &schedule(0, DAY), &false),
+        Err(Ok(VestingError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_create_grant(&admin, &first, &token.address, &1_000, &schedule(2 * DAY, DAY), &false),
+        Err(Ok(VestingError::InvalidSchedule))
+    );
+}
+
+#[test]
+fn test_claim_and_revoke_error_paths() {
+    let (env, client, admin, token) = setup_test_env();
+    let member = Address::generate(&env);
+    let grant_id = grant(&client, &admin, &token, &member, true);
+
+    assert_eq!(
+        client.try_create_grant(&admin, &member, &token.address, &0, &schedule(0, DAY), &false),
+        Err(Ok(VestingError::InvalidAmount))
+    );
+    assert_eq!(client.try_claim(&member, &99), Err(Ok(VestingError::GrantNotFound)));
+    assert_eq!(client.try_revoke(&member, &grant_id), Err(Ok(VestingError::Unauthorized)));
+
+    // A second claim in the same instant has nothing left to pay
+    env.ledger().set_timestamp(50 * DAY);
+    assert_eq!(client.claim(&member, &grant_id), 500);
+    assert_eq!(client.try_claim(&member, &grant_id), Err(Ok(VestingError::NothingToClaim)));
+
+    // Once revoked and claimed out, the grant pays nothing more
+    client.revoke(&admin, &grant_id);
+    env.ledger().set_timestamp(200 * DAY);
+    assert_eq!(client.try_claim(&member, &grant_id), Err(Ok(VestingError::NothingToClaim)));
+    assert_eq!(token.balance(&member), 500);
+}
@@ -0,0 +1,35 @@
+use soroban_sdk::{contracttype, Address};
+
+/// When a grant's tokens vest
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct VestingSchedule {
+    pub start: u64,
+    pub cliff: u64,    // seconds after start before anything vests
+    pub duration: u64, // seconds after start until fully vested
+}
+
+/// Tokens released to a beneficiary over time
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Grant {
+    pub id: u64,
+    pub beneficiary: Address,
+    pub funder: Address, // receives unvested tokens on revocation
+    pub token: Address,
+    pub total: i128,
+    pub claimed: i128,
+    pub schedule: VestingSchedule,
+    pub revocable: bool,
+    pub revoked_at: Option<u64>,
+}
+
+/// Storage keys for the vesting contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    GrantCount,
+    Grant(u64),
+    BeneficiaryGrants(Address),
+}